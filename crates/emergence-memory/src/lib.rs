@@ -1,11 +1,494 @@
 //! **emergence-memory** – Multi-layered memory substrate for EMERGENCE living agents.
 
+use std::collections::{HashMap, HashSet};
+use std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::time::Duration;
+
+use anyhow::Result;
+use emergence_nervous_system::SignalPayload;
+use emergence_physics::EntityId;
+use serde::{Deserialize, Serialize};
+
+/// Memory substrate holding each entity's stored memories
+#[derive(Debug, Default)]
 pub struct MemorySubstrate {
-    // Placeholder implementation
+    /// Memories per entity; entities never see each other's entries
+    memories: RwLock<HashMap<EntityId, EntityMemory>>,
+    /// Values entities have shared with others
+    shared: RwLock<SharedMemory>,
+}
+
+/// Shared values keyed by owner and key, so owners cannot collide
+#[derive(Debug, Default)]
+struct SharedMemory {
+    entries: HashMap<(EntityId, String), SharedEntry>,
+}
+
+/// A shared value and the entities besides its owner that may read it
+#[derive(Debug, Clone)]
+struct SharedEntry {
+    value: SignalPayload,
+    readers: HashSet<EntityId>,
+}
+
+/// Memories belonging to a single entity
+#[derive(Debug, Default)]
+struct EntityMemory {
+    /// Maximum bytes this entity may hold, if limited
+    capacity_bytes: Option<usize>,
+    /// Bytes currently held
+    bytes_used: usize,
+    /// Stored values by key
+    entries: HashMap<String, StoredMemory>,
+    /// Association behaviour for this entity
+    association_settings: AssociationSettings,
+    /// Undirected association weights, keyed by the ordered pair of memory keys
+    associations: HashMap<(String, String), f64>,
+}
+
+/// How an entity's associative memory links and forgets keys
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssociationSettings {
+    /// Maximum number of associations kept; the weakest are evicted beyond this
+    pub max_connections: usize,
+    /// Weight below which associations are not recalled
+    pub association_threshold: f64,
+    /// Weight lost per second by every association
+    pub decay_rate: f64,
+}
+
+impl Default for AssociationSettings {
+    fn default() -> Self {
+        Self {
+            max_connections: 1000,
+            association_threshold: 0.0,
+            decay_rate: 0.0,
+        }
+    }
+}
+
+/// Ordered pair used as the key of an undirected association
+fn association_key(a: &str, b: &str) -> (String, String) {
+    if a <= b {
+        (a.to_string(), b.to_string())
+    } else {
+        (b.to_string(), a.to_string())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct StoredMemory {
+    value: SignalPayload,
+    size: usize,
+}
+
+/// Memory substrate errors
+#[derive(Debug, thiserror::Error)]
+pub enum MemoryError {
+    #[error("Memory capacity exceeded for {entity}: {requested} bytes requested, {available} available")]
+    CapacityExceeded {
+        entity: EntityId,
+        requested: usize,
+        available: usize,
+    },
+
+    #[error("Invalid association weight {weight}: must be positive and finite")]
+    InvalidWeight { weight: f64 },
+
+    #[error("{owner} has shared nothing under '{key}'")]
+    NotShared { owner: EntityId, key: String },
+
+    #[error("{reader} may not read '{key}' shared by {owner}")]
+    AccessDenied {
+        reader: EntityId,
+        owner: EntityId,
+        key: String,
+    },
+}
+
+/// Usage statistics for the memory substrate
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MemoryStatistics {
+    /// Number of stored memories
+    pub stored_memories: usize,
+    /// Approximate bytes held by stored memories
+    pub bytes_used: usize,
+    /// Number of associations between memory keys
+    pub associations: usize,
+    /// Number of values shared between entities
+    pub shared_memories: usize,
 }
 
 impl MemorySubstrate {
     pub fn new() -> Self {
-        Self {}
+        Self::default()
+    }
+
+    // Every update finishes inside one critical section without calling out,
+    // so a panic elsewhere cannot leave the map half-written and a poisoned
+    // lock is safe to keep using
+    fn memories(&self) -> RwLockReadGuard<'_, HashMap<EntityId, EntityMemory>> {
+        self.memories.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn memories_mut(&self) -> RwLockWriteGuard<'_, HashMap<EntityId, EntityMemory>> {
+        self.memories.write().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn shared(&self) -> RwLockReadGuard<'_, SharedMemory> {
+        self.shared.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn shared_mut(&self) -> RwLockWriteGuard<'_, SharedMemory> {
+        self.shared.write().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Limit the total size of an entity's memories
+    pub fn configure_entity(&self, entity: EntityId, capacity_bytes: usize) {
+        self.memories_mut()
+            .entry(entity)
+            .or_default()
+            .capacity_bytes = Some(capacity_bytes);
+    }
+
+    /// Set how an entity's associative memory behaves
+    pub fn configure_associations(&self, entity: EntityId, settings: AssociationSettings) {
+        let mut memories = self.memories_mut();
+        let memory = memories.entry(entity).or_default();
+        memory.association_settings = settings;
+        Self::evict_weakest(memory);
+    }
+
+    /// Link two keys in an entity's memory, replacing any previous weight
+    ///
+    /// When the entity holds more than `max_connections` associations the
+    /// weakest are evicted, which may include the one just added.
+    pub fn associate(&self, entity: EntityId, key_a: &str, key_b: &str, weight: f64) -> Result<()> {
+        if !weight.is_finite() || weight <= 0.0 {
+            return Err(MemoryError::InvalidWeight { weight }.into());
+        }
+
+        let mut memories = self.memories_mut();
+        let memory = memories.entry(entity).or_default();
+        memory.associations.insert(association_key(key_a, key_b), weight);
+        Self::evict_weakest(memory);
+        Ok(())
+    }
+
+    /// Keys associated with `key` at or above both `min_weight` and the
+    /// entity's association threshold, strongest first
+    pub fn recall_associated(&self, entity: EntityId, key: &str, min_weight: f64) -> Vec<(String, f64)> {
+        let memories = self.memories();
+        let Some(memory) = memories.get(&entity) else {
+            return Vec::new();
+        };
+        let threshold = min_weight.max(memory.association_settings.association_threshold);
+
+        let mut associated: Vec<(String, f64)> = memory.associations
+            .iter()
+            .filter(|(_, weight)| **weight >= threshold)
+            .filter_map(|((a, b), weight)| {
+                if a == key {
+                    Some((b.clone(), *weight))
+                } else if b == key {
+                    Some((a.clone(), *weight))
+                } else {
+                    None
+                }
+            })
+            .collect();
+        associated.sort_by(|x, y| y.1.total_cmp(&x.1).then_with(|| x.0.cmp(&y.0)));
+        associated
+    }
+
+    /// Weaken every association by its entity's decay rate over `delta_time`,
+    /// dropping those that reach zero
+    pub fn decay_associations(&self, delta_time: Duration) {
+        let elapsed = delta_time.as_secs_f64();
+        for memory in self.memories_mut().values_mut() {
+            let decay = memory.association_settings.decay_rate * elapsed;
+            memory.associations.retain(|_, weight| {
+                *weight -= decay;
+                *weight > 0.0
+            });
+        }
+    }
+
+    /// Drop the weakest associations until the entity is within its connection limit
+    fn evict_weakest(memory: &mut EntityMemory) {
+        let limit = memory.association_settings.max_connections;
+        while memory.associations.len() > limit {
+            let weakest = memory.associations
+                .iter()
+                .min_by(|x, y| x.1.total_cmp(y.1))
+                .map(|(key, _)| key.clone());
+            match weakest {
+                Some(key) => memory.associations.remove(&key),
+                None => break,
+            };
+        }
+    }
+
+    /// Store a value under a key in an entity's memory, replacing any previous value
+    pub async fn store(&self, entity: EntityId, key: String, value: SignalPayload) -> Result<()> {
+        let size = key.len() + serde_yaml::to_string(&value)?.len();
+        let mut memories = self.memories_mut();
+        let memory = memories.entry(entity).or_default();
+
+        let replaced = memory.entries.get(&key).map_or(0, |m| m.size);
+        if let Some(capacity) = memory.capacity_bytes {
+            let available = capacity.saturating_sub(memory.bytes_used - replaced);
+            if size > available {
+                return Err(MemoryError::CapacityExceeded { entity, requested: size, available }.into());
+            }
+        }
+
+        memory.bytes_used = memory.bytes_used - replaced + size;
+        memory.entries.insert(key, StoredMemory { value, size });
+        Ok(())
+    }
+
+    /// Recall the value an entity stored under a key
+    pub async fn recall(&self, entity: EntityId, key: &str) -> Option<SignalPayload> {
+        self.memories()
+            .get(&entity)?
+            .entries
+            .get(key)
+            .map(|m| m.value.clone())
+    }
+
+    /// Share a value under a key in the owner's namespace, readable by the owner and `readers`
+    ///
+    /// Only the owner writes to its namespace; sharing a key again replaces
+    /// both the value and who may read it.
+    pub fn share(&self, owner: EntityId, key: String, value: SignalPayload, readers: HashSet<EntityId>) -> Result<()> {
+        self.shared_mut()
+            .entries
+            .insert((owner, key), SharedEntry { value, readers });
+        Ok(())
     }
-}
\ No newline at end of file
+
+    /// Read a value `owner` shared, if `reader` is allowed to
+    pub fn read_shared(&self, reader: EntityId, owner: EntityId, key: &str) -> Result<SignalPayload> {
+        let shared = self.shared();
+        let entry = shared.entries
+            .get(&(owner, key.to_string()))
+            .ok_or_else(|| MemoryError::NotShared { owner, key: key.to_string() })?;
+        if reader != owner && !entry.readers.contains(&reader) {
+            return Err(MemoryError::AccessDenied { reader, owner, key: key.to_string() }.into());
+        }
+        Ok(entry.value.clone())
+    }
+
+    /// Let `reader` read a value the owner shared, returning whether it was shared
+    pub fn grant_access(&self, owner: EntityId, key: &str, reader: EntityId) -> bool {
+        self.shared_mut()
+            .entries
+            .get_mut(&(owner, key.to_string()))
+            .map(|entry| entry.readers.insert(reader))
+            .is_some()
+    }
+
+    /// Stop `reader` reading a value the owner shared, returning whether it could before
+    pub fn revoke_access(&self, owner: EntityId, key: &str, reader: EntityId) -> bool {
+        self.shared_mut()
+            .entries
+            .get_mut(&(owner, key.to_string()))
+            .is_some_and(|entry| entry.readers.remove(&reader))
+    }
+
+    /// Remove everything an entity stored, returning how many memories were dropped
+    ///
+    /// Values the entity shared are withdrawn as well.
+    pub fn forget_entity(&self, entity: EntityId) -> usize {
+        self.shared_mut().entries.retain(|(owner, _), _| *owner != entity);
+        self.memories_mut()
+            .remove(&entity)
+            .map_or(0, |memory| memory.entries.len())
+    }
+
+    /// Get current memory usage statistics
+    pub fn get_statistics(&self) -> MemoryStatistics {
+        let memories = self.memories();
+        MemoryStatistics {
+            stored_memories: memories.values().map(|m| m.entries.len()).sum(),
+            bytes_used: memories.values().map(|m| m.bytes_used).sum(),
+            associations: memories.values().map(|m| m.associations.len()).sum(),
+            shared_memories: self.shared().entries.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(payload: Option<SignalPayload>) -> Option<String> {
+        match payload {
+            Some(SignalPayload::Message(text)) => Some(text),
+            _ => None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_store_and_recall() {
+        let memory = MemorySubstrate::new();
+        let entity = EntityId::new();
+
+        memory.store(entity, "anomaly".to_string(), SignalPayload::Message("spike at 03:00".to_string())).await.unwrap();
+        memory.store(entity, "anomaly".to_string(), SignalPayload::Message("spike at 04:00".to_string())).await.unwrap();
+
+        assert_eq!(message(memory.recall(entity, "anomaly").await), Some("spike at 04:00".to_string()));
+        assert!(memory.recall(entity, "missing").await.is_none());
+
+        let stats = memory.get_statistics();
+        assert_eq!(stats.stored_memories, 1);
+        assert!(stats.bytes_used > 0);
+    }
+
+    #[tokio::test]
+    async fn test_memories_are_isolated_per_entity() {
+        let memory = MemorySubstrate::new();
+        let alice = EntityId::new();
+        let bob = EntityId::new();
+
+        memory.store(alice, "secret".to_string(), SignalPayload::Message("alice".to_string())).await.unwrap();
+        memory.store(bob, "secret".to_string(), SignalPayload::Message("bob".to_string())).await.unwrap();
+
+        assert_eq!(message(memory.recall(alice, "secret").await), Some("alice".to_string()));
+        assert_eq!(message(memory.recall(bob, "secret").await), Some("bob".to_string()));
+        assert!(memory.recall(EntityId::new(), "secret").await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_capacity_is_enforced() {
+        let memory = MemorySubstrate::new();
+        let entity = EntityId::new();
+        memory.configure_entity(entity, 64);
+
+        memory.store(entity, "small".to_string(), SignalPayload::Message("ok".to_string())).await.unwrap();
+        let result = memory.store(entity, "large".to_string(), SignalPayload::Message("x".repeat(100))).await;
+
+        let err = result.unwrap_err();
+        assert!(matches!(err.downcast_ref(), Some(MemoryError::CapacityExceeded { .. })));
+        assert!(memory.recall(entity, "large").await.is_none());
+        assert_eq!(memory.get_statistics().stored_memories, 1);
+    }
+
+    #[tokio::test]
+    async fn test_poisoned_lock_keeps_working() {
+        let memory = MemorySubstrate::new();
+        let entity = EntityId::new();
+        memory.store(entity, "before".to_string(), SignalPayload::Message("kept".to_string())).await.unwrap();
+
+        let _ = std::thread::scope(|scope| {
+            scope.spawn(|| {
+                let _guard = memory.memories.write().unwrap();
+                panic!("writer died holding the lock");
+            }).join()
+        });
+        assert!(memory.memories.is_poisoned());
+
+        assert!(memory.recall(entity, "before").await.is_some());
+        memory.store(entity, "after".to_string(), SignalPayload::Message("ok".to_string())).await.unwrap();
+        assert_eq!(memory.get_statistics().stored_memories, 2);
+    }
+
+    #[test]
+    fn test_shared_memory_enforces_readers() {
+        let memory = MemorySubstrate::new();
+        let (debugger, researcher, tester) = (EntityId::new(), EntityId::new(), EntityId::new());
+        let insight = SignalPayload::Message("leak in the pool".to_string());
+
+        memory.share(debugger, "diagnostic_insights".to_string(), insight, HashSet::from([researcher])).unwrap();
+
+        let read = |reader| memory.read_shared(reader, debugger, "diagnostic_insights");
+        assert_eq!(message(read(researcher).ok()), Some("leak in the pool".to_string()));
+        assert_eq!(message(read(debugger).ok()), Some("leak in the pool".to_string()));
+        let denied = read(tester).unwrap_err();
+        assert!(matches!(denied.downcast_ref(), Some(MemoryError::AccessDenied { reader, .. }) if *reader == tester));
+        // Keys are namespaced by owner
+        let missing = memory.read_shared(researcher, tester, "diagnostic_insights").unwrap_err();
+        assert!(matches!(missing.downcast_ref(), Some(MemoryError::NotShared { .. })));
+        assert_eq!(memory.get_statistics().shared_memories, 1);
+
+        assert!(memory.revoke_access(debugger, "diagnostic_insights", researcher));
+        assert!(matches!(read(researcher).unwrap_err().downcast_ref(), Some(MemoryError::AccessDenied { .. })));
+        assert!(memory.grant_access(debugger, "diagnostic_insights", tester));
+        assert!(read(tester).is_ok());
+
+        memory.forget_entity(debugger);
+        assert!(read(tester).is_err());
+    }
+
+    #[test]
+    fn test_associations_are_recalled_by_weight() {
+        let memory = MemorySubstrate::new();
+        let entity = EntityId::new();
+
+        memory.associate(entity, "smoke", "fire", 0.9).unwrap();
+        memory.associate(entity, "alarm", "smoke", 0.6).unwrap();
+        memory.associate(entity, "fire", "heat", 0.8).unwrap();
+
+        let associated = memory.recall_associated(entity, "smoke", 0.0);
+        assert_eq!(associated, vec![("fire".to_string(), 0.9), ("alarm".to_string(), 0.6)]);
+        assert!(memory.recall_associated(EntityId::new(), "smoke", 0.0).is_empty());
+        assert!(memory.associate(entity, "smoke", "ash", 0.0).is_err());
+    }
+
+    #[test]
+    fn test_association_threshold_filters_weak_links() {
+        let memory = MemorySubstrate::new();
+        let entity = EntityId::new();
+        memory.configure_associations(entity, AssociationSettings {
+            association_threshold: 0.5,
+            ..AssociationSettings::default()
+        });
+
+        memory.associate(entity, "smoke", "fire", 0.9).unwrap();
+        memory.associate(entity, "smoke", "fog", 0.3).unwrap();
+        memory.associate(entity, "smoke", "alarm", 0.6).unwrap();
+
+        let keys = |recalled: Vec<(String, f64)>| recalled.into_iter().map(|(k, _)| k).collect::<Vec<_>>();
+        assert_eq!(keys(memory.recall_associated(entity, "smoke", 0.0)), vec!["fire", "alarm"]);
+        assert_eq!(keys(memory.recall_associated(entity, "smoke", 0.7)), vec!["fire"]);
+    }
+
+    #[test]
+    fn test_max_connections_evicts_weakest() {
+        let memory = MemorySubstrate::new();
+        let entity = EntityId::new();
+        memory.configure_associations(entity, AssociationSettings {
+            max_connections: 2,
+            ..AssociationSettings::default()
+        });
+
+        memory.associate(entity, "a", "b", 0.5).unwrap();
+        memory.associate(entity, "a", "c", 0.2).unwrap();
+        memory.associate(entity, "a", "d", 0.8).unwrap();
+
+        let associated = memory.recall_associated(entity, "a", 0.0);
+        assert_eq!(associated, vec![("d".to_string(), 0.8), ("b".to_string(), 0.5)]);
+        assert_eq!(memory.get_statistics().associations, 2);
+    }
+
+    #[test]
+    fn test_decay_prunes_faded_associations() {
+        let memory = MemorySubstrate::new();
+        let entity = EntityId::new();
+        memory.configure_associations(entity, AssociationSettings {
+            decay_rate: 0.1,
+            ..AssociationSettings::default()
+        });
+
+        memory.associate(entity, "smoke", "fire", 0.9).unwrap();
+        memory.associate(entity, "smoke", "fog", 0.15).unwrap();
+
+        memory.decay_associations(Duration::from_secs(2));
+
+        let associated = memory.recall_associated(entity, "smoke", 0.0);
+        assert_eq!(associated.len(), 1);
+        assert_eq!(associated[0].0, "fire");
+        assert!((associated[0].1 - 0.7).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,246 @@
+//! Confidence Calibration - Comparable Confidence Across Models
+//!
+//! Backends report confidence on their own scales. `CalibratedModel` maps each
+//! raw confidence through a per-model logistic fit (Platt scaling) that is
+//! updated online from observed outcomes, so calibrated confidences from
+//! different models can be compared against one threshold.
+
+use super::*;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+/// Raw confidences remembered for later outcome reports
+pub const MAX_PENDING_OUTCOMES: usize = 1000;
+
+/// Smallest slope the fit may reach, keeping the mapping strictly increasing
+const MIN_SLOPE: f64 = 0.01;
+
+/// Keeps raw confidences away from 0 and 1 so their log-odds stay finite
+const CONFIDENCE_EPSILON: f64 = 1e-6;
+
+/// Logistic mapping from raw to calibrated confidence
+///
+/// `calibrated = sigmoid(slope * logit(raw) + intercept)`, starting as the
+/// identity and fitted by gradient steps on each recorded outcome.
+#[derive(Debug, Clone)]
+pub struct LogisticCalibration {
+    pub slope: f64,
+    pub intercept: f64,
+    pub learning_rate: f64,
+}
+
+impl Default for LogisticCalibration {
+    fn default() -> Self {
+        Self {
+            slope: 1.0,
+            intercept: 0.0,
+            learning_rate: 0.1,
+        }
+    }
+}
+
+impl LogisticCalibration {
+    /// Map a raw confidence onto the calibrated scale
+    pub fn apply(&self, raw: f64) -> f64 {
+        sigmoid(self.slope * logit(raw) + self.intercept)
+    }
+
+    /// Move the fit towards an observed outcome for a raw confidence
+    pub fn update(&mut self, raw: f64, was_correct: bool) {
+        let x = logit(raw);
+        let target = if was_correct { 1.0 } else { 0.0 };
+        let error = target - self.apply(raw);
+        self.slope = (self.slope + self.learning_rate * error * x).max(MIN_SLOPE);
+        self.intercept += self.learning_rate * error;
+    }
+}
+
+fn logit(p: f64) -> f64 {
+    let p = p.clamp(CONFIDENCE_EPSILON, 1.0 - CONFIDENCE_EPSILON);
+    (p / (1.0 - p)).ln()
+}
+
+fn sigmoid(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+/// Model wrapper that reports calibrated confidence
+///
+/// Clones share the same calibration and outcome history.
+pub struct CalibratedModel {
+    model: Box<dyn ComposableModel>,
+    calibration: Arc<Mutex<LogisticCalibration>>,
+    /// Raw confidences of recent outputs, oldest first
+    pending: Arc<Mutex<VecDeque<(String, f64)>>>,
+}
+
+impl CalibratedModel {
+    /// Wrap a model with an identity calibration
+    pub fn new(model: Box<dyn ComposableModel>) -> Self {
+        Self::with_calibration(model, LogisticCalibration::default())
+    }
+
+    /// Wrap a model with an existing calibration
+    pub fn with_calibration(model: Box<dyn ComposableModel>, calibration: LogisticCalibration) -> Self {
+        Self {
+            model,
+            calibration: Arc::new(Mutex::new(calibration)),
+            pending: Arc::new(Mutex::new(VecDeque::new())),
+        }
+    }
+
+    /// Current calibration
+    pub fn calibration(&self) -> LogisticCalibration {
+        self.calibration.lock().unwrap().clone()
+    }
+
+    /// Calibrate a raw confidence from the wrapped model
+    pub fn calibrate(&self, raw: f64) -> f64 {
+        self.calibration.lock().unwrap().apply(raw)
+    }
+
+    /// Report whether the most recent output for `input` turned out correct
+    pub fn record_outcome(&self, input: &str, was_correct: bool) -> Result<(), ModelError> {
+        let raw = {
+            let mut pending = self.pending.lock().unwrap();
+            let index = pending.iter().rposition(|(seen, _)| seen == input)
+                .ok_or_else(|| ModelError::InvalidInput(format!(
+                    "No recent output from {} for input '{}'",
+                    self.model.name(),
+                    input
+                )))?;
+            pending.remove(index).map(|(_, raw)| raw)
+        };
+
+        if let Some(raw) = raw {
+            self.calibration.lock().unwrap().update(raw, was_correct);
+        }
+        Ok(())
+    }
+}
+
+impl Clone for CalibratedModel {
+    fn clone(&self) -> Self {
+        Self {
+            model: self.model.clone_box(),
+            calibration: self.calibration.clone(),
+            pending: self.pending.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl ComposableModel for CalibratedModel {
+    async fn process(&self, input: &str, context: &ModelContext) -> Result<ModelOutput, ModelError> {
+        let mut output = self.model.process(input, context).await?;
+
+        {
+            let mut pending = self.pending.lock().unwrap();
+            if pending.len() >= MAX_PENDING_OUTCOMES {
+                pending.pop_front();
+            }
+            pending.push_back((input.to_string(), output.confidence));
+        }
+
+        output.confidence = self.calibrate(output.confidence);
+        Ok(output)
+    }
+
+    fn energy_cost(&self) -> f64 {
+        self.model.energy_cost()
+    }
+
+    fn estimate_energy_cost(&self, input: &str) -> f64 {
+        self.model.estimate_energy_cost(input)
+    }
+
+    fn token_heuristic(&self) -> TokenHeuristic {
+        self.model.token_heuristic()
+    }
+
+    fn memory_requirement(&self) -> usize {
+        self.model.memory_requirement()
+    }
+
+    fn capabilities(&self) -> Vec<Capability> {
+        self.model.capabilities()
+    }
+
+    fn name(&self) -> &str {
+        self.model.name()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.model.is_ready()
+    }
+
+    fn is_cloud(&self) -> bool {
+        self.model.is_cloud()
+    }
+
+    fn clone_box(&self) -> Box<dyn ComposableModel> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Always claims 0.9 confidence
+    #[derive(Clone)]
+    struct OverconfidentModel;
+
+    #[async_trait]
+    impl ComposableModel for OverconfidentModel {
+        async fn process(&self, input: &str, _context: &ModelContext) -> Result<ModelOutput, ModelError> {
+            Ok(ModelOutput {
+                content: input.to_string(),
+                confidence: 0.9,
+                energy_cost: 0.01,
+                capabilities_used: vec![Capability::IntentRecognition],
+                cache_hit: false,
+            })
+        }
+        fn energy_cost(&self) -> f64 { 0.01 }
+        fn memory_requirement(&self) -> usize { 1024 }
+        fn capabilities(&self) -> Vec<Capability> { vec![Capability::IntentRecognition] }
+        fn name(&self) -> &str { "overconfident" }
+        fn is_ready(&self) -> bool { true }
+        fn clone_box(&self) -> Box<dyn ComposableModel> { Box::new(self.clone()) }
+    }
+
+    #[tokio::test]
+    async fn test_wrong_outcomes_pull_confidence_down() {
+        let model = CalibratedModel::new(Box::new(OverconfidentModel));
+        let context = ModelContext::default();
+
+        let before = model.process("question 0", &context).await.unwrap();
+        assert!((before.confidence - 0.9).abs() < 1e-9);
+        model.record_outcome("question 0", false).unwrap();
+
+        for i in 1..10 {
+            let input = format!("question {}", i);
+            model.process(&input, &context).await.unwrap();
+            model.record_outcome(&input, false).unwrap();
+        }
+
+        let after = model.process("question 10", &context).await.unwrap();
+        assert!(after.confidence < 0.6, "calibrated confidence {}", after.confidence);
+        assert!(model.record_outcome("never asked", true).is_err());
+    }
+
+    #[test]
+    fn test_calibration_stays_monotonic() {
+        let mut calibration = LogisticCalibration::default();
+        for i in 0..200 {
+            let raw = (i % 10) as f64 / 10.0 + 0.05;
+            // Outcomes that reward low confidence push the slope down hard
+            calibration.update(raw, raw < 0.5);
+        }
+
+        let mapped: Vec<f64> = (1..100).map(|i| calibration.apply(i as f64 / 100.0)).collect();
+        assert!(calibration.slope >= MIN_SLOPE);
+        assert!(mapped.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
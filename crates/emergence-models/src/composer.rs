@@ -66,13 +66,14 @@ impl ModelComposer {
             // Find the best model that fits within constraints
             let best_model = self.select_best_model(
                 &models_for_capability,
+                &task.input,
                 task.energy_budget - used_energy,
                 task.memory_limit - used_memory,
                 capability,
             )?;
             
             // Check if we can add this model
-            let model_energy = best_model.energy_cost();
+            let model_energy = best_model.estimate_energy_cost(&task.input);
             let model_memory = best_model.memory_requirement();
             
             if used_energy + model_energy > task.energy_budget {
@@ -95,7 +96,7 @@ impl ModelComposer {
         }
         
         // Optimize pipeline order
-        self.optimize_pipeline_order(&mut selected_models);
+        self.optimize_pipeline_order(&mut selected_models, &task.input);
         
         Ok(ModelPipeline::new(selected_models))
     }
@@ -113,6 +114,7 @@ impl ModelComposer {
     fn select_best_model(
         &self,
         models: &[&dyn ComposableModel],
+        input: &str,
         available_energy: f64,
         available_memory: usize,
         capability: &Capability,
@@ -122,7 +124,7 @@ impl ModelComposer {
         
         for model in models {
             // Check constraints
-            if model.energy_cost() > available_energy {
+            if model.estimate_energy_cost(input) > available_energy {
                 continue;
             }
             
@@ -170,28 +172,18 @@ impl ModelComposer {
     }
     
     /// Optimize the order of models in a pipeline
-    fn optimize_pipeline_order(&self, models: &mut Vec<Box<dyn ComposableModel>>) {
+    fn optimize_pipeline_order(&self, models: &mut Vec<Box<dyn ComposableModel>>, input: &str) {
         // Simple optimization: put faster models first
         models.sort_by(|a, b| {
-            let a_speed = 1.0 / a.energy_cost(); // Higher energy cost = slower
-            let b_speed = 1.0 / b.energy_cost();
+            let a_speed = 1.0 / a.estimate_energy_cost(input); // Higher energy cost = slower
+            let b_speed = 1.0 / b.estimate_energy_cost(input);
             b_speed.partial_cmp(&a_speed).unwrap_or(std::cmp::Ordering::Equal)
         });
     }
     
     /// Get model type from model name
     fn get_model_type_from_name(&self, name: &str) -> ModelType {
-        match name {
-            "distilbert-tiny" => ModelType::DistilBertTiny,
-            "gpt2-small" => ModelType::Gpt2Small,
-            "tinyllama" => ModelType::TinyLlama,
-            "phi3-mini" => ModelType::Phi3Mini,
-            "gemma2b" => ModelType::Gemma2B,
-            "qwen2.5-0.5b" => ModelType::Qwen25_0_5B,
-            "sentence-transformer" => ModelType::SentenceTransformer,
-            "t5-small" => ModelType::T5Small,
-            _ => ModelType::Gpt2Small, // Default
-        }
+        ModelType::from_name(name).unwrap_or(ModelType::Gpt2Small)
     }
     
     /// Default composition rules
@@ -308,10 +300,198 @@ impl ModelComposer {
     }
 }
 
+/// Plan a pipeline that covers every capability a task requires
+///
+/// Capabilities are satisfied in the order the task lists them, each by a model
+/// already in the pipeline or else by the model with the cheapest estimated
+/// cost for the task's input that still fits its energy and memory budget. Local models are preferred, and
+/// cloud models are only considered for `TaskComplexity::Complex` tasks.
+pub fn plan_pipeline(task: &Task, available: &[Box<dyn ComposableModel>]) -> Result<ModelPipeline, ModelError> {
+    let cost = |model: &dyn ComposableModel| model.estimate_energy_cost(&task.input);
+    let allow_cloud = matches!(task.complexity, TaskComplexity::Complex);
+    let eligible: Vec<&dyn ComposableModel> = available
+        .iter()
+        .map(|model| model.as_ref())
+        .filter(|model| allow_cloud || !model.is_cloud())
+        .collect();
+    
+    let missing: Vec<String> = task.required_capabilities
+        .iter()
+        .filter(|capability| !eligible.iter().any(|model| model.capabilities().contains(capability)))
+        .map(|capability| format!("{:?}", capability))
+        .collect();
+    if !missing.is_empty() {
+        return Err(ModelError::ModelNotFound(format!(
+            "No available model provides {}",
+            missing.join(", ")
+        )));
+    }
+    
+    let mut selected: Vec<&dyn ComposableModel> = Vec::new();
+    let mut used_energy = 0.0;
+    let mut used_memory = 0;
+    
+    for capability in &task.required_capabilities {
+        if selected.iter().any(|model| model.capabilities().contains(capability)) {
+            continue;
+        }
+        
+        let mut candidates: Vec<&dyn ComposableModel> = eligible
+            .iter()
+            .copied()
+            .filter(|model| model.capabilities().contains(capability))
+            .collect();
+        candidates.sort_by(|a, b| {
+            a.is_cloud()
+                .cmp(&b.is_cloud())
+                .then(cost(*a).partial_cmp(&cost(*b)).unwrap_or(std::cmp::Ordering::Equal))
+        });
+        
+        let fits = |model: &&dyn ComposableModel| {
+            used_energy + cost(*model) <= task.energy_budget
+                && used_memory + model.memory_requirement() <= task.memory_limit
+        };
+        let model = match candidates.iter().copied().find(fits) {
+            Some(model) => model,
+            None => {
+                let cheapest = candidates[0];
+                if used_energy + cost(cheapest) > task.energy_budget {
+                    return Err(ModelError::InsufficientEnergy {
+                        required: used_energy + cost(cheapest),
+                        available: task.energy_budget,
+                    });
+                }
+                let smallest = candidates.iter().map(|model| model.memory_requirement()).min().unwrap_or(0);
+                return Err(ModelError::InsufficientMemory {
+                    required: used_memory + smallest,
+                    available: task.memory_limit,
+                });
+            }
+        };
+        
+        used_energy += cost(model);
+        used_memory += model.memory_requirement();
+        selected.push(model);
+    }
+    
+    Ok(ModelPipeline::new(selected.into_iter().map(|model| model.clone_box()).collect()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     
+    #[derive(Clone)]
+    struct StubModel {
+        name: &'static str,
+        capabilities: Vec<Capability>,
+        energy_cost: f64,
+        memory: usize,
+        cloud: bool,
+    }
+    
+    #[async_trait]
+    impl ComposableModel for StubModel {
+        async fn process(&self, input: &str, _context: &ModelContext) -> Result<ModelOutput, ModelError> {
+            Ok(ModelOutput {
+                content: input.to_string(),
+                confidence: 1.0,
+                energy_cost: self.energy_cost,
+                capabilities_used: self.capabilities.clone(),
+                cache_hit: false,
+            })
+        }
+        fn energy_cost(&self) -> f64 { self.energy_cost }
+        fn memory_requirement(&self) -> usize { self.memory }
+        fn capabilities(&self) -> Vec<Capability> { self.capabilities.clone() }
+        fn name(&self) -> &str { self.name }
+        fn is_ready(&self) -> bool { true }
+        fn is_cloud(&self) -> bool { self.cloud }
+        fn clone_box(&self) -> Box<dyn ComposableModel> { Box::new(self.clone()) }
+    }
+    
+    fn stub(name: &'static str, capabilities: Vec<Capability>, energy_cost: f64, cloud: bool) -> Box<dyn ComposableModel> {
+        Box::new(StubModel { name, capabilities, energy_cost, memory: 100, cloud })
+    }
+    
+    fn available() -> Vec<Box<dyn ComposableModel>> {
+        vec![
+            stub("intent", vec![Capability::IntentRecognition], 0.01, false),
+            stub("small-chat", vec![Capability::ResponseGeneration], 0.05, false),
+            stub("large-chat", vec![Capability::ResponseGeneration, Capability::Reasoning], 0.2, false),
+            stub("cloud-reasoner", vec![Capability::Reasoning, Capability::CodeAnalysis], 0.1, true),
+        ]
+    }
+    
+    fn task(capabilities: Vec<Capability>, complexity: TaskComplexity, energy_budget: f64) -> Task {
+        Task {
+            input: "analyze this".to_string(),
+            required_capabilities: capabilities,
+            complexity,
+            energy_budget,
+            memory_limit: 1000,
+        }
+    }
+    
+    #[test]
+    fn test_plan_pipeline_picks_cheapest_local_models_in_order() {
+        let task = task(
+            vec![Capability::IntentRecognition, Capability::ResponseGeneration, Capability::Reasoning],
+            TaskComplexity::Moderate,
+            1.0,
+        );
+        
+        let pipeline = plan_pipeline(&task, &available()).unwrap();
+        
+        // large-chat covers reasoning; the cloud reasoner is not considered for moderate tasks
+        assert_eq!(pipeline.model_names(), vec!["intent", "small-chat", "large-chat"]);
+        assert!((pipeline.total_energy_cost() - 0.26).abs() < 1e-9);
+        // "analyze this" is three tokens
+        assert!((pipeline.estimate_energy_cost(&task.input) - 0.78).abs() < 1e-9);
+        
+        let complex = Task { complexity: TaskComplexity::Complex, ..task };
+        let pipeline = plan_pipeline(&complex, &available()).unwrap();
+        assert_eq!(pipeline.model_names(), vec!["intent", "small-chat", "large-chat"]);
+        
+        let code = Task { required_capabilities: vec![Capability::CodeAnalysis], ..complex };
+        assert_eq!(plan_pipeline(&code, &available()).unwrap().model_names(), vec!["cloud-reasoner"]);
+    }
+    
+    #[test]
+    fn test_plan_pipeline_rejects_over_budget_task() {
+        let task = task(
+            vec![Capability::IntentRecognition, Capability::Reasoning],
+            TaskComplexity::Simple,
+            0.1,
+        );
+        
+        match plan_pipeline(&task, &available()) {
+            Err(ModelError::InsufficientEnergy { required, available }) => {
+                assert!((required - 0.63).abs() < 1e-9);
+                assert_eq!(available, 0.1);
+            }
+            other => panic!("expected InsufficientEnergy, got {:?}", other.map(|p| p.total_energy_cost())),
+        }
+    }
+    
+    #[test]
+    fn test_plan_pipeline_names_missing_capability() {
+        let task = task(
+            vec![Capability::IntentRecognition, Capability::CodeAnalysis, Capability::MemoryEmbedding],
+            TaskComplexity::Simple,
+            1.0,
+        );
+        
+        match plan_pipeline(&task, &available()) {
+            Err(ModelError::ModelNotFound(message)) => {
+                assert!(message.contains("CodeAnalysis"));
+                assert!(message.contains("MemoryEmbedding"));
+                assert!(!message.contains("IntentRecognition"));
+            }
+            other => panic!("expected ModelNotFound, got {:?}", other.map(|p| p.total_energy_cost())),
+        }
+    }
+    
     #[test]
     fn test_composer_creation() {
         let composer = ModelComposer::new(1.0, 1024 * 1024 * 1024);
@@ -9,9 +9,62 @@ use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+/// Labels [`IntentModel::classify`] can return, besides [`UNKNOWN_INTENT`]
+pub const INTENT_LABELS: [&str; 5] = ["analyze", "collaborate", "query", "observe", "report"];
+
+/// Label for input that matches none of [`INTENT_LABELS`]
+pub const UNKNOWN_INTENT: &str = "unknown";
+
+/// Keywords signalling each label; the first keyword is the label itself
+const LABEL_KEYWORDS: [(&str, &[&str]); 5] = [
+    ("analyze", &["analyze", "analyse", "analysis", "investigate", "examine"]),
+    ("collaborate", &["collaborate", "together", "coordinate", "join"]),
+    ("query", &["query", "recall", "remember", "lookup"]),
+    ("observe", &["observe", "watch", "monitor", "pattern"]),
+    ("report", &["report", "summarize", "summary", "status"]),
+];
+
+/// Endings a keyword may carry and still match, as in "analyzed" or "patterns"
+const KEYWORD_ENDINGS: [&str; 5] = ["s", "es", "d", "ed", "ing"];
+
+/// Words opening a question, which is read as a query when no keyword matches
+const QUESTION_WORDS: [&str; 6] = ["what", "who", "where", "when", "why", "how"];
+
+/// Words dropped from the front of an extracted subject
+const SUBJECT_FILLERS: [&str; 16] = [
+    "the", "a", "an", "on", "about", "of", "for", "to", "is", "are", "was", "were", "do", "does", "did", "you",
+];
+
+/// Confidence when the label itself appears in the input
+const LABEL_CONFIDENCE: f64 = 0.9;
+/// Confidence when only a related keyword appears
+const KEYWORD_CONFIDENCE: f64 = 0.75;
+/// Confidence when a bare question is taken as a query
+const QUESTION_CONFIDENCE: f64 = 0.6;
+/// Confidence reported for unknown intents
+const UNKNOWN_CONFIDENCE: f64 = 0.3;
+
+/// Routing intent with the slots extracted from the input
+///
+/// Slots are `subject`, the words following the intent keyword, and
+/// `partner`, the word following "with".
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoutingIntent {
+    /// One of [`INTENT_LABELS`] or [`UNKNOWN_INTENT`]
+    pub label: String,
+    /// Confidence score (0.0 to 1.0)
+    pub confidence: f64,
+    /// Named values extracted from the input
+    pub slots: HashMap<String, String>,
+}
+
+/// Former name of [`IntentClassification`]
+#[deprecated(note = "renamed to `IntentClassification`")]
+pub type Intent = IntentClassification;
+
 /// Intent classification result
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Intent {
+pub struct IntentClassification {
     /// The classified intent
     pub intent_type: IntentType,
     /// Confidence score (0.0 to 1.0)
@@ -94,8 +147,81 @@ impl IntentModel {
         }
     }
     
+    /// Classify input into one of the routing [`INTENT_LABELS`]
+    pub async fn classify(&self, input: &str, context: &ModelContext) -> Result<RoutingIntent, ModelError> {
+        if !self.ready {
+            return Err(ModelError::NotReady("Intent model not loaded".to_string()));
+        }
+        
+        let required = self.estimate_energy_cost(input);
+        if required > context.energy_budget {
+            return Err(ModelError::InsufficientEnergy {
+                required,
+                available: context.energy_budget,
+            });
+        }
+        
+        Ok(self.recognize(input))
+    }
+    
+    /// Keyword classification behind [`IntentModel::classify`], for synchronous callers
+    ///
+    /// The earliest keyword in the input decides the label. Keywords match
+    /// whole words, allowing only the endings in `KEYWORD_ENDINGS`.
+    pub fn recognize(&self, input: &str) -> RoutingIntent {
+        let words: Vec<&str> = input.split_whitespace().collect();
+        let normalized: Vec<String> = words.iter().map(|word| normalize(word)).collect();
+        
+        let matched = normalized.iter().enumerate().find_map(|(position, word)| {
+            LABEL_KEYWORDS.iter().find_map(|(label, keywords)| {
+                keywords.iter()
+                    .position(|keyword| is_form_of(word, keyword))
+                    .map(|index| {
+                        let confidence = if index == 0 { LABEL_CONFIDENCE } else { KEYWORD_CONFIDENCE };
+                        (*label, confidence, position)
+                    })
+            })
+        });
+        let matched = matched.or_else(|| {
+            let is_question = normalized.first().is_some_and(|word| QUESTION_WORDS.contains(&word.as_str()))
+                || input.trim_end().ends_with('?');
+            is_question.then_some(("query", QUESTION_CONFIDENCE, 0))
+        });
+        
+        let Some((label, confidence, position)) = matched else {
+            return RoutingIntent {
+                label: UNKNOWN_INTENT.to_string(),
+                confidence: UNKNOWN_CONFIDENCE,
+                slots: HashMap::new(),
+            };
+        };
+        
+        let mut slots = HashMap::new();
+        let mut subject = Vec::new();
+        let mut rest = words.iter().zip(&normalized).skip(position + 1);
+        while let Some((word, normalized)) = rest.next() {
+            if normalized == "with" {
+                if let Some((partner, _)) = rest.next() {
+                    slots.insert("partner".to_string(), trim_punctuation(partner).to_string());
+                }
+            } else if !(subject.is_empty() && SUBJECT_FILLERS.contains(&normalized.as_str())) {
+                subject.push(*word);
+            }
+        }
+        let subject = trim_punctuation(&subject.join(" ")).to_string();
+        if !subject.is_empty() {
+            slots.insert("subject".to_string(), subject);
+        }
+        
+        RoutingIntent {
+            label: label.to_string(),
+            confidence,
+            slots,
+        }
+    }
+    
     /// Classify intent from text
-    pub async fn classify_intent(&self, text: &str) -> Result<Intent, ModelError> {
+    pub async fn classify_intent(&self, text: &str) -> Result<IntentClassification, ModelError> {
         if !self.ready {
             return Err(ModelError::NotReady("Intent model not loaded".to_string()));
         }
@@ -135,7 +261,7 @@ impl IntentModel {
             text_lower.split_whitespace().count().into()
         ));
         
-        Ok(Intent {
+        Ok(IntentClassification {
             intent_type,
             confidence,
             metadata,
@@ -143,6 +269,24 @@ impl IntentModel {
     }
 }
 
+fn trim_punctuation(text: &str) -> &str {
+    text.trim_matches(|c: char| c.is_ascii_punctuation())
+}
+
+fn normalize(word: &str) -> String {
+    trim_punctuation(word).to_lowercase()
+}
+
+/// Whether a word is the keyword itself or the keyword with one of [`KEYWORD_ENDINGS`]
+///
+/// A keyword ending in "e" drops it before "ing", as in "analyzing".
+fn is_form_of(word: &str, keyword: &str) -> bool {
+    match word.strip_prefix(keyword) {
+        Some(ending) => ending.is_empty() || KEYWORD_ENDINGS.contains(&ending),
+        None => keyword.strip_suffix('e').and_then(|stem| word.strip_prefix(stem)) == Some("ing"),
+    }
+}
+
 impl Clone for IntentModel {
     fn clone(&self) -> Self {
         IntentModel {
@@ -164,8 +308,9 @@ impl ComposableModel for IntentModel {
         Ok(ModelOutput {
             content,
             confidence: intent.confidence,
-            energy_cost: self.energy_cost(),
+            energy_cost: self.estimate_energy_cost(input),
             capabilities_used: vec![Capability::IntentRecognition],
+            cache_hit: false,
         })
     }
     
@@ -221,6 +366,65 @@ mod tests {
         assert!(intent.confidence <= 0.6);
     }
     
+    #[tokio::test]
+    async fn test_classify_routes_phrases_to_labels() {
+        let model = IntentModel::new();
+        let context = ModelContext::default();
+        let intent = model.classify("Please analyze the memory usage.", &context).await.unwrap();
+        assert_eq!(intent.label, "analyze");
+        assert_eq!(intent.confidence, LABEL_CONFIDENCE);
+        assert_eq!(intent.slots["subject"], "memory usage");
+        
+        let intent = model.classify("Let's collaborate with Architect on the schema design", &context).await.unwrap();
+        assert_eq!(intent.label, "collaborate");
+        assert_eq!(intent.slots["partner"], "Architect");
+        assert_eq!(intent.slots["subject"], "schema design");
+        
+        let intent = model.classify("What do you remember about emergence?", &context).await.unwrap();
+        assert_eq!(intent.label, "query");
+        assert_eq!(intent.slots["subject"], "emergence");
+        
+        let intent = model.classify("Where is the architect?", &context).await.unwrap();
+        assert_eq!(intent.label, "query");
+        assert_eq!(intent.confidence, QUESTION_CONFIDENCE);
+        assert_eq!(intent.slots["subject"], "architect");
+        
+        let intent = model.classify("Watch the signal patterns", &context).await.unwrap();
+        assert_eq!(intent.label, "observe");
+        assert_eq!(intent.confidence, KEYWORD_CONFIDENCE);
+        assert_eq!(intent.slots["subject"], "signal patterns");
+        
+        let intent = model.classify("Summarize recent experiments", &context).await.unwrap();
+        assert_eq!(intent.label, "report");
+        assert_eq!(intent.slots["subject"], "recent experiments");
+        
+        let intent = model.classify("hello there", &context).await.unwrap();
+        assert_eq!(intent.label, UNKNOWN_INTENT);
+        assert!(intent.slots.is_empty());
+    }
+    
+    #[test]
+    fn test_keywords_match_whole_words() {
+        let model = IntentModel::new();
+        assert_eq!(model.recognize("Investigating the crash").label, "analyze");
+        assert_eq!(model.recognize("Keep watching the queue").label, "observe");
+        assert_eq!(model.recognize("They joined the team").label, "collaborate");
+        assert_eq!(model.recognize("The joint is loose").label, UNKNOWN_INTENT);
+        assert_eq!(model.recognize("A reporter called").label, UNKNOWN_INTENT);
+    }
+    
+    #[tokio::test]
+    async fn test_classify_respects_energy_budget() {
+        let model = IntentModel::new();
+        let context = ModelContext {
+            energy_budget: 0.0,
+            ..ModelContext::default()
+        };
+        
+        let result = model.classify("analyze everything", &context).await;
+        assert!(matches!(result, Err(ModelError::InsufficientEnergy { .. })));
+    }
+    
     #[tokio::test]
     async fn test_composable_interface() {
         let model = IntentModel::new();
@@ -19,12 +19,17 @@ pub mod manager;
 pub mod config;
 pub mod composer;
 pub mod cloud;
+pub mod calibration;
+pub mod synthesis;
+pub mod parallel;
 
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use thiserror::Error;
 
+pub use tokio_util::sync::CancellationToken;
+
 /// Core capabilities that models can provide
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Capability {
@@ -86,6 +91,8 @@ pub struct ModelOutput {
     pub energy_cost: f64,
     /// Capabilities used
     pub capabilities_used: Vec<Capability>,
+    /// Whether this output was served from an inference cache
+    pub cache_hit: bool,
 }
 
 /// Composable model trait - all models implement this
@@ -94,9 +101,38 @@ pub trait ComposableModel: Send + Sync {
     /// Process input and return output
     async fn process(&self, input: &str, context: &ModelContext) -> Result<ModelOutput, ModelError>;
     
-    /// Get energy cost for this model
+    /// Process input unless `cancel` fires first
+    ///
+    /// Cancelling drops the in-flight inference, so nothing it would have
+    /// produced is returned or charged.
+    async fn process_cancellable(
+        &self,
+        input: &str,
+        context: &ModelContext,
+        cancel: &CancellationToken,
+    ) -> Result<ModelOutput, ModelError> {
+        tokio::select! {
+            biased;
+            _ = cancel.cancelled() => Err(ModelError::Cancelled),
+            output = self.process(input, context) => output,
+        }
+    }
+    
+    /// Get energy cost for this model, per input token
     fn energy_cost(&self) -> f64;
     
+    /// Estimate the energy cost of processing a specific input
+    fn estimate_energy_cost(&self, input: &str) -> f64 {
+        self.energy_cost() * self.token_heuristic().token_estimate(input) as f64
+    }
+    
+    /// How this model's tokenizer splits text, for cost estimates
+    fn token_heuristic(&self) -> TokenHeuristic {
+        ModelType::from_name(self.name())
+            .map(TokenHeuristic::for_model_type)
+            .unwrap_or_default()
+    }
+    
     /// Get memory requirement in bytes
     fn memory_requirement(&self) -> usize;
     
@@ -109,6 +145,11 @@ pub trait ComposableModel: Send + Sync {
     /// Check if model is loaded and ready
     fn is_ready(&self) -> bool;
     
+    /// Whether inference runs on a cloud service rather than locally
+    fn is_cloud(&self) -> bool {
+        false
+    }
+    
     /// Clone the model as a boxed trait object
     fn clone_box(&self) -> Box<dyn ComposableModel>;
 }
@@ -141,6 +182,65 @@ pub enum ModelType {
     OpenRouterCodeLlama,
 }
 
+impl ModelType {
+    /// Model type for a local model's name
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "distilbert-tiny" => Some(ModelType::DistilBertTiny),
+            "gpt2-small" => Some(ModelType::Gpt2Small),
+            "tinyllama" => Some(ModelType::TinyLlama),
+            "phi3-mini" => Some(ModelType::Phi3Mini),
+            "gemma2b" => Some(ModelType::Gemma2B),
+            "qwen2.5-0.5b" => Some(ModelType::Qwen25_0_5B),
+            "sentence-transformer" => Some(ModelType::SentenceTransformer),
+            "t5-small" => Some(ModelType::T5Small),
+            _ => None,
+        }
+    }
+}
+
+/// Tokenizer-free estimate of how many tokens a model sees in some text
+///
+/// Each whitespace-separated word counts as at least one token, and long
+/// words as one token per `chars_per_token` characters, mimicking subword
+/// tokenizers.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TokenHeuristic {
+    /// Average characters covered by one subword token
+    pub chars_per_token: f64,
+}
+
+impl Default for TokenHeuristic {
+    fn default() -> Self {
+        Self { chars_per_token: 4.0 }
+    }
+}
+
+impl TokenHeuristic {
+    /// Heuristic matching a model family's tokenizer
+    pub fn for_model_type(model_type: ModelType) -> Self {
+        let chars_per_token = match model_type {
+            // WordPiece vocabularies keep most English words whole
+            ModelType::DistilBertTiny | ModelType::SentenceTransformer => 5.0,
+            // SentencePiece unigram
+            ModelType::T5Small => 4.5,
+            // Byte-level BPE and the cloud models
+            _ => 4.0,
+        };
+        Self { chars_per_token }
+    }
+    
+    /// Estimated token count, at least one for any input
+    pub fn token_estimate(&self, input: &str) -> usize {
+        let chars_per_token = self.chars_per_token.max(1.0);
+        let tokens: usize = input
+            .split_whitespace()
+            .map(|word| ((word.chars().count() as f64 / chars_per_token).ceil() as usize).max(1))
+            .sum();
+        tokens.max(1)
+    }
+}
+
 /// Energy profile for a model
 #[derive(Debug, Clone)]
 pub struct EnergyProfile {
@@ -192,10 +292,21 @@ impl ModelPipeline {
         Ok(current_input)
     }
     
+    /// Names of the models in execution order
+    pub fn model_names(&self) -> Vec<&str> {
+        self.models.iter().map(|m| m.name()).collect()
+    }
+    
+    /// Combined cost of the models per input token
     pub fn total_energy_cost(&self) -> f64 {
         self.total_energy_cost
     }
     
+    /// Estimated cost of running every model in the pipeline on `input`
+    pub fn estimate_energy_cost(&self, input: &str) -> f64 {
+        self.models.iter().map(|m| m.estimate_energy_cost(input)).sum()
+    }
+    
     pub fn total_memory_requirement(&self) -> usize {
         self.total_memory_requirement
     }
@@ -224,6 +335,9 @@ pub enum ModelError {
     
     #[error("Invalid input: {0}")]
     InvalidInput(String),
+    
+    #[error("Inference cancelled")]
+    Cancelled,
 }
 
 /// Default personality for researcher essence
@@ -265,6 +379,73 @@ mod tests {
         assert_eq!(personality.collaboration, 0.7);
     }
     
+    /// Takes far longer than any test is willing to wait
+    #[derive(Clone)]
+    struct SlowModel;
+    
+    #[async_trait]
+    impl ComposableModel for SlowModel {
+        async fn process(&self, input: &str, _context: &ModelContext) -> Result<ModelOutput, ModelError> {
+            tokio::time::sleep(std::time::Duration::from_secs(60)).await;
+            Ok(ModelOutput {
+                content: input.to_string(),
+                confidence: 1.0,
+                energy_cost: 0.1,
+                capabilities_used: vec![Capability::ResponseGeneration],
+                cache_hit: false,
+            })
+        }
+        fn energy_cost(&self) -> f64 { 0.1 }
+        fn memory_requirement(&self) -> usize { 100 }
+        fn capabilities(&self) -> Vec<Capability> { vec![Capability::ResponseGeneration] }
+        fn name(&self) -> &str { "slow" }
+        fn is_ready(&self) -> bool { true }
+        fn clone_box(&self) -> Box<dyn ComposableModel> { Box::new(self.clone()) }
+    }
+    
+    #[tokio::test]
+    async fn test_cancelling_inference_stops_it_promptly() {
+        let cancel = CancellationToken::new();
+        let canceller = cancel.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+            canceller.cancel();
+        });
+        
+        let started = std::time::Instant::now();
+        let result = SlowModel.process_cancellable("hello", &ModelContext::default(), &cancel).await;
+        
+        assert!(matches!(result, Err(ModelError::Cancelled)));
+        assert!(started.elapsed() < std::time::Duration::from_secs(5));
+        
+        // An already cancelled token stops inference before it starts
+        let result = SlowModel.process_cancellable("hello", &ModelContext::default(), &cancel).await;
+        assert!(matches!(result, Err(ModelError::Cancelled)));
+    }
+    
+    #[test]
+    fn test_token_estimate_grows_with_input() {
+        let heuristic = TokenHeuristic::default();
+        
+        assert_eq!(heuristic.token_estimate(""), 1);
+        assert_eq!(heuristic.token_estimate("hi you"), 2);
+        assert_eq!(heuristic.token_estimate("internationalization"), 5);
+        assert!(heuristic.token_estimate("a much longer prompt") > heuristic.token_estimate("prompt"));
+    }
+    
+    #[test]
+    fn test_energy_estimate_scales_linearly_with_length() {
+        let model = intent::IntentModel::new();
+        let sentence = "please analyze these results carefully ";
+        
+        let short = model.estimate_energy_cost(sentence);
+        let long = model.estimate_energy_cost(&sentence.repeat(10));
+        
+        assert!(short > 0.0);
+        assert!((long / short - 10.0).abs() < 1e-9);
+        assert_eq!(model.token_heuristic(), TokenHeuristic::for_model_type(ModelType::DistilBertTiny));
+    }
+    
     #[test]
     fn test_model_context_default() {
         let context = ModelContext::default();
@@ -4,9 +4,13 @@
 //! for the composable model architecture.
 
 use super::*;
+use emergence_physics::{EntityId, Physics, PhysicsOperation};
+use ordered_float::OrderedFloat;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
+use tokio::time::Instant;
 use tracing::{debug, info, warn};
 
 /// Model manager for energy-aware model management
@@ -41,7 +45,7 @@ pub struct EnergyTracker {
     pub energy_budget: f64,
     /// Energy consumption history
     pub consumption_history: Vec<EnergyConsumption>,
-    /// Energy cost per model type
+    /// Energy cost per input token for each model type
     pub model_energy_costs: HashMap<ModelType, f64>,
 }
 
@@ -121,7 +125,8 @@ impl ModelManager {
         
         // Check energy budget
         let energy_tracker = self.energy_tracker.read().await;
-        let estimated_cost = energy_tracker.model_energy_costs.get(&model_type).copied().unwrap_or(0.1);
+        let cost_per_token = energy_tracker.model_energy_costs.get(&model_type).copied().unwrap_or(0.1);
+        let estimated_cost = cost_per_token * TokenHeuristic::for_model_type(model_type).token_estimate(input) as f64;
         
         if energy_tracker.energy_budget < estimated_cost {
             return Err(ModelError::InsufficientEnergy {
@@ -266,9 +271,359 @@ impl Default for ModelManager {
     }
 }
 
+/// Run a model on behalf of an entity, paying for it with the entity's physics energy
+///
+/// The model's estimated cost for `input` is checked against both `context.energy_budget`
+/// and the entity's physics energy before anything runs. Afterwards the
+/// reported `energy_cost` is dissipated from the entity and deducted from
+/// the context budget.
+pub async fn run_accounted(
+    model: &dyn ComposableModel,
+    input: &str,
+    context: &mut ModelContext,
+    physics: &dyn Physics,
+    entity: EntityId,
+) -> Result<ModelOutput, ModelError> {
+    let estimated = model.estimate_energy_cost(input);
+    let available = physics.entity_energy(entity).await.0.min(context.energy_budget);
+    if estimated > available {
+        return Err(ModelError::InsufficientEnergy { required: estimated, available });
+    }
+    
+    let output = model.process(input, context).await?;
+    
+    if output.energy_cost > 0.0 {
+        let work = PhysicsOperation::DissipateEnergy {
+            entity,
+            amount: OrderedFloat(output.energy_cost),
+        };
+        if let Err(e) = physics.execute_operation(work).await {
+            warn!("Could not charge entity {} for {} inference: {}", entity, model.name(), e);
+            return Err(ModelError::InsufficientEnergy {
+                required: output.energy_cost,
+                available: physics.entity_energy(entity).await.0,
+            });
+        }
+    }
+    context.energy_budget -= output.energy_cost;
+    
+    debug!("Charged entity {} {} energy for {} inference", entity, output.energy_cost, model.name());
+    Ok(output)
+}
+
+/// Width of the personality trait buckets that share inference cache entries
+pub const PERSONALITY_BUCKET_WIDTH: f64 = 0.1;
+
+/// Cached output with its age and recency
+#[derive(Debug, Clone)]
+struct CachedOutput {
+    output: ModelOutput,
+    stored_at: Instant,
+    /// Access counter value at last use, for LRU eviction
+    last_used: u64,
+}
+
+/// What an inference cache entry answers: the whole input, not a digest of it,
+/// so distinct inferences can never share an entry
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    model: String,
+    input: String,
+    personality: [i64; 5],
+}
+
+#[derive(Debug, Default)]
+struct CacheEntries {
+    outputs: HashMap<CacheKey, CachedOutput>,
+    access_counter: u64,
+}
+
+/// Model wrapper that reuses outputs of identical inferences
+///
+/// Entries are keyed on the input, the model name and the personality with
+/// each trait rounded to a [`PERSONALITY_BUCKET_WIDTH`] bucket. Hits cost no
+/// energy; entries expire after the TTL and the least recently used entry is
+/// evicted when the cache is full. Clones share the same cache.
+pub struct InferenceCache {
+    model: Box<dyn ComposableModel>,
+    entries: Arc<std::sync::Mutex<CacheEntries>>,
+    max_entries: usize,
+    ttl: Duration,
+}
+
+impl InferenceCache {
+    /// Wrap a model with a cache of at most `max_entries` outputs, each kept for `ttl`
+    pub fn new(model: Box<dyn ComposableModel>, max_entries: usize, ttl: Duration) -> Self {
+        Self {
+            model,
+            entries: Arc::new(std::sync::Mutex::new(CacheEntries::default())),
+            max_entries,
+            ttl,
+        }
+    }
+    
+    /// Number of outputs currently cached
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().outputs.len()
+    }
+    
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    
+    /// Drop every cached output
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().outputs.clear();
+    }
+    
+    fn cache_key(&self, input: &str, personality: &Personality) -> CacheKey {
+        CacheKey {
+            model: self.model.name().to_string(),
+            input: input.to_string(),
+            personality: personality_bucket(personality),
+        }
+    }
+    
+    fn lookup(&self, key: &CacheKey) -> Option<ModelOutput> {
+        let mut entries = self.entries.lock().unwrap();
+        entries.access_counter += 1;
+        let now = entries.access_counter;
+        
+        let expired = entries.outputs.get(key)?.stored_at.elapsed() > self.ttl;
+        if expired {
+            entries.outputs.remove(key);
+            return None;
+        }
+        let cached = entries.outputs.get_mut(key)?;
+        cached.last_used = now;
+        Some(cached.output.clone())
+    }
+    
+    fn store(&self, key: CacheKey, output: &ModelOutput) {
+        if self.max_entries == 0 {
+            return;
+        }
+        let mut entries = self.entries.lock().unwrap();
+        entries.access_counter += 1;
+        let now = entries.access_counter;
+        
+        let ttl = self.ttl;
+        entries.outputs.retain(|_, cached| cached.stored_at.elapsed() <= ttl);
+        if !entries.outputs.contains_key(&key) && entries.outputs.len() >= self.max_entries {
+            let oldest = entries.outputs.iter()
+                .min_by_key(|(_, cached)| cached.last_used)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                debug!("Evicting least recently used inference for {}", self.model.name());
+                entries.outputs.remove(&oldest);
+            }
+        }
+        entries.outputs.insert(key, CachedOutput {
+            output: output.clone(),
+            stored_at: Instant::now(),
+            last_used: now,
+        });
+    }
+}
+
+/// Personality traits rounded to shared buckets
+fn personality_bucket(personality: &Personality) -> [i64; 5] {
+    let bucket = |trait_value: f64| (trait_value / PERSONALITY_BUCKET_WIDTH).round() as i64;
+    [
+        bucket(personality.curiosity),
+        bucket(personality.creativity),
+        bucket(personality.skepticism),
+        bucket(personality.patience),
+        bucket(personality.collaboration),
+    ]
+}
+
+impl Clone for InferenceCache {
+    fn clone(&self) -> Self {
+        Self {
+            model: self.model.clone_box(),
+            entries: self.entries.clone(),
+            max_entries: self.max_entries,
+            ttl: self.ttl,
+        }
+    }
+}
+
+#[async_trait]
+impl ComposableModel for InferenceCache {
+    async fn process(&self, input: &str, context: &ModelContext) -> Result<ModelOutput, ModelError> {
+        let key = self.cache_key(input, &context.personality);
+        if let Some(mut output) = self.lookup(&key) {
+            output.energy_cost = 0.0;
+            output.cache_hit = true;
+            return Ok(output);
+        }
+        
+        let output = self.model.process(input, context).await?;
+        self.store(key, &output);
+        Ok(output)
+    }
+    
+    fn energy_cost(&self) -> f64 {
+        self.model.energy_cost()
+    }
+    
+    fn estimate_energy_cost(&self, input: &str) -> f64 {
+        self.model.estimate_energy_cost(input)
+    }
+    
+    fn token_heuristic(&self) -> TokenHeuristic {
+        self.model.token_heuristic()
+    }
+    
+    fn memory_requirement(&self) -> usize {
+        self.model.memory_requirement()
+    }
+    
+    fn capabilities(&self) -> Vec<Capability> {
+        self.model.capabilities()
+    }
+    
+    fn name(&self) -> &str {
+        self.model.name()
+    }
+    
+    fn is_ready(&self) -> bool {
+        self.model.is_ready()
+    }
+    
+    fn is_cloud(&self) -> bool {
+        self.model.is_cloud()
+    }
+    
+    fn clone_box(&self) -> Box<dyn ComposableModel> {
+        Box::new(self.clone())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use emergence_physics::PhysicsEngine;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    
+    /// Echoes its input and counts how often it actually runs
+    #[derive(Clone)]
+    struct CountingModel {
+        calls: Arc<AtomicUsize>,
+    }
+    
+    #[async_trait]
+    impl ComposableModel for CountingModel {
+        async fn process(&self, input: &str, _context: &ModelContext) -> Result<ModelOutput, ModelError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(ModelOutput {
+                content: input.to_uppercase(),
+                confidence: 0.8,
+                energy_cost: 0.05,
+                capabilities_used: vec![Capability::ResponseGeneration],
+                cache_hit: false,
+            })
+        }
+        fn energy_cost(&self) -> f64 { 0.05 }
+        fn memory_requirement(&self) -> usize { 1024 }
+        fn capabilities(&self) -> Vec<Capability> { vec![Capability::ResponseGeneration] }
+        fn name(&self) -> &str { "counting" }
+        fn is_ready(&self) -> bool { true }
+        fn clone_box(&self) -> Box<dyn ComposableModel> { Box::new(self.clone()) }
+    }
+    
+    #[tokio::test]
+    async fn test_run_accounted_debits_entity_and_budget() {
+        let physics = PhysicsEngine::new().await.unwrap();
+        let entity = EntityId::new();
+        physics.allocate_energy_to_entity(entity, OrderedFloat(0.2)).await.unwrap();
+        let model = CountingModel { calls: Arc::new(AtomicUsize::new(0)) };
+        let mut context = ModelContext::default();
+        
+        let output = run_accounted(&model, "hello", &mut context, &physics, entity).await.unwrap();
+        
+        assert_eq!(output.content, "HELLO");
+        assert!((physics.entity_energy(entity).await.0 - 0.15).abs() < 1e-9);
+        let energy = physics.get_engine_state().await.unwrap().energy_state;
+        assert!((energy.dissipated_energy.0 - 0.05).abs() < 1e-9);
+        assert!((context.energy_budget - 0.95).abs() < 1e-9);
+    }
+    
+    #[tokio::test]
+    async fn test_run_accounted_refuses_unaffordable_inference() {
+        let physics = PhysicsEngine::new().await.unwrap();
+        let entity = EntityId::new();
+        physics.allocate_energy_to_entity(entity, OrderedFloat(0.01)).await.unwrap();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let model = CountingModel { calls: calls.clone() };
+        let mut context = ModelContext::default();
+        
+        let result = run_accounted(&model, "hello", &mut context, &physics, entity).await;
+        
+        assert!(matches!(result, Err(ModelError::InsufficientEnergy { .. })));
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+        assert!((physics.entity_energy(entity).await.0 - 0.01).abs() < 1e-9);
+        assert_eq!(context.energy_budget, 1.0);
+    }
+    
+    fn counting_cache(max_entries: usize, ttl: Duration) -> (InferenceCache, Arc<AtomicUsize>) {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let model = Box::new(CountingModel { calls: calls.clone() });
+        (InferenceCache::new(model, max_entries, ttl), calls)
+    }
+    
+    #[tokio::test]
+    async fn test_identical_inference_hits_cache() {
+        let (cache, calls) = counting_cache(10, Duration::from_secs(60));
+        let context = ModelContext::default();
+        
+        let first = cache.process("hello", &context).await.unwrap();
+        let second = cache.process("hello", &context).await.unwrap();
+        
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(!first.cache_hit);
+        assert_eq!(first.energy_cost, 0.05);
+        assert!(second.cache_hit);
+        assert_eq!(second.energy_cost, 0.0);
+        assert_eq!(second.content, "HELLO");
+    }
+    
+    #[tokio::test]
+    async fn test_personality_buckets_share_or_split_entries() {
+        let (cache, calls) = counting_cache(10, Duration::from_secs(60));
+        let context = ModelContext::default();
+        let mut nearly_same = context.clone();
+        nearly_same.personality.curiosity += 0.01;
+        let mut different = context.clone();
+        different.personality.skepticism = 0.1;
+        
+        cache.process("hello", &context).await.unwrap();
+        assert!(cache.process("hello", &nearly_same).await.unwrap().cache_hit);
+        assert!(!cache.process("hello", &different).await.unwrap().cache_hit);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+    
+    #[tokio::test(start_paused = true)]
+    async fn test_cache_evicts_least_recently_used_and_expired() {
+        let (cache, calls) = counting_cache(2, Duration::from_secs(60));
+        let context = ModelContext::default();
+        
+        cache.process("a", &context).await.unwrap();
+        cache.process("b", &context).await.unwrap();
+        cache.process("a", &context).await.unwrap();
+        cache.process("c", &context).await.unwrap();
+        assert_eq!(cache.len(), 2);
+        
+        // "b" was least recently used when "c" arrived
+        assert!(cache.process("a", &context).await.unwrap().cache_hit);
+        assert!(!cache.process("b", &context).await.unwrap().cache_hit);
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+        
+        tokio::time::advance(Duration::from_secs(61)).await;
+        assert!(!cache.process("b", &context).await.unwrap().cache_hit);
+        assert_eq!(calls.load(Ordering::SeqCst), 5);
+    }
     
     #[tokio::test]
     async fn test_model_manager_creation() {
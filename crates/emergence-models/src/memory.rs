@@ -192,8 +192,9 @@ impl ComposableModel for MemoryModel {
         Ok(ModelOutput {
             content,
             confidence: embedding.similarity,
-            energy_cost: self.energy_cost(),
+            energy_cost: self.estimate_energy_cost(input),
             capabilities_used: vec![Capability::MemoryEmbedding],
+            cache_hit: false,
         })
     }
     
@@ -0,0 +1,171 @@
+//! Parallel Pipeline - Concurrent Independent Stages
+//!
+//! `ModelPipeline` feeds each model's output to the next, but some tasks fan
+//! out into stages that only need the original input, such as classifying
+//! intent while embedding the same text for memory. `ParallelPipeline` runs
+//! those branches concurrently and hands their outputs, one paragraph each, to
+//! a merge stage like `SynthesisModel`. All branches and the merge share the
+//! context's energy budget.
+
+use super::*;
+use futures::future::join_all;
+
+/// Independent models run side by side on the same input, then merged
+pub struct ParallelPipeline {
+    branches: Vec<Box<dyn ComposableModel>>,
+    merge: Option<Box<dyn ComposableModel>>,
+}
+
+impl ParallelPipeline {
+    pub fn new(branches: Vec<Box<dyn ComposableModel>>) -> Self {
+        Self { branches, merge: None }
+    }
+
+    /// Combine the branch outputs with `model` instead of concatenating them
+    pub fn with_merge(mut self, model: Box<dyn ComposableModel>) -> Self {
+        self.merge = Some(model);
+        self
+    }
+
+    /// Run every branch on `input` concurrently, returning their outputs in branch order
+    ///
+    /// Fails before running anything when the branches' estimated costs
+    /// together exceed the energy budget, and afterwards when their actual
+    /// costs do.
+    pub async fn execute_branches(&self, input: &str, context: &ModelContext) -> Result<Vec<ModelOutput>, ModelError> {
+        let estimated: f64 = self.branches.iter().map(|m| m.estimate_energy_cost(input)).sum();
+        if estimated > context.energy_budget {
+            return Err(ModelError::InsufficientEnergy { required: estimated, available: context.energy_budget });
+        }
+
+        let outputs = join_all(self.branches.iter().map(|model| model.process(input, context)))
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let spent: f64 = outputs.iter().map(|o| o.energy_cost).sum();
+        if spent > context.energy_budget {
+            return Err(ModelError::InsufficientEnergy { required: spent, available: context.energy_budget });
+        }
+        Ok(outputs)
+    }
+
+    /// Run the branches concurrently and merge their outputs
+    ///
+    /// The merge stage gets whatever budget the branches left, and the
+    /// returned cost covers every stage.
+    pub async fn execute(&self, input: &str, context: &ModelContext) -> Result<ModelOutput, ModelError> {
+        let outputs = self.execute_branches(input, context).await?;
+        let spent: f64 = outputs.iter().map(|o| o.energy_cost).sum();
+        let merge_input = outputs.iter().map(|o| o.content.as_str()).collect::<Vec<_>>().join("\n\n");
+
+        let Some(merge) = &self.merge else {
+            let mut capabilities_used = Vec::new();
+            for capability in outputs.iter().flat_map(|o| &o.capabilities_used) {
+                if !capabilities_used.contains(capability) {
+                    capabilities_used.push(capability.clone());
+                }
+            }
+            return Ok(ModelOutput {
+                content: merge_input,
+                confidence: outputs.iter().map(|o| o.confidence).fold(1.0, f64::min),
+                energy_cost: spent,
+                capabilities_used,
+                cache_hit: !outputs.is_empty() && outputs.iter().all(|o| o.cache_hit),
+            });
+        };
+
+        let remaining = ModelContext { energy_budget: context.energy_budget - spent, ..context.clone() };
+        let mut merged = merge.process(&merge_input, &remaining).await?;
+        if merged.energy_cost > remaining.energy_budget {
+            return Err(ModelError::InsufficientEnergy { required: spent + merged.energy_cost, available: context.energy_budget });
+        }
+        merged.energy_cost += spent;
+        Ok(merged)
+    }
+
+    /// Names of the branch models, then the merge stage's
+    pub fn model_names(&self) -> Vec<&str> {
+        self.branches.iter().chain(&self.merge).map(|m| m.name()).collect()
+    }
+
+    /// Memory needed with every branch loaded at once
+    pub fn total_memory_requirement(&self) -> usize {
+        self.branches.iter().chain(&self.merge).map(|m| m.memory_requirement()).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tokio::sync::Barrier;
+
+    /// Answers only once every model sharing its barrier has been called
+    #[derive(Clone)]
+    struct RendezvousModel {
+        name: &'static str,
+        capability: Capability,
+        cost: f64,
+        barrier: Arc<Barrier>,
+    }
+
+    #[async_trait::async_trait]
+    impl ComposableModel for RendezvousModel {
+        async fn process(&self, input: &str, _context: &ModelContext) -> Result<ModelOutput, ModelError> {
+            self.barrier.wait().await;
+            Ok(ModelOutput {
+                content: format!("{}: {}", self.name, input),
+                confidence: 0.9,
+                energy_cost: self.cost,
+                capabilities_used: vec![self.capability.clone()],
+                cache_hit: false,
+            })
+        }
+        fn energy_cost(&self) -> f64 { self.cost }
+        fn estimate_energy_cost(&self, _input: &str) -> f64 { self.cost }
+        fn memory_requirement(&self) -> usize { 100 }
+        fn capabilities(&self) -> Vec<Capability> { vec![self.capability.clone()] }
+        fn name(&self) -> &str { self.name }
+        fn is_ready(&self) -> bool { true }
+        fn clone_box(&self) -> Box<dyn ComposableModel> { Box::new(self.clone()) }
+    }
+
+    fn fan_out(cost: f64) -> ParallelPipeline {
+        let barrier = Arc::new(Barrier::new(2));
+        ParallelPipeline::new(vec![
+            Box::new(RendezvousModel { name: "intent", capability: Capability::IntentRecognition, cost, barrier: barrier.clone() }),
+            Box::new(RendezvousModel { name: "embed", capability: Capability::MemoryEmbedding, cost, barrier }),
+        ])
+    }
+
+    #[tokio::test]
+    async fn test_independent_stages_run_concurrently() {
+        let context = ModelContext { energy_budget: 0.5, ..ModelContext::default() };
+
+        // Each branch waits for the other, so running them in sequence would never finish
+        let output = tokio::time::timeout(Duration::from_secs(5), fan_out(0.2).execute("remember this", &context))
+            .await
+            .expect("branches should run concurrently")
+            .unwrap();
+
+        assert_eq!(output.content, "intent: remember this\n\nembed: remember this");
+        assert!((output.energy_cost - 0.4).abs() < 1e-9);
+        assert_eq!(output.capabilities_used, vec![Capability::IntentRecognition, Capability::MemoryEmbedding]);
+
+        let merged = fan_out(0.2).with_merge(Box::new(crate::synthesis::SynthesisModel::new()));
+        assert_eq!(merged.model_names(), vec!["intent", "embed", "synthesis"]);
+        let output = merged.execute("remember this", &context).await.unwrap();
+        assert!(output.energy_cost >= 0.4 && output.energy_cost <= 0.5);
+    }
+
+    #[tokio::test]
+    async fn test_budget_must_cover_every_branch() {
+        let context = ModelContext { energy_budget: 0.3, ..ModelContext::default() };
+
+        let err = fan_out(0.2).execute_branches("remember this", &context).await.unwrap_err();
+
+        assert!(matches!(err, ModelError::InsufficientEnergy { required, available } if (required - 0.4).abs() < 1e-9 && available == 0.3));
+    }
+}
@@ -9,9 +9,17 @@ use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+/// Confidence of each step in a reasoning trace
+const TRACE_STEP_CONFIDENCE: f64 = 0.8;
+
+/// Share of a trace's confidence withheld by a fully skeptical personality
+const SKEPTICISM_DISCOUNT: f64 = 0.3;
+
 /// Reasoning result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReasoningResult {
+    /// Final answer, citing the steps it rests on
+    pub answer: String,
     /// The reasoning output
     pub output: String,
     /// Reasoning steps taken
@@ -27,10 +35,10 @@ pub struct ReasoningResult {
 pub struct ReasoningStep {
     /// Step number
     pub step: usize,
-    /// Step description
-    pub description: String,
-    /// Step result
-    pub result: String,
+    /// What the step starts from
+    pub premise: String,
+    /// What the step concludes from its premise
+    pub inference: String,
     /// Step confidence
     pub confidence: f64,
 }
@@ -113,6 +121,7 @@ impl ReasoningModel {
         
         // Generate final output
         let output = self.generate_reasoning_output(&steps);
+        let answer = self.generate_conclusion(&steps);
         
         // Calculate confidence
         let confidence = self.calculate_confidence(&steps);
@@ -123,6 +132,7 @@ impl ReasoningModel {
         metadata.insert("steps_count".to_string(), serde_json::Value::Number(steps.len().into()));
         
         Ok(ReasoningResult {
+            answer,
             output,
             steps,
             confidence,
@@ -130,6 +140,66 @@ impl ReasoningModel {
         })
     }
     
+    /// Reason about each part of a prompt in turn, keeping the chain of steps
+    ///
+    /// Every sentence or `;`-separated clause becomes one step whose premise is
+    /// the clause itself. The answer cites the steps it combines, and skeptical
+    /// personalities report less confidence in it.
+    pub async fn reason_with_trace(&self, input: &str, context: &ModelContext) -> Result<ReasoningResult, ModelError> {
+        if !self.ready {
+            return Err(ModelError::NotReady("Reasoning model not loaded".to_string()));
+        }
+        
+        let required = self.estimate_energy_cost(input);
+        if required > context.energy_budget {
+            return Err(ModelError::InsufficientEnergy {
+                required,
+                available: context.energy_budget,
+            });
+        }
+        
+        let parts: Vec<&str> = input
+            .split(['.', '?', '!', ';', '\n'])
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .collect();
+        if parts.is_empty() {
+            return Err(ModelError::InvalidInput("Nothing to reason about".to_string()));
+        }
+        
+        let steps: Vec<ReasoningStep> = parts.iter().enumerate()
+            .map(|(i, part)| ReasoningStep {
+                step: i + 1,
+                premise: part.to_string(),
+                inference: self.infer(part),
+                confidence: TRACE_STEP_CONFIDENCE,
+            })
+            .collect();
+        
+        let cited: Vec<String> = steps.iter().map(|step| format!("step {}", step.step)).collect();
+        let cited = match cited.split_last() {
+            Some((last, rest)) if !rest.is_empty() => format!("{} and {}", rest.join(", "), last),
+            _ => cited.join(""),
+        };
+        let inferences: Vec<&str> = steps.iter().map(|step| step.inference.as_str()).collect();
+        let answer = format!("From {}, I conclude: {}.", cited, inferences.join("; "));
+        
+        let skepticism = context.personality.skepticism.clamp(0.0, 1.0);
+        let confidence = self.calculate_confidence(&steps) * (1.0 - SKEPTICISM_DISCOUNT * skepticism);
+        
+        let mut metadata = HashMap::new();
+        metadata.insert("reasoning_type".to_string(), serde_json::Value::String("trace".to_string()));
+        metadata.insert("steps_count".to_string(), serde_json::Value::Number(steps.len().into()));
+        
+        Ok(ReasoningResult {
+            answer,
+            output: self.generate_reasoning_output(&steps),
+            steps,
+            confidence,
+            metadata,
+        })
+    }
+    
     /// Create a reasoning chain for complex problems
     pub async fn create_reasoning_chain(
         &self,
@@ -186,14 +256,14 @@ impl ReasoningModel {
         let mut steps = Vec::new();
         
         for (i, template) in templates.iter().enumerate() {
-            let description = template.replace("{}", &self.process_problem(problem));
-            let result = self.generate_step_result(&description, i);
+            let premise = template.replace("{}", &self.process_problem(problem));
+            let inference = self.generate_step_result(&premise, i);
             let confidence = 0.7 + (i as f64 * 0.1); // Increasing confidence
             
             steps.push(ReasoningStep {
                 step: i + 1,
-                description,
-                result,
+                premise,
+                inference,
                 confidence: confidence.min(1.0),
             });
         }
@@ -206,8 +276,8 @@ impl ReasoningModel {
         let mut output = String::new();
         
         for step in steps {
-            output.push_str(&format!("Step {}: {}\n", step.step, step.description));
-            output.push_str(&format!("Result: {}\n\n", step.result));
+            output.push_str(&format!("Step {}: {}\n", step.step, step.premise));
+            output.push_str(&format!("Result: {}\n\n", step.inference));
         }
         
         output
@@ -220,7 +290,18 @@ impl ReasoningModel {
         }
         
         let last_step = &steps[steps.len() - 1];
-        format!("Based on the reasoning process, I conclude: {}", last_step.result)
+        format!("Based on the reasoning process, I conclude: {}", last_step.inference)
+    }
+    
+    /// Inference drawn from one clause of a traced prompt
+    fn infer(&self, clause: &str) -> String {
+        let subject = clause.to_lowercase();
+        match self.determine_reasoning_type(clause).as_str() {
+            "investigate" => format!("{} needs evidence before any hypothesis is accepted", subject),
+            "solve" => format!("{} calls for an approach that can be tested", subject),
+            "pattern" => format!("{} shows a regularity worth tracking", subject),
+            _ => format!("{} breaks down into components whose relationships explain it", subject),
+        }
     }
     
     /// Calculate confidence from steps
@@ -275,8 +356,9 @@ impl ComposableModel for ReasoningModel {
         Ok(ModelOutput {
             content: reasoning.output,
             confidence: reasoning.confidence,
-            energy_cost: self.energy_cost(),
+            energy_cost: self.estimate_energy_cost(input),
             capabilities_used: vec![Capability::Reasoning],
+            cache_hit: false,
         })
     }
     
@@ -336,6 +418,40 @@ mod tests {
         assert!(chain.confidence > 0.0);
     }
     
+    #[tokio::test]
+    async fn test_trace_has_a_step_per_part() {
+        let model = ReasoningModel::new();
+        let context = ModelContext::default();
+        
+        let result = model.reason_with_trace(
+            "Investigate the latency spikes. Is there a pattern in the failures? Solve the slowest query",
+            &context,
+        ).await.unwrap();
+        
+        assert_eq!(result.steps.len(), 3);
+        assert_eq!(result.steps[0].premise, "Investigate the latency spikes");
+        assert!(result.steps[1].inference.contains("regularity"));
+        assert!(result.answer.starts_with("From step 1, step 2 and step 3"));
+        for step in &result.steps {
+            assert!(result.answer.contains(&step.inference));
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_skepticism_lowers_trace_confidence() {
+        let model = ReasoningModel::new();
+        let mut context = ModelContext::default();
+        context.personality.skepticism = 0.0;
+        let trusting = model.reason_with_trace("Analyze the logs", &context).await.unwrap();
+        context.personality.skepticism = 1.0;
+        let skeptical = model.reason_with_trace("Analyze the logs", &context).await.unwrap();
+        
+        assert_eq!(trusting.steps.len(), 1);
+        assert_eq!(trusting.answer, format!("From step 1, I conclude: {}.", trusting.steps[0].inference));
+        assert!(skeptical.confidence < trusting.confidence);
+        assert!(model.reason_with_trace(" . ? ", &context).await.is_err());
+    }
+    
     #[tokio::test]
     async fn test_composable_interface() {
         let model = ReasoningModel::new();
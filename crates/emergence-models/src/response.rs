@@ -226,8 +226,9 @@ impl ComposableModel for ResponseModel {
         Ok(ModelOutput {
             content: response.text,
             confidence: response.confidence,
-            energy_cost: self.energy_cost(),
+            energy_cost: self.estimate_energy_cost(input),
             capabilities_used: vec![Capability::ResponseGeneration],
+            cache_hit: false,
         })
     }
     
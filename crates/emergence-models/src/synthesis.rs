@@ -0,0 +1,235 @@
+//! Data Synthesis Model
+//!
+//! Merges the outputs of several agents or models into one result. Agreement
+//! between outputs is measured as the mean pairwise overlap of their content
+//! words, and scales the merged confidence: concurring outputs keep most of
+//! their confidence, conflicting ones lose up to half of it and have their
+//! disagreement spelled out instead of hidden.
+
+use super::*;
+use async_trait::async_trait;
+use std::collections::HashSet;
+
+/// Agreement at or above which outputs are merged into a consensus
+pub const CONSENSUS_THRESHOLD: f64 = 0.5;
+
+/// Confidence assumed for outputs passed to `process` as plain text
+const UNSCORED_CONFIDENCE: f64 = 0.8;
+
+/// Words ignored when comparing outputs
+const STOP_WORDS: [&str; 24] = [
+    "the", "and", "for", "are", "was", "were", "with", "from", "that", "this", "its", "has",
+    "have", "been", "into", "onto", "than", "then", "there", "their", "they", "but", "not", "all",
+];
+
+/// Data synthesis model implementing the composable interface
+#[derive(Debug, Clone)]
+pub struct SynthesisModel {
+    /// Model name
+    name: String,
+    /// Whether the model is ready
+    ready: bool,
+}
+
+impl SynthesisModel {
+    /// Create a new synthesis model
+    pub fn new() -> Self {
+        Self {
+            name: "synthesis".to_string(),
+            ready: true,
+        }
+    }
+
+    /// Merge several outputs into one, with confidence reflecting their agreement
+    ///
+    /// The budget is checked against the cost of reading the outputs; the
+    /// merged output reports that plus the cost of the text it wrote.
+    pub async fn synthesize(&self, inputs: &[ModelOutput], context: &ModelContext) -> Result<ModelOutput, ModelError> {
+        if !self.ready {
+            return Err(ModelError::NotReady("Synthesis model not loaded".to_string()));
+        }
+        if inputs.is_empty() {
+            return Err(ModelError::InvalidInput("Nothing to synthesize".to_string()));
+        }
+
+        let combined: Vec<&str> = inputs.iter().map(|input| input.content.as_str()).collect();
+        let required = self.estimate_energy_cost(&combined.join("\n"));
+        if required > context.energy_budget {
+            return Err(ModelError::InsufficientEnergy {
+                required,
+                available: context.energy_budget,
+            });
+        }
+
+        let agreement = agreement(inputs);
+        let mean_confidence = inputs.iter().map(|input| input.confidence).sum::<f64>() / inputs.len() as f64;
+        let confidence = mean_confidence * (0.5 + 0.5 * agreement);
+
+        let content = if agreement >= CONSENSUS_THRESHOLD {
+            let best = inputs.iter()
+                .max_by(|a, b| a.confidence.total_cmp(&b.confidence))
+                .expect("inputs are not empty");
+            format!(
+                "{}\n(Consensus of {} sources, agreement {:.2})",
+                best.content,
+                inputs.len(),
+                agreement
+            )
+        } else {
+            let mut content = format!("Sources disagree (agreement {:.2}):", agreement);
+            for (i, input) in inputs.iter().enumerate() {
+                content.push_str(&format!("\n{}. {} (confidence {:.2})", i + 1, input.content, input.confidence));
+            }
+            content
+        };
+
+        let energy_cost = required + self.estimate_energy_cost(&content);
+        Ok(ModelOutput {
+            content,
+            confidence,
+            energy_cost,
+            capabilities_used: vec![Capability::DataSynthesis],
+            cache_hit: false,
+        })
+    }
+}
+
+/// Mean pairwise Jaccard overlap of the outputs' content words
+fn agreement(inputs: &[ModelOutput]) -> f64 {
+    let words: Vec<HashSet<String>> = inputs.iter().map(|input| content_words(&input.content)).collect();
+
+    let mut total = 0.0;
+    let mut pairs = 0;
+    for (i, a) in words.iter().enumerate() {
+        for b in &words[i + 1..] {
+            let union = a.union(b).count();
+            total += if union == 0 { 1.0 } else { a.intersection(b).count() as f64 / union as f64 };
+            pairs += 1;
+        }
+    }
+
+    if pairs == 0 { 1.0 } else { total / pairs as f64 }
+}
+
+fn content_words(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .map(str::to_lowercase)
+        .filter(|word| word.len() > 2 && !STOP_WORDS.contains(&word.as_str()))
+        .collect()
+}
+
+#[async_trait]
+impl ComposableModel for SynthesisModel {
+    /// Synthesize outputs given as blank-line separated paragraphs
+    async fn process(&self, input: &str, context: &ModelContext) -> Result<ModelOutput, ModelError> {
+        let inputs: Vec<ModelOutput> = input.split("\n\n")
+            .map(str::trim)
+            .filter(|paragraph| !paragraph.is_empty())
+            .map(|paragraph| ModelOutput {
+                content: paragraph.to_string(),
+                confidence: UNSCORED_CONFIDENCE,
+                energy_cost: 0.0,
+                capabilities_used: Vec::new(),
+                cache_hit: false,
+            })
+            .collect();
+
+        self.synthesize(&inputs, context).await
+    }
+
+    fn energy_cost(&self) -> f64 {
+        0.002
+    }
+
+    fn memory_requirement(&self) -> usize {
+        10 * 1024 * 1024 // 10MB
+    }
+
+    fn capabilities(&self) -> Vec<Capability> {
+        vec![Capability::DataSynthesis]
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ready
+    }
+
+    fn clone_box(&self) -> Box<dyn ComposableModel> {
+        Box::new(self.clone())
+    }
+}
+
+impl Default for SynthesisModel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn output(content: &str, confidence: f64) -> ModelOutput {
+        ModelOutput {
+            content: content.to_string(),
+            confidence,
+            energy_cost: 0.01,
+            capabilities_used: vec![Capability::Reasoning],
+            cache_hit: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_agreeing_outputs_merge_with_high_confidence() {
+        let model = SynthesisModel::new();
+        let inputs = [
+            output("The memory leak is in the cache layer", 0.8),
+            output("The memory leak comes from the cache layer", 0.9),
+            output("Memory leak located in cache layer", 0.7),
+        ];
+
+        let merged = model.synthesize(&inputs, &ModelContext::default()).await.unwrap();
+
+        assert!(merged.confidence > 0.65, "confidence {}", merged.confidence);
+        assert!(merged.content.starts_with("The memory leak comes from the cache layer"));
+        assert!(merged.content.contains("Consensus of 3 sources"));
+        assert_eq!(merged.capabilities_used, vec![Capability::DataSynthesis]);
+
+        let read: Vec<&str> = inputs.iter().map(|input| input.content.as_str()).collect();
+        let read_cost = model.estimate_energy_cost(&read.join("\n"));
+        assert!((merged.energy_cost - read_cost - model.estimate_energy_cost(&merged.content)).abs() < 1e-12);
+    }
+
+    #[tokio::test]
+    async fn test_conflicting_outputs_surface_disagreement() {
+        let model = SynthesisModel::new();
+        let inputs = [
+            output("The memory leak is in the cache layer", 0.8),
+            output("Network latency causes the timeouts", 0.8),
+            output("Disk contention slows every request", 0.8),
+        ];
+
+        let merged = model.synthesize(&inputs, &ModelContext::default()).await.unwrap();
+
+        assert!(merged.confidence < 0.45, "confidence {}", merged.confidence);
+        assert!(merged.content.starts_with("Sources disagree"));
+        for input in &inputs {
+            assert!(merged.content.contains(&input.content));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_process_splits_paragraphs() {
+        let model = SynthesisModel::new();
+        let context = ModelContext::default();
+
+        let merged = model.process("Cache layer leaks memory\n\nThe cache layer leaks memory", &context).await.unwrap();
+        assert!(merged.content.contains("Consensus of 2 sources, agreement 1.00"));
+        assert!((merged.confidence - UNSCORED_CONFIDENCE).abs() < 1e-9);
+
+        assert!(model.process("  \n\n ", &context).await.is_err());
+    }
+}
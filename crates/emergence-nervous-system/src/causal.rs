@@ -0,0 +1,216 @@
+//! Causal ordering of signal processing.
+//!
+//! When the configuration sets a causal dependency timeout, a queued signal
+//! is held back until every signal in its `causal_dependencies` has been
+//! processed successfully somewhere in the nervous system. Held signals are
+//! released in the order they arrived as soon as they become ready, so a
+//! chain of dependents is processed in topological order whatever order it
+//! was delivered in. Signals still waiting when the timeout passes, or
+//! arriving while a loop already holds [`MAX_HELD_SIGNALS`], are given up.
+
+use std::collections::{HashSet, VecDeque};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use emergence_physics::{EntityId, SharedClock};
+use tokio::sync::watch;
+use tokio::time::Instant;
+use uuid::Uuid;
+
+use crate::NeuralSignal;
+
+/// Processed signal ids remembered for dependency checks, oldest forgotten first
+pub const MAX_REMEMBERED_SIGNALS: usize = 10_000;
+
+/// Signals one processing loop holds at once; any more are turned away
+pub const MAX_HELD_SIGNALS: usize = 1000;
+
+/// Which signals have been processed, shared by every processing loop
+#[derive(Debug)]
+pub(crate) struct CausalOrder {
+    pub(crate) timeout: Duration,
+    processed: Mutex<ProcessedSignals>,
+    /// Bumped whenever a signal is processed, waking loops holding dependents
+    progress: watch::Sender<u64>,
+}
+
+#[derive(Debug, Default)]
+struct ProcessedSignals {
+    order: VecDeque<Uuid>,
+    ids: HashSet<Uuid>,
+}
+
+impl CausalOrder {
+    pub(crate) fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            processed: Mutex::new(ProcessedSignals::default()),
+            progress: watch::channel(0).0,
+        }
+    }
+
+    /// Record that a signal has been processed successfully
+    pub(crate) fn mark_processed(&self, signal_id: Uuid) {
+        {
+            let mut processed = self.processed.lock().unwrap();
+            if !processed.ids.insert(signal_id) {
+                return;
+            }
+            processed.order.push_back(signal_id);
+            if processed.order.len() > MAX_REMEMBERED_SIGNALS {
+                if let Some(oldest) = processed.order.pop_front() {
+                    processed.ids.remove(&oldest);
+                }
+            }
+        }
+        self.progress.send_modify(|count| *count += 1);
+    }
+
+    /// Whether every dependency of a signal has been processed
+    pub(crate) fn is_ready(&self, signal: &NeuralSignal) -> bool {
+        let processed = self.processed.lock().unwrap();
+        signal.causal_dependencies.iter().all(|dependency| processed.ids.contains(dependency))
+    }
+
+    /// Receiver that changes whenever another signal is processed
+    pub(crate) fn subscribe(&self) -> watch::Receiver<u64> {
+        self.progress.subscribe()
+    }
+}
+
+/// Signals one processing loop is holding until their dependencies are processed
+#[derive(Debug)]
+pub(crate) struct HeldSignals {
+    clock: SharedClock,
+    /// Held signals in arrival order
+    held: VecDeque<(EntityId, NeuralSignal, Instant)>,
+}
+
+impl HeldSignals {
+    pub(crate) fn new(clock: SharedClock) -> Self {
+        Self {
+            clock,
+            held: VecDeque::new(),
+        }
+    }
+
+    /// Hold a signal, or hand it back if [`MAX_HELD_SIGNALS`] are already held
+    #[must_use]
+    pub(crate) fn hold(&mut self, entity_id: EntityId, signal: NeuralSignal) -> Option<NeuralSignal> {
+        if self.held.len() >= MAX_HELD_SIGNALS {
+            return Some(signal);
+        }
+        self.held.push_back((entity_id, signal, self.clock.now_instant()));
+        None
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.held.is_empty()
+    }
+
+    /// Take the earliest held signal whose dependencies have all been processed
+    pub(crate) fn take_ready(&mut self, order: &CausalOrder) -> Option<(EntityId, NeuralSignal)> {
+        let index = self.held.iter().position(|(_, signal, _)| order.is_ready(signal))?;
+        self.held.remove(index).map(|(entity_id, signal, _)| (entity_id, signal))
+    }
+
+    /// Take every signal held for longer than the timeout
+    pub(crate) fn take_overdue(&mut self, timeout: Duration) -> Vec<(EntityId, NeuralSignal)> {
+        let now = self.clock.now_instant();
+        let mut overdue = Vec::new();
+        while self.held.front().is_some_and(|(_, _, since)| now.duration_since(*since) >= timeout) {
+            if let Some((entity_id, signal, _)) = self.held.pop_front() {
+                overdue.push((entity_id, signal));
+            }
+        }
+        overdue
+    }
+
+    /// When the longest-held signal times out
+    pub(crate) fn next_deadline(&self, timeout: Duration) -> Option<Instant> {
+        self.held.front().map(|(_, _, since)| *since + timeout)
+    }
+}
+
+/// Wait until another signal is processed or, when signals are held, the longest-held one times out
+///
+/// Never returns without a receiver, so loops without causal ordering can select on it.
+pub(crate) async fn wait_for_progress(progress: Option<&mut watch::Receiver<u64>>, deadline: Option<Instant>, clock: &SharedClock) {
+    let Some(progress) = progress else {
+        return std::future::pending().await;
+    };
+    match deadline {
+        Some(deadline) => tokio::select! {
+            _ = progress.changed() => {}
+            _ = crate::sleep_until(clock, deadline) => {}
+        },
+        None => {
+            let _ = progress.changed().await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SignalPayload, SignalType};
+
+    fn signal(dependencies: &[Uuid]) -> NeuralSignal {
+        let mut signal = NeuralSignal::new(SignalType::Cognitive, EntityId::new(), None, SignalPayload::Message("step".to_string()), 0.5);
+        signal.causal_dependencies = dependencies.to_vec();
+        signal
+    }
+
+    #[test]
+    fn test_held_signals_release_in_topological_order() {
+        let order = CausalOrder::new(Duration::from_secs(1));
+        let entity = EntityId::new();
+        let first = signal(&[]);
+        let second = signal(&[first.signal_id]);
+        let third = signal(&[second.signal_id, first.signal_id]);
+
+        let mut held = HeldSignals::new(emergence_physics::SystemClock::shared());
+        assert!(held.hold(entity, third.clone()).is_none());
+        assert!(held.hold(entity, second.clone()).is_none());
+        assert!(held.take_ready(&order).is_none());
+
+        let mut released = Vec::new();
+        order.mark_processed(first.signal_id);
+        while let Some((_, signal)) = held.take_ready(&order) {
+            order.mark_processed(signal.signal_id);
+            released.push(signal.signal_id);
+        }
+
+        assert_eq!(released, vec![second.signal_id, third.signal_id]);
+        assert!(held.is_empty());
+    }
+
+    #[test]
+    fn test_held_signals_fall_overdue_on_the_injected_clock() {
+        let clock = emergence_physics::MockClock::new();
+        let timeout = Duration::from_secs(30);
+        let mut held = HeldSignals::new(clock.shared());
+        let waiting = signal(&[Uuid::new_v4()]);
+        assert!(held.hold(EntityId::new(), waiting.clone()).is_none());
+        assert!(held.take_overdue(timeout).is_empty());
+
+        clock.advance(timeout);
+        let overdue = held.take_overdue(timeout);
+        assert_eq!(overdue.len(), 1);
+        assert_eq!(overdue[0].1.signal_id, waiting.signal_id);
+        assert!(held.is_empty());
+    }
+    #[test]
+    fn test_held_signals_are_bounded() {
+        let mut held = HeldSignals::new(emergence_physics::SystemClock::shared());
+        let entity = EntityId::new();
+        for _ in 0..MAX_HELD_SIGNALS {
+            assert!(held.hold(entity, signal(&[Uuid::new_v4()])).is_none());
+        }
+
+        let turned_away = signal(&[Uuid::new_v4()]);
+        let returned = held.hold(entity, turned_away.clone()).expect("the loop is full");
+        assert_eq!(returned.signal_id, turned_away.signal_id);
+        assert_eq!(held.held.len(), MAX_HELD_SIGNALS);
+    }
+}
@@ -0,0 +1,96 @@
+//! Compression of large binary signal payloads.
+//!
+//! Payloads are LZ4 blocks prefixed with their inflated length as a
+//! little-endian `u32`. The declared length comes from whoever sent the
+//! signal, so it is checked against a limit before anything is allocated.
+
+/// Largest payload [`decompress`] will inflate when no tighter limit is configured
+pub const MAX_INFLATED_BYTES: usize = 64 * 1024 * 1024;
+
+/// Compressed data that cannot be inflated
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum CompressionError {
+    #[error("Compressed payload is truncated")]
+    Truncated,
+
+    #[error("Compressed payload declares {declared} bytes, over the {limit} byte limit")]
+    TooLarge { declared: usize, limit: usize },
+
+    #[error("Compressed payload inflates to {actual} bytes but declares {declared}")]
+    LengthMismatch { declared: usize, actual: usize },
+
+    #[error("Compressed payload is corrupt: {0}")]
+    Corrupt(String),
+}
+
+/// Compress bytes into a length-prefixed LZ4 block
+pub fn compress(data: &[u8]) -> Vec<u8> {
+    lz4_flex::compress_prepend_size(data)
+}
+
+/// Length the compressed data declares it inflates to
+pub fn inflated_len(compressed: &[u8]) -> Result<usize, CompressionError> {
+    let header = compressed.get(..4).ok_or(CompressionError::Truncated)?;
+    Ok(u32::from_le_bytes(header.try_into().expect("four byte header")) as usize)
+}
+
+/// Inflate data produced by [`compress`], refusing anything that declares more than `limit` bytes
+pub fn decompress(compressed: &[u8], limit: usize) -> Result<Vec<u8>, CompressionError> {
+    let declared = inflated_len(compressed)?;
+    if declared > limit {
+        return Err(CompressionError::TooLarge { declared, limit });
+    }
+
+    let mut data = vec![0; declared];
+    let actual = lz4_flex::decompress_into(&compressed[4..], &mut data)
+        .map_err(|e| CompressionError::Corrupt(e.to_string()))?;
+    if actual != declared {
+        return Err(CompressionError::LengthMismatch { declared, actual });
+    }
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_and_corruption() {
+        let mut frame = vec![0u8; 4000];
+        frame.extend(b"header: sensor 7, reading 42".iter().copied());
+        frame.extend([0xff; 300]);
+        frame.extend((0..=255u8).collect::<Vec<_>>());
+
+        let compressed = compress(&frame);
+        assert!(compressed.len() < frame.len() / 5, "{} bytes", compressed.len());
+        assert_eq!(inflated_len(&compressed), Ok(frame.len()));
+        assert_eq!(decompress(&compressed, MAX_INFLATED_BYTES).unwrap(), frame);
+        assert_eq!(decompress(&compress(&[]), MAX_INFLATED_BYTES).unwrap(), Vec::<u8>::new());
+
+        assert!(decompress(&compressed[..compressed.len() - 1], MAX_INFLATED_BYTES).is_err());
+        assert_eq!(decompress(&compressed[..2], MAX_INFLATED_BYTES), Err(CompressionError::Truncated));
+        let mut understated = compressed.clone();
+        understated[..4].copy_from_slice(&10u32.to_le_bytes());
+        assert!(decompress(&understated, MAX_INFLATED_BYTES).is_err());
+        let mut overstated = compressed.clone();
+        overstated[..4].copy_from_slice(&(frame.len() as u32 + 10).to_le_bytes());
+        assert!(matches!(
+            decompress(&overstated, MAX_INFLATED_BYTES),
+            Err(CompressionError::LengthMismatch { actual, .. }) if actual == frame.len()
+        ));
+    }
+
+    #[test]
+    fn test_declared_length_is_capped_before_allocating() {
+        let mut forged = u32::MAX.to_le_bytes().to_vec();
+        forged.push(0);
+        assert_eq!(
+            decompress(&forged, 1024),
+            Err(CompressionError::TooLarge { declared: u32::MAX as usize, limit: 1024 })
+        );
+        assert_eq!(
+            decompress(&compress(&[1; 2048]), 1024),
+            Err(CompressionError::TooLarge { declared: 2048, limit: 1024 })
+        );
+    }
+}
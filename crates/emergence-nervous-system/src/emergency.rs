@@ -0,0 +1,142 @@
+//! Priority lane for `Emergency` signals.
+//!
+//! Emergency signals skip the per-second rate limit, and a source too short
+//! of energy to pay for one has it paid from a small reserve the nervous
+//! system holds back for that purpose. The reserve is a physics entity of its
+//! own, left out of load balancing: it is filled from the system's free
+//! energy the first time it is needed, spends by dissipating, and refills
+//! from free energy at a configured rate. So the lane cannot become a way
+//! around the normal limits, each source may only send a handful of
+//! emergencies per minute.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use emergence_physics::{EntityId, Physics, PhysicsOperation, PhysicsViolation, SharedClock};
+use ordered_float::OrderedFloat;
+use serde::{Deserialize, Serialize};
+use tokio::time::Instant;
+use tracing::{debug, warn};
+
+/// Window the emergency cap is counted over
+const CAP_WINDOW: Duration = Duration::from_secs(60);
+
+/// Limits on the emergency lane
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmergencyConfig {
+    /// Emergency signals each source may transmit per minute
+    pub max_per_minute: u32,
+    /// Energy set aside to pay for emergencies whose source cannot
+    pub energy_reserve: f64,
+    /// Energy per minute the reserve takes back from the system's free energy, up to `energy_reserve`
+    #[serde(default = "default_refill_per_minute")]
+    pub refill_per_minute: f64,
+}
+
+fn default_refill_per_minute() -> f64 {
+    EmergencyConfig::default().refill_per_minute
+}
+
+impl Default for EmergencyConfig {
+    fn default() -> Self {
+        Self {
+            max_per_minute: 10,
+            energy_reserve: 0.05,
+            refill_per_minute: 0.05,
+        }
+    }
+}
+
+/// Whether physics rejected a signal because its source lacked the energy, the only case the reserve pays for
+pub(crate) fn is_energy_shortfall(rejection: &anyhow::Error) -> bool {
+    rejection.chain().any(|cause| matches!(cause.downcast_ref(), Some(PhysicsViolation::EnergyConservation { .. })))
+}
+
+/// Emergency counts per source and the entity holding the reserve
+#[derive(Debug)]
+pub(crate) struct EmergencyLane {
+    max_per_minute: u32,
+    capacity: f64,
+    refill_per_minute: f64,
+    clock: SharedClock,
+    sent: Mutex<HashMap<EntityId, VecDeque<Instant>>>,
+    reserve: EntityId,
+    /// When the reserve was last topped up; `None` until it is first filled
+    refilled_at: Mutex<Option<Instant>>,
+}
+
+impl EmergencyLane {
+    pub(crate) fn new(config: &EmergencyConfig, clock: SharedClock) -> Self {
+        Self {
+            max_per_minute: config.max_per_minute,
+            capacity: config.energy_reserve,
+            refill_per_minute: config.refill_per_minute,
+            clock,
+            sent: Mutex::new(HashMap::new()),
+            reserve: EntityId::new(),
+            refilled_at: Mutex::new(None),
+        }
+    }
+
+    /// Count an emergency from `entity`, or return how long until it may send another
+    pub(crate) fn admit(&self, entity: EntityId) -> Result<(), Duration> {
+        let now = self.clock.now_instant();
+        let mut sent = self.sent.lock().unwrap();
+        let recent = sent.entry(entity).or_default();
+        while recent.front().is_some_and(|at| now.saturating_duration_since(*at) >= CAP_WINDOW) {
+            recent.pop_front();
+        }
+        if recent.len() >= self.max_per_minute as usize {
+            let retry_after = CAP_WINDOW.saturating_sub(now.saturating_duration_since(recent[0]));
+            warn!("Entity {} exceeded {} emergency signals per minute", entity, self.max_per_minute);
+            return Err(retry_after);
+        }
+        recent.push_back(now);
+        Ok(())
+    }
+
+    /// Spend `amount` from the reserve, if that much is left after refilling
+    pub(crate) async fn draw(&self, physics: &dyn Physics, amount: f64) -> bool {
+        self.refill(physics).await;
+        if physics.entity_energy(self.reserve).await.0 + 1e-12 < amount {
+            return false;
+        }
+        let operation = PhysicsOperation::DissipateEnergy { entity: self.reserve, amount: OrderedFloat(amount) };
+        match physics.execute_operation(operation).await {
+            Ok(result) => result.success,
+            Err(e) => {
+                warn!("Emergency reserve could not pay {}: {:#}", amount, e);
+                false
+            }
+        }
+    }
+
+    /// Fill the reserve on first use, then top it up for the time since it was last refilled
+    async fn refill(&self, physics: &dyn Physics) {
+        let now = self.clock.now_instant();
+        let refilled_at = self.refilled_at.lock().unwrap().replace(now);
+        let allowance = match refilled_at {
+            Some(at) => self.refill_per_minute * now.saturating_duration_since(at).as_secs_f64() / 60.0,
+            None => {
+                physics.exempt_from_load_balancing(self.reserve).await;
+                self.capacity
+            }
+        };
+        let held = physics.entity_energy(self.reserve).await.0;
+        let top_up = allowance.min(self.capacity - held);
+        if top_up > 0.0 {
+            if let Err(e) = physics.allocate_energy_to_entity(self.reserve, OrderedFloat(top_up)).await {
+                debug!("Emergency reserve not refilled: {:#}", e);
+            }
+        }
+    }
+
+    /// Energy the reserve holds, counting a reserve not yet filled as full
+    pub(crate) async fn reserve(&self, physics: &dyn Physics) -> f64 {
+        if self.refilled_at.lock().unwrap().is_none() {
+            return self.capacity;
+        }
+        physics.entity_energy(self.reserve).await.0
+    }
+}
@@ -0,0 +1,137 @@
+//! Interchangeable serialization formats for signals, payloads and schemas.
+//!
+//! YAML remains the native format, and the structured payload variants hold
+//! `serde_yaml::Value`s. JSON and MessagePack go through serde like YAML does,
+//! so anything that serializes with one serializes with all three. YAML tags,
+//! which the other formats cannot express, come back as single-key mappings.
+//!
+//! MessagePack is written with named struct fields and in serde's
+//! human-readable form, so identifiers and times are written as the same
+//! strings in every format.
+
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// Format data is written in or read from
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SerializationFormat {
+    #[default]
+    Yaml,
+    Json,
+    MessagePack,
+}
+
+/// Data that could not be written or read in a format
+#[derive(Debug, thiserror::Error)]
+pub enum FormatError {
+    #[error("YAML: {0}")]
+    Yaml(#[from] serde_yaml::Error),
+
+    #[error("JSON: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("MessagePack: {0}")]
+    MessagePackEncode(#[from] rmp_serde::encode::Error),
+
+    #[error("MessagePack: {0}")]
+    MessagePack(#[from] rmp_serde::decode::Error),
+}
+
+impl SerializationFormat {
+    /// Format conventionally used by files with this path's extension
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Self> {
+        match path.as_ref().extension()?.to_str()?.to_ascii_lowercase().as_str() {
+            "yaml" | "yml" => Some(SerializationFormat::Yaml),
+            "json" => Some(SerializationFormat::Json),
+            "msgpack" | "mpk" => Some(SerializationFormat::MessagePack),
+            _ => None,
+        }
+    }
+
+    /// Serialize a value in this format
+    pub fn to_vec<T: Serialize + ?Sized>(self, value: &T) -> Result<Vec<u8>, FormatError> {
+        match self {
+            SerializationFormat::Yaml => Ok(serde_yaml::to_string(value)?.into_bytes()),
+            SerializationFormat::Json => Ok(serde_json::to_vec(value)?),
+            SerializationFormat::MessagePack => {
+                let mut bytes = Vec::new();
+                let mut serializer = rmp_serde::Serializer::new(&mut bytes).with_struct_map().with_human_readable();
+                value.serialize(&mut serializer)?;
+                Ok(bytes)
+            }
+        }
+    }
+
+    /// Deserialize a value written in this format
+    pub fn from_slice<T: DeserializeOwned>(self, bytes: &[u8]) -> Result<T, FormatError> {
+        match self {
+            SerializationFormat::Yaml => Ok(serde_yaml::from_slice(bytes)?),
+            SerializationFormat::Json => Ok(serde_json::from_slice(bytes)?),
+            SerializationFormat::MessagePack => {
+                let mut input = bytes;
+                let value = T::deserialize(&mut rmp_serde::Deserializer::new(&mut input).with_human_readable())?;
+                if !input.is_empty() {
+                    let trailing = format!("{} trailing bytes", input.len());
+                    return Err(rmp_serde::decode::Error::Syntax(trailing).into());
+                }
+                Ok(value)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{NeuralSignal, SignalPayload, SignalType};
+    use emergence_physics::EntityId;
+
+    const FORMATS: [SerializationFormat; 3] = [
+        SerializationFormat::Yaml,
+        SerializationFormat::Json,
+        SerializationFormat::MessagePack,
+    ];
+
+    #[test]
+    fn test_signals_round_trip_through_every_format() {
+        let data: serde_yaml::Value = serde_yaml::from_str(
+            "reading: 42\noffset: -7000\nratio: 0.25\nlabels: [a, b]\nnested: {ok: true, missing: null}\nlong: 'a string longer than thirty-one bytes'",
+        ).unwrap();
+        let signal = NeuralSignal::new(SignalType::Sensory, EntityId::new(), EntityId::new(), SignalPayload::Data(data.clone()), 0.5)
+            .with_ttl(std::time::Duration::from_millis(1500));
+
+        for format in FORMATS {
+            let bytes = format.to_vec(&signal).unwrap();
+            let parsed: NeuralSignal = format.from_slice(&bytes).unwrap();
+            assert_eq!(parsed.signal_id, signal.signal_id, "{:?}", format);
+            assert_eq!(parsed.target, signal.target, "{:?}", format);
+            assert_eq!(parsed.ttl, signal.ttl, "{:?}", format);
+            assert!(matches!(&parsed.payload, SignalPayload::Data(value) if *value == data), "{:?}", format);
+        }
+    }
+
+    #[test]
+    fn test_message_pack_values_and_errors() {
+        let value = serde_json::json!({
+            "small": 5, "byte": 200, "wide": 70_000, "huge": u64::MAX,
+            "negative": -5, "below": -100, "lower": -40_000, "lowest": i64::MIN,
+            "float": 1.5, "text": "x".repeat(300), "items": (0..20).collect::<Vec<_>>(),
+        });
+        let bytes = SerializationFormat::MessagePack.to_vec(&value).unwrap();
+        assert_eq!(SerializationFormat::MessagePack.from_slice::<serde_json::Value>(&bytes).unwrap(), value);
+        assert_eq!(SerializationFormat::MessagePack.to_vec(&-1).unwrap(), vec![0xff]);
+
+        let truncated = SerializationFormat::MessagePack.from_slice::<serde_json::Value>(&bytes[..bytes.len() - 1]);
+        assert!(matches!(truncated, Err(FormatError::MessagePack(_))));
+        let integer_key = SerializationFormat::MessagePack.from_slice::<serde_json::Value>(&[0x81, 0x01, 0xc0]);
+        assert!(matches!(integer_key, Err(FormatError::MessagePack(_))));
+        let trailing = SerializationFormat::MessagePack.from_slice::<serde_json::Value>(&[0xc0, 0xc0]);
+        assert!(matches!(trailing, Err(FormatError::MessagePack(_))));
+
+        assert_eq!(SerializationFormat::from_path("agents/researcher.json"), Some(SerializationFormat::Json));
+        assert_eq!(SerializationFormat::from_path("agents/researcher.YML"), Some(SerializationFormat::Yaml));
+        assert_eq!(SerializationFormat::from_path("agents/researcher"), None);
+    }
+}
@@ -0,0 +1,51 @@
+//! Signal queues of entities that process their signals by hand.
+//!
+//! An entity registered with `register_manual_entity` has no processing
+//! loop; directed signals wait in its inbox until tooling reads them with
+//! `peek_inbox` or takes them with `drain_inbox`.
+
+use std::collections::VecDeque;
+
+use tokio::sync::mpsc;
+use tracing::warn;
+
+use crate::NeuralSignal;
+
+/// An entity's signal queue, with the signals already taken off it but not yet drained
+#[derive(Debug)]
+pub(crate) struct Inbox {
+    queue: mpsc::Receiver<NeuralSignal>,
+    /// Received signals in arrival order
+    received: VecDeque<NeuralSignal>,
+}
+
+impl Inbox {
+    pub(crate) fn new(queue: mpsc::Receiver<NeuralSignal>) -> Self {
+        Self {
+            queue,
+            received: VecDeque::new(),
+        }
+    }
+
+    /// Up to `max` waiting signals, oldest first, leaving them in the inbox
+    pub(crate) fn peek(&mut self, max: usize, max_payload_bytes: Option<usize>) -> Vec<NeuralSignal> {
+        self.receive(max_payload_bytes);
+        self.received.iter().take(max).cloned().collect()
+    }
+
+    /// Every waiting signal, oldest first, emptying the inbox
+    pub(crate) fn drain(&mut self, max_payload_bytes: Option<usize>) -> Vec<NeuralSignal> {
+        self.receive(max_payload_bytes);
+        self.received.drain(..).collect()
+    }
+
+    /// Move everything queued so far into `received`, inflating compressed payloads
+    fn receive(&mut self, max_payload_bytes: Option<usize>) {
+        while let Ok(mut signal) = self.queue.try_recv() {
+            match signal.inflate_payload(max_payload_bytes) {
+                Ok(()) => self.received.push_back(signal),
+                Err(e) => warn!("Dropping signal {} from inbox: {}", signal.signal_id, e),
+            }
+        }
+    }
+}
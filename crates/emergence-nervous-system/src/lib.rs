@@ -5,7 +5,10 @@
 //! implements event-driven patterns with physics-constrained signal propagation,
 //! neural pathway routing, and emergent behavior coordination.
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
@@ -13,29 +16,318 @@ use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
 use futures::{Stream, StreamExt};
 use serde::{Deserialize, Serialize};
-use tokio::sync::{broadcast, mpsc, RwLock};
+use tokio::sync::{broadcast, mpsc, Mutex, Notify, RwLock};
+use tokio::task::JoinHandle;
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
 use tokio_stream::wrappers::BroadcastStream;
-use tracing::{debug, error, info};
+use tokio_util::sync::{CancellationToken, DropGuard};
+use tracing::{debug, error, info, instrument, warn};
 use uuid::Uuid;
 
-use emergence_physics::{EntityId, PhysicsEngine, PhysicsOperation};
+use emergence_physics::{EntityId, Physics, PhysicsOperation, SharedClock, SystemClock};
+
+mod causal;
+mod emergency;
+mod inbox;
+pub mod compression;
+pub mod format;
+mod liveness;
+pub mod middleware;
+mod rate_limit;
+pub mod recording;
+pub mod scheduling;
+pub mod versioning;
+
+pub use recording::{RecordedSignal, RecorderHandle, SignalLog};
+pub use causal::MAX_REMEMBERED_SIGNALS;
+pub use compression::CompressionError;
+pub use emergency::EmergencyConfig;
+pub use format::{FormatError, SerializationFormat};
+pub use middleware::{MiddlewareAction, SignalMiddleware};
+use causal::{CausalOrder, HeldSignals};
+use emergency::{is_energy_shortfall, EmergencyLane};
+use inbox::Inbox;
+use liveness::Heartbeat;
+use rate_limit::SignalRateLimiter;
+use recording::SignalRecorder;
+pub use scheduling::{ProcessingConfig, ProcessingStrategy};
+use scheduling::EnergyWeightedQueue;
+pub use versioning::{migrate_signal, SignalVersionError, SIGNAL_VERSION};
 
 /// Core nervous system that coordinates event-driven communication
 pub struct NervousSystem {
     /// Physics engine for constraint enforcement
-    physics_engine: Arc<PhysicsEngine>,
+    physics_engine: Arc<dyn Physics>,
     /// Event broadcast channels for different signal types
-    signal_channels: Arc<RwLock<HashMap<SignalType, broadcast::Sender<NeuralSignal>>>>,
+    signal_channels: SignalChannels,
     /// Neural pathways for routing signals between entities
     neural_pathways: Arc<RwLock<HashMap<EntityId, HashSet<EntityId>>>>,
     /// Active signal processors for each entity
     signal_processors: Arc<RwLock<HashMap<EntityId, SignalProcessor>>>,
-    /// System configuration
-    config: NervousSystemConfig,
+    /// Background signal processing tasks for each entity
+    processing_tasks: Arc<RwLock<HashMap<EntityId, JoinHandle<()>>>>,
+    /// Processing progress for each entity, readable while a processor is stuck
+    heartbeats: Heartbeats,
+    /// System configuration, replaced whole by `update_config`
+    config: SharedConfig,
     /// System start time for relative timing
     genesis_time: Instant,
     /// Unique system instance identifier
     instance_id: Uuid,
+    /// Active signal recording, if any
+    recorder: Arc<std::sync::Mutex<Option<SignalRecorder>>>,
+    /// Directed signals that could not be delivered, oldest first
+    dead_letters: Arc<Mutex<VecDeque<DeadLetter>>>,
+    /// Named cohorts of entities that group signals are delivered to
+    groups: Groups,
+    /// Per-source throttle, present when the configuration sets a limit
+    rate_limiter: Option<Arc<SignalRateLimiter>>,
+    /// Cap and energy reserve for Emergency signals, which bypass the throttle
+    emergency: Arc<EmergencyLane>,
+    /// Hands new entity queues to the energy-weighted dispatcher, when that strategy is used
+    dispatcher_queues: Option<mpsc::UnboundedSender<(EntityId, mpsc::Receiver<NeuralSignal>)>>,
+    /// Wakes the energy-weighted dispatcher when a signal is queued
+    signal_ready: Arc<Notify>,
+    /// Processed signals that dependents wait on, when causal ordering is enabled
+    causal_order: Option<Arc<CausalOrder>>,
+    /// Entities cut off from new signals, with the reason
+    quarantines: Quarantines,
+    /// Source of the current time for expiry, throttling and dead letters
+    clock: SharedClock,
+    /// Traffic counters for each signal type
+    type_stats: SignalTypeCounters,
+    /// Attempts made on failed signals waiting to be retried
+    retry_attempts: RetryAttempts,
+    /// Hooks every transmitted signal passes through, in registration order
+    middleware: Arc<std::sync::RwLock<Vec<Box<dyn SignalMiddleware>>>>,
+    /// Signals waiting for their sources to afford them, oldest first
+    deferred_signals: Arc<Mutex<VecDeque<NeuralSignal>>>,
+    /// Queues of entities that process their signals by hand
+    inboxes: Arc<Mutex<HashMap<EntityId, Inbox>>>,
+    /// Processors' responses, transmitted in turn by the response task
+    responses: mpsc::UnboundedSender<NeuralSignal>,
+    /// Responses queued for the response task and not yet transmitted
+    pending_responses: Arc<AtomicUsize>,
+    /// Stops the system's background tasks when the owning handle is dropped
+    _background: Option<DropGuard>,
+}
+
+type Heartbeats = Arc<std::sync::RwLock<HashMap<EntityId, Arc<Heartbeat>>>>;
+
+type Groups = Arc<RwLock<HashMap<String, HashSet<EntityId>>>>;
+
+type Quarantines = Arc<RwLock<HashMap<EntityId, String>>>;
+
+type SignalTypeCounters = Arc<std::sync::Mutex<HashMap<SignalType, SignalTypeStats>>>;
+
+/// Configuration read by transmission and processing; each signal sees the snapshot current when it starts
+type SharedConfig = Arc<std::sync::RwLock<Arc<NervousSystemConfig>>>;
+
+/// Processing attempts made so far for signals that failed and are waiting to be retried
+type RetryAttempts = Arc<std::sync::Mutex<HashMap<Uuid, u32>>>;
+
+/// Shared state needed to process an entity's queued signals
+#[derive(Clone)]
+struct ProcessingContext {
+    signal_processors: Arc<RwLock<HashMap<EntityId, SignalProcessor>>>,
+    heartbeats: Heartbeats,
+    dead_letters: Arc<Mutex<VecDeque<DeadLetter>>>,
+    causal_order: Option<Arc<CausalOrder>>,
+    quarantines: Quarantines,
+    clock: SharedClock,
+    type_stats: SignalTypeCounters,
+    signal_ready: Arc<Notify>,
+    retry_attempts: RetryAttempts,
+    config: SharedConfig,
+    responses: mpsc::UnboundedSender<NeuralSignal>,
+    pending_responses: Arc<AtomicUsize>,
+}
+
+impl ProcessingContext {
+    fn config(&self) -> Arc<NervousSystemConfig> {
+        self.config.read().unwrap().clone()
+    }
+}
+
+/// Maximum undeliverable signals kept before the oldest are discarded
+pub const MAX_DEAD_LETTERS: usize = 1000;
+
+/// A directed signal that could not be delivered to its target
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeadLetter {
+    /// The undelivered signal
+    pub signal: NeuralSignal,
+    /// Why delivery failed
+    pub reason: DeadLetterReason,
+    /// When delivery was abandoned
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Why a directed signal could not be delivered
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DeadLetterReason {
+    /// The target is not registered with the nervous system
+    EntityNotFound,
+    /// The target's signal queue has closed
+    QueueClosed,
+    /// The signal's time to live passed before it was processed
+    Expired,
+    /// The signal targets a group that does not exist
+    GroupNotFound,
+    /// The signal's causal dependencies were not processed within the timeout
+    DependencyTimeout,
+    /// The target's processing loop was already holding as many signals waiting on their dependencies as it may
+    TooManyHeld,
+    /// The target is quarantined
+    Quarantined,
+    /// The target's processor failed on the signal and retrying it would not help
+    ProcessingFailed { attempts: u32, error: String },
+}
+
+/// Broadcast channels keyed by the signal type they carry
+type SignalChannels = Arc<RwLock<HashMap<SignalType, SignalChannel>>>;
+
+/// How a broadcast is charged when its source cannot pay for every subscriber
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BroadcastCostPolicy {
+    /// Reject the broadcast unless the source can pay to reach every subscriber
+    #[default]
+    AllOrNothing,
+    /// Reach as many subscribers as the source can pay for
+    ///
+    /// The paid subscribers are not chosen when the signal is sent: whichever
+    /// subscribers read it from the channel first see it, and later readers
+    /// skip it.
+    BestEffort,
+}
+
+/// What happens to a signal whose source cannot pay its energy cost
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DegradationPolicy {
+    /// Fail the transmission
+    #[default]
+    Fail,
+    /// Scale the signal's strength, reach and cost down to what the source can afford
+    Downgrade,
+    /// Hold the signal until a retry finds its source can pay, every
+    /// [`DEFERRED_RETRY_INTERVAL`] or on [`NervousSystem::retry_deferred_signals`]
+    QueueAndRetry,
+}
+
+/// Maximum signals held for their sources' energy to recover before new ones fail outright
+pub const MAX_DEFERRED_SIGNALS: usize = 1000;
+
+/// How often deferred signals are retried in the background
+pub const DEFERRED_RETRY_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How signals whose processing failed are tried again
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetryPolicy {
+    /// Times a signal is processed, counting the first attempt, before it is dead-lettered
+    pub max_attempts: u32,
+    /// Delay before a failed signal is queued again
+    pub backoff: Duration,
+    /// Whether a processing error is worth retrying; others are dead-lettered at once
+    #[serde(skip, default = "RetryPolicy::default_retryable")]
+    pub retryable: fn(&anyhow::Error) -> bool,
+}
+
+impl RetryPolicy {
+    /// Retry every error up to `max_attempts` attempts, waiting `backoff` between them
+    pub fn new(max_attempts: u32, backoff: Duration) -> Self {
+        Self {
+            max_attempts,
+            backoff,
+            retryable: Self::retry_all,
+        }
+    }
+    
+    /// Only retry errors `retryable` accepts
+    pub fn with_retryable(mut self, retryable: fn(&anyhow::Error) -> bool) -> Self {
+        self.retryable = retryable;
+        self
+    }
+    
+    /// Treat every error as transient
+    pub fn retry_all(_error: &anyhow::Error) -> bool {
+        true
+    }
+    
+    fn default_retryable() -> fn(&anyhow::Error) -> bool {
+        Self::retry_all
+    }
+}
+
+/// A signal on a broadcast channel, with the subscribers a partly paid broadcast may still reach
+#[derive(Debug, Clone)]
+struct ChannelSignal {
+    signal: NeuralSignal,
+    /// Remaining recipients; unlimited when `None`
+    remaining: Option<Arc<AtomicUsize>>,
+}
+
+impl ChannelSignal {
+    /// Take one recipient slot, returning whether the subscriber may see the signal
+    ///
+    /// Slots go to subscribers in the order they read the signal.
+    fn claim(&self) -> bool {
+        self.remaining.as_ref().is_none_or(|remaining| {
+            remaining.fetch_update(Ordering::AcqRel, Ordering::Acquire, |left| left.checked_sub(1)).is_ok()
+        })
+    }
+}
+
+/// Broadcast channel for one signal type with overflow accounting
+#[derive(Debug)]
+struct SignalChannel {
+    sender: broadcast::Sender<ChannelSignal>,
+    /// Ring buffer size; tokio rounds the requested capacity up to a power of two
+    capacity: usize,
+    /// Signals evicted before every subscriber had seen them
+    dropped: AtomicU64,
+}
+
+impl SignalChannel {
+    fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        Self {
+            sender,
+            capacity: capacity.next_power_of_two(),
+            dropped: AtomicU64::new(0),
+        }
+    }
+    
+    /// Broadcast a signal, counting the unseen signal it evicts when the buffer is full
+    ///
+    /// Sending with no subscribers is not a drop; targeted signals still reach
+    /// their entity through its processing queue. With a `reach`, only that
+    /// many subscribers see the signal.
+    fn send(&self, signal: NeuralSignal, reach: Option<usize>) {
+        let signal_type = signal.signal_type.clone();
+        let overflowing = self.sender.receiver_count() > 0 && self.sender.len() >= self.capacity;
+        let remaining = reach.map(|reach| Arc::new(AtomicUsize::new(reach)));
+        
+        if self.sender.send(ChannelSignal { signal, remaining }).is_err() {
+            debug!("No subscribers for {:?} signal", signal_type);
+        } else if overflowing {
+            let dropped = self.dropped.fetch_add(1, Ordering::Relaxed) + 1;
+            warn!("{:?} channel overflowed; lagging subscribers missed a signal ({} dropped so far)",
+                  signal_type, dropped);
+        }
+    }
+}
+
+/// Item yielded by a signal stream
+///
+/// Signals are carried unboxed so existing matches on `Signal` keep compiling;
+/// streams hand out one event at a time, so the enum's size does not matter.
+#[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
+pub enum SignalStreamEvent {
+    /// A signal addressed to the entity, one of its groups, or broadcast
+    Signal(NeuralSignal),
+    /// The stream fell behind and this many signals were skipped
+    Lagged { missed: u64 },
 }
 
 /// Configuration for the nervous system
@@ -53,6 +345,54 @@ pub struct NervousSystemConfig {
     pub enforce_physics: bool,
     /// Neural pathway formation threshold
     pub pathway_formation_threshold: f64,
+    /// Broadcast channel capacity per signal type; unlisted types use `max_concurrent_signals`
+    #[serde(default)]
+    pub channel_capacities: HashMap<SignalType, usize>,
+    /// Signals each source may transmit per second; unlimited when `None`
+    #[serde(default)]
+    pub max_signals_per_second_per_entity: Option<u32>,
+    /// Time to live for signals that do not set their own
+    #[serde(default)]
+    pub default_signal_ttl: Option<Duration>,
+    /// Send expired directed signals to the dead-letter queue instead of discarding them
+    #[serde(default)]
+    pub dead_letter_expired: bool,
+    /// How signal processing is scheduled across entities
+    #[serde(default)]
+    pub processing: ProcessingConfig,
+    /// Restart the processing tasks of entities that `check_liveness` finds stalled
+    #[serde(default)]
+    pub restart_stalled_tasks: bool,
+    /// How broadcasts are charged when the source cannot reach every subscriber
+    #[serde(default)]
+    pub broadcast_cost: BroadcastCostPolicy,
+    /// Hold signals until their causal dependencies are processed, for at most this long;
+    /// signals are processed in arrival order when `None`
+    #[serde(default)]
+    pub causal_dependency_timeout: Option<Duration>,
+    /// Quarantine an entity after this many processing errors in a row; never when `None`
+    #[serde(default)]
+    pub quarantine_after_errors: Option<u32>,
+    /// Largest binary payload, in inflated bytes, a signal may carry; unlimited when `None`
+    #[serde(default)]
+    pub max_payload_bytes: Option<usize>,
+    /// Compress binary payloads larger than this many bytes before routing them
+    #[serde(default)]
+    pub compress_payloads_over: Option<usize>,
+    /// What happens to signals whose source cannot pay their energy cost
+    #[serde(default)]
+    pub degradation: DegradationPolicy,
+    /// Discard responses that would be more than this many responses deep in their chain,
+    /// so entities answering each other cannot loop forever; unlimited when `None`
+    #[serde(default)]
+    pub max_response_depth: Option<u32>,
+    /// Queue signals whose processing failed again under this policy; failed signals are
+    /// dropped when `None`
+    #[serde(default)]
+    pub retry: Option<RetryPolicy>,
+    /// Per-source cap and energy reserve for Emergency signals
+    #[serde(default)]
+    pub emergency: EmergencyConfig,
 }
 
 /// Types of neural signals that can be transmitted
@@ -74,29 +414,93 @@ pub enum SignalType {
     Emergency,
 }
 
+impl SignalType {
+    /// Every signal type, each of which has its own broadcast channel
+    pub const ALL: [SignalType; 7] = [
+        SignalType::Sensory,
+        SignalType::Cognitive,
+        SignalType::Motor,
+        SignalType::Emotional,
+        SignalType::Memory,
+        SignalType::Coordination,
+        SignalType::Emergency,
+    ];
+}
+
+/// Recipients of a neural signal
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum SignalTarget {
+    /// A single entity
+    One(EntityId),
+    /// The members of a named group when the signal is routed
+    Group(String),
+    /// Every subscriber to the signal's channel
+    #[default]
+    Broadcast,
+}
+
+impl SignalTarget {
+    /// The entity targeted by a single-entity signal
+    pub fn entity(&self) -> Option<EntityId> {
+        match self {
+            SignalTarget::One(entity) => Some(*entity),
+            _ => None,
+        }
+    }
+    
+    pub fn is_broadcast(&self) -> bool {
+        *self == SignalTarget::Broadcast
+    }
+}
+
+impl From<EntityId> for SignalTarget {
+    fn from(entity: EntityId) -> Self {
+        SignalTarget::One(entity)
+    }
+}
+
+impl From<Option<EntityId>> for SignalTarget {
+    fn from(target: Option<EntityId>) -> Self {
+        target.map_or(SignalTarget::Broadcast, SignalTarget::One)
+    }
+}
+
 /// Neural signal with physics-constrained properties
+///
+/// Serialization is versioned; see [`versioning`].
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(remote = "Self")]
 pub struct NeuralSignal {
+    /// Wire format version, [`SIGNAL_VERSION`] for signals created here
+    pub version: u16,
     /// Unique signal identifier
     pub signal_id: Uuid,
+    /// Identifier shared by a signal and the responses and cascades it causes
+    pub correlation_id: Uuid,
     /// Signal type
     pub signal_type: SignalType,
     /// Source entity
     pub source: EntityId,
-    /// Target entity (None for broadcast)
-    pub target: Option<EntityId>,
+    /// Recipients of the signal
+    pub target: SignalTarget,
     /// Signal payload
     pub payload: SignalPayload,
     /// Signal strength (0.0 to 1.0)
     pub strength: f64,
     /// Signal propagation distance
     pub propagation_distance: u32,
-    /// Signal timestamp
+    /// Signal timestamp, restamped from the nervous system's clock on transmission
     pub timestamp: DateTime<Utc>,
     /// Energy cost for signal transmission
     pub energy_cost: f64,
     /// Causal dependencies
     pub causal_dependencies: Vec<Uuid>,
+    /// How long after `timestamp` the signal stays relevant; falls back to the configured default
+    #[serde(default)]
+    pub ttl: Option<Duration>,
+    /// Responses between this signal and the one that started its chain
+    #[serde(default)]
+    pub response_depth: u32,
 }
 
 /// Payload carried by neural signals
@@ -104,10 +508,12 @@ pub struct NeuralSignal {
 pub enum SignalPayload {
     /// Text-based message
     Message(String),
-    /// Structured data
+    /// Structured data; see [`format`] for how it converts to formats other than YAML
     Data(serde_yaml::Value),
     /// Binary data
     Binary(Vec<u8>),
+    /// Binary data packed with [`compression::compress`], inflated back to `Binary` on receipt
+    Compressed(Vec<u8>),
     /// Command/instruction
     Command(String),
     /// Query/request
@@ -120,6 +526,27 @@ pub enum SignalPayload {
     StateUpdate(serde_yaml::Value),
 }
 
+impl SignalPayload {
+    /// Size of a binary payload once inflated; `None` for other payloads
+    pub fn binary_len(&self) -> Result<Option<usize>, CompressionError> {
+        match self {
+            SignalPayload::Binary(bytes) => Ok(Some(bytes.len())),
+            SignalPayload::Compressed(compressed) => compression::inflated_len(compressed).map(Some),
+            _ => Ok(None),
+        }
+    }
+    
+    /// Reject a binary payload larger than `limit` once inflated
+    pub fn check_size(&self, limit: Option<usize>) -> Result<(), NervousSystemError> {
+        if let (Some(limit), Some(size)) = (limit, self.binary_len()?) {
+            if size > limit {
+                return Err(NervousSystemError::PayloadTooLarge { size, limit });
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Signal processor for individual entities
 pub struct SignalProcessor {
     /// Entity identifier
@@ -127,11 +554,11 @@ pub struct SignalProcessor {
     /// Signal processing capabilities
     pub capabilities: HashSet<SignalType>,
     /// Signal processing function
-    pub processor: Box<dyn SignalProcessorFn + Send + Sync>,
+    pub processor: EntityProcessor,
     /// Current signal queue
     pub signal_queue: mpsc::Sender<NeuralSignal>,
-    /// Processing statistics
-    pub stats: ProcessingStats,
+    /// Processing statistics, recorded without locking the processor map
+    pub stats: Arc<std::sync::Mutex<ProcessingStats>>,
 }
 
 /// Signal processing function signature
@@ -139,6 +566,42 @@ pub trait SignalProcessorFn {
     fn process_signal(&self, signal: &NeuralSignal) -> Result<Option<NeuralSignal>>;
 }
 
+/// Future returned by an [`AsyncSignalProcessorFn`]
+pub type SignalProcessorFuture<'a> = Pin<Box<dyn Future<Output = Result<Option<NeuralSignal>>> + Send + 'a>>;
+
+/// Signal processing function that awaits its work, such as a model inference
+pub trait AsyncSignalProcessorFn: Send + Sync {
+    fn process_signal<'a>(&'a self, signal: &'a NeuralSignal) -> SignalProcessorFuture<'a>;
+}
+
+/// Processing function registered for an entity
+#[derive(Clone)]
+pub enum EntityProcessor {
+    /// Runs to completion on the processing task
+    Sync(Arc<dyn SignalProcessorFn + Send + Sync>),
+    /// Awaited on the processing task, so it yields while it waits
+    Async(Arc<dyn AsyncSignalProcessorFn>),
+}
+
+impl EntityProcessor {
+    /// Process a signal with whichever kind of function is registered
+    pub async fn process(&self, signal: &NeuralSignal) -> Result<Option<NeuralSignal>> {
+        match self {
+            EntityProcessor::Sync(processor) => processor.process_signal(signal),
+            EntityProcessor::Async(processor) => processor.process_signal(signal).await,
+        }
+    }
+}
+
+/// Stand-in processor for manually processed entities, which no processing loop calls
+struct ManualProcessing;
+
+impl SignalProcessorFn for ManualProcessing {
+    fn process_signal(&self, _signal: &NeuralSignal) -> Result<Option<NeuralSignal>> {
+        Ok(None)
+    }
+}
+
 /// Signal processing statistics
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProcessingStats {
@@ -148,6 +611,15 @@ pub struct ProcessingStats {
     pub avg_processing_time: Duration,
     /// Error count
     pub error_count: u64,
+    /// Errors since the last successfully processed signal
+    #[serde(default)]
+    pub consecutive_errors: u64,
+    /// Signals dropped unprocessed because their time to live had passed
+    #[serde(default)]
+    pub expired_signals: u64,
+    /// Failed signals queued for another attempt
+    #[serde(default)]
+    pub retried: u64,
     /// Last processing timestamp
     pub last_processed: Option<DateTime<Utc>>,
 }
@@ -165,6 +637,255 @@ pub struct NervousSystemResult {
     pub energy_consumed: f64,
     /// Signals generated
     pub signals_generated: u32,
+    /// Entities or subscribers the signal was delivered to
+    pub recipients: usize,
+}
+
+/// Invalid nervous system configuration values
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ConfigError {
+    #[error("Signal decay rate {rate} must be between 0.0 and 1.0")]
+    DecayRateOutOfRange { rate: f64 },
+    
+    #[error("Channel capacity must be at least 1 (signal type: {signal_type:?})")]
+    ZeroCapacity { signal_type: Option<SignalType> },
+    
+    #[error("Signal timeout must be greater than zero")]
+    ZeroTimeout,
+    
+    #[error("Pathway formation threshold {threshold} must be between 0.0 and 1.0")]
+    FormationThresholdOutOfRange { threshold: f64 },
+    
+    #[error("Per-entity signal rate limit must be at least 1 per second")]
+    ZeroRateLimit,
+    
+    #[error("Default signal TTL must be greater than zero")]
+    ZeroTtl,
+    
+    #[error("Causal dependency timeout must be greater than zero")]
+    ZeroCausalTimeout,
+    
+    #[error("Auto-quarantine must wait for at least one processing error")]
+    ZeroQuarantineThreshold,
+    
+    #[error("Maximum payload size must be at least one byte")]
+    ZeroPayloadLimit,
+    
+    #[error("Retry policy must allow at least one processing attempt")]
+    ZeroRetryAttempts,
+    
+    #[error("Emergency signals must be allowed at least once per minute")]
+    ZeroEmergencyLimit,
+    
+    #[error("Emergency energy reserve {reserve} must not be negative")]
+    NegativeEmergencyReserve { reserve: f64 },
+    
+    #[error("Emergency reserve refill {refill} per minute must not be negative")]
+    NegativeEmergencyRefill { refill: f64 },
+}
+
+/// Builder for a validated [`NervousSystemConfig`]
+#[derive(Debug, Clone, Default)]
+pub struct NervousSystemConfigBuilder {
+    config: NervousSystemConfig,
+}
+
+impl NervousSystemConfigBuilder {
+    /// Create a builder starting from the default configuration
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Set the maximum signal propagation distance
+    pub fn max_propagation_distance(mut self, distance: u32) -> Self {
+        self.config.max_propagation_distance = distance;
+        self
+    }
+    
+    /// Set the signal decay rate per hop
+    pub fn signal_decay_rate(mut self, rate: f64) -> Self {
+        self.config.signal_decay_rate = rate;
+        self
+    }
+    
+    /// Set the default channel and queue capacity
+    pub fn max_concurrent_signals(mut self, capacity: usize) -> Self {
+        self.config.max_concurrent_signals = capacity;
+        self
+    }
+    
+    /// Set the broadcast channel capacity for one signal type
+    pub fn channel_capacity(mut self, signal_type: SignalType, capacity: usize) -> Self {
+        self.config.channel_capacities.insert(signal_type, capacity);
+        self
+    }
+    
+    /// Set the signal processing timeout
+    pub fn signal_timeout(mut self, timeout: Duration) -> Self {
+        self.config.signal_timeout = timeout;
+        self
+    }
+    
+    /// Enable or disable physics constraint enforcement
+    pub fn enforce_physics(mut self, enforce: bool) -> Self {
+        self.config.enforce_physics = enforce;
+        self
+    }
+    
+    /// Set the neural pathway formation threshold
+    pub fn pathway_formation_threshold(mut self, threshold: f64) -> Self {
+        self.config.pathway_formation_threshold = threshold;
+        self
+    }
+    
+    /// Limit how many signals each source entity may transmit per second
+    pub fn max_signals_per_second_per_entity(mut self, limit: u32) -> Self {
+        self.config.max_signals_per_second_per_entity = Some(limit);
+        self
+    }
+    
+    /// Set the time to live for signals that do not set their own
+    pub fn default_signal_ttl(mut self, ttl: Duration) -> Self {
+        self.config.default_signal_ttl = Some(ttl);
+        self
+    }
+    
+    /// Choose whether expired directed signals go to the dead-letter queue
+    pub fn dead_letter_expired(mut self, enabled: bool) -> Self {
+        self.config.dead_letter_expired = enabled;
+        self
+    }
+    
+    /// Restart stalled processing tasks when `check_liveness` finds them
+    pub fn restart_stalled_tasks(mut self, enabled: bool) -> Self {
+        self.config.restart_stalled_tasks = enabled;
+        self
+    }
+    
+    /// Choose how signal processing is scheduled across entities
+    pub fn processing_strategy(mut self, strategy: ProcessingStrategy) -> Self {
+        self.config.processing.strategy = strategy;
+        self
+    }
+    
+    /// Choose how broadcasts are charged when the source cannot reach every subscriber
+    pub fn broadcast_cost_policy(mut self, policy: BroadcastCostPolicy) -> Self {
+        self.config.broadcast_cost = policy;
+        self
+    }
+    
+    /// Choose what happens to signals whose source cannot pay their energy cost
+    pub fn degradation_policy(mut self, policy: DegradationPolicy) -> Self {
+        self.config.degradation = policy;
+        self
+    }
+    
+    /// Process signals only after their causal dependencies, waiting at most `timeout` for them
+    pub fn causal_ordering(mut self, timeout: Duration) -> Self {
+        self.config.causal_dependency_timeout = Some(timeout);
+        self
+    }
+    
+    /// Quarantine entities whose processor fails this many times in a row
+    pub fn quarantine_after_errors(mut self, errors: u32) -> Self {
+        self.config.quarantine_after_errors = Some(errors);
+        self
+    }
+    
+    /// Reject signals whose binary payload inflates to more than `bytes`
+    pub fn max_payload_bytes(mut self, bytes: usize) -> Self {
+        self.config.max_payload_bytes = Some(bytes);
+        self
+    }
+    
+    /// Compress binary payloads larger than `bytes` before routing them
+    pub fn compress_payloads_over(mut self, bytes: usize) -> Self {
+        self.config.compress_payloads_over = Some(bytes);
+        self
+    }
+    
+    /// Stop answering once a chain of responses reaches `depth`
+    pub fn max_response_depth(mut self, depth: u32) -> Self {
+        self.config.max_response_depth = Some(depth);
+        self
+    }
+    
+    /// Retry signals whose processing fails under `policy`
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.config.retry = Some(policy);
+        self
+    }
+    
+    /// Let each source send `max_per_minute` Emergency signals, paid from
+    /// `energy_reserve` when the source cannot afford them
+    pub fn emergency_lane(mut self, max_per_minute: u32, energy_reserve: f64) -> Self {
+        self.config.emergency = EmergencyConfig { max_per_minute, energy_reserve, ..self.config.emergency };
+        self
+    }
+    
+    /// Refill the emergency reserve by `per_minute` energy from the system's free energy
+    pub fn emergency_refill(mut self, per_minute: f64) -> Self {
+        self.config.emergency.refill_per_minute = per_minute;
+        self
+    }
+    
+    /// Validate and return the configuration
+    pub fn build(self) -> Result<NervousSystemConfig, ConfigError> {
+        self.config.validate()?;
+        Ok(self.config)
+    }
+}
+
+/// Settings to change on a running nervous system; `None` fields are left as they are
+///
+/// Fields holding an optional setting take `Some(None)` to switch it off.
+/// Channel capacities, rate limits, the emergency lane, the processing
+/// strategy and causal ordering are fixed when the system is built and cannot
+/// be changed here.
+#[derive(Debug, Clone, Default)]
+pub struct NervousSystemConfigUpdate {
+    pub max_propagation_distance: Option<u32>,
+    pub signal_decay_rate: Option<f64>,
+    pub signal_timeout: Option<Duration>,
+    pub enforce_physics: Option<bool>,
+    pub pathway_formation_threshold: Option<f64>,
+    pub default_signal_ttl: Option<Option<Duration>>,
+    pub dead_letter_expired: Option<bool>,
+    pub restart_stalled_tasks: Option<bool>,
+    pub broadcast_cost: Option<BroadcastCostPolicy>,
+    pub degradation: Option<DegradationPolicy>,
+    pub quarantine_after_errors: Option<Option<u32>>,
+    pub max_payload_bytes: Option<Option<usize>>,
+    pub compress_payloads_over: Option<Option<usize>>,
+    pub max_response_depth: Option<Option<u32>>,
+    pub retry: Option<Option<RetryPolicy>>,
+}
+
+impl NervousSystemConfigUpdate {
+    /// Overwrite the settings this update names
+    fn apply_to(self, config: &mut NervousSystemConfig) {
+        fn set<T>(setting: &mut T, value: Option<T>) {
+            if let Some(value) = value {
+                *setting = value;
+            }
+        }
+        
+        set(&mut config.max_propagation_distance, self.max_propagation_distance);
+        set(&mut config.signal_decay_rate, self.signal_decay_rate);
+        set(&mut config.signal_timeout, self.signal_timeout);
+        set(&mut config.enforce_physics, self.enforce_physics);
+        set(&mut config.pathway_formation_threshold, self.pathway_formation_threshold);
+        set(&mut config.default_signal_ttl, self.default_signal_ttl);
+        set(&mut config.dead_letter_expired, self.dead_letter_expired);
+        set(&mut config.restart_stalled_tasks, self.restart_stalled_tasks);
+        set(&mut config.broadcast_cost, self.broadcast_cost);
+        set(&mut config.degradation, self.degradation);
+        set(&mut config.quarantine_after_errors, self.quarantine_after_errors);
+        set(&mut config.max_payload_bytes, self.max_payload_bytes);
+        set(&mut config.compress_payloads_over, self.compress_payloads_over);
+        set(&mut config.max_response_depth, self.max_response_depth);
+        set(&mut config.retry, self.retry);
+    }
 }
 
 /// Nervous system errors
@@ -189,6 +910,63 @@ pub enum NervousSystemError {
     /// Invalid signal configuration
     #[error("Invalid signal configuration: {reason}")]
     InvalidConfiguration { reason: String },
+    
+    /// Source exceeded its per-second signal limit
+    #[error("Entity {entity} is sending signals too fast; retry after {retry_after:?}")]
+    RateLimited { entity: EntityId, retry_after: Duration },
+    
+    /// Source exceeded its per-minute Emergency signal cap
+    #[error("Entity {entity} has sent too many emergency signals; retry after {retry_after:?}")]
+    EmergencyCapExceeded { entity: EntityId, retry_after: Duration },
+    
+    /// No group has this name
+    #[error("Group '{name}' not found in nervous system")]
+    GroupNotFound { name: String },
+    
+    /// A group with this name already exists
+    #[error("Group '{name}' already exists")]
+    GroupExists { name: String },
+    
+    /// The source cannot pay to reach every subscriber of a broadcast
+    #[error("Entity {entity} cannot afford to broadcast to {recipients} subscribers: needs {required} energy, has {available}")]
+    BroadcastUnaffordable { entity: EntityId, recipients: usize, required: f64, available: f64 },
+    
+    /// A binary payload is larger than the configured maximum
+    #[error("Signal payload of {size} bytes exceeds the {limit} byte limit")]
+    PayloadTooLarge { size: usize, limit: usize },
+    
+    /// A compressed payload cannot be inflated
+    #[error("Signal payload is corrupt: {source}")]
+    CorruptPayload {
+        #[from]
+        source: CompressionError,
+    },
+    
+    /// Middleware refused to let the signal through
+    #[error("Signal rejected by middleware: {reason}")]
+    SignalRejected { reason: String },
+}
+
+impl ProcessingStats {
+    /// Record the outcome of processing one signal, finished at `now`
+    pub fn record(&mut self, duration: Duration, success: bool, now: DateTime<Utc>) {
+        let previous_total = self.avg_processing_time * self.signals_processed as u32;
+        self.signals_processed += 1;
+        self.avg_processing_time = (previous_total + duration) / self.signals_processed as u32;
+        if success {
+            self.consecutive_errors = 0;
+        } else {
+            self.error_count += 1;
+            self.consecutive_errors += 1;
+        }
+        self.last_processed = Some(now);
+    }
+    
+    /// Count a signal the entity was refused for sending too fast as a failure
+    pub fn record_violation(&mut self) {
+        self.error_count += 1;
+        self.consecutive_errors += 1;
+    }
 }
 
 impl Default for NervousSystemConfig {
@@ -200,68 +978,317 @@ impl Default for NervousSystemConfig {
             signal_timeout: Duration::from_secs(30),
             enforce_physics: true,
             pathway_formation_threshold: 0.5,
+            channel_capacities: HashMap::new(),
+            max_signals_per_second_per_entity: None,
+            default_signal_ttl: None,
+            dead_letter_expired: false,
+            processing: ProcessingConfig::default(),
+            restart_stalled_tasks: false,
+            broadcast_cost: BroadcastCostPolicy::default(),
+            causal_dependency_timeout: None,
+            quarantine_after_errors: None,
+            max_payload_bytes: None,
+            compress_payloads_over: None,
+            degradation: DegradationPolicy::default(),
+            max_response_depth: None,
+            retry: None,
+            emergency: EmergencyConfig::default(),
+        }
+    }
+}
+
+impl NervousSystemConfig {
+    /// Start building a validated configuration from the defaults
+    pub fn builder() -> NervousSystemConfigBuilder {
+        NervousSystemConfigBuilder::new()
+    }
+    
+    /// Check that every setting is within its meaningful range
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if !(0.0..=1.0).contains(&self.signal_decay_rate) {
+            return Err(ConfigError::DecayRateOutOfRange { rate: self.signal_decay_rate });
+        }
+        if self.max_concurrent_signals == 0 {
+            return Err(ConfigError::ZeroCapacity { signal_type: None });
+        }
+        if let Some((signal_type, _)) = self.channel_capacities.iter().find(|(_, capacity)| **capacity == 0) {
+            return Err(ConfigError::ZeroCapacity { signal_type: Some(signal_type.clone()) });
+        }
+        if self.signal_timeout.is_zero() {
+            return Err(ConfigError::ZeroTimeout);
+        }
+        if !(0.0..=1.0).contains(&self.pathway_formation_threshold) {
+            return Err(ConfigError::FormationThresholdOutOfRange { threshold: self.pathway_formation_threshold });
+        }
+        if self.max_signals_per_second_per_entity == Some(0) {
+            return Err(ConfigError::ZeroRateLimit);
         }
+        if self.default_signal_ttl.is_some_and(|ttl| ttl.is_zero()) {
+            return Err(ConfigError::ZeroTtl);
+        }
+        if self.causal_dependency_timeout.is_some_and(|timeout| timeout.is_zero()) {
+            return Err(ConfigError::ZeroCausalTimeout);
+        }
+        if self.quarantine_after_errors == Some(0) {
+            return Err(ConfigError::ZeroQuarantineThreshold);
+        }
+        if self.max_payload_bytes == Some(0) {
+            return Err(ConfigError::ZeroPayloadLimit);
+        }
+        if self.retry.as_ref().is_some_and(|retry| retry.max_attempts == 0) {
+            return Err(ConfigError::ZeroRetryAttempts);
+        }
+        if self.emergency.max_per_minute == 0 {
+            return Err(ConfigError::ZeroEmergencyLimit);
+        }
+        if self.emergency.energy_reserve < 0.0 {
+            return Err(ConfigError::NegativeEmergencyReserve { reserve: self.emergency.energy_reserve });
+        }
+        if self.emergency.refill_per_minute < 0.0 {
+            return Err(ConfigError::NegativeEmergencyRefill { refill: self.emergency.refill_per_minute });
+        }
+        Ok(())
+    }
+    
+    /// Broadcast channel capacity for a signal type
+    pub fn channel_capacity(&self, signal_type: &SignalType) -> usize {
+        self.channel_capacities
+            .get(signal_type)
+            .copied()
+            .unwrap_or(self.max_concurrent_signals)
     }
 }
 
 impl NervousSystem {
     /// Create a new nervous system with physics engine integration
-    pub async fn new(physics_engine: Arc<PhysicsEngine>) -> Result<Self> {
-        let config = NervousSystemConfig::default();
+    ///
+    /// Any [`Physics`] implementation is accepted, so tests can pass a stub
+    /// such as `MockPhysicsEngine` in place of a real engine.
+    pub async fn new(physics_engine: Arc<dyn Physics>) -> Result<Self> {
+        Self::with_config(physics_engine, NervousSystemConfig::default()).await
+    }
+    
+    /// Create a nervous system with a custom configuration
+    ///
+    /// The configuration is validated, so one built by hand is held to the
+    /// same rules as [`NervousSystemConfigBuilder::build`].
+    pub async fn with_config(physics_engine: Arc<dyn Physics>, config: NervousSystemConfig) -> Result<Self> {
+        Self::with_clock(physics_engine, config, SystemClock::shared()).await
+    }
+    
+    /// Create a nervous system that reads the time from `clock`
+    pub async fn with_clock(physics_engine: Arc<dyn Physics>, config: NervousSystemConfig, clock: SharedClock) -> Result<Self> {
+        config.validate()?;
         let instance_id = Uuid::new_v4();
         let genesis_time = Instant::now();
         
         // Initialize signal channels for each signal type
         let mut signal_channels = HashMap::new();
-        for signal_type in [
-            SignalType::Sensory,
-            SignalType::Cognitive,
-            SignalType::Motor,
-            SignalType::Emotional,
-            SignalType::Memory,
-            SignalType::Coordination,
-            SignalType::Emergency,
-        ] {
-            let (tx, _) = broadcast::channel(config.max_concurrent_signals);
-            signal_channels.insert(signal_type, tx);
+        for signal_type in SignalType::ALL {
+            let capacity = config.channel_capacity(&signal_type);
+            signal_channels.insert(signal_type, SignalChannel::new(capacity));
         }
         
         info!("Initializing nervous system with instance ID: {}", instance_id);
         
-        Ok(Self {
+        let signal_channels = Arc::new(RwLock::new(signal_channels));
+        let signal_processors = Arc::new(RwLock::new(HashMap::new()));
+        let heartbeats: Heartbeats = Arc::new(std::sync::RwLock::new(HashMap::new()));
+        let dead_letters = Arc::new(Mutex::new(VecDeque::new()));
+        let signal_ready = Arc::new(Notify::new());
+        let causal_order = config.causal_dependency_timeout.map(|timeout| Arc::new(CausalOrder::new(timeout)));
+        let quarantines: Quarantines = Arc::new(RwLock::new(HashMap::new()));
+        let type_stats: SignalTypeCounters = Arc::new(std::sync::Mutex::new(HashMap::new()));
+        let retry_attempts: RetryAttempts = Arc::new(std::sync::Mutex::new(HashMap::new()));
+        let shared_config: SharedConfig = Arc::new(std::sync::RwLock::new(Arc::new(config.clone())));
+        let (responses, response_queue) = mpsc::unbounded_channel();
+        let pending_responses = Arc::new(AtomicUsize::new(0));
+        let background = CancellationToken::new();
+        
+        let dispatcher_queues = match config.processing.strategy {
+            ProcessingStrategy::PerEntity => None,
+            ProcessingStrategy::EnergyWeighted => {
+                let (tx, rx) = mpsc::unbounded_channel();
+                let context = ProcessingContext {
+                    signal_processors: signal_processors.clone(),
+                    heartbeats: heartbeats.clone(),
+                    dead_letters: dead_letters.clone(),
+                    causal_order: causal_order.clone(),
+                    quarantines: quarantines.clone(),
+                    clock: clock.clone(),
+                    type_stats: type_stats.clone(),
+                    signal_ready: signal_ready.clone(),
+                    retry_attempts: retry_attempts.clone(),
+                    config: shared_config.clone(),
+                    responses: responses.clone(),
+                    pending_responses: pending_responses.clone(),
+                };
+                tokio::spawn(Self::dispatch_energy_weighted(physics_engine.clone(), context, rx, signal_ready.clone(), background.clone()));
+                Some(tx)
+            }
+        };
+        
+        let nervous_system = Self {
             physics_engine,
-            signal_channels: Arc::new(RwLock::new(signal_channels)),
+            signal_channels,
             neural_pathways: Arc::new(RwLock::new(HashMap::new())),
-            signal_processors: Arc::new(RwLock::new(HashMap::new())),
-            config,
+            signal_processors,
+            processing_tasks: Arc::new(RwLock::new(HashMap::new())),
+            heartbeats,
             genesis_time,
             instance_id,
-        })
+            recorder: Arc::new(std::sync::Mutex::new(None)),
+            dead_letters,
+            groups: Arc::new(RwLock::new(HashMap::new())),
+            rate_limiter: config.max_signals_per_second_per_entity
+                .map(|limit| Arc::new(SignalRateLimiter::new(limit, clock.clone()))),
+            emergency: Arc::new(EmergencyLane::new(&config.emergency, clock.clone())),
+            dispatcher_queues,
+            signal_ready,
+            causal_order,
+            quarantines,
+            clock,
+            type_stats,
+            retry_attempts,
+            middleware: Arc::new(std::sync::RwLock::new(Vec::new())),
+            deferred_signals: Arc::new(Mutex::new(VecDeque::new())),
+            inboxes: Arc::new(Mutex::new(HashMap::new())),
+            config: shared_config,
+            responses,
+            pending_responses,
+            _background: Some(background.clone().drop_guard()),
+        };
+        tokio::spawn(Self::transmit_responses(nervous_system.share(), response_queue, background.clone()));
+        tokio::spawn(Self::retry_deferred(nervous_system.share(), background));
+        Ok(nervous_system)
     }
     
-    /// Register an entity with the nervous system
-    pub async fn register_entity(
-        &self,
-        entity_id: EntityId,
-        capabilities: HashSet<SignalType>,
-        processor: Box<dyn SignalProcessorFn + Send + Sync>,
-    ) -> Result<()> {
-        info!("Registering entity {} with capabilities: {:?}", entity_id, capabilities);
-        
-        // Create signal queue for the entity
-        let (tx, rx) = mpsc::channel(self.config.max_concurrent_signals);
+    /// Another handle on this system's state, for its background tasks
+    ///
+    /// The handle does not keep those tasks running once the owner is dropped.
+    fn share(&self) -> Self {
+        Self {
+            physics_engine: self.physics_engine.clone(),
+            signal_channels: self.signal_channels.clone(),
+            neural_pathways: self.neural_pathways.clone(),
+            signal_processors: self.signal_processors.clone(),
+            processing_tasks: self.processing_tasks.clone(),
+            heartbeats: self.heartbeats.clone(),
+            config: self.config.clone(),
+            genesis_time: self.genesis_time,
+            instance_id: self.instance_id,
+            recorder: self.recorder.clone(),
+            dead_letters: self.dead_letters.clone(),
+            groups: self.groups.clone(),
+            rate_limiter: self.rate_limiter.clone(),
+            emergency: self.emergency.clone(),
+            dispatcher_queues: self.dispatcher_queues.clone(),
+            signal_ready: self.signal_ready.clone(),
+            causal_order: self.causal_order.clone(),
+            quarantines: self.quarantines.clone(),
+            clock: self.clock.clone(),
+            type_stats: self.type_stats.clone(),
+            retry_attempts: self.retry_attempts.clone(),
+            middleware: self.middleware.clone(),
+            deferred_signals: self.deferred_signals.clone(),
+            inboxes: self.inboxes.clone(),
+            responses: self.responses.clone(),
+            pending_responses: self.pending_responses.clone(),
+            _background: None,
+        }
+    }
+    
+    /// Transmit processors' responses like any other signal until the system is dropped
+    ///
+    /// Responses pass through one task rather than each processing task, so an
+    /// entity waiting for room in a full queue never holds up its own processing,
+    /// and two entities answering each other cannot deadlock.
+    async fn transmit_responses(
+        nervous_system: NervousSystem,
+        mut responses: mpsc::UnboundedReceiver<NeuralSignal>,
+        background: CancellationToken,
+    ) {
+        loop {
+            let response = tokio::select! {
+                _ = background.cancelled() => break,
+                response = responses.recv() => match response {
+                    Some(response) => response,
+                    None => break,
+                },
+            };
+            let (signal_id, source) = (response.signal_id, response.source);
+            if let Err(e) = nervous_system.transmit_signal(response).await {
+                warn!("Response signal {} from {} was not transmitted: {:#}", signal_id, source, e);
+            }
+            nervous_system.pending_responses.fetch_sub(1, Ordering::AcqRel);
+        }
+        debug!("Response transmission stopped");
+    }
+    
+    /// Retry deferred signals every [`DEFERRED_RETRY_INTERVAL`] until the system is dropped
+    async fn retry_deferred(nervous_system: NervousSystem, background: CancellationToken) {
+        let mut ticks = tokio::time::interval(DEFERRED_RETRY_INTERVAL);
+        ticks.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            tokio::select! {
+                _ = background.cancelled() => break,
+                _ = ticks.tick() => {}
+            }
+            if nervous_system.deferred_signals.lock().await.is_empty() {
+                continue;
+            }
+            if let Err(e) = nervous_system.retry_deferred_signals().await {
+                warn!("Deferred signals could not be retried: {:#}", e);
+            }
+        }
+        debug!("Deferred signal retries stopped");
+    }
+    
+    /// Register an entity with the nervous system
+    pub async fn register_entity(
+        &self,
+        entity_id: EntityId,
+        capabilities: HashSet<SignalType>,
+        processor: Box<dyn SignalProcessorFn + Send + Sync>,
+    ) -> Result<()> {
+        self.register_processor(entity_id, capabilities, EntityProcessor::Sync(Arc::from(processor))).await
+    }
+    
+    /// Register an entity whose processor awaits its work
+    pub async fn register_async_entity(
+        &self,
+        entity_id: EntityId,
+        capabilities: HashSet<SignalType>,
+        processor: Arc<dyn AsyncSignalProcessorFn>,
+    ) -> Result<()> {
+        self.register_processor(entity_id, capabilities, EntityProcessor::Async(processor)).await
+    }
+    
+    async fn register_processor(
+        &self,
+        entity_id: EntityId,
+        capabilities: HashSet<SignalType>,
+        processor: EntityProcessor,
+    ) -> Result<()> {
+        info!("Registering entity {} with capabilities: {:?}", entity_id, capabilities);
+        
+        // Create signal queue for the entity
+        let (tx, rx) = mpsc::channel(self.config().max_concurrent_signals);
         
         let signal_processor = SignalProcessor {
             entity_id,
             capabilities,
             processor,
             signal_queue: tx,
-            stats: ProcessingStats {
+            stats: Arc::new(std::sync::Mutex::new(ProcessingStats {
                 signals_processed: 0,
                 avg_processing_time: Duration::from_millis(0),
                 error_count: 0,
+                consecutive_errors: 0,
+                expired_signals: 0,
+                retried: 0,
                 last_processed: None,
-            },
+            })),
         };
         
         // Store the processor
@@ -269,6 +1296,7 @@ impl NervousSystem {
             let mut processors = self.signal_processors.write().await;
             processors.insert(entity_id, signal_processor);
         }
+        self.heartbeats.write().unwrap().insert(entity_id, Arc::new(Heartbeat::new(self.clock.clone())));
         
         // Initialize neural pathways
         {
@@ -276,41 +1304,302 @@ impl NervousSystem {
             pathways.insert(entity_id, HashSet::new());
         }
         
-        // Start signal processing loop
-        let physics_engine = self.physics_engine.clone();
-        let signal_channels = self.signal_channels.clone();
-        let config = self.config.clone();
+        // Hand the queue to the dispatcher, or start the entity's own processing loop
+        if let Some(dispatcher) = &self.dispatcher_queues {
+            dispatcher.send((entity_id, rx))
+                .map_err(|_| NervousSystemError::SignalProcessingError {
+                    reason: "energy-weighted dispatcher has stopped".to_string(),
+                })?;
+            return Ok(());
+        }
         
-        tokio::spawn(async move {
-            Self::process_entity_signals(
-                entity_id,
-                rx,
-                physics_engine,
-                signal_channels,
-                config,
-            ).await;
-        });
+        let task = tokio::spawn(Self::process_entity_signals(entity_id, rx, self.processing_context()));
+        
+        self.processing_tasks.write().await.insert(entity_id, task);
+        
+        Ok(())
+    }
+    
+    /// Register an entity that reads its signals with [`Self::peek_inbox`] and [`Self::drain_inbox`]
+    ///
+    /// Signals are routed to it like any other entity, but no processing loop
+    /// consumes them; they wait, up to `max_concurrent_signals`, in its inbox.
+    pub async fn register_manual_entity(&self, entity_id: EntityId, capabilities: HashSet<SignalType>) -> Result<()> {
+        info!("Registering manually processed entity {} with capabilities: {:?}", entity_id, capabilities);
+        
+        let (tx, rx) = mpsc::channel(self.config().max_concurrent_signals);
+        let signal_processor = SignalProcessor {
+            entity_id,
+            capabilities,
+            processor: EntityProcessor::Sync(Arc::new(ManualProcessing)),
+            signal_queue: tx,
+            stats: Arc::new(std::sync::Mutex::new(ProcessingStats {
+                signals_processed: 0,
+                avg_processing_time: Duration::from_millis(0),
+                error_count: 0,
+                consecutive_errors: 0,
+                expired_signals: 0,
+                retried: 0,
+                last_processed: None,
+            })),
+        };
+        
+        self.signal_processors.write().await.insert(entity_id, signal_processor);
+        self.neural_pathways.write().await.insert(entity_id, HashSet::new());
+        self.inboxes.lock().await.insert(entity_id, Inbox::new(rx));
+        Ok(())
+    }
+    
+    /// Up to `max` signals waiting for a manually processed entity, oldest first, without taking them
+    ///
+    /// Empty for entities with a processor, whose queue is consumed as signals arrive.
+    pub async fn peek_inbox(&self, entity: EntityId, max: usize) -> Vec<NeuralSignal> {
+        self.inboxes.lock().await
+            .get_mut(&entity)
+            .map(|inbox| inbox.peek(max, self.config().max_payload_bytes))
+            .unwrap_or_default()
+    }
+    
+    /// Take every signal waiting for a manually processed entity, oldest first
+    pub async fn drain_inbox(&self, entity: EntityId) -> Vec<NeuralSignal> {
+        self.inboxes.lock().await
+            .get_mut(&entity)
+            .map(|inbox| inbox.drain(self.config().max_payload_bytes))
+            .unwrap_or_default()
+    }
+    
+    /// The configuration in effect
+    pub fn config(&self) -> Arc<NervousSystemConfig> {
+        self.config.read().unwrap().clone()
+    }
+    
+    /// Change settings of the running system
+    ///
+    /// The updated configuration is validated as a whole and, if valid,
+    /// applies to signals transmitted or processed from now on; signals
+    /// already being transmitted or processed keep the settings they started
+    /// with.
+    pub async fn update_config(&self, update: NervousSystemConfigUpdate) -> Result<()> {
+        let mut config = NervousSystemConfig::clone(&self.config());
+        update.apply_to(&mut config);
+        config.validate()?;
+        
+        info!("Updating nervous system configuration");
+        *self.config.write().unwrap() = Arc::new(config);
+        Ok(())
+    }
+    
+    /// Count a rate-limit violation against the sending entity, quarantining it once it keeps failing
+    async fn record_violation(&self, entity_id: EntityId) {
+        if let Some(processor) = self.signal_processors.read().await.get(&entity_id) {
+            processor.stats.lock().unwrap().record_violation();
+        }
+        Self::quarantine_if_failing(entity_id, &self.processing_context()).await;
+    }
+    
+    fn processing_context(&self) -> ProcessingContext {
+        ProcessingContext {
+            signal_processors: self.signal_processors.clone(),
+            heartbeats: self.heartbeats.clone(),
+            dead_letters: self.dead_letters.clone(),
+            causal_order: self.causal_order.clone(),
+            quarantines: self.quarantines.clone(),
+            clock: self.clock.clone(),
+            type_stats: self.type_stats.clone(),
+            signal_ready: self.signal_ready.clone(),
+            retry_attempts: self.retry_attempts.clone(),
+            config: self.config.clone(),
+            responses: self.responses.clone(),
+            pending_responses: self.pending_responses.clone(),
+        }
+    }
+    
+    /// Entities whose processing has been stuck on one signal for longer than `max_idle`
+    /// while signals are pending for them
+    ///
+    /// Pending signals are those in the entity's queue, waiting in the
+    /// energy-weighted dispatcher or for their causal dependencies, and in flight.
+    ///
+    /// When `restart_stalled_tasks` is enabled each reported entity's processing
+    /// task is restarted; signals already queued to the stalled task are lost.
+    pub async fn check_liveness(&self, max_idle: Duration) -> Vec<EntityId> {
+        let stalled: Vec<(EntityId, usize)> = self.heartbeats.read().unwrap().iter()
+            .filter(|(_, heartbeat)| heartbeat.is_stalled(max_idle))
+            .map(|(entity_id, heartbeat)| (*entity_id, heartbeat.taken()))
+            .collect();
+        
+        let mut wedged: Vec<EntityId> = {
+            let processors = self.signal_processors.read().await;
+            stalled.into_iter()
+                .filter(|(entity_id, taken)| processors.get(entity_id).is_some_and(|processor| {
+                    let queued = processor.signal_queue.max_capacity() - processor.signal_queue.capacity();
+                    queued + taken > 0
+                }))
+                .map(|(entity_id, _)| entity_id)
+                .collect()
+        };
+        wedged.sort_by_key(|entity_id| entity_id.0);
+        
+        for entity_id in &wedged {
+            warn!("Signal processing for entity {} has made no progress in {:?}", entity_id, max_idle);
+            if self.config().restart_stalled_tasks {
+                if let Err(e) = self.restart_processing(*entity_id).await {
+                    error!("Failed to restart signal processing for entity {}: {}", entity_id, e);
+                }
+            }
+        }
+        
+        wedged
+    }
+    
+    /// Abandon an entity's processing task and start a fresh one with an empty queue
+    ///
+    /// The old task is aborted, which takes effect once its processor returns.
+    /// Only available with per-entity processing.
+    pub async fn restart_processing(&self, entity_id: EntityId) -> Result<()> {
+        if self.dispatcher_queues.is_some() {
+            return Err(NervousSystemError::InvalidConfiguration {
+                reason: "processing tasks can only be restarted with per-entity processing".to_string(),
+            }.into());
+        }
+        if self.inboxes.lock().await.contains_key(&entity_id) {
+            return Err(NervousSystemError::InvalidConfiguration {
+                reason: format!("entity {} processes its signals manually", entity_id),
+            }.into());
+        }
+        
+        let (tx, rx) = mpsc::channel(self.config().max_concurrent_signals);
+        {
+            let mut processors = self.signal_processors.write().await;
+            let processor = processors.get_mut(&entity_id)
+                .ok_or(NervousSystemError::EntityNotFound { entity: entity_id })?;
+            processor.signal_queue = tx;
+        }
+        self.heartbeats.write().unwrap().insert(entity_id, Arc::new(Heartbeat::new(self.clock.clone())));
+        
+        let task = tokio::spawn(Self::process_entity_signals(entity_id, rx, self.processing_context()));
+        if let Some(old) = self.processing_tasks.write().await.insert(entity_id, task) {
+            old.abort();
+        }
         
+        info!("Restarted signal processing for entity {}", entity_id);
         Ok(())
     }
     
+    /// Deregister an entity, stopping its processing task and removing its pathways
+    ///
+    /// Returns `true` if a processing task was stopped and joined. A task that does not stop
+    /// within the signal timeout is aborted and reported as an error.
+    pub async fn deregister_entity(&self, entity_id: EntityId) -> Result<bool> {
+        info!("Deregistering entity {}", entity_id);
+        
+        // Dropping the processor closes the signal queue, ending the processing loop
+        let processor = self.signal_processors.write().await.remove(&entity_id);
+        drop(processor);
+        self.inboxes.lock().await.remove(&entity_id);
+        self.heartbeats.write().unwrap().remove(&entity_id);
+        self.quarantines.write().await.remove(&entity_id);
+        
+        {
+            let mut pathways = self.neural_pathways.write().await;
+            pathways.remove(&entity_id);
+            for connections in pathways.values_mut() {
+                connections.remove(&entity_id);
+            }
+        }
+        
+        let task = self.processing_tasks.write().await.remove(&entity_id);
+        match task {
+            Some(mut task) => {
+                let timeout = self.config().signal_timeout;
+                match tokio::time::timeout(timeout, &mut task).await {
+                    Ok(joined) => {
+                        joined.context("Signal processing task panicked")?;
+                        Ok(true)
+                    }
+                    Err(_) => {
+                        task.abort();
+                        warn!("Signal processing task for entity {} did not stop within {:?}; aborted", entity_id, timeout);
+                        Err(NervousSystemError::SignalTimeout { timeout }.into())
+                    }
+                }
+            }
+            None => Ok(false),
+        }
+    }
+    
     /// Transmit a neural signal through the nervous system
-    pub async fn transmit_signal(&self, signal: NeuralSignal) -> Result<NervousSystemResult> {
+    #[instrument(skip_all, fields(signal_id = %signal.signal_id, correlation_id = %signal.correlation_id))]
+    pub async fn transmit_signal(&self, mut signal: NeuralSignal) -> Result<NervousSystemResult> {
         let start_time = Instant::now();
+        let config = self.config();
+        // A signal's time to live counts from transmission on the nervous system's clock
+        signal.timestamp = self.clock.now_utc();
         
         debug!("Transmitting signal {} from {} to {:?}", 
                signal.signal_id, signal.source, signal.target);
         
+        // Throttle the source before spending any energy on the signal
+        let emergency = signal.signal_type == SignalType::Emergency;
+        if emergency {
+            if let Err(retry_after) = self.emergency.admit(signal.source) {
+                self.record_violation(signal.source).await;
+                return Err(NervousSystemError::EmergencyCapExceeded { entity: signal.source, retry_after }.into());
+            }
+        } else if let Some(limiter) = &self.rate_limiter {
+            if let Err(retry_after) = limiter.check(signal.source) {
+                self.record_violation(signal.source).await;
+                return Err(NervousSystemError::RateLimited { entity: signal.source, retry_after }.into());
+            }
+        }
+        
+        let action = middleware::apply(&self.middleware.read().unwrap(), &mut signal);
+        match action {
+            MiddlewareAction::Continue => {}
+            MiddlewareAction::Drop => {
+                debug!("Middleware dropped signal {}", signal.signal_id);
+                return Ok(NervousSystemResult {
+                    success: true,
+                    message: "Signal dropped by middleware".to_string(),
+                    duration: start_time.elapsed(),
+                    energy_consumed: 0.0,
+                    signals_generated: 0,
+                    recipients: 0,
+                });
+            }
+            MiddlewareAction::Reject(reason) => {
+                return Err(NervousSystemError::SignalRejected { reason }.into());
+            }
+        }
+        
+        signal.payload.check_size(config.max_payload_bytes)?;
+        if let Some(threshold) = config.compress_payloads_over {
+            if matches!(&signal.payload, SignalPayload::Binary(bytes) if bytes.len() > threshold) {
+                signal = signal.with_compressed_payload();
+            }
+        }
+        
         // Validate signal with physics engine
-        if self.config.enforce_physics {
-            self.validate_signal_physics(&signal).await?;
+        if config.enforce_physics {
+            if let Err(rejection) = self.validate_signal_physics(&signal).await {
+                match config.degradation {
+                    _ if emergency && is_energy_shortfall(&rejection)
+                        && self.emergency.draw(self.physics_engine.as_ref(), signal.energy_cost).await => {
+                        warn!("Emergency signal {} from {} paid from the emergency reserve: {:#}",
+                              signal.signal_id, signal.source, rejection);
+                    }
+                    DegradationPolicy::Fail => return Err(rejection),
+                    DegradationPolicy::Downgrade => signal = self.downgrade_signal(signal, rejection).await?,
+                    DegradationPolicy::QueueAndRetry => return self.defer_signal(signal, rejection, start_time).await,
+                }
+            }
         }
         
         // Calculate energy cost
-        let energy_cost = self.calculate_signal_energy_cost(&signal);
+        let energy_cost = Self::calculate_signal_energy_cost(&signal, &config);
         
         // Transmit signal through appropriate channel
-        let signal_result = self.route_signal(signal).await?;
+        let signal_result = self.route_signal(signal, &config).await?;
         
         let duration = start_time.elapsed();
         
@@ -318,23 +1607,96 @@ impl NervousSystem {
             success: true,
             message: format!("Signal transmitted successfully"),
             duration,
-            energy_consumed: energy_cost,
+            energy_consumed: energy_cost + signal_result.energy_consumed,
             signals_generated: signal_result.signals_generated,
+            recipients: signal_result.recipients,
         })
     }
     
+    /// Replace the broadcast channel for a signal type with one of a new capacity
+    ///
+    /// This resets the channel: existing subscribers' streams end, queued
+    /// signals are discarded and its dropped-signal count starts again at zero.
+    pub async fn reconfigure_channel(&self, signal_type: SignalType, capacity: usize) -> Result<()> {
+        if capacity == 0 {
+            return Err(ConfigError::ZeroCapacity { signal_type: Some(signal_type) }.into());
+        }
+        
+        info!("Reconfiguring {:?} channel with capacity {}", signal_type, capacity);
+        self.signal_channels.write().await.insert(signal_type, SignalChannel::new(capacity));
+        Ok(())
+    }
+    
+    /// Run every transmitted signal through `middleware`, after the middleware added before it
+    pub fn add_middleware(&self, middleware: Box<dyn SignalMiddleware>) {
+        self.middleware.write().unwrap().push(middleware);
+    }
+    
+    /// Start recording every transmitted signal
+    ///
+    /// Only one recording runs at a time; starting a new one ends the previous
+    /// recording, whose handle still yields the signals captured so far.
+    pub fn start_recording(&self) -> RecorderHandle {
+        let (recorder, handle) = recording::start(self.clock.clone());
+        *self.recorder.lock().unwrap() = Some(recorder);
+        info!("Started signal recording {}", handle.id);
+        handle
+    }
+    
+    /// Stop a recording and return its signal log
+    pub async fn stop_recording(&self, handle: RecorderHandle) -> Result<SignalLog> {
+        {
+            let mut recorder = self.recorder.lock().unwrap();
+            if recorder.as_ref().is_some_and(|r| r.id == handle.id) {
+                *recorder = None;
+            }
+        }
+        
+        let log = handle.finish().await?;
+        info!("Stopped signal recording with {} signals ({} dropped)",
+              log.signals.len(), log.dropped_signals);
+        Ok(log)
+    }
+    
+    /// Re-transmit the signals of a recorded log, preserving their relative timing
+    ///
+    /// Inter-arrival gaps are divided by `speed`, so `2.0` replays twice as fast.
+    /// Each signal is stamped with the time it is replayed, so TTLs and causal
+    /// ordering judge it as a new arrival. Returns the number of signals transmitted.
+    pub async fn replay(&self, log: &SignalLog, speed: f64) -> Result<usize> {
+        if !speed.is_finite() || speed <= 0.0 {
+            return Err(NervousSystemError::InvalidConfiguration {
+                reason: format!("replay speed must be positive, got {}", speed),
+            }.into());
+        }
+        
+        let replay_start = tokio::time::Instant::now();
+        for recorded in &log.signals {
+            tokio::time::sleep_until(replay_start + recorded.offset.div_f64(speed)).await;
+            let mut signal = recorded.signal.clone();
+            signal.timestamp = self.clock.now_utc();
+            self.transmit_signal(signal).await
+                .with_context(|| format!("Failed to replay signal {}", recorded.signal.signal_id))?;
+        }
+        
+        Ok(log.signals.len())
+    }
+    
     /// Create a signal stream for an entity
     pub async fn create_signal_stream(
         &self,
         entity_id: EntityId,
         signal_types: Vec<SignalType>,
-    ) -> Result<impl Stream<Item = NeuralSignal>> {
+    ) -> Result<impl Stream<Item = SignalStreamEvent>> {
         let channels = self.signal_channels.read().await;
+        let groups = self.groups.clone();
+        let quarantines = self.quarantines.clone();
+        let config = self.config.clone();
         let mut streams = Vec::new();
         
         for signal_type in signal_types {
             if let Some(channel) = channels.get(&signal_type) {
-                let stream = BroadcastStream::new(channel.subscribe());
+                let stream = BroadcastStream::new(channel.sender.subscribe());
                 streams.push(stream);
             }
         }
@@ -343,17 +1705,33 @@ impl NervousSystem {
         let combined_stream = futures::stream::select_all(streams)
             .filter_map(move |signal_result| {
                 let entity_id = entity_id;
+                let groups = groups.clone();
+                let quarantines = quarantines.clone();
+                let max_payload_bytes = config.read().unwrap().max_payload_bytes;
                 async move {
                     match signal_result {
-                        Ok(signal) => {
-                            let entity_matches = signal.target.is_none() || signal.target == Some(entity_id);
-                            if entity_matches {
-                                Some(signal)
-                            } else {
-                                None
+                        Ok(_) if quarantines.read().await.contains_key(&entity_id) => None,
+                        Ok(delivery) => {
+                            let entity_matches = match &delivery.signal.target {
+                                SignalTarget::Broadcast => true,
+                                SignalTarget::One(target) => *target == entity_id,
+                                SignalTarget::Group(name) => groups.read().await
+                                    .get(name)
+                                    .is_some_and(|members| members.contains(&entity_id)),
+                            };
+                            if !entity_matches || !delivery.claim() {
+                                return None;
+                            }
+                            let mut signal = delivery.signal;
+                            if let Err(e) = signal.inflate_payload(max_payload_bytes) {
+                                warn!("Dropping signal {} for entity {}: {}", signal.signal_id, entity_id, e);
+                                return None;
                             }
+                            Some(SignalStreamEvent::Signal(signal))
+                        }
+                        Err(BroadcastStreamRecvError::Lagged(missed)) => {
+                            Some(SignalStreamEvent::Lagged { missed })
                         }
-                        Err(_) => None,
                     }
                 }
             });
@@ -361,6 +1739,39 @@ impl NervousSystem {
         Ok(combined_stream)
     }
     
+    /// Create a named group that `SignalTarget::Group` signals are delivered to
+    pub async fn create_group(&self, name: &str, members: HashSet<EntityId>) -> Result<()> {
+        let mut groups = self.groups.write().await;
+        if groups.contains_key(name) {
+            return Err(NervousSystemError::GroupExists { name: name.to_string() }.into());
+        }
+        
+        info!("Creating group '{}' with {} members", name, members.len());
+        groups.insert(name.to_string(), members);
+        Ok(())
+    }
+    
+    /// Add an entity to a group, returning whether it was newly added
+    pub async fn add_to_group(&self, name: &str, entity_id: EntityId) -> Result<bool> {
+        let mut groups = self.groups.write().await;
+        let members = groups.get_mut(name)
+            .ok_or_else(|| NervousSystemError::GroupNotFound { name: name.to_string() })?;
+        Ok(members.insert(entity_id))
+    }
+    
+    /// Remove an entity from a group, returning whether it was a member
+    pub async fn remove_from_group(&self, name: &str, entity_id: EntityId) -> Result<bool> {
+        let mut groups = self.groups.write().await;
+        let members = groups.get_mut(name)
+            .ok_or_else(|| NervousSystemError::GroupNotFound { name: name.to_string() })?;
+        Ok(members.remove(&entity_id))
+    }
+    
+    /// Current members of a group
+    pub async fn group_members(&self, name: &str) -> Option<HashSet<EntityId>> {
+        self.groups.read().await.get(name).cloned()
+    }
+    
     /// Form neural pathway between entities
     pub async fn form_pathway(&self, from: EntityId, to: EntityId) -> Result<()> {
         info!("Forming neural pathway from {} to {}", from, to);
@@ -378,15 +1789,32 @@ impl NervousSystem {
     pub async fn get_statistics(&self) -> Result<NervousSystemStats> {
         let processors = self.signal_processors.read().await;
         let pathways = self.neural_pathways.read().await;
+        let channels = self.signal_channels.read().await;
+        let pending_signals = channels.values().map(|channel| channel.sender.len()).sum();
+        let dropped_signals: HashMap<SignalType, u64> = channels
+            .iter()
+            .map(|(signal_type, channel)| (signal_type.clone(), channel.dropped.load(Ordering::Relaxed)))
+            .collect();
+        let mut by_type = self.type_stats.lock().unwrap().clone();
+        for (signal_type, evicted) in &dropped_signals {
+            if *evicted > 0 {
+                by_type.entry(signal_type.clone()).or_default().dropped += evicted;
+            }
+        }
         
         let mut total_signals = 0;
         let mut total_errors = 0;
+        let mut expired_signals = 0;
+        let mut retried_signals = 0;
         let mut avg_processing_time = Duration::from_millis(0);
         
         for processor in processors.values() {
-            total_signals += processor.stats.signals_processed;
-            total_errors += processor.stats.error_count;
-            avg_processing_time += processor.stats.avg_processing_time;
+            let stats = processor.stats.lock().unwrap();
+            total_signals += stats.signals_processed;
+            total_errors += stats.error_count;
+            expired_signals += stats.expired_signals;
+            retried_signals += stats.retried;
+            avg_processing_time += stats.avg_processing_time;
         }
         
         let avg_time = if processors.len() > 0 {
@@ -403,16 +1831,119 @@ impl NervousSystem {
             total_signals_processed: total_signals,
             total_errors: total_errors,
             avg_processing_time: avg_time,
+            pending_signals,
+            dropped_signals,
+            dead_letters: self.dead_letters.lock().await.len(),
+            expired_signals,
+            retried_signals,
+            by_type,
+        })
+    }
+    
+    /// Stop delivering new signals to an entity
+    ///
+    /// Directed and group signals for the entity are dead-lettered and its
+    /// signal streams skip everything until [`release_quarantine`](Self::release_quarantine).
+    /// Signals already queued are still processed.
+    pub async fn quarantine(&self, entity_id: EntityId, reason: String) {
+        Self::isolate(&self.quarantines, entity_id, reason).await;
+    }
+    
+    /// Resume delivering signals to an entity, returning whether it was quarantined
+    pub async fn release_quarantine(&self, entity_id: EntityId) -> bool {
+        let released = self.quarantines.write().await.remove(&entity_id).is_some();
+        if released {
+            info!("Released entity {} from quarantine", entity_id);
+            // A fresh start, so one more failure does not quarantine it again at once
+            if let Some(processor) = self.signal_processors.read().await.get(&entity_id) {
+                processor.stats.lock().unwrap().consecutive_errors = 0;
+            }
+        }
+        released
+    }
+    
+    /// Why an entity is quarantined, if it is
+    pub async fn quarantine_reason(&self, entity_id: EntityId) -> Option<String> {
+        self.quarantines.read().await.get(&entity_id).cloned()
+    }
+    
+    async fn isolate(quarantines: &Quarantines, entity_id: EntityId, reason: String) {
+        warn!("Quarantining entity {}: {}", entity_id, reason);
+        quarantines.write().await.insert(entity_id, reason);
+    }
+    
+    /// Snapshot every registered entity, ordered by id
+    ///
+    /// Only read locks are taken, so processing carries on while the snapshot is built.
+    pub async fn list_entities(&self) -> Vec<EntityInfo> {
+        let quarantines = self.quarantines.read().await.clone();
+        let waiting: HashMap<EntityId, usize> = self.heartbeats.read().unwrap().iter()
+            .map(|(entity_id, heartbeat)| (*entity_id, heartbeat.waiting()))
+            .collect();
+        let mut entities: Vec<EntityInfo> = {
+            let processors = self.signal_processors.read().await;
+            processors.values()
+                .map(|processor| EntityInfo {
+                    entity_id: processor.entity_id,
+                    capabilities: processor.capabilities.clone(),
+                    pathway_count: 0,
+                    queue_len: processor.signal_queue.max_capacity() - processor.signal_queue.capacity()
+                        + waiting.get(&processor.entity_id).copied().unwrap_or(0),
+                    stats: processor.stats.lock().unwrap().clone(),
+                    quarantine: quarantines.get(&processor.entity_id).cloned(),
+                })
+                .collect()
+        };
+        
+        let pathways = self.neural_pathways.read().await;
+        for entity in &mut entities {
+            entity.pathway_count = pathways.get(&entity.entity_id).map_or(0, HashSet::len);
+        }
+        entities.sort_by_key(|entity| entity.entity_id.0);
+        entities
+    }
+    
+    /// Signals still to be processed by entities with a processor, including
+    /// those in flight and responses waiting to be transmitted
+    ///
+    /// Signals waiting on the clock, such as retries in their backoff or
+    /// deferred signals, are not counted, nor are manually processed inboxes.
+    pub async fn pending_signals(&self) -> usize {
+        let taken: HashMap<EntityId, usize> = self.heartbeats.read().unwrap().iter()
+            .map(|(entity_id, heartbeat)| (*entity_id, heartbeat.taken()))
+            .collect();
+        let queued: usize = self.signal_processors.read().await.values()
+            .filter_map(|processor| {
+                let taken = taken.get(&processor.entity_id)?;
+                Some(processor.signal_queue.max_capacity() - processor.signal_queue.capacity() + taken)
+            })
+            .sum();
+        queued + self.pending_responses.load(Ordering::Acquire)
+    }
+    
+    /// Wait until no signals are pending, giving up after `timeout`
+    ///
+    /// Returns whether the system went idle. Polls on the runtime's timer, so
+    /// it also works while a mock clock stands still.
+    pub async fn wait_until_idle(&self, timeout: Duration) -> bool {
+        tokio::time::timeout(timeout, async {
+            while self.pending_signals().await > 0 {
+                tokio::time::sleep(IDLE_POLL_INTERVAL).await;
+            }
         })
+        .await
+        .is_ok()
     }
     
     /// Validate signal with physics constraints
     async fn validate_signal_physics(&self, signal: &NeuralSignal) -> Result<()> {
-        // Check energy constraints
-        let energy_operation = PhysicsOperation::TransferEnergy {
-            from: signal.source,
-            to: signal.target.unwrap_or(signal.source), // Self-transfer for broadcast
-            amount: ordered_float::OrderedFloat(signal.energy_cost),
+        // Directed signals hand their energy to the target and groups lose it to
+        // transmission; broadcasts are charged per subscriber when routed
+        let amount = ordered_float::OrderedFloat(self.transmission_cost(signal).await);
+        let energy_operation = match signal.target.entity() {
+            Some(target) => PhysicsOperation::TransferEnergy { from: signal.source, to: target, amount },
+            None if signal.target.is_broadcast() => return Ok(()),
+            None => PhysicsOperation::DissipateEnergy { entity: signal.source, amount },
         };
         
         let physics_result = self.physics_engine.execute_operation(energy_operation).await
@@ -427,28 +1958,83 @@ impl NervousSystem {
         Ok(())
     }
     
+    /// Energy a non-broadcast signal's source pays to send it
+    ///
+    /// Group signals pay `energy_cost` for each member, and at least once for
+    /// a group that is empty or missing.
+    async fn transmission_cost(&self, signal: &NeuralSignal) -> f64 {
+        match &signal.target {
+            SignalTarget::Group(name) => {
+                let members = self.groups.read().await.get(name).map_or(0, HashSet::len);
+                signal.energy_cost * members.max(1) as f64
+            }
+            _ => signal.energy_cost,
+        }
+    }
+    
     /// Calculate energy cost for signal transmission
-    fn calculate_signal_energy_cost(&self, signal: &NeuralSignal) -> f64 {
+    fn calculate_signal_energy_cost(signal: &NeuralSignal, config: &NervousSystemConfig) -> f64 {
         let base_cost = 0.001; // Base energy cost
-        let distance_multiplier = signal.propagation_distance as f64 * self.config.signal_decay_rate;
+        let distance_multiplier = signal.propagation_distance as f64 * config.signal_decay_rate;
         let strength_multiplier = signal.strength;
         
         base_cost * (1.0 + distance_multiplier) * strength_multiplier
     }
     
     /// Route signal to appropriate channels
-    async fn route_signal(&self, signal: NeuralSignal) -> Result<NervousSystemResult> {
+    async fn route_signal(&self, signal: NeuralSignal, config: &NervousSystemConfig) -> Result<NervousSystemResult> {
         let channels = self.signal_channels.read().await;
+        let channel = channels.get(&signal.signal_type);
+        
+        // Broadcasts pay for each subscriber they reach, counted now
+        let subscribers = channel.map_or(0, |channel| channel.sender.receiver_count());
+        let (reach, fan_out_cost) = if signal.target.is_broadcast() && config.enforce_physics {
+            let (reach, cost) = self.charge_fan_out(&signal, subscribers, config.broadcast_cost).await?;
+            (Some(reach), cost)
+        } else {
+            (None, 0.0)
+        };
         
-        if let Some(channel) = channels.get(&signal.signal_type) {
-            let _ = channel.send(signal.clone());
+        if let Some(recorder) = self.recorder.lock().unwrap().as_ref() {
+            recorder.record(&signal);
         }
+        self.type_stats.lock().unwrap()
+            .entry(signal.signal_type.clone())
+            .or_default()
+            .record_transmitted(signal.strength);
         
-        // Also send to target entity's processor if specified
-        if let Some(target) = signal.target {
-            let processors = self.signal_processors.read().await;
-            if let Some(processor) = processors.get(&target) {
-                let _ = processor.signal_queue.send(signal).await;
+        if let Some(channel) = channel {
+            channel.send(signal.clone(), reach.filter(|reach| *reach < subscribers));
+        }
+        
+        // Also send to the processors of targeted entities
+        let signal_id = signal.signal_id;
+        let broadcast = signal.target.is_broadcast();
+        let recipients = match signal.target.clone() {
+            SignalTarget::One(target) => usize::from(self.deliver(target, signal).await),
+            SignalTarget::Group(name) => {
+                let members = self.groups.read().await.get(&name).cloned();
+                match members {
+                    Some(members) => {
+                        let mut delivered = 0;
+                        for member in members {
+                            delivered += usize::from(self.deliver(member, signal.clone()).await);
+                        }
+                        delivered
+                    }
+                    None => {
+                        Self::dead_letter(&self.dead_letters, signal, DeadLetterReason::GroupNotFound, self.clock.now_utc()).await;
+                        0
+                    }
+                }
+            }
+            SignalTarget::Broadcast => reach.unwrap_or(subscribers),
+        };
+        
+        // No processor will handle a broadcast or undelivered signal, so its dependents need not wait
+        if let Some(causal_order) = &self.causal_order {
+            if broadcast || recipients == 0 {
+                causal_order.mark_processed(signal_id);
             }
         }
         
@@ -456,94 +2042,591 @@ impl NervousSystem {
             success: true,
             message: "Signal routed successfully".to_string(),
             duration: Duration::from_millis(1),
-            energy_consumed: 0.0,
+            energy_consumed: fan_out_cost,
             signals_generated: 1,
+            recipients,
         })
     }
     
-    /// Process signals for a specific entity
-    async fn process_entity_signals(
-        entity_id: EntityId,
-        mut rx: mpsc::Receiver<NeuralSignal>,
-        physics_engine: Arc<PhysicsEngine>,
-        signal_channels: Arc<RwLock<HashMap<SignalType, broadcast::Sender<NeuralSignal>>>>,
-        config: NervousSystemConfig,
-    ) {
-        info!("Starting signal processing for entity {}", entity_id);
+    /// Dissipate a broadcast's per-subscriber energy cost from its source
+    ///
+    /// Returns how many subscribers were paid for and the energy spent. Under
+    /// [`BroadcastCostPolicy::AllOrNothing`] a source that cannot reach every
+    /// subscriber is charged nothing and the broadcast fails. The emergency
+    /// reserve pays for the subscribers an Emergency broadcast's source cannot.
+    async fn charge_fan_out(&self, signal: &NeuralSignal, subscribers: usize, policy: BroadcastCostPolicy) -> Result<(usize, f64)> {
+        if subscribers == 0 || signal.energy_cost <= 0.0 {
+            return Ok((subscribers, 0.0));
+        }
         
-        while let Some(signal) = rx.recv().await {
-            let start_time = Instant::now();
-            
-            debug!("Processing signal {} for entity {}", signal.signal_id, entity_id);
-            
-            // Process signal with timeout
-            let processing_result = tokio::time::timeout(
-                config.signal_timeout,
-                Self::process_single_signal(signal, &physics_engine, &signal_channels)
-            ).await;
-            
-            match processing_result {
-                Ok(Ok(response_signal)) => {
-                    if let Some(response) = response_signal {
-                        // Transmit response signal
-                        let _ = Self::transmit_response_signal(response, &signal_channels).await;
-                    }
-                }
-                Ok(Err(e)) => {
-                    error!("Signal processing error for entity {}: {}", entity_id, e);
-                }
-                Err(_) => {
-                    error!("Signal processing timeout for entity {}", entity_id);
-                }
+        let available = self.physics_engine.entity_energy(signal.source).await.0;
+        // Tolerate rounding so exactly enough energy still pays for everyone
+        let mut affordable = (available / signal.energy_cost + 1e-9).floor() as usize;
+        let shortfall = subscribers.saturating_sub(affordable);
+        let reserved = if signal.signal_type == SignalType::Emergency && shortfall > 0
+            && self.emergency.draw(self.physics_engine.as_ref(), signal.energy_cost * shortfall as f64).await
+        {
+            warn!("Emergency broadcast {} from {} paid for {} subscribers from the emergency reserve",
+                  signal.signal_id, signal.source, shortfall);
+            affordable = subscribers;
+            signal.energy_cost * shortfall as f64
+        } else {
+            0.0
+        };
+        let reach = match policy {
+            BroadcastCostPolicy::AllOrNothing if affordable < subscribers => {
+                return Err(NervousSystemError::BroadcastUnaffordable {
+                    entity: signal.source,
+                    recipients: subscribers,
+                    required: signal.energy_cost * subscribers as f64,
+                    available,
+                }.into());
             }
-            
-            let processing_time = start_time.elapsed();
-            debug!("Signal processed in {:?} for entity {}", processing_time, entity_id);
+            _ => subscribers.min(affordable),
+        };
+        if reach < subscribers {
+            warn!("Broadcast {} from {} reaches only {} of {} subscribers it can pay for",
+                  signal.signal_id, signal.source, reach, subscribers);
+        }
+        let cost = (signal.energy_cost * reach as f64 - reserved).min(available);
+        if reach == 0 || cost <= 0.0 {
+            return Ok((reach, reserved));
         }
         
-        info!("Signal processing stopped for entity {}", entity_id);
+        let operation = PhysicsOperation::DissipateEnergy {
+            entity: signal.source,
+            amount: ordered_float::OrderedFloat(cost),
+        };
+        let physics_result = self.physics_engine.execute_operation(operation).await
+            .context("Failed to charge broadcast fan-out")?;
+        if !physics_result.success {
+            return Err(NervousSystemError::PhysicsViolation {
+                reason: physics_result.message,
+            }.into());
+        }
+        
+        Ok((reach, cost + reserved))
     }
     
-    /// Process a single signal
-    async fn process_single_signal(
-        signal: NeuralSignal,
-        _physics_engine: &Arc<PhysicsEngine>,
-        _signal_channels: &Arc<RwLock<HashMap<SignalType, broadcast::Sender<NeuralSignal>>>>,
-    ) -> Result<Option<NeuralSignal>> {
-        // For now, just acknowledge the signal
-        // In a real implementation, this would invoke the entity's processor
-        debug!("Processing signal: {:?}", signal.signal_type);
+    /// Energy left in the reserve that pays for Emergency signals whose source cannot
+    pub async fn emergency_reserve(&self) -> f64 {
+        self.emergency.reserve(self.physics_engine.as_ref()).await
+    }
+    
+    /// Queue a signal for one entity's processor, dead-lettering it if that fails
+    ///
+    /// Returns whether the signal was queued.
+    async fn deliver(&self, target: EntityId, signal: NeuralSignal) -> bool {
+        if self.quarantines.read().await.contains_key(&target) {
+            Self::dead_letter(&self.dead_letters, signal, DeadLetterReason::Quarantined, self.clock.now_utc()).await;
+            return false;
+        }
         
-        // Return a simple acknowledgment signal
-        let response = NeuralSignal {
-            signal_id: Uuid::new_v4(),
-            signal_type: SignalType::Coordination,
-            source: signal.target.unwrap_or(signal.source),
-            target: Some(signal.source),
-            payload: SignalPayload::Message("Signal received".to_string()),
-            strength: signal.strength * 0.8, // Decay response strength
-            propagation_distance: 0,
-            timestamp: Utc::now(),
-            energy_cost: 0.001,
-            causal_dependencies: vec![signal.signal_id],
+        // Wait for queue space without holding the map, which the processing tasks need
+        let queue = self.signal_processors.read().await.get(&target)
+            .map(|processor| processor.signal_queue.clone());
+        let undelivered = match queue {
+            Some(queue) => queue.send(signal).await
+                .err()
+                .map(|e| (e.0, DeadLetterReason::QueueClosed)),
+            None => Some((signal, DeadLetterReason::EntityNotFound)),
         };
         
-        Ok(Some(response))
+        match undelivered {
+            None => {
+                self.signal_ready.notify_one();
+                true
+            }
+            Some((signal, reason)) => {
+                Self::dead_letter(&self.dead_letters, signal, reason, self.clock.now_utc()).await;
+                false
+            }
+        }
     }
     
-    /// Transmit response signal
-    async fn transmit_response_signal(
-        response: NeuralSignal,
-        signal_channels: &Arc<RwLock<HashMap<SignalType, broadcast::Sender<NeuralSignal>>>>,
-    ) -> Result<()> {
-        let channels = signal_channels.read().await;
+    /// Keep an undeliverable signal, discarding the oldest beyond [`MAX_DEAD_LETTERS`]
+    async fn dead_letter(
+        dead_letters: &Mutex<VecDeque<DeadLetter>>,
+        signal: NeuralSignal,
+        reason: DeadLetterReason,
+        timestamp: DateTime<Utc>,
+    ) {
+        warn!("Signal {} to {:?} could not be delivered: {:?}", signal.signal_id, signal.target, reason);
         
-        if let Some(channel) = channels.get(&response.signal_type) {
-            let _ = channel.send(response);
+        let mut dead_letters = dead_letters.lock().await;
+        if dead_letters.len() >= MAX_DEAD_LETTERS {
+            dead_letters.pop_front();
+        }
+        dead_letters.push_back(DeadLetter { signal, reason, timestamp });
+    }
+    
+    /// Take every undelivered signal collected so far, oldest first
+    pub async fn drain_dead_letters(&self) -> Vec<DeadLetter> {
+        self.dead_letters.lock().await.drain(..).collect()
+    }
+    
+    /// Weaken a signal to the energy its source has left and validate it again
+    ///
+    /// Strength, propagation distance and cost shrink by the same fraction.
+    /// Signals rejected for anything but their cost, or from sources with no
+    /// energy at all, still fail with the original rejection.
+    async fn downgrade_signal(&self, mut signal: NeuralSignal, rejection: anyhow::Error) -> Result<NeuralSignal> {
+        let available = self.physics_engine.entity_energy(signal.source).await.0;
+        let cost = self.transmission_cost(&signal).await;
+        if available <= 0.0 || available >= cost {
+            return Err(rejection);
         }
         
-        Ok(())
+        let fraction = available / cost;
+        signal.strength *= fraction;
+        signal.propagation_distance = (signal.propagation_distance as f64 * fraction).floor() as u32;
+        signal.energy_cost *= fraction;
+        debug!("Downgraded signal {} to {:.3} of its strength", signal.signal_id, fraction);
+        
+        self.validate_signal_physics(&signal).await?;
+        Ok(signal)
     }
+    
+    /// Hold a signal its source cannot yet pay for, unless the queue is full
+    async fn defer_signal(&self, signal: NeuralSignal, rejection: anyhow::Error, start_time: Instant) -> Result<NervousSystemResult> {
+        if self.physics_engine.entity_energy(signal.source).await.0 >= self.transmission_cost(&signal).await {
+            return Err(rejection);
+        }
+        let mut deferred = self.deferred_signals.lock().await;
+        if deferred.len() >= MAX_DEFERRED_SIGNALS {
+            return Err(rejection);
+        }
+        
+        debug!("Deferring signal {} until {} can pay {} energy", signal.signal_id, signal.source, signal.energy_cost);
+        deferred.push_back(signal);
+        Ok(NervousSystemResult {
+            success: true,
+            message: "Signal deferred until its source can pay for it".to_string(),
+            duration: start_time.elapsed(),
+            energy_consumed: 0.0,
+            signals_generated: 0,
+            recipients: 0,
+        })
+    }
+    
+    /// Transmit the deferred signals whose sources can now pay, returning how many were sent
+    ///
+    /// Signals are tried oldest first; those still unaffordable stay queued in order.
+    /// A signal is stamped with the time it is finally sent, so its time to live
+    /// runs from then rather than from when it was deferred.
+    pub async fn retry_deferred_signals(&self) -> Result<usize> {
+        let pending: Vec<NeuralSignal> = self.deferred_signals.lock().await.drain(..).collect();
+        let mut still_deferred = VecDeque::new();
+        let mut sent = 0;
+        
+        for mut signal in pending {
+            if self.validate_signal_physics(&signal).await.is_err() {
+                still_deferred.push_back(signal);
+                continue;
+            }
+            signal.timestamp = self.clock.now_utc();
+            let signal_id = signal.signal_id;
+            match self.route_signal(signal, &self.config()).await {
+                Ok(_) => sent += 1,
+                Err(e) => warn!("Deferred signal {} could not be routed: {}", signal_id, e),
+            }
+        }
+        
+        let mut deferred = self.deferred_signals.lock().await;
+        still_deferred.extend(deferred.drain(..));
+        *deferred = still_deferred;
+        Ok(sent)
+    }
+    
+    /// Process signals for a specific entity
+    #[instrument(skip(rx, context))]
+    async fn process_entity_signals(
+        entity_id: EntityId,
+        mut rx: mpsc::Receiver<NeuralSignal>,
+        context: ProcessingContext,
+    ) {
+        info!("Starting signal processing for entity {}", entity_id);
+        
+        match context.causal_order.clone() {
+            None => {
+                while let Some(signal) = rx.recv().await {
+                    Self::take_signal(&context, entity_id);
+                    Self::handle_queued_signal(entity_id, signal, &context).await;
+                }
+            }
+            Some(causal_order) => {
+                let mut held = HeldSignals::new(context.clock.clone());
+                let mut progress = causal_order.subscribe();
+                loop {
+                    Self::release_held_signals(&mut held, &context, &causal_order).await;
+                    let deadline = held.next_deadline(causal_order.timeout);
+                    tokio::select! {
+                        received = rx.recv() => match received {
+                            Some(signal) => {
+                                Self::take_signal(&context, entity_id);
+                                Self::hold_signal(&mut held, entity_id, signal, &context).await;
+                            }
+                            None => break,
+                        },
+                        _ = causal::wait_for_progress(Some(&mut progress), deadline, &context.clock), if !held.is_empty() => {}
+                    }
+                }
+            }
+        }
+        
+        info!("Signal processing stopped for entity {}", entity_id);
+    }
+    
+    /// Hold a signal until its dependencies are processed, dead-lettering it if too many are held
+    async fn hold_signal(held: &mut HeldSignals, entity_id: EntityId, signal: NeuralSignal, context: &ProcessingContext) {
+        if let Some(signal) = held.hold(entity_id, signal) {
+            warn!("Signal {} for entity {} turned away: too many signals are waiting on their dependencies", signal.signal_id, entity_id);
+            if let Some(heartbeat) = Self::heartbeat(context, entity_id) {
+                heartbeat.abandon();
+            }
+            Self::dead_letter(&context.dead_letters, signal, DeadLetterReason::TooManyHeld, context.clock.now_utc()).await;
+        }
+    }
+    
+    /// Process every held signal whose dependencies have been processed, then give up on overdue ones
+    async fn release_held_signals(held: &mut HeldSignals, context: &ProcessingContext, causal_order: &CausalOrder) {
+        while let Some((entity_id, signal)) = held.take_ready(causal_order) {
+            Self::handle_queued_signal(entity_id, signal, context).await;
+        }
+        for (entity_id, signal) in held.take_overdue(causal_order.timeout) {
+            warn!("Signal {} for entity {} gave up waiting for its causal dependencies", signal.signal_id, entity_id);
+            if let Some(heartbeat) = Self::heartbeat(context, entity_id) {
+                heartbeat.abandon();
+            }
+            Self::dead_letter(&context.dead_letters, signal, DeadLetterReason::DependencyTimeout, context.clock.now_utc()).await;
+        }
+    }
+    
+    /// Process signals from every entity queue, favouring entities with more energy
+    ///
+    /// Only the next signal of each entity is taken off its queue, so the rest
+    /// stay in the bounded queue and senders wait once it is full. Senders
+    /// wait without holding the processor map, which the dispatcher needs to
+    /// process the signals that make room. The dispatcher stops when the
+    /// nervous system is dropped.
+    async fn dispatch_energy_weighted(
+        physics_engine: Arc<dyn Physics>,
+        context: ProcessingContext,
+        mut new_queues: mpsc::UnboundedReceiver<(EntityId, mpsc::Receiver<NeuralSignal>)>,
+        signal_ready: Arc<Notify>,
+        background: CancellationToken,
+    ) {
+        info!("Starting energy-weighted signal dispatcher");
+        
+        let mut receivers: HashMap<EntityId, mpsc::Receiver<NeuralSignal>> = HashMap::new();
+        let mut queue = EnergyWeightedQueue::default();
+        let mut adopting = true;
+        let mut held = HeldSignals::new(context.clock.clone());
+        let mut progress = context.causal_order.as_ref().map(|causal_order| causal_order.subscribe());
+        
+        while !background.is_cancelled() {
+            if let Some(causal_order) = &context.causal_order {
+                Self::release_held_signals(&mut held, &context, causal_order).await;
+            }
+            
+            while let Ok((entity_id, rx)) = new_queues.try_recv() {
+                receivers.insert(entity_id, rx);
+            }
+            
+            // Take the next signal of every entity without one waiting, forgetting closed queues
+            receivers.retain(|entity_id, rx| {
+                if queue.is_waiting(entity_id) {
+                    return true;
+                }
+                match rx.try_recv() {
+                    Ok(signal) => {
+                        Self::take_signal(&context, *entity_id);
+                        queue.push(*entity_id, signal);
+                        true
+                    }
+                    Err(mpsc::error::TryRecvError::Empty) => {
+                        queue.forget(entity_id);
+                        true
+                    }
+                    Err(mpsc::error::TryRecvError::Disconnected) => {
+                        queue.forget(entity_id);
+                        false
+                    }
+                }
+            });
+            
+            if queue.is_empty() {
+                if !adopting && receivers.is_empty() {
+                    break;
+                }
+                let deadline = context.causal_order.as_ref()
+                    .and_then(|causal_order| held.next_deadline(causal_order.timeout));
+                tokio::select! {
+                    _ = background.cancelled() => break,
+                    _ = signal_ready.notified() => {}
+                    _ = causal::wait_for_progress(progress.as_mut(), deadline, &context.clock), if !held.is_empty() => {}
+                    adopted = new_queues.recv(), if adopting => match adopted {
+                        Some((entity_id, rx)) => {
+                            receivers.insert(entity_id, rx);
+                        }
+                        None => adopting = false,
+                    }
+                }
+                continue;
+            }
+            
+            let mut weights = HashMap::new();
+            for entity_id in queue.waiting_entities() {
+                weights.insert(entity_id, physics_engine.entity_energy(entity_id).await.0);
+            }
+            if let Some((entity_id, signal)) = queue.pop(&weights) {
+                match &context.causal_order {
+                    Some(_) => Self::hold_signal(&mut held, entity_id, signal, &context).await,
+                    None => Self::handle_queued_signal(entity_id, signal, &context).await,
+                }
+            }
+        }
+        
+        info!("Energy-weighted signal dispatcher stopped");
+    }
+    
+    /// Process one dequeued signal, dropping it instead if it has expired
+    async fn handle_queued_signal(entity_id: EntityId, signal: NeuralSignal, context: &ProcessingContext) {
+        let heartbeat = Self::heartbeat(context, entity_id);
+        if let Some(heartbeat) = &heartbeat {
+            heartbeat.begin();
+        }
+        
+        Self::handle_signal(entity_id, signal, context).await;
+        
+        if let Some(heartbeat) = &heartbeat {
+            heartbeat.finish();
+        }
+    }
+    
+    fn heartbeat(context: &ProcessingContext, entity_id: EntityId) -> Option<Arc<Heartbeat>> {
+        context.heartbeats.read().unwrap().get(&entity_id).cloned()
+    }
+    
+    /// Count a signal taken off an entity's queue until it is finished or given up
+    fn take_signal(context: &ProcessingContext, entity_id: EntityId) {
+        if let Some(heartbeat) = Self::heartbeat(context, entity_id) {
+            heartbeat.take();
+        }
+    }
+    
+    #[instrument(skip(signal, context), fields(signal_id = %signal.signal_id, correlation_id = %signal.correlation_id))]
+    async fn handle_signal(entity_id: EntityId, signal: NeuralSignal, context: &ProcessingContext) {
+        let config = context.config();
+        if signal.is_expired(context.clock.now_utc(), config.default_signal_ttl) {
+            debug!("Dropping expired signal {} for entity {}", signal.signal_id, entity_id);
+            if let Some(processor) = context.signal_processors.read().await.get(&entity_id) {
+                processor.stats.lock().unwrap().expired_signals += 1;
+            }
+            context.type_stats.lock().unwrap().entry(signal.signal_type.clone()).or_default().dropped += 1;
+            if config.dead_letter_expired {
+                Self::dead_letter(&context.dead_letters, signal, DeadLetterReason::Expired, context.clock.now_utc()).await;
+            }
+            return;
+        }
+        
+        let start_time = Instant::now();
+        let signal_id = signal.signal_id;
+        let correlation_id = signal.correlation_id;
+        let response_depth = signal.response_depth + 1;
+        let retry_copy = config.retry.is_some().then(|| signal.clone());
+        context.type_stats.lock().unwrap().entry(signal.signal_type.clone()).or_default().processed += 1;
+        
+        debug!("Processing signal {} for entity {}", signal.signal_id, entity_id);
+        
+        // Process signal with timeout
+        let processing_result = tokio::time::timeout(
+            config.signal_timeout,
+            Self::process_single_signal(entity_id, signal, &context.signal_processors, config.max_payload_bytes, &context.clock)
+        ).await;
+        
+        match processing_result {
+            Ok(Ok(response_signal)) => {
+                if retry_copy.is_some() {
+                    context.retry_attempts.lock().unwrap().remove(&signal_id);
+                }
+                // Only a successfully processed signal releases its dependents
+                if let Some(causal_order) = &context.causal_order {
+                    causal_order.mark_processed(signal_id);
+                }
+                if let Some(mut response) = response_signal {
+                    // Responses continue the request's chain unless the processor chose another
+                    if response.correlation_id == response.signal_id {
+                        response.correlation_id = correlation_id;
+                    }
+                    response.response_depth = response_depth;
+                    if config.max_response_depth.is_some_and(|max| response_depth > max) {
+                        warn!("Cutting response loop in chain {} at depth {}: entity {} will not answer",
+                              correlation_id, response_depth, entity_id);
+                    } else {
+                        Self::transmit_response_signal(response, context);
+                    }
+                }
+            }
+            Ok(Err(e)) => {
+                error!("Signal processing error for entity {}: {}", entity_id, e);
+                if let (Some(policy), Some(signal)) = (&config.retry, retry_copy) {
+                    Self::retry_or_dead_letter(entity_id, signal, &e, policy, context).await;
+                }
+                Self::quarantine_if_failing(entity_id, context).await;
+            }
+            Err(_) => {
+                error!("Signal processing timeout for entity {}", entity_id);
+                if let Some(processor) = context.signal_processors.read().await.get(&entity_id) {
+                    processor.stats.lock().unwrap().record(config.signal_timeout, false, context.clock.now_utc());
+                }
+                let e = anyhow::Error::new(NervousSystemError::SignalTimeout { timeout: config.signal_timeout });
+                if let (Some(policy), Some(signal)) = (&config.retry, retry_copy) {
+                    Self::retry_or_dead_letter(entity_id, signal, &e, policy, context).await;
+                }
+                Self::quarantine_if_failing(entity_id, context).await;
+            }
+        }
+        
+        let processing_time = start_time.elapsed();
+        debug!("Signal processed in {:?} for entity {}", processing_time, entity_id);
+    }
+    
+    /// Quarantine an entity whose processor has failed too many times in a row
+    async fn quarantine_if_failing(entity_id: EntityId, context: &ProcessingContext) {
+        let Some(limit) = context.config().quarantine_after_errors else {
+            return;
+        };
+        let failures = context.signal_processors.read().await
+            .get(&entity_id)
+            .map_or(0, |processor| processor.stats.lock().unwrap().consecutive_errors);
+        if failures >= u64::from(limit) && !context.quarantines.read().await.contains_key(&entity_id) {
+            Self::isolate(&context.quarantines, entity_id, format!("{} consecutive processing errors", failures)).await;
+        }
+    }
+    
+    /// Queue a failed or timed-out signal again after the policy's backoff, or
+    /// dead-letter it once its error is not retryable or it has used up its attempts
+    ///
+    /// The backoff is measured on the system's clock, and a retried signal is
+    /// stamped with the time it is queued again.
+    async fn retry_or_dead_letter(
+        entity_id: EntityId,
+        signal: NeuralSignal,
+        error: &anyhow::Error,
+        policy: &RetryPolicy,
+        context: &ProcessingContext,
+    ) {
+        let attempts = {
+            let mut retry_attempts = context.retry_attempts.lock().unwrap();
+            let attempts = retry_attempts.entry(signal.signal_id).or_insert(0);
+            *attempts += 1;
+            *attempts
+        };
+        
+        if attempts < policy.max_attempts && (policy.retryable)(error) {
+            let queue = context.signal_processors.read().await.get(&entity_id).map(|processor| {
+                processor.stats.lock().unwrap().retried += 1;
+                processor.signal_queue.downgrade()
+            });
+            if let Some(queue) = queue {
+                debug!("Retrying signal {} for entity {} in {:?} (attempt {} of {})",
+                       signal.signal_id, entity_id, policy.backoff, attempts + 1, policy.max_attempts);
+                let due = context.clock.now_instant() + policy.backoff;
+                let context = context.clone();
+                tokio::spawn(async move {
+                    sleep_until(&context.clock, due).await;
+                    let mut signal = signal;
+                    signal.timestamp = context.clock.now_utc();
+                    // A weak handle lets deregistration close the queue while the retry waits
+                    let sent = match queue.upgrade() {
+                        Some(queue) => queue.send(signal).await,
+                        None => Err(mpsc::error::SendError(signal)),
+                    };
+                    match sent {
+                        Ok(()) => context.signal_ready.notify_one(),
+                        Err(mpsc::error::SendError(signal)) => {
+                            context.retry_attempts.lock().unwrap().remove(&signal.signal_id);
+                            Self::dead_letter(&context.dead_letters, signal, DeadLetterReason::QueueClosed, context.clock.now_utc()).await;
+                        }
+                    }
+                });
+                return;
+            }
+        }
+        
+        context.retry_attempts.lock().unwrap().remove(&signal.signal_id);
+        let reason = DeadLetterReason::ProcessingFailed { attempts, error: format!("{:#}", error) };
+        Self::dead_letter(&context.dead_letters, signal, reason, context.clock.now_utc()).await;
+    }
+    
+    /// Process a single signal with the entity's registered processor
+    async fn process_single_signal(
+        entity_id: EntityId,
+        mut signal: NeuralSignal,
+        signal_processors: &Arc<RwLock<HashMap<EntityId, SignalProcessor>>>,
+        max_payload_bytes: Option<usize>,
+        clock: &SharedClock,
+    ) -> Result<Option<NeuralSignal>> {
+        debug!("Processing signal: {:?}", signal.signal_type);
+        signal.inflate_payload(max_payload_bytes)?;
+        
+        // Run the processor and record its stats without holding the map, so one
+        // slow entity cannot block the others
+        let (processor, stats) = signal_processors.read().await.get(&entity_id)
+            .map(|processor| (processor.processor.clone(), processor.stats.clone()))
+            .ok_or(NervousSystemError::EntityNotFound { entity: entity_id })?;
+        
+        let start_time = Instant::now();
+        let result = processor.process(&signal).await;
+        stats.lock().unwrap().record(start_time.elapsed(), result.is_ok(), clock.now_utc());
+        
+        result
+    }
+    
+    /// Hand a response to the response task, which transmits it through the checked pipeline
+    #[instrument(skip_all, fields(signal_id = %response.signal_id, correlation_id = %response.correlation_id))]
+    fn transmit_response_signal(response: NeuralSignal, context: &ProcessingContext) {
+        debug!("Emitting response signal {} from {}", response.signal_id, response.source);
+        context.pending_responses.fetch_add(1, Ordering::AcqRel);
+        if context.responses.send(response).is_err() {
+            context.pending_responses.fetch_sub(1, Ordering::AcqRel);
+            debug!("Response task has stopped; dropping response");
+        }
+    }
+}
+
+/// How often [`NervousSystem::wait_until_idle`] checks for pending signals
+const IDLE_POLL_INTERVAL: Duration = Duration::from_millis(1);
+
+/// Longest a wait on the clock sleeps before reading the clock again
+const CLOCK_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Wait until `clock` reads `due`, noticing a clock that is moved by hand
+async fn sleep_until(clock: &SharedClock, due: tokio::time::Instant) {
+    loop {
+        let remaining = due.saturating_duration_since(clock.now_instant());
+        if remaining.is_zero() {
+            return;
+        }
+        tokio::time::sleep(remaining.min(CLOCK_POLL_INTERVAL)).await;
+    }
+}
+
+/// Snapshot of one registered entity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntityInfo {
+    pub entity_id: EntityId,
+    /// Signal types the entity processes
+    pub capabilities: HashSet<SignalType>,
+    /// Neural pathways to other entities
+    pub pathway_count: usize,
+    /// Signals waiting for the entity, whether in its queue, the energy-weighted
+    /// dispatcher or on their causal dependencies
+    pub queue_len: usize,
+    pub stats: ProcessingStats,
+    /// Why the entity is quarantined, if it is
+    pub quarantine: Option<String>,
 }
 
 /// Nervous system statistics
@@ -563,31 +2646,85 @@ pub struct NervousSystemStats {
     pub total_errors: u64,
     /// Average processing time
     pub avg_processing_time: Duration,
+    /// Signals queued in broadcast channels not yet seen by every receiver
+    pub pending_signals: usize,
+    /// Signals per channel evicted before every subscriber had seen them
+    pub dropped_signals: HashMap<SignalType, u64>,
+    /// Undelivered directed signals waiting to be drained
+    pub dead_letters: usize,
+    /// Signals dropped unprocessed because their time to live had passed
+    pub expired_signals: u64,
+    /// Failed signals queued for another attempt
+    #[serde(default)]
+    pub retried_signals: u64,
+    /// Traffic for each signal type seen so far
+    #[serde(default)]
+    pub by_type: HashMap<SignalType, SignalTypeStats>,
+}
+
+/// Traffic counters for one signal type
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SignalTypeStats {
+    /// Signals routed by `transmit_signal`
+    pub transmitted: u64,
+    /// Signals handed to an entity's processor
+    pub processed: u64,
+    /// Signals evicted from their channel or expired before processing
+    pub dropped: u64,
+    /// Mean strength of the transmitted signals
+    pub avg_strength: f64,
+}
+
+impl SignalTypeStats {
+    fn record_transmitted(&mut self, strength: f64) {
+        self.transmitted += 1;
+        self.avg_strength += (strength - self.avg_strength) / self.transmitted as f64;
+    }
 }
 
 impl NeuralSignal {
     /// Create a new neural signal
+    ///
+    /// The payload size is not checked; see [`Self::try_new`].
     pub fn new(
         signal_type: SignalType,
         source: EntityId,
-        target: Option<EntityId>,
+        target: impl Into<SignalTarget>,
         payload: SignalPayload,
         strength: f64,
     ) -> Self {
+        let signal_id = Uuid::new_v4();
         Self {
-            signal_id: Uuid::new_v4(),
+            version: SIGNAL_VERSION,
+            signal_id,
+            correlation_id: signal_id,
             signal_type,
             source,
-            target,
+            target: target.into(),
             payload,
             strength: strength.clamp(0.0, 1.0),
             propagation_distance: 0,
             timestamp: Utc::now(),
             energy_cost: 0.001,
             causal_dependencies: Vec::new(),
+            ttl: None,
+            response_depth: 0,
         }
     }
     
+    /// Create a new neural signal, rejecting a binary payload over `max_payload_bytes` once inflated
+    pub fn try_new(
+        signal_type: SignalType,
+        source: EntityId,
+        target: impl Into<SignalTarget>,
+        payload: SignalPayload,
+        strength: f64,
+        max_payload_bytes: Option<usize>,
+    ) -> Result<Self, NervousSystemError> {
+        payload.check_size(max_payload_bytes)?;
+        Ok(Self::new(signal_type, source, target, payload, strength))
+    }
+    
     /// Create a broadcast signal
     pub fn broadcast(
         signal_type: SignalType,
@@ -595,7 +2732,13 @@ impl NeuralSignal {
         payload: SignalPayload,
         strength: f64,
     ) -> Self {
-        Self::new(signal_type, source, None, payload, strength)
+        Self::new(signal_type, source, SignalTarget::Broadcast, payload, strength)
+    }
+    
+    /// Join the chain of signals sharing `correlation_id`
+    pub fn with_correlation_id(mut self, correlation_id: Uuid) -> Self {
+        self.correlation_id = correlation_id;
+        self
     }
     
     /// Add causal dependency
@@ -604,11 +2747,50 @@ impl NeuralSignal {
         self
     }
     
+    /// Compress a binary payload, keeping it uncompressed if that would not make it smaller
+    pub fn with_compressed_payload(mut self) -> Self {
+        if let SignalPayload::Binary(bytes) = &self.payload {
+            let compressed = compression::compress(bytes);
+            if compressed.len() < bytes.len() {
+                self.payload = SignalPayload::Compressed(compressed);
+            }
+        }
+        self
+    }
+    
+    /// Turn a compressed payload back into `Binary`; other payloads are left alone
+    ///
+    /// Payloads declaring more than `max_payload_bytes`, or [`compression::MAX_INFLATED_BYTES`]
+    /// when that is `None`, are rejected without being inflated.
+    pub fn inflate_payload(&mut self, max_payload_bytes: Option<usize>) -> Result<(), CompressionError> {
+        if let SignalPayload::Compressed(compressed) = &self.payload {
+            let limit = max_payload_bytes.unwrap_or(compression::MAX_INFLATED_BYTES);
+            self.payload = SignalPayload::Binary(compression::decompress(compressed, limit)?);
+        }
+        Ok(())
+    }
+    
     /// Set energy cost
     pub fn with_energy_cost(mut self, cost: f64) -> Self {
         self.energy_cost = cost;
         self
     }
+    
+    /// Set how long the signal stays relevant after its timestamp
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+    
+    /// Whether the signal's time to live, or `default_ttl` if it has none, has passed at `now`
+    pub fn is_expired(&self, now: DateTime<Utc>, default_ttl: Option<Duration>) -> bool {
+        let Some(ttl) = self.ttl.or(default_ttl) else {
+            return false;
+        };
+        chrono::Duration::from_std(ttl)
+            .map(|ttl| self.timestamp + ttl < now)
+            .unwrap_or(false)
+    }
 }
 
 impl Default for NervousSystem {
@@ -620,15 +2802,159 @@ impl Default for NervousSystem {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use emergence_physics::PhysicsEngine;
+    use emergence_physics::{Clock, MockClock, MockPhysicsEngine, PhysicsEngine, PhysicsViolation};
     
     struct TestProcessor;
     
+    struct FailingProcessor;
+    
+    impl SignalProcessorFn for FailingProcessor {
+        fn process_signal(&self, _signal: &NeuralSignal) -> Result<Option<NeuralSignal>> {
+            Err(anyhow::anyhow!("processor failure"))
+        }
+    }
+    
+    /// Fails on its first `failures` signals and succeeds afterwards
+    struct FlakyProcessor {
+        failures: u32,
+        attempts: Arc<std::sync::atomic::AtomicU32>,
+    }
+    
+    impl SignalProcessorFn for FlakyProcessor {
+        fn process_signal(&self, _signal: &NeuralSignal) -> Result<Option<NeuralSignal>> {
+            if self.attempts.fetch_add(1, Ordering::SeqCst) < self.failures {
+                return Err(anyhow::anyhow!("transient failure"));
+            }
+            Ok(None)
+        }
+    }
+    
+    /// Records the message of every signal it receives
+    struct CapturingProcessor {
+        received: Arc<std::sync::Mutex<Vec<String>>>,
+    }
+    
+    impl SignalProcessorFn for CapturingProcessor {
+        fn process_signal(&self, signal: &NeuralSignal) -> Result<Option<NeuralSignal>> {
+            if let SignalPayload::Message(text) = &signal.payload {
+                self.received.lock().unwrap().push(text.clone());
+            }
+            Ok(None)
+        }
+    }
+    
+    /// Replies to every signal with a Cognitive "pong" for its source
+    struct ReplyingProcessor {
+        entity_id: EntityId,
+    }
+    
+    impl SignalProcessorFn for ReplyingProcessor {
+        fn process_signal(&self, signal: &NeuralSignal) -> Result<Option<NeuralSignal>> {
+            let reply = SignalPayload::Message("pong".to_string());
+            Ok(Some(NeuralSignal::new(SignalType::Cognitive, self.entity_id, signal.source, reply, 0.5)))
+        }
+    }
+    
+    /// Waits on the runtime before replying like [`ReplyingProcessor`]
+    struct SleepingReplier {
+        entity_id: EntityId,
+    }
+    
+    impl AsyncSignalProcessorFn for SleepingReplier {
+        fn process_signal<'a>(&'a self, signal: &'a NeuralSignal) -> SignalProcessorFuture<'a> {
+            Box::pin(async move {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                let reply = SignalPayload::Message("pong".to_string());
+                Ok(Some(NeuralSignal::new(SignalType::Cognitive, self.entity_id, signal.source, reply, 0.5)))
+            })
+        }
+    }
+    
+    /// Records the span and correlation id each event was logged under
+    #[derive(Clone, Default)]
+    struct CorrelationCapture {
+        events: Arc<std::sync::Mutex<Vec<(&'static str, String)>>>,
+    }
+    
+    struct CorrelationField(String);
+    
+    impl<S> tracing_subscriber::Layer<S> for CorrelationCapture
+    where
+        S: tracing::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+    {
+        fn on_new_span(&self, attrs: &tracing::span::Attributes<'_>, id: &tracing::span::Id, ctx: tracing_subscriber::layer::Context<'_, S>) {
+            struct Visitor(Option<String>);
+            impl tracing::field::Visit for Visitor {
+                fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+                    if field.name() == "correlation_id" {
+                        self.0 = Some(format!("{:?}", value));
+                    }
+                }
+            }
+            let mut visitor = Visitor(None);
+            attrs.record(&mut visitor);
+            if let (Some(correlation_id), Some(span)) = (visitor.0, ctx.span(id)) {
+                span.extensions_mut().insert(CorrelationField(correlation_id));
+            }
+        }
+        
+        fn on_event(&self, event: &tracing::Event<'_>, ctx: tracing_subscriber::layer::Context<'_, S>) {
+            let Some(scope) = ctx.event_scope(event) else {
+                return;
+            };
+            for span in scope {
+                if let Some(CorrelationField(correlation_id)) = span.extensions().get::<CorrelationField>() {
+                    self.events.lock().unwrap().push((span.name(), correlation_id.clone()));
+                    return;
+                }
+            }
+        }
+    }
+    
+    /// Build a system with an energized sender and a capturing receiver
+    async fn capturing_system(
+        sender: EntityId,
+        receiver: EntityId,
+    ) -> (NervousSystem, Arc<std::sync::Mutex<Vec<String>>>) {
+        capturing_system_with_config(sender, receiver, NervousSystemConfig::default()).await
+    }
+    
+    async fn capturing_system_with_config(
+        sender: EntityId,
+        receiver: EntityId,
+        config: NervousSystemConfig,
+    ) -> (NervousSystem, Arc<std::sync::Mutex<Vec<String>>>) {
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        physics_engine
+            .allocate_energy_to_entity(sender, ordered_float::OrderedFloat(0.1))
+            .await
+            .unwrap();
+        let nervous_system = NervousSystem::with_config(physics_engine, config).await.unwrap();
+        
+        let received = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let processor = Box::new(CapturingProcessor { received: received.clone() });
+        nervous_system
+            .register_entity(receiver, HashSet::from([SignalType::Sensory]), processor)
+            .await
+            .unwrap();
+        (nervous_system, received)
+    }
+    
+    async fn wait_for_messages(received: &std::sync::Mutex<Vec<String>>, count: usize) -> Vec<String> {
+        for _ in 0..50 {
+            if received.lock().unwrap().len() >= count {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        received.lock().unwrap().clone()
+    }
+    
     impl SignalProcessorFn for TestProcessor {
         fn process_signal(&self, signal: &NeuralSignal) -> Result<Option<NeuralSignal>> {
             Ok(Some(NeuralSignal::new(
                 SignalType::Coordination,
-                signal.target.unwrap_or(signal.source),
+                signal.target.entity().unwrap_or(signal.source),
                 Some(signal.source),
                 SignalPayload::Message("Test response".to_string()),
                 0.5,
@@ -637,28 +2963,638 @@ mod tests {
     }
     
     #[tokio::test]
-    async fn test_nervous_system_creation() {
-        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
-        let nervous_system = NervousSystem::new(physics_engine).await.unwrap();
+    async fn test_group_signals_reach_current_members() {
+        let sender = EntityId::new();
+        let (first, second, outsider) = (EntityId::new(), EntityId::new(), EntityId::new());
+        let (nervous_system, first_received) = capturing_system(sender, first).await;
+        let mut received = Vec::new();
+        for entity in [second, outsider] {
+            let messages = Arc::new(std::sync::Mutex::new(Vec::new()));
+            let processor = Box::new(CapturingProcessor { received: messages.clone() });
+            nervous_system.register_entity(entity, HashSet::from([SignalType::Sensory]), processor).await.unwrap();
+            received.push(messages);
+        }
+        let (second_received, outsider_received) = (&received[0], &received[1]);
+        let team = |text: &str| NeuralSignal::new(
+            SignalType::Sensory,
+            sender,
+            SignalTarget::Group("team".to_string()),
+            SignalPayload::Message(text.to_string()),
+            0.5,
+        );
         
-        let stats = nervous_system.get_statistics().await.unwrap();
-        assert_eq!(stats.registered_entities, 0);
-        assert_eq!(stats.instance_id, nervous_system.instance_id);
-    }
-    
-    #[tokio::test]
-    async fn test_entity_registration() {
-        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
-        let nervous_system = NervousSystem::new(physics_engine).await.unwrap();
+        nervous_system.create_group("team", HashSet::from([first, second])).await.unwrap();
+        assert!(nervous_system.create_group("team", HashSet::new()).await.is_err());
+        nervous_system.transmit_signal(team("before")).await.unwrap();
+        assert_eq!(wait_for_messages(&first_received, 1).await, vec!["before".to_string()]);
+        assert_eq!(wait_for_messages(second_received, 1).await, vec!["before".to_string()]);
         
-        let entity_id = EntityId::new();
-        let capabilities = HashSet::from([SignalType::Sensory, SignalType::Cognitive]);
-        let processor = Box::new(TestProcessor);
+        assert!(nervous_system.remove_from_group("team", second).await.unwrap());
+        assert!(nervous_system.add_to_group("team", outsider).await.unwrap());
+        nervous_system.transmit_signal(team("after")).await.unwrap();
         
-        nervous_system.register_entity(entity_id, capabilities, processor).await.unwrap();
+        assert_eq!(wait_for_messages(&first_received, 2).await, vec!["before".to_string(), "after".to_string()]);
+        assert_eq!(wait_for_messages(outsider_received, 1).await, vec!["after".to_string()]);
+        assert_eq!(second_received.lock().unwrap().clone(), vec!["before".to_string()]);
+        assert_eq!(nervous_system.group_members("team").await, Some(HashSet::from([first, outsider])));
         
-        let stats = nervous_system.get_statistics().await.unwrap();
-        assert_eq!(stats.registered_entities, 1);
+        let stray = NeuralSignal::new(SignalType::Sensory, sender, SignalTarget::Group("ghosts".to_string()), SignalPayload::Message("boo".to_string()), 0.5);
+        nervous_system.transmit_signal(stray).await.unwrap();
+        let dead_letters = nervous_system.drain_dead_letters().await;
+        assert_eq!(dead_letters.len(), 1);
+        assert_eq!(dead_letters[0].reason, DeadLetterReason::GroupNotFound);
+        assert!(nervous_system.add_to_group("ghosts", first).await.is_err());
+    }
+    
+    /// Answers every signal with a "relayed" message to a group
+    struct GroupRelay {
+        entity: EntityId,
+        group: String,
+    }
+    
+    impl SignalProcessorFn for GroupRelay {
+        fn process_signal(&self, _signal: &NeuralSignal) -> Result<Option<NeuralSignal>> {
+            Ok(Some(NeuralSignal::new(
+                SignalType::Sensory,
+                self.entity,
+                SignalTarget::Group(self.group.clone()),
+                SignalPayload::Message("relayed".to_string()),
+                0.5,
+            )))
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_responses_reach_group_members() {
+        let (sender, first, second, relay) = (EntityId::new(), EntityId::new(), EntityId::new(), EntityId::new());
+        let (nervous_system, first_received) = capturing_system(sender, first).await;
+        let second_received = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let processor = Box::new(CapturingProcessor { received: second_received.clone() });
+        nervous_system.register_entity(second, HashSet::from([SignalType::Sensory]), processor).await.unwrap();
+        let processor = Box::new(GroupRelay { entity: relay, group: "team".to_string() });
+        nervous_system.register_entity(relay, HashSet::from([SignalType::Sensory]), processor).await.unwrap();
+        nervous_system.physics_engine.allocate_energy_to_entity(relay, ordered_float::OrderedFloat(0.01)).await.unwrap();
+        nervous_system.create_group("team", HashSet::from([first, second])).await.unwrap();
+        
+        nervous_system.transmit_signal(message(sender, relay, "relay this")).await.unwrap();
+        
+        assert_eq!(wait_for_messages(&first_received, 1).await, vec!["relayed".to_string()]);
+        assert_eq!(wait_for_messages(&second_received, 1).await, vec!["relayed".to_string()]);
+        // The relay received one signal's energy and paid for two members
+        let relay_energy = nervous_system.physics_engine.entity_energy(relay).await.0;
+        assert!((relay_energy - (0.01 + 0.001 - 0.002)).abs() < 1e-9, "{}", relay_energy);
+    }
+    
+    /// Takes a fixed time over every signal, recording the messages
+    struct PacedProcessor {
+        received: Arc<std::sync::Mutex<Vec<String>>>,
+        pace: Duration,
+    }
+    
+    impl AsyncSignalProcessorFn for PacedProcessor {
+        fn process_signal<'a>(&'a self, signal: &'a NeuralSignal) -> SignalProcessorFuture<'a> {
+            Box::pin(async move {
+                tokio::time::sleep(self.pace).await;
+                if let SignalPayload::Message(text) = &signal.payload {
+                    self.received.lock().unwrap().push(text.clone());
+                }
+                Ok(None)
+            })
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_full_member_queue_does_not_wedge_group_delivery() {
+        let (sender, fast, slow) = (EntityId::new(), EntityId::new(), EntityId::new());
+        let config = NervousSystemConfig::builder().max_concurrent_signals(1).build().unwrap();
+        let (nervous_system, fast_received) = capturing_system_with_config(sender, fast, config).await;
+        let slow_received = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let processor = Arc::new(PacedProcessor { received: slow_received.clone(), pace: Duration::from_millis(50) });
+        nervous_system.register_async_entity(slow, HashSet::from([SignalType::Sensory]), processor).await.unwrap();
+        nervous_system.create_group("team", HashSet::from([fast, slow])).await.unwrap();
+        let texts: Vec<String> = (0..5).map(|i| format!("signal {}", i)).collect();
+        
+        let sent = tokio::time::timeout(Duration::from_secs(5), async {
+            for text in &texts {
+                let signal = NeuralSignal::new(SignalType::Sensory, sender, SignalTarget::Group("team".to_string()), SignalPayload::Message(text.clone()), 0.5);
+                nervous_system.transmit_signal(signal).await.unwrap();
+            }
+        }).await;
+        
+        assert!(sent.is_ok(), "group delivery stalled behind a full queue");
+        assert_eq!(wait_for_messages(&fast_received, texts.len()).await, texts);
+        assert!(nervous_system.wait_until_idle(Duration::from_secs(5)).await);
+        assert_eq!(slow_received.lock().unwrap().clone(), texts);
+    }
+    
+    /// Send more signals than a slow receiver's one-slot queue holds, checking they all get through
+    async fn assert_backpressure_drains(config: NervousSystemConfig) {
+        let (sender, receiver) = (EntityId::new(), EntityId::new());
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        physics_engine.allocate_energy_to_entity(sender, ordered_float::OrderedFloat(0.1)).await.unwrap();
+        let nervous_system = NervousSystem::with_config(physics_engine, config).await.unwrap();
+        let received = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let processor = Arc::new(PacedProcessor { received: received.clone(), pace: Duration::from_millis(50) });
+        nervous_system.register_async_entity(receiver, HashSet::from([SignalType::Sensory]), processor).await.unwrap();
+        let texts: Vec<String> = (0..5).map(|i| format!("signal {}", i)).collect();
+        
+        let sent = tokio::time::timeout(Duration::from_secs(5), async {
+            for text in &texts {
+                nervous_system.transmit_signal(message(sender, receiver, text)).await.unwrap();
+            }
+        }).await;
+        
+        assert!(sent.is_ok(), "senders stalled on a full queue");
+        assert!(nervous_system.wait_until_idle(Duration::from_secs(5)).await);
+        assert_eq!(received.lock().unwrap().clone(), texts);
+        let stats = nervous_system.list_entities().await[0].stats.clone();
+        assert_eq!(stats.signals_processed, texts.len() as u64);
+    }
+    
+    #[tokio::test]
+    async fn test_full_queue_does_not_deadlock_processing() {
+        assert_backpressure_drains(NervousSystemConfig::builder().max_concurrent_signals(1).build().unwrap()).await;
+    }
+    
+    #[tokio::test]
+    async fn test_energy_weighted_dispatch_drains_full_queues() {
+        let config = NervousSystemConfig::builder()
+            .max_concurrent_signals(1)
+            .processing_strategy(ProcessingStrategy::EnergyWeighted)
+            .build()
+            .unwrap();
+        assert_backpressure_drains(config).await;
+    }
+    
+    fn message(sender: EntityId, receiver: EntityId, text: &str) -> NeuralSignal {
+        NeuralSignal::new(SignalType::Sensory, sender, receiver, SignalPayload::Message(text.to_string()), 0.5)
+    }
+    
+    #[tokio::test]
+    async fn test_dependent_signal_waits_for_its_dependency() {
+        for strategy in [ProcessingStrategy::PerEntity, ProcessingStrategy::EnergyWeighted] {
+            let (sender, receiver) = (EntityId::new(), EntityId::new());
+            let config = NervousSystemConfig::builder()
+                .causal_ordering(Duration::from_secs(5))
+                .processing_strategy(strategy)
+                .build()
+                .unwrap();
+            let (nervous_system, received) = capturing_system_with_config(sender, receiver, config).await;
+            
+            let cause = message(sender, receiver, "cause");
+            let effect = message(sender, receiver, "effect").with_causal_dependency(cause.signal_id);
+            let consequence = message(sender, receiver, "consequence").with_causal_dependency(effect.signal_id);
+            nervous_system.transmit_signal(consequence).await.unwrap();
+            nervous_system.transmit_signal(effect).await.unwrap();
+            nervous_system.transmit_signal(cause).await.unwrap();
+            
+            assert_eq!(
+                wait_for_messages(&received, 3).await,
+                vec!["cause".to_string(), "effect".to_string(), "consequence".to_string()],
+                "{:?}", strategy
+            );
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_signal_with_missing_dependency_times_out() {
+        let (sender, receiver) = (EntityId::new(), EntityId::new());
+        let config = NervousSystemConfig::builder().causal_ordering(Duration::from_millis(50)).build().unwrap();
+        let (nervous_system, received) = capturing_system_with_config(sender, receiver, config).await;
+        
+        let orphan = message(sender, receiver, "orphan").with_causal_dependency(Uuid::new_v4());
+        let orphan_id = orphan.signal_id;
+        nervous_system.transmit_signal(orphan).await.unwrap();
+        nervous_system.transmit_signal(message(sender, receiver, "independent")).await.unwrap();
+        
+        assert_eq!(wait_for_messages(&received, 1).await, vec!["independent".to_string()]);
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        
+        assert_eq!(received.lock().unwrap().clone(), vec!["independent".to_string()]);
+        let dead_letters = nervous_system.drain_dead_letters().await;
+        assert_eq!(dead_letters.len(), 1);
+        assert_eq!(dead_letters[0].signal.signal_id, orphan_id);
+        assert_eq!(dead_letters[0].reason, DeadLetterReason::DependencyTimeout);
+    }
+    
+    #[tokio::test]
+    async fn test_failed_dependency_does_not_release_its_dependents() {
+        let (sender, receiver, failer) = (EntityId::new(), EntityId::new(), EntityId::new());
+        let config = NervousSystemConfig::builder().causal_ordering(Duration::from_millis(50)).build().unwrap();
+        let (nervous_system, received) = capturing_system_with_config(sender, receiver, config).await;
+        nervous_system.register_entity(failer, HashSet::from([SignalType::Sensory]), Box::new(FailingProcessor)).await.unwrap();
+        
+        let cause = message(sender, failer, "cause");
+        let effect = message(sender, receiver, "effect").with_causal_dependency(cause.signal_id);
+        let effect_id = effect.signal_id;
+        nervous_system.transmit_signal(cause).await.unwrap();
+        nervous_system.transmit_signal(effect).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        
+        assert!(received.lock().unwrap().is_empty());
+        let dead_letters = nervous_system.drain_dead_letters().await;
+        assert!(dead_letters.iter().any(|dead| dead.signal.signal_id == effect_id && dead.reason == DeadLetterReason::DependencyTimeout));
+    }
+    
+    #[tokio::test]
+    async fn test_list_entities_describes_each_registration() {
+        let (sender, listener) = (EntityId::new(), EntityId::new());
+        let (nervous_system, received) = capturing_system(sender, listener).await;
+        let planner = EntityId::new();
+        nervous_system
+            .register_entity(planner, HashSet::from([SignalType::Cognitive, SignalType::Motor]), Box::new(TestProcessor))
+            .await
+            .unwrap();
+        nervous_system.form_pathway(listener, planner).await.unwrap();
+        nervous_system.transmit_signal(message(sender, listener, "hello")).await.unwrap();
+        wait_for_messages(&received, 1).await;
+        
+        // Statistics are recorded just after the processor returns
+        let mut entities = nervous_system.list_entities().await;
+        for _ in 0..50 {
+            if entities.iter().any(|info| info.stats.signals_processed > 0) {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            entities = nervous_system.list_entities().await;
+        }
+        
+        assert_eq!(entities.len(), 2);
+        let info = |entity_id| entities.iter().find(|info| info.entity_id == entity_id).unwrap();
+        assert_eq!(info(listener).capabilities, HashSet::from([SignalType::Sensory]));
+        assert_eq!(info(listener).stats.signals_processed, 1);
+        assert_eq!(info(listener).queue_len, 0);
+        assert_eq!(info(planner).capabilities, HashSet::from([SignalType::Cognitive, SignalType::Motor]));
+        assert_eq!(info(planner).stats.signals_processed, 0);
+        assert!(entities.iter().all(|info| info.pathway_count == 1));
+    }
+    
+    #[tokio::test]
+    async fn test_quarantined_entity_receives_nothing_until_released() {
+        let (sender, receiver) = (EntityId::new(), EntityId::new());
+        let (nervous_system, received) = capturing_system(sender, receiver).await;
+        
+        nervous_system.quarantine(receiver, "flooding the cortex".to_string()).await;
+        nervous_system.transmit_signal(message(sender, receiver, "blocked")).await.unwrap();
+        
+        let entities = nervous_system.list_entities().await;
+        assert_eq!(entities[0].quarantine.as_deref(), Some("flooding the cortex"));
+        let dead_letters = nervous_system.drain_dead_letters().await;
+        assert_eq!(dead_letters.len(), 1);
+        assert_eq!(dead_letters[0].reason, DeadLetterReason::Quarantined);
+        
+        assert!(nervous_system.release_quarantine(receiver).await);
+        assert!(!nervous_system.release_quarantine(receiver).await);
+        nervous_system.transmit_signal(message(sender, receiver, "resumed")).await.unwrap();
+        
+        assert_eq!(wait_for_messages(&received, 1).await, vec!["resumed".to_string()]);
+        assert_eq!(nervous_system.list_entities().await[0].quarantine, None);
+    }
+    
+    #[tokio::test]
+    async fn test_repeated_failures_trigger_quarantine() {
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        let config = NervousSystemConfig::builder().quarantine_after_errors(3).build().unwrap();
+        let nervous_system = NervousSystem::with_config(physics_engine.clone(), config).await.unwrap();
+        let (sender, faulty) = (EntityId::new(), EntityId::new());
+        physics_engine
+            .allocate_energy_to_entity(sender, ordered_float::OrderedFloat(0.1))
+            .await
+            .unwrap();
+        nervous_system
+            .register_entity(faulty, HashSet::from([SignalType::Sensory]), Box::new(FailingProcessor))
+            .await
+            .unwrap();
+        
+        for i in 0..3 {
+            nervous_system.transmit_signal(message(sender, faulty, &format!("ping {}", i))).await.unwrap();
+        }
+        let mut info = nervous_system.list_entities().await.remove(0);
+        for _ in 0..50 {
+            if info.quarantine.is_some() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            info = nervous_system.list_entities().await.remove(0);
+        }
+        
+        assert_eq!(info.quarantine.as_deref(), Some("3 consecutive processing errors"));
+        assert_eq!(info.stats.error_count, 3);
+        nervous_system.transmit_signal(message(sender, faulty, "ping 3")).await.unwrap();
+        let dead_letters = nervous_system.drain_dead_letters().await;
+        assert_eq!(dead_letters.len(), 1);
+        assert_eq!(dead_letters[0].reason, DeadLetterReason::Quarantined);
+    }
+    
+    #[tokio::test]
+    async fn test_hung_processor_is_quarantined_after_timeouts() {
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        let config = NervousSystemConfig::builder()
+            .signal_timeout(Duration::from_millis(20))
+            .quarantine_after_errors(2)
+            .build()
+            .unwrap();
+        let nervous_system = NervousSystem::with_config(physics_engine.clone(), config).await.unwrap();
+        let (sender, hung) = (EntityId::new(), EntityId::new());
+        physics_engine
+            .allocate_energy_to_entity(sender, ordered_float::OrderedFloat(0.1))
+            .await
+            .unwrap();
+        nervous_system
+            .register_async_entity(hung, HashSet::from([SignalType::Sensory]), Arc::new(StallingProcessor))
+            .await
+            .unwrap();
+        
+        for i in 0..2 {
+            nervous_system.transmit_signal(message(sender, hung, &format!("ping {}", i))).await.unwrap();
+        }
+        let mut quarantine = None;
+        for _ in 0..50 {
+            quarantine = nervous_system.quarantine_reason(hung).await;
+            if quarantine.is_some() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        
+        assert_eq!(quarantine.as_deref(), Some("2 consecutive processing errors"));
+        assert_eq!(nervous_system.list_entities().await[0].stats.error_count, 2);
+    }
+    
+    #[tokio::test]
+    async fn test_rate_limit_violations_count_toward_quarantine() {
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        let config = NervousSystemConfig::builder()
+            .max_signals_per_second_per_entity(1)
+            .quarantine_after_errors(3)
+            .build()
+            .unwrap();
+        let nervous_system = NervousSystem::with_config(physics_engine.clone(), config).await.unwrap();
+        let (chatty, receiver) = (EntityId::new(), EntityId::new());
+        physics_engine
+            .allocate_energy_to_entity(chatty, ordered_float::OrderedFloat(0.1))
+            .await
+            .unwrap();
+        for entity in [chatty, receiver] {
+            nervous_system
+                .register_entity(entity, HashSet::from([SignalType::Sensory]), Box::new(CapturingProcessor { received: Default::default() }))
+                .await
+                .unwrap();
+        }
+        
+        nervous_system.transmit_signal(message(chatty, receiver, "hello")).await.unwrap();
+        for i in 0..3 {
+            assert!(nervous_system.transmit_signal(message(chatty, receiver, &format!("again {}", i))).await.is_err());
+        }
+        
+        assert_eq!(nervous_system.quarantine_reason(chatty).await.as_deref(), Some("3 consecutive processing errors"));
+        assert_eq!(nervous_system.quarantine_reason(receiver).await, None);
+    }
+    
+    #[tokio::test]
+    async fn test_nervous_system_creation() {
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        let nervous_system = NervousSystem::new(physics_engine).await.unwrap();
+        
+        let stats = nervous_system.get_statistics().await.unwrap();
+        assert_eq!(stats.registered_entities, 0);
+        assert_eq!(stats.instance_id, nervous_system.instance_id);
+    }
+    
+    #[tokio::test]
+    async fn test_entity_registration() {
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        let nervous_system = NervousSystem::new(physics_engine).await.unwrap();
+        
+        let entity_id = EntityId::new();
+        let capabilities = HashSet::from([SignalType::Sensory, SignalType::Cognitive]);
+        let processor = Box::new(TestProcessor);
+        
+        nervous_system.register_entity(entity_id, capabilities, processor).await.unwrap();
+        
+        let stats = nervous_system.get_statistics().await.unwrap();
+        assert_eq!(stats.registered_entities, 1);
+    }
+    
+    #[tokio::test]
+    async fn test_entity_deregistration() {
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        let nervous_system = NervousSystem::new(physics_engine).await.unwrap();
+        
+        let entity_id = EntityId::new();
+        let other_id = EntityId::new();
+        let capabilities = HashSet::from([SignalType::Sensory]);
+        nervous_system.register_entity(entity_id, capabilities, Box::new(TestProcessor)).await.unwrap();
+        nervous_system.form_pathway(entity_id, other_id).await.unwrap();
+        
+        assert!(nervous_system.deregister_entity(entity_id).await.unwrap());
+        
+        let stats = nervous_system.get_statistics().await.unwrap();
+        assert_eq!(stats.registered_entities, 0);
+        assert_eq!(stats.total_pathways, 0);
+        
+        // Deregistering twice has nothing left to join
+        assert!(!nervous_system.deregister_entity(entity_id).await.unwrap());
+    }
+    
+    #[tokio::test]
+    async fn test_processing_errors_are_counted() {
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        let nervous_system = NervousSystem::new(physics_engine.clone()).await.unwrap();
+        
+        let sender = EntityId::new();
+        let entity_id = EntityId::new();
+        physics_engine
+            .allocate_energy_to_entity(sender, ordered_float::OrderedFloat(0.1))
+            .await
+            .unwrap();
+        nervous_system
+            .register_entity(entity_id, HashSet::from([SignalType::Sensory]), Box::new(FailingProcessor))
+            .await
+            .unwrap();
+        
+        for _ in 0..3 {
+            let signal = NeuralSignal::new(
+                SignalType::Sensory,
+                sender,
+                Some(entity_id),
+                SignalPayload::Message("ping".to_string()),
+                0.5,
+            );
+            nervous_system.transmit_signal(signal).await.unwrap();
+        }
+        
+        let mut stats = nervous_system.get_statistics().await.unwrap();
+        for _ in 0..50 {
+            if stats.total_signals_processed == 3 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            stats = nervous_system.get_statistics().await.unwrap();
+        }
+        
+        assert_eq!(stats.total_signals_processed, 3);
+        assert_eq!(stats.total_errors, 3);
+    }
+    
+    #[tokio::test]
+    async fn test_statistics_break_down_by_signal_type() {
+        let nervous_system = NervousSystem::new(Arc::new(MockPhysicsEngine::new())).await.unwrap();
+        let (sender, listener, coordinator) = (EntityId::new(), EntityId::new(), EntityId::new());
+        nervous_system.register_entity(listener, HashSet::from([SignalType::Sensory]), Box::new(TestProcessor)).await.unwrap();
+        nervous_system.register_entity(coordinator, HashSet::from([SignalType::Coordination]), Box::new(TestProcessor)).await.unwrap();
+        
+        let mix = [
+            (SignalType::Sensory, Some(listener), 0.2),
+            (SignalType::Sensory, Some(listener), 0.4),
+            (SignalType::Sensory, Some(listener), 0.6),
+            (SignalType::Coordination, Some(coordinator), 0.9),
+            (SignalType::Emergency, None, 1.0),
+        ];
+        for (signal_type, target, strength) in mix {
+            let signal = NeuralSignal::new(signal_type, sender, target, SignalPayload::Message("mix".to_string()), strength);
+            nervous_system.transmit_signal(signal).await.unwrap();
+        }
+        
+        // Each processed signal is answered with a Coordination response, which is transmitted too
+        let mut stats = nervous_system.get_statistics().await.unwrap();
+        for _ in 0..50 {
+            if stats.total_signals_processed == 4 && stats.by_type[&SignalType::Coordination].transmitted == 5 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            stats = nervous_system.get_statistics().await.unwrap();
+        }
+        
+        let sensory = &stats.by_type[&SignalType::Sensory];
+        assert_eq!((sensory.transmitted, sensory.processed, sensory.dropped), (3, 3, 0));
+        assert!((sensory.avg_strength - 0.4).abs() < 1e-9);
+        let coordination = &stats.by_type[&SignalType::Coordination];
+        assert_eq!((coordination.transmitted, coordination.processed), (5, 1));
+        let emergency = &stats.by_type[&SignalType::Emergency];
+        assert_eq!((emergency.transmitted, emergency.processed), (1, 0));
+        assert!(!stats.by_type.contains_key(&SignalType::Memory));
+    }
+    
+    /// Drops every signal whose payload mentions a secret
+    struct DropSecrets;
+    
+    impl SignalMiddleware for DropSecrets {
+        fn on_transmit(&self, signal: &mut NeuralSignal) -> MiddlewareAction {
+            match &signal.payload {
+                SignalPayload::Message(text) if text.contains("secret") => MiddlewareAction::Drop,
+                _ => MiddlewareAction::Continue,
+            }
+        }
+    }
+    
+    /// Halves the strength of every signal, rejecting those that become too weak
+    struct Dampen;
+    
+    impl SignalMiddleware for Dampen {
+        fn on_transmit(&self, signal: &mut NeuralSignal) -> MiddlewareAction {
+            signal.strength /= 2.0;
+            if signal.strength < 0.1 {
+                return MiddlewareAction::Reject("too weak".to_string());
+            }
+            MiddlewareAction::Continue
+        }
+    }
+    
+    /// Answers every signal by leaking a secret to its source
+    struct LeakingProcessor {
+        entity_id: EntityId,
+    }
+    
+    impl SignalProcessorFn for LeakingProcessor {
+        fn process_signal(&self, signal: &NeuralSignal) -> Result<Option<NeuralSignal>> {
+            let reply = SignalPayload::Message("the secret plan".to_string());
+            Ok(Some(NeuralSignal::new(SignalType::Cognitive, self.entity_id, signal.source, reply, 0.5)))
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_responses_pass_through_middleware_and_physics() {
+        let (sender, leaker, pauper) = (EntityId::new(), EntityId::new(), EntityId::new());
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        for entity in [sender, leaker] {
+            physics_engine.allocate_energy_to_entity(entity, ordered_float::OrderedFloat(0.1)).await.unwrap();
+        }
+        let nervous_system = NervousSystem::new(physics_engine).await.unwrap();
+        nervous_system.add_middleware(Box::new(DropSecrets));
+        nervous_system
+            .register_entity(leaker, HashSet::from([SignalType::Sensory]), Box::new(LeakingProcessor { entity_id: leaker }))
+            .await
+            .unwrap();
+        nervous_system
+            .register_entity(pauper, HashSet::from([SignalType::Sensory]), Box::new(ReplyingProcessor { entity_id: pauper }))
+            .await
+            .unwrap();
+        let mut replies: SubscriberStream = Box::pin(nervous_system.create_signal_stream(sender, vec![SignalType::Cognitive]).await.unwrap());
+        
+        // The leak is dropped by middleware and the pauper, handed no energy, cannot pay for its reply
+        nervous_system.transmit_signal(message(sender, leaker, "ping")).await.unwrap();
+        nervous_system.transmit_signal(message(sender, pauper, "ping").with_energy_cost(0.0)).await.unwrap();
+        assert!(tokio::time::timeout(Duration::from_millis(100), replies.next()).await.is_err());
+        
+        nervous_system.physics_engine.allocate_energy_to_entity(pauper, ordered_float::OrderedFloat(0.1)).await.unwrap();
+        nervous_system.transmit_signal(message(sender, pauper, "ping")).await.unwrap();
+        match tokio::time::timeout(Duration::from_secs(1), replies.next()).await {
+            Ok(Some(SignalStreamEvent::Signal(reply))) => assert_eq!(reply.source, pauper),
+            other => panic!("expected the pauper's reply, got {:?}", other),
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_middleware_runs_in_order_before_delivery() {
+        let nervous_system = NervousSystem::new(Arc::new(MockPhysicsEngine::new())).await.unwrap();
+        nervous_system.add_middleware(Box::new(DropSecrets));
+        nervous_system.add_middleware(Box::new(Dampen));
+        let (sender, listener) = (EntityId::new(), EntityId::new());
+        let mut stream: SubscriberStream = Box::pin(nervous_system.create_signal_stream(listener, vec![SignalType::Sensory]).await.unwrap());
+        let signal = |text: &str, strength| NeuralSignal::new(SignalType::Sensory, sender, listener, SignalPayload::Message(text.to_string()), strength);
+        
+        let result = nervous_system.transmit_signal(signal("the secret plan", 0.8)).await.unwrap();
+        assert_eq!(result.recipients, 0);
+        
+        nervous_system.transmit_signal(signal("weather report", 0.8)).await.unwrap();
+        match tokio::time::timeout(Duration::from_millis(50), stream.next()).await {
+            Ok(Some(SignalStreamEvent::Signal(delivered))) => {
+                assert!(matches!(delivered.payload, SignalPayload::Message(ref text) if text == "weather report"));
+                assert!((delivered.strength - 0.4).abs() < 1e-9);
+            }
+            other => panic!("expected the dampened signal, got {:?}", other),
+        }
+        
+        let error = nervous_system.transmit_signal(signal("whisper", 0.1)).await.unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<NervousSystemError>(),
+            Some(NervousSystemError::SignalRejected { reason }) if reason == "too weak"
+        ));
+        assert!(tokio::time::timeout(Duration::from_millis(50), stream.next()).await.is_err());
+    }
+    
+    #[tokio::test]
+    async fn test_peek_leaves_signals_in_the_inbox_and_drain_takes_them() {
+        let nervous_system = NervousSystem::new(Arc::new(MockPhysicsEngine::new())).await.unwrap();
+        let (sender, stepper) = (EntityId::new(), EntityId::new());
+        nervous_system.register_manual_entity(stepper, HashSet::from([SignalType::Cognitive])).await.unwrap();
+        
+        let mut sent = Vec::new();
+        for step in ["first", "second", "third"] {
+            let signal = NeuralSignal::new(SignalType::Cognitive, sender, stepper, SignalPayload::Message(step.to_string()), 0.5);
+            sent.push(signal.signal_id);
+            assert_eq!(nervous_system.transmit_signal(signal).await.unwrap().recipients, 1);
+        }
+        let ids = |signals: Vec<NeuralSignal>| signals.into_iter().map(|signal| signal.signal_id).collect::<Vec<_>>();
+        
+        assert_eq!(ids(nervous_system.peek_inbox(stepper, 2).await), sent[..2]);
+        assert_eq!(ids(nervous_system.peek_inbox(stepper, 10).await), sent);
+        assert_eq!(ids(nervous_system.drain_inbox(stepper).await), sent);
+        assert!(nervous_system.peek_inbox(stepper, 10).await.is_empty());
+        assert!(nervous_system.drain_inbox(stepper).await.is_empty());
+        assert!(nervous_system.peek_inbox(EntityId::new(), 10).await.is_empty());
+        assert!(nervous_system.restart_processing(stepper).await.is_err());
     }
     
     #[tokio::test]
@@ -685,4 +3621,1316 @@ mod tests {
         assert!(result.success);
         assert_eq!(result.signals_generated, 1);
     }
-}
\ No newline at end of file
+    
+    #[tokio::test]
+    async fn test_directed_signals_route_only_to_their_target() {
+        // The mock approves transfers from an unfunded sender, isolating routing from conservation
+        let physics_engine = Arc::new(MockPhysicsEngine::new());
+        let nervous_system = NervousSystem::new(physics_engine.clone()).await.unwrap();
+        let (sender, target, bystander) = (EntityId::new(), EntityId::new(), EntityId::new());
+        let mut streams: Vec<SubscriberStream> = Vec::new();
+        for entity in [target, bystander] {
+            let stream = nervous_system.create_signal_stream(entity, vec![SignalType::Sensory]).await.unwrap();
+            streams.push(Box::pin(stream));
+        }
+        let directed = || NeuralSignal::new(SignalType::Sensory, sender, target, SignalPayload::Message("ping".to_string()), 0.5);
+        
+        nervous_system.transmit_signal(directed()).await.unwrap();
+        assert_eq!(streams_reached(&mut streams[..1]).await, 1);
+        assert_eq!(streams_reached(&mut streams[1..]).await, 0);
+        
+        physics_engine.fail_next(PhysicsViolation::EnergyConservation { reason: "simulated".to_string() });
+        assert!(nervous_system.transmit_signal(directed()).await.is_err());
+        assert_eq!(streams_reached(&mut streams).await, 0);
+        assert!(matches!(
+            physics_engine.operations().as_slice(),
+            [PhysicsOperation::TransferEnergy { to, .. }, PhysicsOperation::TransferEnergy { .. }] if *to == target
+        ));
+    }
+    
+    type SubscriberStream = std::pin::Pin<Box<dyn Stream<Item = SignalStreamEvent> + Send>>;
+    
+    /// Broadcast subscribers' streams and the energized sender of a fan-out test
+    async fn fan_out_system(
+        policy: BroadcastCostPolicy,
+        sender_energy: f64,
+        subscribers: usize,
+    ) -> (NervousSystem, Arc<PhysicsEngine>, EntityId, Vec<SubscriberStream>) {
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        let sender = EntityId::new();
+        physics_engine
+            .allocate_energy_to_entity(sender, ordered_float::OrderedFloat(sender_energy))
+            .await
+            .unwrap();
+        let config = NervousSystemConfig::builder().broadcast_cost_policy(policy).build().unwrap();
+        let nervous_system = NervousSystem::with_config(physics_engine.clone(), config).await.unwrap();
+        
+        let mut streams = Vec::new();
+        for _ in 0..subscribers {
+            let stream = nervous_system.create_signal_stream(EntityId::new(), vec![SignalType::Sensory]).await.unwrap();
+            streams.push(Box::pin(stream) as SubscriberStream);
+        }
+        (nervous_system, physics_engine, sender, streams)
+    }
+    
+    /// Count the streams that yield a signal within a short wait
+    async fn streams_reached(streams: &mut [SubscriberStream]) -> usize {
+        let mut reached = 0;
+        for stream in streams {
+            if let Ok(Some(SignalStreamEvent::Signal(_))) = tokio::time::timeout(Duration::from_millis(50), stream.next()).await {
+                reached += 1;
+            }
+        }
+        reached
+    }
+    
+    fn costly_broadcast(sender: EntityId) -> NeuralSignal {
+        NeuralSignal::broadcast(SignalType::Sensory, sender, SignalPayload::Message("everyone".to_string()), 0.5)
+            .with_energy_cost(0.1)
+    }
+    
+    #[tokio::test]
+    async fn test_broadcast_cost_scales_with_subscribers() {
+        let (nervous_system, physics_engine, sender, mut streams) =
+            fan_out_system(BroadcastCostPolicy::AllOrNothing, 0.5, 3).await;
+        
+        let result = nervous_system.transmit_signal(costly_broadcast(sender)).await.unwrap();
+        assert_eq!(result.recipients, 3);
+        assert!((result.energy_consumed - 0.3).abs() < 0.01);
+        assert_eq!(streams_reached(&mut streams).await, 3);
+        assert!((physics_engine.entity_energy(sender).await.0 - 0.2).abs() < 1e-9);
+        
+        // Reaching all three again would take 0.3 of the remaining 0.2
+        let error = nervous_system.transmit_signal(costly_broadcast(sender)).await.unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<NervousSystemError>(),
+            Some(NervousSystemError::BroadcastUnaffordable { recipients: 3, .. })
+        ));
+        assert_eq!(streams_reached(&mut streams).await, 0);
+        assert!((physics_engine.entity_energy(sender).await.0 - 0.2).abs() < 1e-9);
+    }
+    
+    #[tokio::test]
+    async fn test_best_effort_broadcast_reaches_what_it_can_afford() {
+        let (nervous_system, physics_engine, sender, mut streams) =
+            fan_out_system(BroadcastCostPolicy::BestEffort, 0.25, 3).await;
+        
+        let result = nervous_system.transmit_signal(costly_broadcast(sender)).await.unwrap();
+        
+        assert_eq!(result.recipients, 2);
+        assert_eq!(streams_reached(&mut streams).await, 2);
+        assert!((physics_engine.entity_energy(sender).await.0 - 0.05).abs() < 1e-9);
+        let energy = physics_engine.get_engine_state().await.unwrap().energy_state;
+        assert!((energy.dissipated_energy.0 - 0.2).abs() < 1e-9);
+    }
+    
+    #[tokio::test]
+    async fn test_best_effort_broadcast_goes_to_the_first_readers() {
+        let (nervous_system, physics_engine, sender, mut streams) =
+            fan_out_system(BroadcastCostPolicy::BestEffort, 0.25, 3).await;
+        
+        // Each broadcast pays for two of the three subscribers
+        nervous_system.transmit_signal(costly_broadcast(sender)).await.unwrap();
+        assert_eq!(streams_reached(&mut streams[..2]).await, 2);
+        assert_eq!(streams_reached(&mut streams[2..]).await, 0);
+        
+        physics_engine.allocate_energy_to_entity(sender, ordered_float::OrderedFloat(0.2)).await.unwrap();
+        streams.reverse();
+        nervous_system.transmit_signal(costly_broadcast(sender)).await.unwrap();
+        assert_eq!(streams_reached(&mut streams[..2]).await, 2);
+        assert_eq!(streams_reached(&mut streams[2..]).await, 0);
+    }
+    
+    #[tokio::test]
+    async fn test_group_signal_cost_is_dissipated() {
+        let (nervous_system, physics_engine, sender, _streams) =
+            fan_out_system(BroadcastCostPolicy::AllOrNothing, 0.5, 0).await;
+        nervous_system.create_group("team", HashSet::from([EntityId::new(), EntityId::new(), EntityId::new()])).await.unwrap();
+        let signal = NeuralSignal::new(SignalType::Sensory, sender, SignalTarget::Group("team".to_string()), SignalPayload::Message("hi".to_string()), 0.5)
+            .with_energy_cost(0.1);
+        
+        nervous_system.transmit_signal(signal).await.unwrap();
+        
+        // Each of the three members costs the sender one signal's energy
+        let energy = physics_engine.get_engine_state().await.unwrap().energy_state;
+        assert!((physics_engine.entity_energy(sender).await.0 - 0.2).abs() < 1e-9);
+        assert!((energy.dissipated_energy.0 - 0.3).abs() < 1e-9);
+        assert!((energy.allocated_energy + energy.free_energy + energy.dissipated_energy - energy.total_energy).abs() < 1e-9);
+    }
+    
+    /// A directed-signal system whose sender holds `sender_energy`, and the target's stream
+    async fn depleted_system(policy: DegradationPolicy, sender_energy: f64) -> (NervousSystem, Arc<PhysicsEngine>, EntityId, EntityId, SubscriberStream) {
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        let (sender, target) = (EntityId::new(), EntityId::new());
+        if sender_energy > 0.0 {
+            physics_engine.allocate_energy_to_entity(sender, ordered_float::OrderedFloat(sender_energy)).await.unwrap();
+        }
+        let config = NervousSystemConfig::builder().degradation_policy(policy).build().unwrap();
+        let nervous_system = NervousSystem::with_config(physics_engine.clone(), config).await.unwrap();
+        let stream = nervous_system.create_signal_stream(target, vec![SignalType::Sensory]).await.unwrap();
+        (nervous_system, physics_engine, sender, target, Box::pin(stream))
+    }
+    
+    fn costly_signal(sender: EntityId, target: EntityId) -> NeuralSignal {
+        NeuralSignal::new(SignalType::Sensory, sender, target, SignalPayload::Message("urgent".to_string()), 0.8)
+            .with_energy_cost(0.1)
+    }
+    
+    async fn next_signal(stream: &mut SubscriberStream) -> Option<NeuralSignal> {
+        match tokio::time::timeout(Duration::from_millis(50), stream.next()).await {
+            Ok(Some(SignalStreamEvent::Signal(signal))) => Some(signal),
+            _ => None,
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_unaffordable_signals_fail_by_default() {
+        let (nervous_system, _, sender, target, mut stream) = depleted_system(DegradationPolicy::Fail, 0.05).await;
+        
+        assert!(nervous_system.transmit_signal(costly_signal(sender, target)).await.is_err());
+        assert!(next_signal(&mut stream).await.is_none());
+    }
+    
+    #[tokio::test]
+    async fn test_downgrade_delivers_what_the_source_can_afford() {
+        let (nervous_system, physics_engine, sender, target, mut stream) = depleted_system(DegradationPolicy::Downgrade, 0.05).await;
+        
+        nervous_system.transmit_signal(costly_signal(sender, target)).await.unwrap();
+        
+        let delivered = next_signal(&mut stream).await.expect("downgraded signal is delivered");
+        assert!((delivered.strength - 0.4).abs() < 1e-9);
+        assert!((delivered.energy_cost - 0.05).abs() < 1e-9);
+        assert_eq!(physics_engine.entity_energy(sender).await.0, 0.0);
+        
+        // Nothing left to downgrade to
+        assert!(nervous_system.transmit_signal(costly_signal(sender, target)).await.is_err());
+    }
+    
+    #[tokio::test]
+    async fn test_deferred_signals_are_sent_once_energy_recovers() {
+        let (nervous_system, physics_engine, sender, target, mut stream) = depleted_system(DegradationPolicy::QueueAndRetry, 0.0).await;
+        let signal = costly_signal(sender, target);
+        let (signal_id, deferred_at) = (signal.signal_id, signal.timestamp);
+        
+        let result = nervous_system.transmit_signal(signal).await.unwrap();
+        assert_eq!(result.recipients, 0);
+        assert_eq!(nervous_system.retry_deferred_signals().await.unwrap(), 0);
+        assert!(next_signal(&mut stream).await.is_none());
+        
+        // Nothing has to ask for the retry once the sender can pay
+        physics_engine.allocate_energy_to_entity(sender, ordered_float::OrderedFloat(0.2)).await.unwrap();
+        let delivered = match tokio::time::timeout(Duration::from_secs(1), stream.next()).await {
+            Ok(Some(SignalStreamEvent::Signal(signal))) => signal,
+            _ => panic!("deferred signal was not delivered"),
+        };
+        assert_eq!(delivered.signal_id, signal_id);
+        assert!(delivered.timestamp > deferred_at);
+        assert!((delivered.strength - 0.8).abs() < 1e-9);
+        assert_eq!(nervous_system.retry_deferred_signals().await.unwrap(), 0);
+    }
+    
+    #[tokio::test]
+    async fn test_recorded_signals_replay_in_order() {
+        let sender = EntityId::new();
+        let receiver = EntityId::new();
+        let (original, received) = capturing_system(sender, receiver).await;
+        
+        let handle = original.start_recording();
+        for text in ["first", "second", "third"] {
+            let signal = NeuralSignal::new(
+                SignalType::Sensory,
+                sender,
+                Some(receiver),
+                SignalPayload::Message(text.to_string()),
+                0.5,
+            );
+            original.transmit_signal(signal).await.unwrap();
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        let log = original.stop_recording(handle).await.unwrap();
+        
+        let expected = vec!["first".to_string(), "second".to_string(), "third".to_string()];
+        assert_eq!(wait_for_messages(&received, 3).await, expected);
+        assert_eq!(log.signals.len(), 3);
+        assert_eq!(log.dropped_signals, 0);
+        assert!(log.signals.windows(2).all(|pair| pair[0].offset <= pair[1].offset));
+        
+        let restored = SignalLog::from_yaml(&log.to_yaml().unwrap()).unwrap();
+        let (fresh, replayed) = capturing_system(sender, receiver).await;
+        
+        assert_eq!(fresh.replay(&restored, 4.0).await.unwrap(), 3);
+        assert_eq!(wait_for_messages(&replayed, 3).await, expected);
+        assert!(fresh.replay(&restored, 0.0).await.is_err());
+    }
+    
+    #[tokio::test]
+    async fn test_replayed_signals_are_stamped_when_replayed() {
+        let sender = EntityId::new();
+        let receiver = EntityId::new();
+        let (original, _) = capturing_system(sender, receiver).await;
+        
+        let handle = original.start_recording();
+        let signal = NeuralSignal::new(SignalType::Sensory, sender, Some(receiver), SignalPayload::Message("old news".to_string()), 0.5);
+        original.transmit_signal(signal).await.unwrap();
+        let mut log = original.stop_recording(handle).await.unwrap();
+        log.signals[0].signal.timestamp -= chrono::Duration::hours(1);
+        
+        // A recorded timestamp would put the signal far past its time to live
+        let config = NervousSystemConfig::builder().default_signal_ttl(Duration::from_secs(60)).build().unwrap();
+        let (fresh, replayed) = capturing_system_with_config(sender, receiver, config).await;
+        
+        fresh.replay(&log, 1.0).await.unwrap();
+        
+        assert_eq!(wait_for_messages(&replayed, 1).await, vec!["old news".to_string()]);
+    }
+    
+    #[tokio::test]
+    async fn test_signals_outside_recording_are_not_logged() {
+        let sender = EntityId::new();
+        let receiver = EntityId::new();
+        let (nervous_system, _) = capturing_system(sender, receiver).await;
+        let ping = || NeuralSignal::new(
+            SignalType::Sensory,
+            sender,
+            Some(receiver),
+            SignalPayload::Message("ping".to_string()),
+            0.5,
+        );
+        
+        nervous_system.transmit_signal(ping()).await.unwrap();
+        let handle = nervous_system.start_recording();
+        nervous_system.transmit_signal(ping()).await.unwrap();
+        let log = nervous_system.stop_recording(handle).await.unwrap();
+        nervous_system.transmit_signal(ping()).await.unwrap();
+        
+        assert_eq!(log.signals.len(), 1);
+    }
+    
+    #[tokio::test]
+    async fn test_channel_overflow_is_counted_and_reported() {
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        let nervous_system = NervousSystem::new(physics_engine.clone()).await.unwrap();
+        let sender = EntityId::new();
+        physics_engine
+            .allocate_energy_to_entity(sender, ordered_float::OrderedFloat(0.5))
+            .await
+            .unwrap();
+        
+        // Subscribed but never read until the channel has overflowed
+        let stream = nervous_system.create_signal_stream(sender, vec![SignalType::Sensory]).await.unwrap();
+        tokio::pin!(stream);
+        
+        let capacity = NervousSystemConfig::default().max_concurrent_signals.next_power_of_two();
+        flood(&nervous_system, sender, SignalType::Sensory, capacity + 5).await;
+        
+        let stats = nervous_system.get_statistics().await.unwrap();
+        assert_eq!(stats.dropped_signals[&SignalType::Sensory], 5);
+        assert_eq!(stats.dropped_signals[&SignalType::Emergency], 0);
+        
+        assert!(matches!(stream.next().await, Some(SignalStreamEvent::Lagged { missed: 5 })));
+        assert!(matches!(stream.next().await, Some(SignalStreamEvent::Signal(_))));
+    }
+    
+    /// Flood a channel with broadcasts from `sender` while one subscriber never reads
+    async fn flood(nervous_system: &NervousSystem, sender: EntityId, signal_type: SignalType, count: usize) {
+        for i in 0..count {
+            let signal = NeuralSignal::broadcast(
+                signal_type.clone(),
+                sender,
+                SignalPayload::Message(format!("flood {}", i)),
+                0.1,
+            );
+            nervous_system.transmit_signal(signal).await.unwrap();
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_channel_capacities_are_per_signal_type() {
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        let config = NervousSystemConfig {
+            channel_capacities: HashMap::from([(SignalType::Sensory, 4), (SignalType::Emergency, 32)]),
+            // Room for the whole emergency flood below
+            emergency: EmergencyConfig { max_per_minute: 20, ..EmergencyConfig::default() },
+            ..NervousSystemConfig::default()
+        };
+        let nervous_system = NervousSystem::with_config(physics_engine.clone(), config).await.unwrap();
+        let sender = EntityId::new();
+        physics_engine
+            .allocate_energy_to_entity(sender, ordered_float::OrderedFloat(0.5))
+            .await
+            .unwrap();
+        
+        let _stream = nervous_system
+            .create_signal_stream(sender, vec![SignalType::Sensory, SignalType::Emergency])
+            .await
+            .unwrap();
+        flood(&nervous_system, sender, SignalType::Sensory, 20).await;
+        flood(&nervous_system, sender, SignalType::Emergency, 20).await;
+        
+        let stats = nervous_system.get_statistics().await.unwrap();
+        assert_eq!(stats.dropped_signals[&SignalType::Sensory], 16);
+        assert_eq!(stats.dropped_signals[&SignalType::Emergency], 0);
+        
+        let zero_capacity = NervousSystemConfig {
+            channel_capacities: HashMap::from([(SignalType::Motor, 0)]),
+            ..NervousSystemConfig::default()
+        };
+        assert!(NervousSystem::with_config(physics_engine, zero_capacity).await.is_err());
+    }
+    
+    #[tokio::test]
+    async fn test_reconfigure_channel_resets_capacity() {
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        let nervous_system = NervousSystem::new(physics_engine.clone()).await.unwrap();
+        let sender = EntityId::new();
+        physics_engine
+            .allocate_energy_to_entity(sender, ordered_float::OrderedFloat(0.5))
+            .await
+            .unwrap();
+        
+        nervous_system.reconfigure_channel(SignalType::Cognitive, 2).await.unwrap();
+        let _stream = nervous_system.create_signal_stream(sender, vec![SignalType::Cognitive]).await.unwrap();
+        flood(&nervous_system, sender, SignalType::Cognitive, 5).await;
+        
+        let stats = nervous_system.get_statistics().await.unwrap();
+        assert_eq!(stats.dropped_signals[&SignalType::Cognitive], 3);
+        assert!(nervous_system.reconfigure_channel(SignalType::Cognitive, 0).await.is_err());
+    }
+    
+    #[test]
+    fn test_config_builder_rejects_invalid_values() {
+        assert_eq!(
+            NervousSystemConfig::builder().signal_decay_rate(2.0).build().unwrap_err(),
+            ConfigError::DecayRateOutOfRange { rate: 2.0 },
+        );
+        assert_eq!(
+            NervousSystemConfig::builder().max_concurrent_signals(0).build().unwrap_err(),
+            ConfigError::ZeroCapacity { signal_type: None },
+        );
+        assert_eq!(
+            NervousSystemConfig::builder().channel_capacity(SignalType::Motor, 0).build().unwrap_err(),
+            ConfigError::ZeroCapacity { signal_type: Some(SignalType::Motor) },
+        );
+        assert_eq!(
+            NervousSystemConfig::builder().signal_timeout(Duration::ZERO).build().unwrap_err(),
+            ConfigError::ZeroTimeout,
+        );
+        assert_eq!(
+            NervousSystemConfig::builder().pathway_formation_threshold(-0.1).build().unwrap_err(),
+            ConfigError::FormationThresholdOutOfRange { threshold: -0.1 },
+        );
+        assert_eq!(
+            NervousSystemConfig::builder().max_signals_per_second_per_entity(0).build().unwrap_err(),
+            ConfigError::ZeroRateLimit,
+        );
+        assert_eq!(
+            NervousSystemConfig::builder().causal_ordering(Duration::ZERO).build().unwrap_err(),
+            ConfigError::ZeroCausalTimeout,
+        );
+        assert_eq!(
+            NervousSystemConfig::builder().quarantine_after_errors(0).build().unwrap_err(),
+            ConfigError::ZeroQuarantineThreshold,
+        );
+        assert_eq!(
+            NervousSystemConfig::builder().max_payload_bytes(0).build().unwrap_err(),
+            ConfigError::ZeroPayloadLimit,
+        );
+        assert_eq!(
+            NervousSystemConfig::builder().retry_policy(RetryPolicy::new(0, Duration::ZERO)).build().unwrap_err(),
+            ConfigError::ZeroRetryAttempts,
+        );
+        assert_eq!(
+            NervousSystemConfig::builder().emergency_lane(0, 0.05).build().unwrap_err(),
+            ConfigError::ZeroEmergencyLimit,
+        );
+        assert_eq!(
+            NervousSystemConfig::builder().emergency_lane(5, -0.1).build().unwrap_err(),
+            ConfigError::NegativeEmergencyReserve { reserve: -0.1 },
+        );
+    }
+    
+    #[tokio::test]
+    async fn test_signal_lifecycle_shares_one_correlation_id() {
+        use tracing_subscriber::layer::SubscriberExt;
+        let capture = CorrelationCapture::default();
+        let _guard = tracing::subscriber::set_default(tracing_subscriber::registry().with(capture.clone()));
+        
+        let (sender, receiver) = (EntityId::new(), EntityId::new());
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        physics_engine.allocate_energy_to_entity(sender, ordered_float::OrderedFloat(0.1)).await.unwrap();
+        let nervous_system = NervousSystem::new(physics_engine).await.unwrap();
+        nervous_system
+            .register_entity(receiver, HashSet::from([SignalType::Sensory]), Box::new(ReplyingProcessor { entity_id: receiver }))
+            .await
+            .unwrap();
+        let replies = nervous_system.create_signal_stream(sender, vec![SignalType::Cognitive]).await.unwrap();
+        tokio::pin!(replies);
+        
+        let request = message(sender, receiver, "ping");
+        let correlation_id = request.correlation_id;
+        nervous_system.transmit_signal(request).await.unwrap();
+        let reply = match tokio::time::timeout(Duration::from_secs(1), replies.next()).await {
+            Ok(Some(SignalStreamEvent::Signal(reply))) => reply,
+            _ => panic!("no reply"),
+        };
+        assert_eq!(reply.correlation_id, correlation_id);
+        assert_ne!(reply.signal_id, correlation_id);
+        
+        let events = capture.events.lock().unwrap().clone();
+        for span in ["transmit_signal", "handle_signal", "transmit_response_signal"] {
+            assert!(events.iter().any(|(name, _)| *name == span), "no events in {}: {:?}", span, events);
+        }
+        assert!(events.iter().all(|(_, id)| *id == correlation_id.to_string()), "{:?}", events);
+    }
+    
+    #[tokio::test]
+    async fn test_async_processor_is_awaited_on_a_current_thread_runtime() {
+        let (sender, receiver) = (EntityId::new(), EntityId::new());
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        physics_engine.allocate_energy_to_entity(sender, ordered_float::OrderedFloat(0.1)).await.unwrap();
+        let nervous_system = NervousSystem::new(physics_engine).await.unwrap();
+        nervous_system
+            .register_async_entity(receiver, HashSet::from([SignalType::Sensory]), Arc::new(SleepingReplier { entity_id: receiver }))
+            .await
+            .unwrap();
+        let replies = nervous_system.create_signal_stream(sender, vec![SignalType::Cognitive]).await.unwrap();
+        tokio::pin!(replies);
+        
+        nervous_system.transmit_signal(message(sender, receiver, "ping")).await.unwrap();
+        let reply = match tokio::time::timeout(Duration::from_secs(1), replies.next()).await {
+            Ok(Some(SignalStreamEvent::Signal(reply))) => reply,
+            _ => panic!("no reply"),
+        };
+        assert_eq!(reply.source, receiver);
+        assert_eq!(nervous_system.get_statistics().await.unwrap().total_signals_processed, 1);
+    }
+    
+    async fn retrying_system(policy: RetryPolicy, processor: Box<dyn SignalProcessorFn + Send + Sync>) -> (NervousSystem, EntityId, EntityId) {
+        let (sender, receiver) = (EntityId::new(), EntityId::new());
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        physics_engine.allocate_energy_to_entity(sender, ordered_float::OrderedFloat(0.1)).await.unwrap();
+        let config = NervousSystemConfig::builder()
+            .retry_policy(policy)
+            .build()
+            .unwrap();
+        let nervous_system = NervousSystem::with_config(physics_engine, config).await.unwrap();
+        nervous_system.register_entity(receiver, HashSet::from([SignalType::Sensory]), processor).await.unwrap();
+        (nervous_system, sender, receiver)
+    }
+    
+    #[tokio::test]
+    async fn test_failed_signal_is_retried_until_it_succeeds() {
+        let attempts = Arc::new(std::sync::atomic::AtomicU32::new(0));
+        let processor = FlakyProcessor { failures: 2, attempts: attempts.clone() };
+        let policy = RetryPolicy::new(3, Duration::from_millis(10));
+        let (nervous_system, sender, receiver) = retrying_system(policy, Box::new(processor)).await;
+        
+        nervous_system.transmit_signal(message(sender, receiver, "flaky")).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+        assert!(nervous_system.drain_dead_letters().await.is_empty());
+        let stats = nervous_system.get_statistics().await.unwrap();
+        assert_eq!(stats.retried_signals, 2);
+        assert_eq!(stats.total_errors, 2);
+    }
+    
+    #[tokio::test]
+    async fn test_signal_is_dead_lettered_after_max_attempts() {
+        let policy = RetryPolicy::new(3, Duration::from_millis(10));
+        let (nervous_system, sender, receiver) = retrying_system(policy.clone(), Box::new(FailingProcessor)).await;
+        
+        nervous_system.transmit_signal(message(sender, receiver, "doomed")).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        
+        let dead_letters = nervous_system.drain_dead_letters().await;
+        assert_eq!(dead_letters.len(), 1);
+        assert_eq!(dead_letters[0].reason, DeadLetterReason::ProcessingFailed { attempts: 3, error: "processor failure".to_string() });
+        assert_eq!(nervous_system.get_statistics().await.unwrap().retried_signals, 2);
+        
+        // Errors the policy does not consider transient are dead-lettered at once
+        let (nervous_system, sender, receiver) = retrying_system(policy.with_retryable(|_| false), Box::new(FailingProcessor)).await;
+        nervous_system.transmit_signal(message(sender, receiver, "doomed")).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        
+        let dead_letters = nervous_system.drain_dead_letters().await;
+        assert!(matches!(dead_letters[0].reason, DeadLetterReason::ProcessingFailed { attempts: 1, .. }));
+        assert_eq!(nervous_system.get_statistics().await.unwrap().retried_signals, 0);
+    }
+    
+    /// Never finishes within any reasonable signal timeout
+    struct StallingProcessor;
+    
+    impl AsyncSignalProcessorFn for StallingProcessor {
+        fn process_signal<'a>(&'a self, _signal: &'a NeuralSignal) -> SignalProcessorFuture<'a> {
+            Box::pin(async move {
+                tokio::time::sleep(Duration::from_secs(10)).await;
+                Ok(None)
+            })
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_timed_out_signal_is_retried_then_dead_lettered() {
+        let (sender, receiver) = (EntityId::new(), EntityId::new());
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        physics_engine.allocate_energy_to_entity(sender, ordered_float::OrderedFloat(0.1)).await.unwrap();
+        let config = NervousSystemConfig::builder()
+            .signal_timeout(Duration::from_millis(20))
+            .retry_policy(RetryPolicy::new(2, Duration::from_millis(10)))
+            .build()
+            .unwrap();
+        let nervous_system = NervousSystem::with_config(physics_engine, config).await.unwrap();
+        nervous_system.register_async_entity(receiver, HashSet::from([SignalType::Sensory]), Arc::new(StallingProcessor)).await.unwrap();
+        
+        nervous_system.transmit_signal(message(sender, receiver, "slow")).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        
+        let dead_letters = nervous_system.drain_dead_letters().await;
+        assert_eq!(dead_letters.len(), 1);
+        match &dead_letters[0].reason {
+            DeadLetterReason::ProcessingFailed { attempts, error } => {
+                assert_eq!(*attempts, 2);
+                assert!(error.contains("timeout"), "{}", error);
+            }
+            other => panic!("unexpected reason {:?}", other),
+        }
+        assert_eq!(nervous_system.get_statistics().await.unwrap().retried_signals, 1);
+    }
+    
+    #[tokio::test]
+    async fn test_retry_for_a_deregistered_entity_is_dead_lettered() {
+        let policy = RetryPolicy::new(3, Duration::from_millis(50));
+        let (nervous_system, sender, receiver) = retrying_system(policy, Box::new(FailingProcessor)).await;
+        let signal = message(sender, receiver, "orphaned");
+        let sent_at = signal.timestamp;
+        
+        nervous_system.transmit_signal(signal).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        nervous_system.deregister_entity(receiver).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        
+        let dead_letters = nervous_system.drain_dead_letters().await;
+        assert_eq!(dead_letters.len(), 1);
+        assert_eq!(dead_letters[0].reason, DeadLetterReason::QueueClosed);
+        assert!(dead_letters[0].signal.timestamp > sent_at);
+        assert!(nervous_system.retry_attempts.lock().unwrap().is_empty());
+    }
+    
+    /// Holds the signal saying "hold" until the test lets it through
+    struct Gate {
+        entered: std::sync::mpsc::Sender<()>,
+        release: std::sync::Mutex<std::sync::mpsc::Receiver<()>>,
+    }
+    
+    impl SignalMiddleware for Gate {
+        fn on_transmit(&self, signal: &mut NeuralSignal) -> MiddlewareAction {
+            if matches!(&signal.payload, SignalPayload::Message(text) if text == "hold") {
+                self.entered.send(()).unwrap();
+                self.release.lock().unwrap().recv().unwrap();
+            }
+            MiddlewareAction::Continue
+        }
+    }
+    
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_config_update_applies_to_signals_sent_afterwards() {
+        let sender = EntityId::new();
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        physics_engine.allocate_energy_to_entity(sender, ordered_float::OrderedFloat(0.1)).await.unwrap();
+        let nervous_system = Arc::new(NervousSystem::new(physics_engine).await.unwrap());
+        let (entered, entered_rx) = std::sync::mpsc::channel();
+        let (release, release_rx) = std::sync::mpsc::channel();
+        nervous_system.add_middleware(Box::new(Gate { entered, release: std::sync::Mutex::new(release_rx) }));
+        let four_hops = |text: &str| {
+            let mut signal = NeuralSignal::broadcast(SignalType::Sensory, sender, SignalPayload::Message(text.to_string()), 1.0);
+            signal.propagation_distance = 4;
+            signal
+        };
+        
+        let held = tokio::spawn({
+            let nervous_system = nervous_system.clone();
+            let signal = four_hops("hold");
+            async move { nervous_system.transmit_signal(signal).await.unwrap() }
+        });
+        entered_rx.recv().unwrap();
+        
+        let decay = |rate| NervousSystemConfigUpdate { signal_decay_rate: Some(rate), ..Default::default() };
+        assert!(nervous_system.update_config(decay(2.0)).await.is_err());
+        assert_eq!(nervous_system.config().signal_decay_rate, 0.1);
+        nervous_system.update_config(decay(0.5)).await.unwrap();
+        assert_eq!(nervous_system.config().signal_decay_rate, 0.5);
+        
+        let after = nervous_system.transmit_signal(four_hops("go")).await.unwrap();
+        assert!((after.energy_consumed - 0.003).abs() < 1e-12, "{}", after.energy_consumed);
+        
+        release.send(()).unwrap();
+        let in_flight = held.await.unwrap();
+        assert!((in_flight.energy_consumed - 0.0014).abs() < 1e-12, "{}", in_flight.energy_consumed);
+    }
+    
+    #[tokio::test]
+    async fn test_response_loop_is_cut_at_max_depth() {
+        let (first, second) = (EntityId::new(), EntityId::new());
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        physics_engine.allocate_energy_to_entity(first, ordered_float::OrderedFloat(0.1)).await.unwrap();
+        let config = NervousSystemConfig::builder().max_response_depth(3).build().unwrap();
+        let nervous_system = NervousSystem::with_config(physics_engine, config).await.unwrap();
+        for entity_id in [first, second] {
+            nervous_system
+                .register_entity(entity_id, HashSet::from([SignalType::Cognitive]), Box::new(ReplyingProcessor { entity_id }))
+                .await
+                .unwrap();
+        }
+        
+        let mut to_second: SubscriberStream = Box::pin(nervous_system.create_signal_stream(second, vec![SignalType::Cognitive]).await.unwrap());
+        
+        let ping = NeuralSignal::new(SignalType::Cognitive, first, second, SignalPayload::Message("ping".to_string()), 0.5);
+        nervous_system.transmit_signal(ping).await.unwrap();
+        for depth in [0, 2] {
+            match tokio::time::timeout(Duration::from_secs(1), to_second.next()).await {
+                Ok(Some(SignalStreamEvent::Signal(signal))) => assert_eq!(signal.response_depth, depth),
+                other => panic!("expected the signal at depth {}, got {:?}", depth, other),
+            }
+        }
+        let mut stats = nervous_system.get_statistics().await.unwrap();
+        while stats.total_signals_processed < 4 {
+            tokio::task::yield_now().await;
+            stats = nervous_system.get_statistics().await.unwrap();
+        }
+        
+        // The ping and three responses are processed; the fourth response, back to second, is never sent
+        assert!(tokio::time::timeout(Duration::from_millis(50), to_second.next()).await.is_err());
+        let stats = nervous_system.get_statistics().await.unwrap();
+        assert_eq!(stats.by_type[&SignalType::Cognitive].processed, 4);
+        assert_eq!(stats.total_signals_processed, 4);
+    }
+    
+    #[tokio::test]
+    async fn test_binary_payloads_are_bounded_and_travel_compressed() {
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        let sender = EntityId::new();
+        physics_engine.allocate_energy_to_entity(sender, ordered_float::OrderedFloat(0.1)).await.unwrap();
+        let config = NervousSystemConfig::builder()
+            .max_payload_bytes(1024)
+            .compress_payloads_over(256)
+            .build()
+            .unwrap();
+        let nervous_system = NervousSystem::with_config(physics_engine, config).await.unwrap();
+        let stream = nervous_system.create_signal_stream(EntityId::new(), vec![SignalType::Sensory]).await.unwrap();
+        tokio::pin!(stream);
+        let binary = |bytes: Vec<u8>| NeuralSignal::broadcast(SignalType::Sensory, sender, SignalPayload::Binary(bytes), 0.5);
+        
+        let error = nervous_system.transmit_signal(binary(vec![7; 2000])).await.unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<NervousSystemError>(),
+            Some(NervousSystemError::PayloadTooLarge { size: 2000, limit: 1024 })
+        ));
+        
+        // The limit applies to the inflated size, however well the payload compresses
+        let packed = binary(vec![7; 2000]).with_compressed_payload();
+        assert!(matches!(&packed.payload, SignalPayload::Compressed(bytes) if bytes.len() < 64));
+        assert!(nervous_system.transmit_signal(packed).await.is_err());
+        assert!(matches!(
+            NeuralSignal::try_new(SignalType::Sensory, sender, None, SignalPayload::Binary(vec![7; 2000]), 0.5, Some(1024)),
+            Err(NervousSystemError::PayloadTooLarge { size: 2000, limit: 1024 })
+        ));
+        let mut forged = u32::MAX.to_le_bytes().to_vec();
+        forged.push(0);
+        assert!(matches!(
+            NeuralSignal::try_new(SignalType::Sensory, sender, None, SignalPayload::Compressed(forged), 0.5, Some(1024)),
+            Err(NervousSystemError::PayloadTooLarge { limit: 1024, .. })
+        ));
+        assert!(NeuralSignal::try_new(SignalType::Sensory, sender, None, SignalPayload::Binary(vec![7; 1024]), 0.5, Some(1024)).is_ok());
+        
+        let mut frame = vec![0u8; 900];
+        frame.extend(b"reading 42");
+        nervous_system.transmit_signal(binary(frame.clone())).await.unwrap();
+        match stream.next().await {
+            Some(SignalStreamEvent::Signal(signal)) => {
+                assert!(matches!(&signal.payload, SignalPayload::Binary(bytes) if *bytes == frame));
+            }
+            other => panic!("expected the inflated signal, got {:?}", other.is_some()),
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_config_builder_builds_usable_config() {
+        let config = NervousSystemConfig::builder()
+            .signal_decay_rate(0.2)
+            .max_concurrent_signals(16)
+            .channel_capacity(SignalType::Emergency, 4)
+            .signal_timeout(Duration::from_secs(5))
+            .enforce_physics(false)
+            .pathway_formation_threshold(0.7)
+            .build()
+            .unwrap();
+        
+        assert_eq!(config.channel_capacity(&SignalType::Emergency), 4);
+        assert_eq!(config.channel_capacity(&SignalType::Sensory), 16);
+        
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        let nervous_system = NervousSystem::with_config(physics_engine, config).await.unwrap();
+        assert!(!nervous_system.config().enforce_physics);
+    }
+    
+    #[tokio::test]
+    async fn test_undeliverable_signal_is_dead_lettered() {
+        let sender = EntityId::new();
+        let receiver = EntityId::new();
+        let (nervous_system, _) = capturing_system(sender, receiver).await;
+        let unknown = EntityId::new();
+        
+        let lost = NeuralSignal::new(
+            SignalType::Sensory,
+            sender,
+            Some(unknown),
+            SignalPayload::Message("anyone there?".to_string()),
+            0.5,
+        );
+        let lost_id = lost.signal_id;
+        nervous_system.transmit_signal(lost).await.unwrap();
+        let delivered = NeuralSignal::new(
+            SignalType::Sensory,
+            sender,
+            Some(receiver),
+            SignalPayload::Message("hello".to_string()),
+            0.5,
+        );
+        nervous_system.transmit_signal(delivered).await.unwrap();
+        
+        assert_eq!(nervous_system.get_statistics().await.unwrap().dead_letters, 1);
+        
+        let dead_letters = nervous_system.drain_dead_letters().await;
+        assert_eq!(dead_letters.len(), 1);
+        assert_eq!(dead_letters[0].signal.signal_id, lost_id);
+        assert_eq!(dead_letters[0].reason, DeadLetterReason::EntityNotFound);
+        assert!(nervous_system.drain_dead_letters().await.is_empty());
+    }
+    
+    #[tokio::test(start_paused = true)]
+    async fn test_rate_limit_rejects_bursts_and_refills() {
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        let config = NervousSystemConfig::builder()
+            .max_signals_per_second_per_entity(5)
+            .build()
+            .unwrap();
+        let nervous_system = NervousSystem::with_config(physics_engine.clone(), config).await.unwrap();
+        let sender = EntityId::new();
+        let other = EntityId::new();
+        for entity in [sender, other] {
+            physics_engine
+                .allocate_energy_to_entity(entity, ordered_float::OrderedFloat(0.2))
+                .await
+                .unwrap();
+        }
+        let ping = |source: EntityId| NeuralSignal::broadcast(
+            SignalType::Sensory,
+            source,
+            SignalPayload::Message("ping".to_string()),
+            0.5,
+        );
+        
+        for _ in 0..5 {
+            nervous_system.transmit_signal(ping(sender)).await.unwrap();
+        }
+        
+        let err = nervous_system.transmit_signal(ping(sender)).await.unwrap_err();
+        let retry_after = match err.downcast_ref() {
+            Some(NervousSystemError::RateLimited { entity, retry_after }) => {
+                assert_eq!(*entity, sender);
+                *retry_after
+            }
+            other => panic!("expected a rate limit error, got {:?}", other),
+        };
+        assert!(retry_after > Duration::ZERO && retry_after <= Duration::from_secs(1));
+        
+        // Other sources have their own window
+        nervous_system.transmit_signal(ping(other)).await.unwrap();
+        
+        tokio::time::advance(Duration::from_secs(2)).await;
+        for _ in 0..5 {
+            nervous_system.transmit_signal(ping(sender)).await.unwrap();
+        }
+    }
+    
+    /// A rate-limited system whose emergency lane allows `per_minute` signals,
+    /// with a sender holding too little energy for a 0.01 signal
+    async fn emergency_system(per_minute: u32) -> (NervousSystem, Arc<PhysicsEngine>, EntityId, EntityId) {
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        let config = NervousSystemConfig::builder()
+            .max_signals_per_second_per_entity(1)
+            .emergency_lane(per_minute, 0.05)
+            .build()
+            .unwrap();
+        let nervous_system = NervousSystem::with_config(physics_engine.clone(), config).await.unwrap();
+        let (sender, target) = (EntityId::new(), EntityId::new());
+        physics_engine.allocate_energy_to_entity(sender, ordered_float::OrderedFloat(0.002)).await.unwrap();
+        (nervous_system, physics_engine, sender, target)
+    }
+    
+    fn alarm(sender: EntityId, target: EntityId) -> NeuralSignal {
+        NeuralSignal::new(SignalType::Emergency, sender, target, SignalPayload::Message("constraint violated, shut down".to_string()), 1.0)
+            .with_energy_cost(0.01)
+    }
+    
+    #[tokio::test(start_paused = true)]
+    async fn test_emergency_signal_gets_through_when_throttled_and_starved() {
+        let (nervous_system, physics_engine, sender, target) = emergency_system(5).await;
+        let chatter = || NeuralSignal::new(SignalType::Sensory, sender, target, SignalPayload::Message("chatter".to_string()), 0.5)
+            .with_energy_cost(0.001);
+        nervous_system.transmit_signal(chatter()).await.unwrap();
+        assert!(matches!(
+            nervous_system.transmit_signal(chatter()).await.unwrap_err().downcast_ref(),
+            Some(NervousSystemError::RateLimited { .. })
+        ));
+        
+        nervous_system.transmit_signal(alarm(sender, target)).await.unwrap();
+        
+        // The reserve is real energy, spent by dissipating it
+        assert!((nervous_system.emergency_reserve().await - 0.04).abs() < 1e-9);
+        assert!((physics_engine.entity_energy(sender).await.0 - 0.001).abs() < 1e-9);
+        let energy = physics_engine.get_engine_state().await.unwrap().energy_state;
+        assert!((energy.dissipated_energy.0 - 0.01).abs() < 1e-9);
+        assert!((energy.allocated_energy + energy.free_energy + energy.dissipated_energy - energy.total_energy).abs() < 1e-9);
+        
+        // A minute later it has refilled from the system's free energy before paying again
+        tokio::time::advance(Duration::from_secs(60)).await;
+        nervous_system.transmit_signal(alarm(sender, target)).await.unwrap();
+        assert!((nervous_system.emergency_reserve().await - 0.04).abs() < 1e-9);
+    }
+    
+    #[tokio::test]
+    async fn test_emergency_reserve_only_pays_for_energy_shortfalls() {
+        let physics_engine = Arc::new(MockPhysicsEngine::new());
+        let nervous_system = NervousSystem::new(physics_engine.clone()).await.unwrap();
+        let (sender, target) = (EntityId::new(), EntityId::new());
+        
+        physics_engine.fail_next(PhysicsViolation::SecurityBreach { reason: "revoked".to_string() });
+        let error = nervous_system.transmit_signal(alarm(sender, target)).await.unwrap_err();
+        assert!(format!("{:#}", error).contains("revoked"), "{:#}", error);
+        assert!((nervous_system.emergency_reserve().await - 0.05).abs() < 1e-9);
+        
+        physics_engine.fail_next(PhysicsViolation::EnergyConservation { reason: "insufficient energy".to_string() });
+        nervous_system.transmit_signal(alarm(sender, target)).await.unwrap();
+        assert!((nervous_system.emergency_reserve().await - 0.04).abs() < 1e-9);
+    }
+    
+    #[tokio::test(start_paused = true)]
+    async fn test_emergency_spam_is_capped() {
+        let (nervous_system, _physics_engine, sender, target) = emergency_system(3).await;
+        for _ in 0..3 {
+            nervous_system.transmit_signal(alarm(sender, target)).await.unwrap();
+        }
+        
+        let err = nervous_system.transmit_signal(alarm(sender, target)).await.unwrap_err();
+        match err.downcast_ref() {
+            Some(NervousSystemError::EmergencyCapExceeded { entity, retry_after }) => {
+                assert_eq!(*entity, sender);
+                assert!(*retry_after > Duration::ZERO && *retry_after <= Duration::from_secs(60));
+            }
+            other => panic!("expected the emergency cap, got {:?}", other),
+        }
+        assert!((nervous_system.emergency_reserve().await - 0.02).abs() < 1e-9);
+        
+        tokio::time::advance(Duration::from_secs(60)).await;
+        nervous_system.transmit_signal(alarm(sender, target)).await.unwrap();
+    }
+    
+    /// Takes a while over its first signal, recording every message it handles
+    struct SlowProcessor {
+        received: Arc<std::sync::Mutex<Vec<String>>>,
+    }
+    
+    impl AsyncSignalProcessorFn for SlowProcessor {
+        fn process_signal<'a>(&'a self, signal: &'a NeuralSignal) -> SignalProcessorFuture<'a> {
+            Box::pin(async move {
+                let first = self.received.lock().unwrap().is_empty();
+                if first {
+                    tokio::time::sleep(Duration::from_millis(100)).await;
+                }
+                if let SignalPayload::Message(text) = &signal.payload {
+                    self.received.lock().unwrap().push(text.clone());
+                }
+                Ok(None)
+            })
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_expired_signal_is_dropped_unprocessed() {
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        let config = NervousSystemConfig::builder().dead_letter_expired(true).build().unwrap();
+        let nervous_system = NervousSystem::with_config(physics_engine.clone(), config).await.unwrap();
+        let sender = EntityId::new();
+        let receiver = EntityId::new();
+        physics_engine
+            .allocate_energy_to_entity(sender, ordered_float::OrderedFloat(0.1))
+            .await
+            .unwrap();
+        let received = Arc::new(std::sync::Mutex::new(Vec::new()));
+        nervous_system
+            .register_async_entity(receiver, HashSet::from([SignalType::Sensory]), Arc::new(SlowProcessor { received: received.clone() }))
+            .await
+            .unwrap();
+        
+        let message = |text: &str| NeuralSignal::new(
+            SignalType::Sensory,
+            sender,
+            Some(receiver),
+            SignalPayload::Message(text.to_string()),
+            0.5,
+        );
+        nervous_system.transmit_signal(message("slow")).await.unwrap();
+        let stale = message("stale").with_ttl(Duration::from_millis(20));
+        let stale_id = stale.signal_id;
+        nervous_system.transmit_signal(stale).await.unwrap();
+        nervous_system.transmit_signal(message("fresh")).await.unwrap();
+        
+        assert_eq!(wait_for_messages(&received, 2).await, vec!["slow".to_string(), "fresh".to_string()]);
+        
+        let stats = nervous_system.get_statistics().await.unwrap();
+        assert_eq!(stats.expired_signals, 1);
+        assert_eq!(stats.total_signals_processed, 2);
+        
+        let dead_letters = nervous_system.drain_dead_letters().await;
+        assert_eq!(dead_letters.len(), 1);
+        assert_eq!(dead_letters[0].signal.signal_id, stale_id);
+        assert_eq!(dead_letters[0].reason, DeadLetterReason::Expired);
+    }
+    
+    #[tokio::test]
+    async fn test_expiry_follows_the_injected_clock() {
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        let clock = MockClock::new();
+        let config = NervousSystemConfig::builder().dead_letter_expired(true).build().unwrap();
+        let nervous_system = NervousSystem::with_clock(physics_engine.clone(), config, clock.shared()).await.unwrap();
+        let (sender, receiver) = (EntityId::new(), EntityId::new());
+        physics_engine
+            .allocate_energy_to_entity(sender, ordered_float::OrderedFloat(0.1))
+            .await
+            .unwrap();
+        let (entered, gate) = (Arc::new(Notify::new()), Arc::new(Notify::new()));
+        let processor = GateProcessor { entered: entered.clone(), gate: gate.clone() };
+        nervous_system.register_async_entity(receiver, HashSet::from([SignalType::Sensory]), Arc::new(processor)).await.unwrap();
+        
+        // Built long before it is sent, but its TTL only starts at transmission
+        let late = message(sender, receiver, "late").with_ttl(Duration::from_secs(60));
+        nervous_system.transmit_signal(message(sender, receiver, "first")).await.unwrap();
+        entered.notified().await;
+        let outlived = message(sender, receiver, "outlived").with_ttl(Duration::from_secs(60));
+        let outlived_id = outlived.signal_id;
+        nervous_system.transmit_signal(outlived).await.unwrap();
+        
+        // Two simulated minutes pass while the signal waits in the queue
+        clock.advance(Duration::from_secs(120));
+        nervous_system.transmit_signal(late).await.unwrap();
+        gate.notify_one();
+        entered.notified().await;
+        gate.notify_one();
+        
+        let dead_letters = nervous_system.drain_dead_letters().await;
+        assert_eq!(dead_letters.len(), 1);
+        assert_eq!(dead_letters[0].signal.signal_id, outlived_id);
+        assert_eq!(dead_letters[0].reason, DeadLetterReason::Expired);
+        assert_eq!(dead_letters[0].timestamp, clock.now_utc());
+    }
+    
+    #[tokio::test]
+    async fn test_energy_weighted_dispatcher_delivers_signals() {
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        let config = NervousSystemConfig::builder()
+            .processing_strategy(ProcessingStrategy::EnergyWeighted)
+            .build()
+            .unwrap();
+        let nervous_system = NervousSystem::with_config(physics_engine.clone(), config).await.unwrap();
+        let sender = EntityId::new();
+        let receiver = EntityId::new();
+        physics_engine
+            .allocate_energy_to_entity(sender, ordered_float::OrderedFloat(0.1))
+            .await
+            .unwrap();
+        let received = Arc::new(std::sync::Mutex::new(Vec::new()));
+        nervous_system
+            .register_entity(receiver, HashSet::from([SignalType::Sensory]), Box::new(CapturingProcessor { received: received.clone() }))
+            .await
+            .unwrap();
+        
+        for text in ["one", "two", "three"] {
+            let signal = NeuralSignal::new(
+                SignalType::Sensory,
+                sender,
+                Some(receiver),
+                SignalPayload::Message(text.to_string()),
+                0.5,
+            );
+            nervous_system.transmit_signal(signal).await.unwrap();
+        }
+        
+        let expected = vec!["one".to_string(), "two".to_string(), "three".to_string()];
+        assert_eq!(wait_for_messages(&received, 3).await, expected);
+        assert!(nervous_system.processing_tasks.read().await.is_empty());
+    }
+    
+    /// Holds the first signal it receives until the test opens the gate
+    struct GateProcessor {
+        entered: Arc<Notify>,
+        gate: Arc<Notify>,
+    }
+    
+    impl AsyncSignalProcessorFn for GateProcessor {
+        fn process_signal<'a>(&'a self, _signal: &'a NeuralSignal) -> SignalProcessorFuture<'a> {
+            Box::pin(async move {
+                self.entered.notify_one();
+                self.gate.notified().await;
+                Ok(None)
+            })
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_wait_until_idle_covers_in_flight_signals() {
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        let nervous_system = NervousSystem::new(physics_engine.clone()).await.unwrap();
+        let (sender, receiver, inbox) = (EntityId::new(), EntityId::new(), EntityId::new());
+        physics_engine.allocate_energy_to_entity(sender, ordered_float::OrderedFloat(0.1)).await.unwrap();
+        let (entered, gate) = (Arc::new(Notify::new()), Arc::new(Notify::new()));
+        let processor = GateProcessor { entered: entered.clone(), gate: gate.clone() };
+        nervous_system.register_async_entity(receiver, HashSet::from([SignalType::Sensory]), Arc::new(processor)).await.unwrap();
+        nervous_system.register_manual_entity(inbox, HashSet::from([SignalType::Sensory])).await.unwrap();
+        
+        nervous_system.transmit_signal(message(sender, inbox, "unread")).await.unwrap();
+        nervous_system.transmit_signal(message(sender, receiver, "held")).await.unwrap();
+        nervous_system.transmit_signal(message(sender, receiver, "queued")).await.unwrap();
+        entered.notified().await;
+        assert_eq!(nervous_system.pending_signals().await, 2);
+        assert!(!nervous_system.wait_until_idle(Duration::from_millis(20)).await);
+        
+        gate.notify_one();
+        entered.notified().await;
+        gate.notify_one();
+        assert!(nervous_system.wait_until_idle(Duration::from_secs(1)).await);
+        assert_eq!(nervous_system.pending_signals().await, 0);
+    }
+    
+    #[tokio::test]
+    async fn test_energy_weighted_dispatch_favours_energetic_entities_end_to_end() {
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        let config = NervousSystemConfig::builder()
+            .processing_strategy(ProcessingStrategy::EnergyWeighted)
+            .build()
+            .unwrap();
+        let nervous_system = NervousSystem::with_config(physics_engine.clone(), config).await.unwrap();
+        let (sender, gatekeeper, rich, poor) = (EntityId::new(), EntityId::new(), EntityId::new(), EntityId::new());
+        for (entity, energy) in [(sender, 0.1), (rich, 0.4), (poor, 0.04)] {
+            physics_engine.allocate_energy_to_entity(entity, ordered_float::OrderedFloat(energy)).await.unwrap();
+        }
+        let (entered, gate) = (Arc::new(Notify::new()), Arc::new(Notify::new()));
+        let processor = GateProcessor { entered: entered.clone(), gate: gate.clone() };
+        nervous_system.register_async_entity(gatekeeper, HashSet::from([SignalType::Sensory]), Arc::new(processor)).await.unwrap();
+        let received = Arc::new(std::sync::Mutex::new(Vec::new()));
+        for entity in [rich, poor] {
+            let processor = CapturingProcessor { received: received.clone() };
+            nervous_system.register_entity(entity, HashSet::from([SignalType::Sensory]), Box::new(processor)).await.unwrap();
+        }
+        
+        // Hold the dispatcher at the gate while both entities' queues fill up
+        nervous_system.transmit_signal(message(sender, gatekeeper, "wait")).await.unwrap();
+        entered.notified().await;
+        for _ in 0..20 {
+            for (entity, name) in [(rich, "rich"), (poor, "poor")] {
+                let signal = message(sender, entity, name).with_energy_cost(0.0);
+                nervous_system.transmit_signal(signal).await.unwrap();
+            }
+        }
+        gate.notify_one();
+        
+        let processed = wait_for_messages(&received, 11).await;
+        let rich_count = processed[..11].iter().filter(|name| *name == "rich").count();
+        assert_eq!(rich_count, 10, "{:?}", processed);
+        assert_eq!(wait_for_messages(&received, 40).await.len(), 40);
+    }
+    
+    #[tokio::test]
+    async fn test_background_tasks_stop_when_the_system_is_dropped() {
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        let config = NervousSystemConfig::builder()
+            .processing_strategy(ProcessingStrategy::EnergyWeighted)
+            .build()
+            .unwrap();
+        let nervous_system = NervousSystem::with_config(physics_engine.clone(), config).await.unwrap();
+        nervous_system.register_entity(EntityId::new(), HashSet::from([SignalType::Sensory]), Box::new(TestProcessor)).await.unwrap();
+        assert!(Arc::strong_count(&physics_engine) > 1);
+        
+        drop(nervous_system);
+        tokio::time::timeout(Duration::from_secs(1), async {
+            while Arc::strong_count(&physics_engine) > 1 {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .expect("the dispatcher and response task should let go of the physics engine");
+    }
+    
+    /// Holds every signal until the test releases it
+    struct BlockingProcessor {
+        release: std::sync::Mutex<std::sync::mpsc::Receiver<()>>,
+        received: Arc<std::sync::Mutex<Vec<String>>>,
+    }
+    
+    impl SignalProcessorFn for BlockingProcessor {
+        fn process_signal(&self, signal: &NeuralSignal) -> Result<Option<NeuralSignal>> {
+            let _ = self.release.lock().unwrap().recv();
+            if let SignalPayload::Message(text) = &signal.payload {
+                self.received.lock().unwrap().push(text.clone());
+            }
+            Ok(None)
+        }
+    }
+    
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_stuck_processor_is_reported_and_restarted() {
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        let sender = EntityId::new();
+        let healthy = EntityId::new();
+        let stuck = EntityId::new();
+        physics_engine
+            .allocate_energy_to_entity(sender, ordered_float::OrderedFloat(0.1))
+            .await
+            .unwrap();
+        let config = NervousSystemConfig::builder().restart_stalled_tasks(true).build().unwrap();
+        let nervous_system = NervousSystem::with_config(physics_engine, config).await.unwrap();
+        
+        let healthy_received = Arc::new(std::sync::Mutex::new(Vec::new()));
+        nervous_system
+            .register_entity(healthy, HashSet::from([SignalType::Sensory]), Box::new(CapturingProcessor { received: healthy_received.clone() }))
+            .await
+            .unwrap();
+        let (release, release_rx) = std::sync::mpsc::channel();
+        let stuck_received = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let blocking = BlockingProcessor {
+            release: std::sync::Mutex::new(release_rx),
+            received: stuck_received.clone(),
+        };
+        nervous_system
+            .register_entity(stuck, HashSet::from([SignalType::Sensory]), Box::new(blocking))
+            .await
+            .unwrap();
+        
+        let send = |target: EntityId, text: &str| {
+            NeuralSignal::new(SignalType::Sensory, sender, Some(target), SignalPayload::Message(text.to_string()), 0.5)
+        };
+        nervous_system.transmit_signal(send(healthy, "ping")).await.unwrap();
+        assert_eq!(wait_for_messages(&healthy_received, 1).await, vec!["ping".to_string()]);
+        
+        nervous_system.transmit_signal(send(stuck, "first")).await.unwrap();
+        nervous_system.transmit_signal(send(stuck, "second")).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        
+        assert_eq!(nervous_system.check_liveness(Duration::from_millis(50)).await, vec![stuck]);
+        
+        // The restarted task takes new signals once the processor stops blocking
+        drop(release);
+        nervous_system.transmit_signal(send(stuck, "after restart")).await.unwrap();
+        for _ in 0..50 {
+            if stuck_received.lock().unwrap().contains(&"after restart".to_string()) {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert!(stuck_received.lock().unwrap().contains(&"after restart".to_string()));
+        assert!(nervous_system.check_liveness(Duration::from_millis(50)).await.is_empty());
+    }
+    
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_stuck_processor_is_reported_under_energy_weighted_dispatch() {
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        let sender = EntityId::new();
+        let stuck = EntityId::new();
+        physics_engine
+            .allocate_energy_to_entity(sender, ordered_float::OrderedFloat(0.1))
+            .await
+            .unwrap();
+        let config = NervousSystemConfig::builder()
+            .processing_strategy(ProcessingStrategy::EnergyWeighted)
+            .build()
+            .unwrap();
+        let nervous_system = NervousSystem::with_config(physics_engine, config).await.unwrap();
+        
+        let (release, release_rx) = std::sync::mpsc::channel();
+        let blocking = BlockingProcessor {
+            release: std::sync::Mutex::new(release_rx),
+            received: Arc::new(std::sync::Mutex::new(Vec::new())),
+        };
+        nervous_system
+            .register_entity(stuck, HashSet::from([SignalType::Sensory]), Box::new(blocking))
+            .await
+            .unwrap();
+        nervous_system
+            .transmit_signal(NeuralSignal::new(SignalType::Sensory, sender, Some(stuck), SignalPayload::Message("hang".to_string()), 0.5))
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        
+        // The dispatcher drained the entity's queue, so only the in-flight signal is pending
+        assert_eq!(nervous_system.check_liveness(Duration::from_millis(50)).await, vec![stuck]);
+        
+        // One more waits in the dispatcher and the rest in the entity's queue
+        for text in ["second", "third"] {
+            nervous_system
+                .transmit_signal(NeuralSignal::new(SignalType::Sensory, sender, Some(stuck), SignalPayload::Message(text.to_string()), 0.5))
+                .await
+                .unwrap();
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        let queue_len = nervous_system.list_entities().await
+            .into_iter()
+            .find(|entity| entity.entity_id == stuck)
+            .unwrap()
+            .queue_len;
+        assert_eq!(queue_len, 2);
+        
+        drop(release);
+        for _ in 0..50 {
+            if nervous_system.check_liveness(Duration::from_millis(50)).await.is_empty() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert!(nervous_system.check_liveness(Duration::from_millis(50)).await.is_empty());
+    }
+    
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_deregister_gives_up_on_a_stuck_task() {
+        let physics_engine = Arc::new(PhysicsEngine::new().await.unwrap());
+        let sender = EntityId::new();
+        let stuck = EntityId::new();
+        physics_engine
+            .allocate_energy_to_entity(sender, ordered_float::OrderedFloat(0.1))
+            .await
+            .unwrap();
+        let config = NervousSystemConfig::builder().signal_timeout(Duration::from_millis(50)).build().unwrap();
+        let nervous_system = NervousSystem::with_config(physics_engine, config).await.unwrap();
+        
+        let (release, release_rx) = std::sync::mpsc::channel();
+        let blocking = BlockingProcessor {
+            release: std::sync::Mutex::new(release_rx),
+            received: Arc::new(std::sync::Mutex::new(Vec::new())),
+        };
+        nervous_system
+            .register_entity(stuck, HashSet::from([SignalType::Sensory]), Box::new(blocking))
+            .await
+            .unwrap();
+        nervous_system
+            .transmit_signal(NeuralSignal::new(SignalType::Sensory, sender, Some(stuck), SignalPayload::Message("hang".to_string()), 0.5))
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        
+        let started = Instant::now();
+        assert!(nervous_system.deregister_entity(stuck).await.is_err());
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert!(nervous_system.processing_tasks.read().await.is_empty());
+        drop(release);
+    }
+}
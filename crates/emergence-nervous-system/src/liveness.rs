@@ -0,0 +1,96 @@
+//! Progress tracking for entity signal processing.
+//!
+//! Processing stamps an entity's [`Heartbeat`] whenever it starts on a signal
+//! and again when it finishes with it. A processor that never returns leaves
+//! its heartbeat busy and unstamped, which is what
+//! [`NervousSystem::check_liveness`](crate::NervousSystem::check_liveness)
+//! looks for. The heartbeat also counts signals taken off the entity's queue
+//! that are not finished yet, whether in flight or waiting in the dispatcher
+//! or for their causal dependencies.
+
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::time::Duration;
+
+use emergence_physics::SharedClock;
+use tokio::time::Instant;
+
+/// Last progress made by one entity's signal processing
+#[derive(Debug)]
+pub(crate) struct Heartbeat {
+    clock: SharedClock,
+    epoch: Instant,
+    /// Nanoseconds after `epoch` at which processing last advanced
+    last_progress: AtomicU64,
+    /// Whether a signal is being processed right now
+    busy: AtomicBool,
+    /// Signals taken off the queue and not yet finished
+    taken: AtomicUsize,
+}
+
+impl Heartbeat {
+    pub(crate) fn new(clock: SharedClock) -> Self {
+        Self {
+            epoch: clock.now_instant(),
+            clock,
+            last_progress: AtomicU64::new(0),
+            busy: AtomicBool::new(false),
+            taken: AtomicUsize::new(0),
+        }
+    }
+
+    /// Record that a signal was taken off the queue
+    pub(crate) fn take(&self) {
+        self.taken.fetch_add(1, Ordering::AcqRel);
+    }
+
+    /// Record that a taken signal was given up without being processed
+    pub(crate) fn abandon(&self) {
+        self.settle();
+    }
+
+    /// Signals taken off the queue that are in flight or waiting their turn
+    pub(crate) fn taken(&self) -> usize {
+        self.taken.load(Ordering::Acquire)
+    }
+
+    /// Taken signals that are waiting their turn rather than in flight
+    pub(crate) fn waiting(&self) -> usize {
+        self.taken().saturating_sub(usize::from(self.busy.load(Ordering::Acquire)))
+    }
+
+    /// Record that processing of a signal started
+    pub(crate) fn begin(&self) {
+        self.stamp();
+        self.busy.store(true, Ordering::Release);
+    }
+
+    /// Record that processing of the current signal finished
+    pub(crate) fn finish(&self) {
+        self.stamp();
+        self.busy.store(false, Ordering::Release);
+        self.settle();
+    }
+
+    /// Whether processing has been stuck on one signal for longer than `max_idle`
+    pub(crate) fn is_stalled(&self, max_idle: Duration) -> bool {
+        self.busy.load(Ordering::Acquire) && self.idle_for() > max_idle
+    }
+
+    fn idle_for(&self) -> Duration {
+        let last = Duration::from_nanos(self.last_progress.load(Ordering::Acquire));
+        self.elapsed().saturating_sub(last)
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.clock.now_instant().saturating_duration_since(self.epoch)
+    }
+
+    fn settle(&self) {
+        let _ = self.taken.fetch_update(Ordering::AcqRel, Ordering::Acquire, |taken| taken.checked_sub(1));
+    }
+
+    fn stamp(&self) {
+        let now = self.elapsed().as_nanos() as u64;
+        self.last_progress.store(now, Ordering::Release);
+    }
+}
@@ -0,0 +1,34 @@
+//! Hooks run on every signal before it is delivered.
+//!
+//! Middleware added with [`NervousSystem::add_middleware`] sees each signal
+//! passed to `transmit_signal`, in registration order, before physics
+//! validation and routing. Each one may rewrite the signal (to redact a
+//! payload or tag it, say), drop it quietly, or reject it with an error.
+//!
+//! [`NervousSystem::add_middleware`]: crate::NervousSystem::add_middleware
+
+use crate::NeuralSignal;
+
+/// What happens to a signal after a middleware has seen it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MiddlewareAction {
+    /// Hand the signal, possibly modified, to the next middleware
+    Continue,
+    /// Discard the signal without delivering it; transmission still succeeds
+    Drop,
+    /// Discard the signal and fail the transmission with this reason
+    Reject(String),
+}
+
+/// Cross-cutting logic applied to signals on their way in
+pub trait SignalMiddleware: Send + Sync {
+    fn on_transmit(&self, signal: &mut NeuralSignal) -> MiddlewareAction;
+}
+
+/// Run a signal through every middleware in order, stopping at the first that does not continue
+pub(crate) fn apply(middleware: &[Box<dyn SignalMiddleware>], signal: &mut NeuralSignal) -> MiddlewareAction {
+    middleware.iter()
+        .map(|middleware| middleware.on_transmit(signal))
+        .find(|action| *action != MiddlewareAction::Continue)
+        .unwrap_or(MiddlewareAction::Continue)
+}
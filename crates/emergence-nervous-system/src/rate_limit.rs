@@ -0,0 +1,87 @@
+//! Per-source signal rate limiting.
+//!
+//! Each source entity gets a sliding-window counter built from the count in
+//! the current one-second window and the count in the previous one, weighted
+//! by how much of the previous window still overlaps the last second. The
+//! counters are atomics, so checking a known entity only takes a read lock on
+//! the entity map.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use emergence_physics::{EntityId, SharedClock};
+use tokio::time::Instant;
+
+/// Length of one counting window
+const WINDOW: Duration = Duration::from_secs(1);
+
+/// Sliding-window signal counters per source entity
+#[derive(Debug)]
+pub(crate) struct SignalRateLimiter {
+    max_per_second: u32,
+    clock: SharedClock,
+    epoch: Instant,
+    windows: RwLock<HashMap<EntityId, Arc<RateWindow>>>,
+}
+
+#[derive(Debug, Default)]
+struct RateWindow {
+    /// Index of the window `current` counts
+    index: AtomicU64,
+    previous: AtomicU32,
+    current: AtomicU32,
+}
+
+impl SignalRateLimiter {
+    pub(crate) fn new(max_per_second: u32, clock: SharedClock) -> Self {
+        Self {
+            max_per_second,
+            epoch: clock.now_instant(),
+            clock,
+            windows: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Count a signal from `entity`, or return how long to wait if it is over the limit
+    ///
+    /// Under heavy concurrency from one entity the count is approximate at
+    /// window boundaries, which only shifts when the limit starts to apply.
+    pub(crate) fn check(&self, entity: EntityId) -> Result<(), Duration> {
+        let window = self.window_for(entity);
+        let elapsed = self.clock.now_instant().saturating_duration_since(self.epoch);
+        let index = (elapsed.as_nanos() / WINDOW.as_nanos()) as u64;
+        let progress = (elapsed.as_nanos() % WINDOW.as_nanos()) as f64 / WINDOW.as_nanos() as f64;
+
+        let seen = window.index.load(Ordering::Acquire);
+        if seen != index && window.index.compare_exchange(seen, index, Ordering::AcqRel, Ordering::Acquire).is_ok() {
+            let carried = if index == seen + 1 { window.current.load(Ordering::Acquire) } else { 0 };
+            window.previous.store(carried, Ordering::Release);
+            window.current.store(0, Ordering::Release);
+        }
+
+        let previous = window.previous.load(Ordering::Acquire) as f64;
+        let current = window.current.fetch_add(1, Ordering::AcqRel) as f64 + 1.0;
+        let estimate = previous * (1.0 - progress) + current;
+        if estimate <= self.max_per_second as f64 {
+            return Ok(());
+        }
+
+        window.current.fetch_sub(1, Ordering::AcqRel);
+        let remaining = 1.0 - progress;
+        let wait = if previous > 0.0 {
+            ((estimate - self.max_per_second as f64) / previous).min(remaining)
+        } else {
+            remaining
+        };
+        Err(WINDOW.mul_f64(wait.max(0.0)))
+    }
+
+    fn window_for(&self, entity: EntityId) -> Arc<RateWindow> {
+        if let Some(window) = self.windows.read().unwrap().get(&entity) {
+            return window.clone();
+        }
+        self.windows.write().unwrap().entry(entity).or_default().clone()
+    }
+}
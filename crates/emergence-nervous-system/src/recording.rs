@@ -0,0 +1,131 @@
+//! Signal recording for reproducing nervous system runs.
+//!
+//! While a recording is active every transmitted [`NeuralSignal`] is pushed
+//! through a bounded channel to a collector task, so transmission never waits
+//! on the recorder. Signals that do not fit are dropped and counted. The
+//! finished [`SignalLog`] can be saved as YAML and replayed later with
+//! [`NervousSystem::replay`](crate::NervousSystem::replay).
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use emergence_physics::SharedClock;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+use uuid::Uuid;
+
+use crate::NeuralSignal;
+
+/// Signals buffered between transmission and the recording task
+pub const RECORDING_CHANNEL_CAPACITY: usize = 1024;
+
+/// A transmitted signal and when it arrived relative to the recording start
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedSignal {
+    /// Time since the recording started
+    pub offset: Duration,
+    /// Arrival time on the nervous system's clock
+    pub arrived_at: DateTime<Utc>,
+    /// The transmitted signal
+    pub signal: NeuralSignal,
+}
+
+/// Ordered log of signals captured during a recording
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SignalLog {
+    /// When the recording started
+    pub started_at: DateTime<Utc>,
+    /// How long the recording ran
+    pub duration: Duration,
+    /// Recorded signals in arrival order
+    pub signals: Vec<RecordedSignal>,
+    /// Signals lost because the recording channel was full
+    pub dropped_signals: u64,
+}
+
+impl SignalLog {
+    /// Serialize the log as YAML
+    pub fn to_yaml(&self) -> Result<String> {
+        serde_yaml::to_string(self).context("Failed to serialize signal log")
+    }
+
+    /// Parse a log previously written with [`SignalLog::to_yaml`]
+    pub fn from_yaml(yaml: &str) -> Result<Self> {
+        serde_yaml::from_str(yaml).context("Failed to parse signal log")
+    }
+}
+
+/// Handle to an active recording, consumed by
+/// [`NervousSystem::stop_recording`](crate::NervousSystem::stop_recording)
+#[derive(Debug)]
+pub struct RecorderHandle {
+    pub(crate) id: Uuid,
+    started_at: DateTime<Utc>,
+    started: Instant,
+    clock: SharedClock,
+    dropped: Arc<AtomicU64>,
+    collector: JoinHandle<Vec<RecordedSignal>>,
+}
+
+/// Sending side of a recording, held by the nervous system
+#[derive(Debug)]
+pub(crate) struct SignalRecorder {
+    pub(crate) id: Uuid,
+    started: Instant,
+    clock: SharedClock,
+    dropped: Arc<AtomicU64>,
+    tx: mpsc::Sender<RecordedSignal>,
+}
+
+/// Start a recording timed by `clock`, returning the sending side and the caller's handle
+pub(crate) fn start(clock: SharedClock) -> (SignalRecorder, RecorderHandle) {
+    let (tx, mut rx) = mpsc::channel(RECORDING_CHANNEL_CAPACITY);
+    let id = Uuid::new_v4();
+    let started = clock.now_instant();
+    let dropped = Arc::new(AtomicU64::new(0));
+
+    let collector = tokio::spawn(async move {
+        let mut signals = Vec::new();
+        while let Some(recorded) = rx.recv().await {
+            signals.push(recorded);
+        }
+        signals
+    });
+
+    let recorder = SignalRecorder { id, started, clock: clock.clone(), dropped: dropped.clone(), tx };
+    let handle = RecorderHandle { id, started_at: clock.now_utc(), started, clock, dropped, collector };
+    (recorder, handle)
+}
+
+impl SignalRecorder {
+    /// Record a signal without waiting, counting it as dropped if the channel is full
+    pub(crate) fn record(&self, signal: &NeuralSignal) {
+        let recorded = RecordedSignal {
+            offset: self.clock.now_instant().saturating_duration_since(self.started),
+            arrived_at: self.clock.now_utc(),
+            signal: signal.clone(),
+        };
+        if self.tx.try_send(recorded).is_err() {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+impl RecorderHandle {
+    /// Wait for the collector to drain once the sending side is gone
+    pub(crate) async fn finish(self) -> Result<SignalLog> {
+        let duration = self.clock.now_instant().saturating_duration_since(self.started);
+        let signals = self.collector.await.context("Signal recording task panicked")?;
+        Ok(SignalLog {
+            started_at: self.started_at,
+            duration,
+            signals,
+            dropped_signals: self.dropped.load(Ordering::Relaxed),
+        })
+    }
+}
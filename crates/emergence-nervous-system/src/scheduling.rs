@@ -0,0 +1,146 @@
+//! How queued signals are scheduled onto entity processors.
+//!
+//! With [`ProcessingStrategy::PerEntity`] every entity drains its own queue in
+//! its own task. With [`ProcessingStrategy::EnergyWeighted`] a single
+//! dispatcher takes signals from all queues, one at a time, choosing the next
+//! entity by smooth weighted round-robin over the entities' current energy, so
+//! under contention an entity with ten times the energy processes about ten
+//! times as many signals.
+
+use std::collections::{HashMap, VecDeque};
+
+use emergence_physics::EntityId;
+use serde::{Deserialize, Serialize};
+
+use crate::NeuralSignal;
+
+/// Weight given to entities with little or no energy, so they are slowed rather than starved
+pub const MIN_SCHEDULING_WEIGHT: f64 = 0.001;
+
+/// Strategy for scheduling signal processing across entities
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProcessingStrategy {
+    /// One processing task per entity, scheduled independently by tokio
+    #[default]
+    PerEntity,
+    /// One dispatcher favouring entities in proportion to their energy
+    EnergyWeighted,
+}
+
+/// Signal processing configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProcessingConfig {
+    pub strategy: ProcessingStrategy,
+}
+
+/// Signals waiting for the energy-weighted dispatcher
+#[derive(Debug, Default)]
+pub(crate) struct EnergyWeightedQueue {
+    pending: HashMap<EntityId, VecDeque<NeuralSignal>>,
+    /// Smooth weighted round-robin credit per waiting entity
+    credit: HashMap<EntityId, f64>,
+}
+
+impl EnergyWeightedQueue {
+    pub(crate) fn push(&mut self, entity: EntityId, signal: NeuralSignal) {
+        self.pending.entry(entity).or_default().push_back(signal);
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Whether a signal for `entity` is waiting
+    pub(crate) fn is_waiting(&self, entity: &EntityId) -> bool {
+        self.pending.contains_key(entity)
+    }
+
+    /// Entities with signals waiting
+    pub(crate) fn waiting_entities(&self) -> impl Iterator<Item = EntityId> + '_ {
+        self.pending.keys().copied()
+    }
+
+    /// Drop the round-robin credit of an entity that has stopped waiting
+    pub(crate) fn forget(&mut self, entity: &EntityId) {
+        if !self.pending.contains_key(entity) {
+            self.credit.remove(entity);
+        }
+    }
+
+    /// Take the next signal, choosing among waiting entities by weight
+    ///
+    /// Entities missing from `weights` use [`MIN_SCHEDULING_WEIGHT`]. An
+    /// entity keeps its credit when its last signal is taken, until
+    /// [`Self::forget`] is called, so one fed a signal at a time keeps its share.
+    pub(crate) fn pop(&mut self, weights: &HashMap<EntityId, f64>) -> Option<(EntityId, NeuralSignal)> {
+        let weight = |entity: &EntityId| weights.get(entity).copied().unwrap_or(0.0).max(MIN_SCHEDULING_WEIGHT);
+        let total: f64 = self.pending.keys().map(weight).sum();
+
+        let mut chosen: Option<(EntityId, f64)> = None;
+        for entity in self.pending.keys() {
+            let credit = self.credit.entry(*entity).or_insert(0.0);
+            *credit += weight(entity);
+            // Ties go to the smaller id so the choice does not depend on map order
+            let better = chosen.is_none_or(|(best, best_credit)| {
+                *credit > best_credit || (*credit == best_credit && entity.0 < best.0)
+            });
+            if better {
+                chosen = Some((*entity, *credit));
+            }
+        }
+
+        let (entity, _) = chosen?;
+        *self.credit.get_mut(&entity)? -= total;
+
+        let queue = self.pending.get_mut(&entity)?;
+        let signal = queue.pop_front()?;
+        if queue.is_empty() {
+            self.pending.remove(&entity);
+        }
+        Some((entity, signal))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SignalPayload, SignalType};
+
+    fn signal(source: EntityId) -> NeuralSignal {
+        NeuralSignal::new(SignalType::Sensory, source, None, SignalPayload::Message("work".to_string()), 0.5)
+    }
+
+    #[test]
+    fn test_high_energy_entity_gets_more_of_a_fixed_budget() {
+        let rich = EntityId::new();
+        let poor = EntityId::new();
+        let mut queue = EnergyWeightedQueue::default();
+        for _ in 0..20 {
+            queue.push(rich, signal(rich));
+            queue.push(poor, signal(poor));
+        }
+        let weights = HashMap::from([(rich, 0.4), (poor, 0.04)]);
+
+        let budget = 11;
+        let processed: Vec<EntityId> = (0..budget).map(|_| queue.pop(&weights).unwrap().0).collect();
+        let rich_count = processed.iter().filter(|e| **e == rich).count();
+
+        assert_eq!(rich_count, 10);
+        assert_eq!(budget - rich_count, 1);
+    }
+
+    #[test]
+    fn test_dormant_entity_is_not_starved() {
+        let active = EntityId::new();
+        let dormant = EntityId::new();
+        let mut queue = EnergyWeightedQueue::default();
+        queue.push(active, signal(active));
+        queue.push(dormant, signal(dormant));
+        let weights = HashMap::from([(active, 0.5), (dormant, 0.0)]);
+
+        assert_eq!(queue.pop(&weights).unwrap().0, active);
+        assert_eq!(queue.pop(&weights).unwrap().0, dormant);
+        assert!(queue.is_empty());
+        assert!(queue.pop(&weights).is_none());
+    }
+}
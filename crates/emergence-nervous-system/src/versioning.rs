@@ -0,0 +1,202 @@
+//! Wire format versioning for persisted and transmitted signals.
+//!
+//! Every serialized [`NeuralSignal`] carries the [`SIGNAL_VERSION`] it was
+//! written with. Deserialization upgrades older signals one version at a time
+//! through [`migrate_signal`] and rejects signals from newer versions with a
+//! [`SignalVersionError`] rather than a field-level serde failure.
+
+use serde::de::{self, Deserialize, Deserializer};
+use serde::{Serialize, Serializer};
+use serde_yaml::value::{Tag, TaggedValue};
+use serde_yaml::Value;
+
+use crate::NeuralSignal;
+
+/// Wire format version written into new signals
+pub const SIGNAL_VERSION: u16 = 3;
+
+/// Version assumed for signals written before the format was versioned
+pub const UNVERSIONED_SIGNAL: u16 = 0;
+
+/// Fields holding enums with data, which formats other than YAML write as `{variant: value}`
+const ENUM_FIELDS: [&str; 2] = ["target", "payload"];
+
+/// Signals whose wire format cannot be read
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum SignalVersionError {
+    #[error("Signal version {version} is newer than the supported version {supported}")]
+    Unsupported { version: u16, supported: u16 },
+
+    #[error("Signal version field is not a valid version: {found}")]
+    InvalidVersion { found: String },
+
+    #[error("Signal is not a mapping of fields")]
+    NotAMapping,
+}
+
+/// Upgrade a serialized signal from `from_version` to [`SIGNAL_VERSION`]
+pub fn migrate_signal(mut value: Value, from_version: u16) -> Result<Value, SignalVersionError> {
+    if from_version > SIGNAL_VERSION {
+        return Err(SignalVersionError::Unsupported { version: from_version, supported: SIGNAL_VERSION });
+    }
+    let fields = value.as_mapping_mut().ok_or(SignalVersionError::NotAMapping)?;
+
+    for version in from_version..SIGNAL_VERSION {
+        match version {
+            // Version 1 only added the version field itself
+            0 => {}
+            // Version 2 replaced the optional target entity with a `SignalTarget`
+            1 => {
+                let target = match fields.remove("target") {
+                    None | Some(Value::Null) => Value::from("Broadcast"),
+                    Some(entity) => Value::Tagged(Box::new(TaggedValue { tag: Tag::new("One"), value: entity })),
+                };
+                fields.insert("target".into(), target);
+            }
+            // Version 3 added a correlation id, which starts a new chain at each older signal
+            2 => {
+                let signal_id = fields.get("signal_id").cloned().unwrap_or(Value::Null);
+                fields.insert("correlation_id".into(), signal_id);
+            }
+            _ => unreachable!("no migration from signal version {}", version),
+        }
+    }
+
+    fields.insert("version".into(), SIGNAL_VERSION.into());
+    Ok(value)
+}
+
+/// Version recorded in a serialized signal
+fn signal_version(value: &Value) -> Result<u16, SignalVersionError> {
+    let fields = value.as_mapping().ok_or(SignalVersionError::NotAMapping)?;
+    match fields.get("version") {
+        None => Ok(UNVERSIONED_SIGNAL),
+        Some(version) => version.as_u64()
+            .and_then(|version| u16::try_from(version).ok())
+            .ok_or_else(|| SignalVersionError::InvalidVersion {
+                found: serde_yaml::to_string(version).unwrap_or_default().trim_end().to_string(),
+            }),
+    }
+}
+
+/// Rewrite `{variant: value}` enum fields as YAML tags, which is how the buffered value expects them
+fn tag_enum_fields(value: &mut Value) {
+    let Some(fields) = value.as_mapping_mut() else {
+        return;
+    };
+    for field in ENUM_FIELDS {
+        let Some(Value::Mapping(variant)) = fields.get(field) else {
+            continue;
+        };
+        if variant.len() != 1 {
+            continue;
+        }
+        if let Some((Value::String(name), inner)) = variant.iter().next() {
+            let tagged = TaggedValue { tag: Tag::new(name), value: inner.clone() };
+            fields.insert(field.into(), Value::Tagged(Box::new(tagged)));
+        }
+    }
+}
+
+impl Serialize for NeuralSignal {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        NeuralSignal::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for NeuralSignal {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut value = Value::deserialize(deserializer)?;
+        tag_enum_fields(&mut value);
+        let version = signal_version(&value).map_err(de::Error::custom)?;
+        let value = if version == SIGNAL_VERSION {
+            value
+        } else {
+            migrate_signal(value, version).map_err(de::Error::custom)?
+        };
+        NeuralSignal::deserialize(value).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SignalPayload, SignalTarget, SignalType};
+    use emergence_physics::EntityId;
+
+    fn signal() -> NeuralSignal {
+        NeuralSignal::new(SignalType::Cognitive, EntityId::new(), None, SignalPayload::Message("hello".to_string()), 0.5)
+    }
+
+    #[test]
+    fn test_current_signal_round_trips() {
+        let original = signal();
+        let yaml = serde_yaml::to_string(&original).unwrap();
+        assert!(yaml.contains(&format!("version: {}", SIGNAL_VERSION)));
+
+        let parsed: NeuralSignal = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(parsed.signal_id, original.signal_id);
+        assert_eq!(parsed.version, SIGNAL_VERSION);
+    }
+
+    #[test]
+    fn test_json_signal_round_trips() {
+        let original = signal();
+        let json = serde_json::to_string(&original).unwrap();
+        assert!(json.contains(r#""payload":{"Message":"hello"}"#));
+
+        let parsed: NeuralSignal = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.signal_id, original.signal_id);
+        assert!(matches!(parsed.payload, SignalPayload::Message(ref text) if text == "hello"));
+
+        let directed = NeuralSignal::new(SignalType::Memory, EntityId::new(), SignalTarget::Group("team".to_string()), SignalPayload::Query("k".to_string()), 0.5);
+        let parsed: NeuralSignal = serde_json::from_str(&serde_json::to_string(&directed).unwrap()).unwrap();
+        assert_eq!(parsed.target, directed.target);
+    }
+
+    #[test]
+    fn test_newer_version_is_rejected_clearly() {
+        let yaml = serde_yaml::to_string(&signal()).unwrap()
+            .replace(&format!("version: {}", SIGNAL_VERSION), "version: 7");
+
+        let error = serde_yaml::from_str::<NeuralSignal>(&yaml).unwrap_err().to_string();
+        assert!(error.contains(&format!("Signal version 7 is newer than the supported version {}", SIGNAL_VERSION)), "{}", error);
+    }
+
+    #[test]
+    fn test_version_1_targets_are_migrated() {
+        let entity = EntityId::new();
+        let mut directed = serde_yaml::to_value(NeuralSignal::new(SignalType::Memory, entity, entity, SignalPayload::Query("k".to_string()), 0.5)).unwrap();
+        let mut broadcast = serde_yaml::to_value(signal()).unwrap();
+        for (value, target) in [(&mut directed, serde_yaml::to_value(entity).unwrap()), (&mut broadcast, Value::Null)] {
+            let fields = value.as_mapping_mut().unwrap();
+            fields.insert("version".into(), 1.into());
+            fields.insert("target".into(), target);
+        }
+
+        let directed: NeuralSignal = serde_yaml::from_value(directed).unwrap();
+        let broadcast: NeuralSignal = serde_yaml::from_value(broadcast).unwrap();
+        assert_eq!(directed.target, SignalTarget::One(entity));
+        assert_eq!(broadcast.target, SignalTarget::Broadcast);
+    }
+
+    #[test]
+    fn test_unversioned_signal_is_migrated() {
+        let mut value = serde_yaml::to_value(signal()).unwrap();
+        let fields = value.as_mapping_mut().unwrap();
+        fields.remove("version");
+        fields.remove("correlation_id");
+        fields.insert("target".into(), Value::Null);
+
+        let parsed: NeuralSignal = serde_yaml::from_value(value.clone()).unwrap();
+        assert_eq!(parsed.version, SIGNAL_VERSION);
+        assert_eq!(parsed.correlation_id, parsed.signal_id);
+
+        let migrated = migrate_signal(value, UNVERSIONED_SIGNAL).unwrap();
+        assert_eq!(migrated["version"], Value::from(SIGNAL_VERSION));
+        assert_eq!(
+            migrate_signal(Value::from("not a signal"), 0),
+            Err(SignalVersionError::NotAMapping)
+        );
+    }
+}
@@ -1,15 +1,17 @@
 //! Causality enforcement for temporal ordering in the EMERGENCE system.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
 use anyhow::{Result, anyhow};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
 use uuid::Uuid;
 
 /// Causality enforcement engine
 #[derive(Debug)]
 pub struct CausalityEngine {
-    event_chain: HashMap<Uuid, EventNode>,
+    event_chain: RwLock<HashMap<Uuid, CausalEvent>>,
 }
 
 /// Causal chain of events
@@ -24,17 +26,18 @@ pub struct EventOrdering {
     pub strict_ordering: bool,
 }
 
-#[derive(Debug, Clone)]
-struct EventNode {
-    id: Uuid,
-    timestamp: DateTime<Utc>,
-    parents: Vec<Uuid>,
+/// An event recorded in the causal graph
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CausalEvent {
+    pub id: Uuid,
+    pub timestamp: DateTime<Utc>,
+    pub parents: Vec<Uuid>,
 }
 
 impl CausalityEngine {
     pub fn new() -> Self {
         Self {
-            event_chain: HashMap::new(),
+            event_chain: RwLock::new(HashMap::new()),
         }
     }
     
@@ -42,17 +45,144 @@ impl CausalityEngine {
         Ok(())
     }
     
+    /// Record an event and its parents in the causal graph
+    ///
+    /// Ordering itself is not enforced yet; recording lets the event's
+    /// ancestry be queried later.
     pub async fn validate_event_ordering(
         &self,
         event_id: Uuid,
         parent_events: &[Uuid],
         timestamp: DateTime<Utc>,
     ) -> Result<()> {
-        // Stub implementation - in real version would check causal ordering
+        self.event_chain.write().await.insert(event_id, CausalEvent {
+            id: event_id,
+            timestamp,
+            parents: parent_events.to_vec(),
+        });
         Ok(())
     }
     
+    /// The event recorded under `event_id`, if any
+    pub async fn event(&self, event_id: Uuid) -> Option<CausalEvent> {
+        self.event_chain.read().await.get(&event_id).cloned()
+    }
+    
+    /// Undo recording an event, putting back whatever was recorded under its id before
+    pub(crate) async fn unrecord_event(&self, event_id: Uuid, previous: Option<CausalEvent>) {
+        let mut chain = self.event_chain.write().await;
+        match previous {
+            Some(previous) => chain.insert(event_id, previous),
+            None => chain.remove(&event_id),
+        };
+    }
+    
+    /// Every recorded event, oldest first
+    pub async fn events(&self) -> Vec<CausalEvent> {
+        let mut events: Vec<CausalEvent> = self.event_chain.read().await.values().cloned().collect();
+        events.sort_by_key(|event| (event.timestamp, event.id));
+        events
+    }
+    
+    /// Replace the recorded graph with the given events
+    pub async fn restore(&self, events: Vec<CausalEvent>) {
+        *self.event_chain.write().await = events.into_iter().map(|event| (event.id, event)).collect();
+    }
+    
+    /// Every event the given event transitively depends on, ancestors first
+    ///
+    /// Parents that were never recorded are included but have no ancestry of
+    /// their own. Fails if the graph contains a cycle through the event.
+    pub async fn ancestry(&self, event_id: Uuid) -> Result<Vec<Uuid>> {
+        let chain = self.event_chain.read().await;
+        let mut ordered = Vec::new();
+        let mut visited = HashSet::new();
+        let mut in_progress = HashSet::from([event_id]);
+        // Depth-first over parents; an event is emitted once all its parents have been
+        let mut stack = vec![(event_id, 0)];
+        while let Some((current, next_parent)) = stack.pop() {
+            let parents = chain.get(&current).map_or(&[][..], |node| &node.parents[..]);
+            let Some(&parent) = parents.get(next_parent) else {
+                in_progress.remove(&current);
+                visited.insert(current);
+                if current != event_id {
+                    ordered.push(current);
+                }
+                continue;
+            };
+            stack.push((current, next_parent + 1));
+            if in_progress.contains(&parent) {
+                return Err(anyhow!("Causal cycle: event {} is its own ancestor", parent));
+            }
+            if !visited.contains(&parent) {
+                in_progress.insert(parent);
+                stack.push((parent, 0));
+            }
+        }
+        Ok(ordered)
+    }
+    
+    /// The causal graph in Graphviz DOT format, with edges from parent to child
+    pub async fn export_dot(&self) -> String {
+        let chain = self.event_chain.read().await;
+        let mut nodes: Vec<&CausalEvent> = chain.values().collect();
+        nodes.sort_by_key(|node| (node.timestamp, node.id));
+        
+        let mut dot = String::from("digraph causality {\n");
+        for node in &nodes {
+            let _ = writeln!(dot, "    \"{}\" [label=\"{}\\n{}\"];", node.id, node.id, node.timestamp.to_rfc3339());
+        }
+        for node in &nodes {
+            for parent in &node.parents {
+                let _ = writeln!(dot, "    \"{}\" -> \"{}\";", parent, node.id);
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+    
     pub async fn get_statistics(&self) -> serde_yaml::Value {
         serde_yaml::Value::Null
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[tokio::test]
+    async fn test_ancestry_is_topological() {
+        let engine = CausalityEngine::new();
+        let [root, left, right, merge, leaf] = [(); 5].map(|_| Uuid::new_v4());
+        let now = Utc::now();
+        engine.validate_event_ordering(root, &[], now).await.unwrap();
+        engine.validate_event_ordering(left, &[root], now).await.unwrap();
+        engine.validate_event_ordering(right, &[root], now).await.unwrap();
+        engine.validate_event_ordering(merge, &[left, right], now).await.unwrap();
+        engine.validate_event_ordering(leaf, &[merge], now).await.unwrap();
+        
+        assert_eq!(engine.ancestry(leaf).await.unwrap(), vec![root, left, right, merge]);
+        assert_eq!(engine.ancestry(right).await.unwrap(), vec![root]);
+        assert!(engine.ancestry(root).await.unwrap().is_empty());
+        
+        let dot = engine.export_dot().await;
+        assert!(dot.starts_with("digraph causality {"));
+        for (parent, child) in [(root, left), (root, right), (left, merge), (right, merge), (merge, leaf)] {
+            assert!(dot.contains(&format!("\"{}\" -> \"{}\";", parent, child)), "{}", dot);
+        }
+        assert_eq!(dot.matches(" -> ").count(), 5);
+    }
+    
+    #[tokio::test]
+    async fn test_cycles_are_reported() {
+        let engine = CausalityEngine::new();
+        let [first, second, third] = [(); 3].map(|_| Uuid::new_v4());
+        let now = Utc::now();
+        engine.validate_event_ordering(first, &[third], now).await.unwrap();
+        engine.validate_event_ordering(second, &[first], now).await.unwrap();
+        engine.validate_event_ordering(third, &[second], now).await.unwrap();
+        
+        let error = engine.ancestry(third).await.unwrap_err();
+        assert!(error.to_string().contains("Causal cycle"), "{}", error);
+    }
+}
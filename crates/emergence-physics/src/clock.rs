@@ -0,0 +1,118 @@
+//! Time sources for time-dependent physics and signalling.
+//!
+//! Components that decay, expire or throttle read the time through a
+//! [`Clock`] instead of calling `Utc::now()` or `Instant::now()` directly, so
+//! tests can substitute a [`MockClock`] and move time forward without
+//! sleeping.
+
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use tokio::time::Instant;
+
+/// Source of wall-clock and monotonic time
+pub trait Clock: Debug + Send + Sync {
+    /// Current wall-clock time
+    fn now_utc(&self) -> DateTime<Utc>;
+    /// Current monotonic time
+    fn now_instant(&self) -> Instant;
+}
+
+/// Clock shared between the components it drives
+pub type SharedClock = Arc<dyn Clock>;
+
+/// The real time
+///
+/// Monotonic time comes from tokio, so it also follows a paused test runtime.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl SystemClock {
+    /// A shared handle to the real time
+    pub fn shared() -> SharedClock {
+        Arc::new(SystemClock)
+    }
+}
+
+impl Clock for SystemClock {
+    fn now_utc(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    fn now_instant(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// A clock that only moves when told to
+///
+/// Clones share the same time, so a test can keep one and hand another to
+/// the component under test.
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    now: Arc<Mutex<(DateTime<Utc>, Instant)>>,
+}
+
+impl MockClock {
+    /// A clock stopped at the current time
+    pub fn new() -> Self {
+        Self::starting_at(Utc::now())
+    }
+
+    /// A clock stopped at the given wall-clock time
+    pub fn starting_at(utc: DateTime<Utc>) -> Self {
+        Self {
+            now: Arc::new(Mutex::new((utc, Instant::now()))),
+        }
+    }
+
+    /// Move both wall-clock and monotonic time forward
+    pub fn advance(&self, duration: Duration) {
+        let mut now = self.now.lock().unwrap();
+        now.0 += chrono::Duration::from_std(duration).unwrap_or(chrono::Duration::MAX);
+        now.1 += duration;
+    }
+
+    /// A shared handle to this clock
+    pub fn shared(&self) -> SharedClock {
+        Arc::new(self.clone())
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for MockClock {
+    fn now_utc(&self) -> DateTime<Utc> {
+        self.now.lock().unwrap().0
+    }
+
+    fn now_instant(&self) -> Instant {
+        self.now.lock().unwrap().1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mock_clock_moves_only_when_advanced() {
+        let clock = MockClock::new();
+        let shared = clock.shared();
+        let (utc, instant) = (shared.now_utc(), shared.now_instant());
+
+        std::thread::sleep(Duration::from_millis(5));
+        assert_eq!(shared.now_utc(), utc);
+        assert_eq!(shared.now_instant(), instant);
+
+        clock.advance(Duration::from_secs(90));
+        assert_eq!(shared.now_utc() - utc, chrono::Duration::seconds(90));
+        assert_eq!(shared.now_instant() - instant, Duration::from_secs(90));
+    }
+}
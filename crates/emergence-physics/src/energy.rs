@@ -11,7 +11,8 @@
 //! - Emergent optimization through energy flow analysis
 //! - Predictive allocation using historical patterns
 
-use std::collections::{HashMap, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::Duration;
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
 use ordered_float::OrderedFloat;
@@ -19,15 +20,22 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use tracing::{debug, warn, info};
 
+use crate::clock::{SharedClock, SystemClock};
+use crate::flow::{FlowEdge, FlowGraph, FlowNode};
 use crate::EntityId;
 
+/// Window over which `max_transfer_rate` is measured
+pub const TRANSFER_RATE_WINDOW: Duration = Duration::from_secs(1);
+
 /// Energy conservation enforcement engine with advanced distribution algorithms
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct EnergyConservation {
     /// Total system energy (conserved quantity)
     total_energy: OrderedFloat<f64>,
     /// Current energy allocations per entity
     allocations: HashMap<EntityId, OrderedFloat<f64>>,
+    /// Energy lost to work and heat, out of reach of every entity
+    dissipated_energy: OrderedFloat<f64>,
     /// Transaction history for auditing and pattern analysis
     transaction_log: Vec<EnergyTransaction>,
     /// Entity activity patterns for adaptive allocation
@@ -40,6 +48,20 @@ pub struct EnergyConservation {
     energy_history: VecDeque<EnergyState>,
     /// Maximum history size for memory efficiency
     max_history_size: usize,
+    /// Source of the current time
+    clock: SharedClock,
+    /// When decay was last applied by `apply_elapsed_decay`
+    last_decay: tokio::time::Instant,
+    /// Recent outgoing transfers per source, for rate limiting
+    recent_transfers: HashMap<EntityId, VecDeque<(tokio::time::Instant, OrderedFloat<f64>)>>,
+    /// Outgoing transfer rate limit (per second) of each rate-limited entity
+    transfer_rate_limits: HashMap<EntityId, OrderedFloat<f64>>,
+    /// When load balancing last ran
+    last_rebalance: Option<tokio::time::Instant>,
+    /// Whether imbalance has fallen below `imbalance_low` since the last rebalance
+    rebalance_armed: bool,
+    /// Whether imbalance has stayed above `imbalance_high` since the last rebalance
+    rebalance_unfinished: bool,
 }
 
 /// Entity activity pattern for adaptive energy allocation
@@ -83,7 +105,7 @@ pub struct EfficiencyMetrics {
     pub system_efficiency: OrderedFloat<f64>,
     /// Energy waste rate (unused allocated energy)
     pub waste_rate: OrderedFloat<f64>,
-    /// Load balancing score (0.0 to 1.0)
+    /// Load balancing score (0.0 to 1.0), one minus the Gini coefficient
     pub load_balance_score: OrderedFloat<f64>,
     /// Predictive allocation accuracy
     pub prediction_accuracy: OrderedFloat<f64>,
@@ -96,7 +118,12 @@ pub struct EnergyConfig {
     pub total_system_energy: OrderedFloat<f64>,
     /// Energy decay rate for idle entities (per second)
     pub decay_rate: OrderedFloat<f64>,
-    /// Maximum energy transfer rate (per second)
+    /// Slow decay for recently active entities; when off, every entity decays
+    /// by exactly `decay_rate` per second, independent of wall-clock activity
+    #[serde(default = "adaptive_decay_default")]
+    pub adaptive_decay_enabled: bool,
+    /// Maximum energy transfer rate (per second) of entities placed under a
+    /// limit without one of their own
     pub max_transfer_rate: OrderedFloat<f64>,
     /// Minimum energy threshold below which entities become dormant
     pub dormancy_threshold: OrderedFloat<f64>,
@@ -108,6 +135,30 @@ pub struct EnergyConfig {
     pub load_balancing: LoadBalancingConfig,
     /// Predictive allocation parameters
     pub predictive_allocation: PredictiveAllocationConfig,
+    /// Limits on the bookkeeping kept per entity and transaction
+    #[serde(default)]
+    pub retention: RetentionConfig,
+}
+
+/// How much activity, transaction and flow history is kept
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetentionConfig {
+    /// Seconds after its last activity that an entity without energy keeps its activity pattern
+    pub pattern_idle_window: f64,
+    /// Most transactions kept in the log; the oldest quarter is dropped when it fills
+    pub max_transaction_log: usize,
+    /// Most entity-to-entity flow rates tracked; the weakest is dropped to make room
+    pub max_flow_rates: usize,
+}
+
+impl Default for RetentionConfig {
+    fn default() -> Self {
+        Self {
+            pattern_idle_window: 3600.0,
+            max_transaction_log: 10_000,
+            max_flow_rates: 10_000,
+        }
+    }
 }
 
 /// Adaptive allocation configuration
@@ -125,17 +176,62 @@ pub struct AdaptiveAllocationConfig {
 
 /// Load balancing configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "LoadBalancingFields")]
 pub struct LoadBalancingConfig {
-    /// Load balancing frequency (seconds)
+    /// Minimum time between rebalances (seconds)
     pub rebalance_interval: f64,
     /// Maximum energy transfer per rebalance
     pub max_transfer_per_rebalance: OrderedFloat<f64>,
-    /// Load imbalance threshold for triggering rebalance
-    pub imbalance_threshold: OrderedFloat<f64>,
+    /// Load imbalance above which a rebalance is triggered
+    pub imbalance_high: OrderedFloat<f64>,
+    /// Load imbalance the system must fall below before an oscillating
+    /// imbalance can be rebalanced again
+    pub imbalance_low: OrderedFloat<f64>,
     /// Priority entities (exempt from load balancing)
     pub priority_entities: Vec<EntityId>,
 }
 
+impl LoadBalancingConfig {
+    /// Check that `imbalance_low` does not exceed `imbalance_high`
+    pub fn validate(&self) -> Result<(), EnergyError> {
+        if self.imbalance_low > self.imbalance_high {
+            return Err(EnergyError::InvalidConfig {
+                reason: format!("imbalance_low {} is above imbalance_high {}", self.imbalance_low, self.imbalance_high),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Serialized form of [`LoadBalancingConfig`], validated when deserialized
+#[derive(Deserialize)]
+struct LoadBalancingFields {
+    rebalance_interval: f64,
+    max_transfer_per_rebalance: OrderedFloat<f64>,
+    #[serde(alias = "imbalance_threshold")]
+    imbalance_high: OrderedFloat<f64>,
+    /// Configs with a single threshold have no hysteresis band
+    #[serde(default)]
+    imbalance_low: Option<OrderedFloat<f64>>,
+    priority_entities: Vec<EntityId>,
+}
+
+impl TryFrom<LoadBalancingFields> for LoadBalancingConfig {
+    type Error = EnergyError;
+
+    fn try_from(fields: LoadBalancingFields) -> Result<Self, Self::Error> {
+        let config = Self {
+            rebalance_interval: fields.rebalance_interval,
+            max_transfer_per_rebalance: fields.max_transfer_per_rebalance,
+            imbalance_high: fields.imbalance_high,
+            imbalance_low: fields.imbalance_low.unwrap_or(fields.imbalance_high),
+            priority_entities: fields.priority_entities,
+        };
+        config.validate()?;
+        Ok(config)
+    }
+}
+
 /// Predictive allocation configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PredictiveAllocationConfig {
@@ -158,10 +254,19 @@ pub struct EnergyState {
     pub allocated_energy: OrderedFloat<f64>,
     /// Free energy available for allocation
     pub free_energy: OrderedFloat<f64>,
+    /// Energy dissipated by work; total = allocated + free + dissipated
+    #[serde(default)]
+    pub dissipated_energy: OrderedFloat<f64>,
     /// Number of active entities
     pub active_entities: usize,
     /// Energy distribution statistics
     pub energy_distribution: EnergyDistribution,
+    /// Energy held by each entity
+    #[serde(default)]
+    pub per_entity: HashMap<EntityId, OrderedFloat<f64>>,
+    /// Inequality of the allocations, from 0.0 (equal) towards 1.0 (held by one entity)
+    #[serde(default)]
+    pub gini_coefficient: OrderedFloat<f64>,
 }
 
 /// Energy distribution statistics
@@ -219,6 +324,14 @@ pub enum EnergyError {
     /// Entity not found
     #[error("Entity {entity} not found in energy system")]
     EntityNotFound { entity: EntityId },
+    
+    /// Configuration is inconsistent
+    #[error("Invalid energy configuration: {reason}")]
+    InvalidConfig { reason: String },
+}
+
+fn adaptive_decay_default() -> bool {
+    true
 }
 
 impl Default for EnergyConfig {
@@ -226,6 +339,7 @@ impl Default for EnergyConfig {
         Self {
             total_system_energy: OrderedFloat(1.0),
             decay_rate: OrderedFloat(0.01), // 1% per second
+            adaptive_decay_enabled: true,
             max_transfer_rate: OrderedFloat(0.1), // 10% per second
             dormancy_threshold: OrderedFloat(0.05), // 5% energy minimum
             base_operation_cost: OrderedFloat(0.001), // 0.1% per operation
@@ -238,7 +352,8 @@ impl Default for EnergyConfig {
             load_balancing: LoadBalancingConfig {
                 rebalance_interval: 300.0,
                 max_transfer_per_rebalance: OrderedFloat(0.5),
-                imbalance_threshold: OrderedFloat(0.2),
+                imbalance_high: OrderedFloat(0.2),
+                imbalance_low: OrderedFloat(0.1),
                 priority_entities: Vec::new(),
             },
             predictive_allocation: PredictiveAllocationConfig {
@@ -247,6 +362,7 @@ impl Default for EnergyConfig {
                 confidence_threshold: OrderedFloat(0.9),
                 max_prediction_error: OrderedFloat(0.05),
             },
+            retention: RetentionConfig::default(),
         }
     }
 }
@@ -254,12 +370,18 @@ impl Default for EnergyConfig {
 impl EnergyConservation {
     /// Create new energy conservation system with default configuration
     pub fn new() -> Self {
+        Self::with_clock(SystemClock::shared())
+    }
+    
+    /// Create an energy conservation system that reads the time from `clock`
+    pub fn with_clock(clock: SharedClock) -> Self {
         let config = EnergyConfig::default();
         let total_energy = config.total_system_energy;
         
         Self {
             total_energy,
             allocations: HashMap::new(),
+            dissipated_energy: OrderedFloat(0.0),
             transaction_log: Vec::new(),
             activity_patterns: HashMap::new(),
             flow_analysis: EnergyFlowAnalysis {
@@ -272,11 +394,18 @@ impl EnergyConservation {
                     load_balance_score: OrderedFloat(1.0),
                     prediction_accuracy: OrderedFloat(1.0),
                 },
-                last_analysis: Utc::now(),
+                last_analysis: clock.now_utc(),
             },
             config,
             energy_history: VecDeque::new(),
             max_history_size: 100,
+            last_decay: clock.now_instant(),
+            recent_transfers: HashMap::new(),
+            transfer_rate_limits: HashMap::new(),
+            last_rebalance: None,
+            rebalance_armed: true,
+            rebalance_unfinished: false,
+            clock,
         }
     }
     
@@ -289,6 +418,10 @@ impl EnergyConservation {
             }
         }
         
+        if let Some(adaptive) = schema.get("adaptive_decay").and_then(|a| a.as_bool()) {
+            self.config.adaptive_decay_enabled = adaptive;
+        }
+        
         if let Some(allocation_rules) = schema.get("allocation_rules") {
             if let Some(rules) = allocation_rules.as_sequence() {
                 for rule in rules {
@@ -331,7 +464,7 @@ impl EnergyConservation {
     pub async fn allocate_energy(&mut self, entity: EntityId, amount: OrderedFloat<f64>) -> Result<(), EnergyError> {
         let current_allocation = self.allocations.get(&entity).copied().unwrap_or(OrderedFloat(0.0));
         let total_allocated = self.get_total_allocated();
-        let available = self.total_energy - total_allocated;
+        let available = self.total_energy - total_allocated - self.dissipated_energy;
         
         if amount > available {
             return Err(EnergyError::InsufficientEnergy {
@@ -349,11 +482,11 @@ impl EnergyConservation {
             to: entity,
             amount,
             transaction_id: Uuid::new_v4(),
-            timestamp: Utc::now(),
+            timestamp: self.clock.now_utc(),
         };
         
         self.allocations.insert(entity, current_allocation + amount);
-        self.transaction_log.push(transaction);
+        self.record_transaction(transaction);
         
         // Update energy history for predictive analysis
         self.update_energy_history().await;
@@ -368,9 +501,24 @@ impl EnergyConservation {
     }
     
     /// Execute energy transfer with flow analysis
+    ///
+    /// Transfers from an entity placed under a limit with
+    /// [`Self::limit_transfer_rate`] are rejected once its outgoing amount
+    /// within the last [`TRANSFER_RATE_WINDOW`] would exceed that limit.
+    /// Transfers to oneself are not rate limited.
     pub async fn execute_transaction(&mut self, transaction: EnergyTransaction) -> Result<(), EnergyError> {
+        let rate_limited = transaction.from.is_some_and(|from| from != transaction.to && self.transfer_rate_limits.contains_key(&from));
+        self.apply_transaction(transaction, rate_limited).await
+    }
+    
+    /// Move energy, counting the transfer against the source's rate limit when `rate_limited`
+    async fn apply_transaction(&mut self, transaction: EnergyTransaction, rate_limited: bool) -> Result<(), EnergyError> {
         // Validate source has sufficient energy
         if let Some(from_entity) = transaction.from {
+            if rate_limited {
+                self.check_transfer_rate(from_entity, transaction.amount)?;
+            }
+
             let source_energy = self.allocations.get(&from_entity)
                 .copied()
                 .ok_or(EnergyError::EntityNotFound { entity: from_entity })?;
@@ -384,6 +532,11 @@ impl EnergyConservation {
             
             // Deduct from source
             self.allocations.insert(from_entity, source_energy - transaction.amount);
+            if rate_limited {
+                self.recent_transfers.entry(from_entity)
+                    .or_default()
+                    .push_back((self.clock.now_instant(), transaction.amount));
+            }
         }
         
         // Add to destination
@@ -394,7 +547,7 @@ impl EnergyConservation {
         self.update_flow_analysis(&transaction).await;
         
         // Record transaction
-        self.transaction_log.push(transaction.clone());
+        self.record_transaction(transaction.clone());
         
         // Update energy history
         self.update_energy_history().await;
@@ -409,6 +562,91 @@ impl EnergyConservation {
         Ok(())
     }
     
+    /// Lose energy from an entity's allocation to work or heat
+    ///
+    /// The energy leaves the pool for good: it is neither free for
+    /// allocation nor held by any entity, but counted in `dissipated_energy`.
+    pub async fn dissipate_energy(&mut self, entity: EntityId, amount: OrderedFloat<f64>) -> Result<(), EnergyError> {
+        let held = self.allocations.get(&entity)
+            .copied()
+            .ok_or(EnergyError::EntityNotFound { entity })?;
+        if held < amount {
+            return Err(EnergyError::InsufficientEnergy { requested: amount, available: held });
+        }
+        
+        self.allocations.insert(entity, held - amount);
+        self.dissipated_energy += amount;
+        self.update_energy_history().await;
+        self.verify_conservation()?;
+        
+        debug!("Entity {} dissipated {} energy", entity, amount);
+        Ok(())
+    }
+    
+    /// Energy dissipated since the system was created
+    pub fn dissipated_energy(&self) -> OrderedFloat<f64> {
+        self.dissipated_energy
+    }
+    
+    /// Limit how fast an entity can transfer energy out, `None` meaning `max_transfer_rate`
+    pub fn limit_transfer_rate(&mut self, entity: EntityId, limit: Option<OrderedFloat<f64>>) {
+        self.transfer_rate_limits.insert(entity, limit.unwrap_or(self.config.max_transfer_rate));
+    }
+    
+    /// Let an entity transfer energy out as fast as it likes again
+    pub fn lift_transfer_rate_limit(&mut self, entity: EntityId) {
+        self.transfer_rate_limits.remove(&entity);
+        self.recent_transfers.remove(&entity);
+    }
+    
+    /// Keep load balancing from moving energy into or out of an entity
+    pub fn exempt_from_load_balancing(&mut self, entity: EntityId) {
+        let priority_entities = &mut self.config.load_balancing.priority_entities;
+        if !priority_entities.contains(&entity) {
+            priority_entities.push(entity);
+        }
+    }
+    
+    /// Reject a transfer that would push the source's windowed transfer rate past its limit
+    fn check_transfer_rate(&mut self, source: EntityId, amount: OrderedFloat<f64>) -> Result<(), EnergyError> {
+        let limit = self.transfer_rate_limits.get(&source).copied().unwrap_or(self.config.max_transfer_rate);
+        let now = self.clock.now_instant();
+        let Some(recent) = self.recent_transfers.get_mut(&source) else {
+            return Self::within_rate_limit(amount, limit);
+        };
+        while recent.front().is_some_and(|(at, _)| now.saturating_duration_since(*at) >= TRANSFER_RATE_WINDOW) {
+            recent.pop_front();
+        }
+        let windowed: OrderedFloat<f64> = recent.iter().map(|(_, amount)| *amount).sum();
+        if recent.is_empty() {
+            self.recent_transfers.remove(&source);
+        }
+        Self::within_rate_limit(windowed + amount, limit)
+    }
+    
+    fn within_rate_limit(amount: OrderedFloat<f64>, limit: OrderedFloat<f64>) -> Result<(), EnergyError> {
+        let rate = amount / OrderedFloat(TRANSFER_RATE_WINDOW.as_secs_f64());
+        if rate > limit {
+            return Err(EnergyError::RateLimitExceeded { rate, limit });
+        }
+        Ok(())
+    }
+    
+    /// Release all energy held by an entity back to the system pool
+    ///
+    /// Returns the amount released (zero if the entity held no energy).
+    pub async fn release_energy(&mut self, entity: EntityId) -> Result<OrderedFloat<f64>, EnergyError> {
+        let released = self.allocations.remove(&entity).unwrap_or(OrderedFloat(0.0));
+        self.activity_patterns.remove(&entity);
+        self.recent_transfers.remove(&entity);
+        
+        self.update_energy_history().await;
+        self.verify_conservation()?;
+        
+        debug!("Released {} energy from entity {} back to the system", released, entity);
+        Ok(released)
+    }
+    
     /// Apply energy decay with adaptive optimization
     pub async fn apply_decay(&mut self, delta_time: f64) -> Result<(), EnergyError> {
         let decay_amount = self.config.decay_rate * OrderedFloat(delta_time);
@@ -417,8 +655,12 @@ impl EnergyConservation {
         let mut decay_plan: Vec<(EntityId, OrderedFloat<f64>)> = Vec::new();
         
         for (entity, energy) in &self.allocations {
-            let adaptive_decay = self.calculate_adaptive_decay(*entity, *energy, delta_time).await;
-            decay_plan.push((*entity, adaptive_decay));
+            let decay = if self.config.adaptive_decay_enabled {
+                self.calculate_adaptive_decay(*entity, *energy, delta_time).await
+            } else {
+                decay_amount
+            };
+            decay_plan.push((*entity, decay));
         }
         
         // Apply decay according to plan
@@ -439,9 +681,45 @@ impl EnergyConservation {
         // Update activity patterns after decay
         self.update_activity_patterns_after_decay(delta_time).await;
         
+        let idle_window = Duration::from_secs_f64(self.config.retention.pattern_idle_window.max(0.0));
+        self.prune_stale_patterns(idle_window);
+        
         Ok(())
     }
     
+    /// Forget entities that hold no energy and have been idle for longer than `max_idle`
+    ///
+    /// Drops their activity patterns and any flow rates to or from forgotten
+    /// entities. Returns the number of patterns removed.
+    pub fn prune_stale_patterns(&mut self, max_idle: Duration) -> usize {
+        let cutoff = self.clock.now_utc() - chrono::Duration::from_std(max_idle).unwrap_or(chrono::Duration::MAX);
+        let before = self.activity_patterns.len();
+        let allocations = &self.allocations;
+        self.activity_patterns.retain(|entity, pattern| {
+            allocations.contains_key(entity) || pattern.last_activity >= cutoff
+        });
+        let patterns = &self.activity_patterns;
+        let known = |entity: &EntityId| allocations.contains_key(entity) || patterns.contains_key(entity);
+        self.flow_analysis.flow_rates.retain(|(from, to), _| known(from) && known(to));
+        
+        let pruned = before - self.activity_patterns.len();
+        if pruned > 0 {
+            debug!("Pruned {} stale activity patterns", pruned);
+        }
+        pruned
+    }
+    
+    /// Apply decay for the time passed since the previous call, or since creation
+    ///
+    /// Returns the seconds of decay applied.
+    pub async fn apply_elapsed_decay(&mut self) -> Result<f64, EnergyError> {
+        let now = self.clock.now_instant();
+        let elapsed = now.saturating_duration_since(self.last_decay).as_secs_f64();
+        self.last_decay = now;
+        self.apply_decay(elapsed).await?;
+        Ok(elapsed)
+    }
+    
     /// Optimize energy distribution using advanced algorithms
     pub async fn optimize_energy_distribution(&mut self) -> Result<(), EnergyError> {
         info!("Starting energy distribution optimization");
@@ -452,9 +730,9 @@ impl EnergyConservation {
         // Identify bottlenecks and optimization opportunities
         let optimization_plan = self.generate_optimization_plan().await;
         
-        // Execute optimization transfers
+        // Execute optimization transfers, which the system makes rather than the entities
         for transfer in optimization_plan {
-            self.execute_transaction(transfer).await?;
+            self.apply_transaction(transfer, false).await?;
         }
         
         // Update efficiency metrics
@@ -492,7 +770,7 @@ impl EnergyConservation {
     
     /// Update activity pattern for an entity
     async fn update_activity_pattern(&mut self, entity: EntityId, allocated_amount: OrderedFloat<f64>) {
-        let now = Utc::now();
+        let now = self.clock.now_utc();
         let current_energy = self.get_entity_energy(entity);
         
         let pattern = self.activity_patterns.entry(entity).or_insert_with(|| ActivityPattern {
@@ -556,10 +834,17 @@ impl EnergyConservation {
             let new_flow_rate = current_flow * (OrderedFloat(1.0) - learning_rate) + 
                                transaction.amount * learning_rate;
             
-            self.flow_analysis.flow_rates.insert(flow_key, new_flow_rate);
+            let flow_rates = &mut self.flow_analysis.flow_rates;
+            if !flow_rates.contains_key(&flow_key) && flow_rates.len() >= self.config.retention.max_flow_rates {
+                let weakest = flow_rates.iter().min_by_key(|(_, rate)| **rate).map(|(key, _)| *key);
+                if let Some(weakest) = weakest {
+                    flow_rates.remove(&weakest);
+                }
+            }
+            flow_rates.insert(flow_key, new_flow_rate);
         }
         
-        self.flow_analysis.last_analysis = Utc::now();
+        self.flow_analysis.last_analysis = self.clock.now_utc();
     }
     
     /// Analyze energy flow patterns
@@ -599,7 +884,8 @@ impl EnergyConservation {
         // Simple fallback: transfer from any entity with >0.2 to any with <0.2
         let mut donors = Vec::new();
         let mut receivers = Vec::new();
-        for (entity, energy) in &self.allocations {
+        let exempt = &self.config.load_balancing.priority_entities;
+        for (entity, energy) in self.allocations.iter().filter(|(entity, _)| !exempt.contains(entity)) {
             if *energy > OrderedFloat(0.2) {
                 donors.push(*entity);
             } else if *energy < OrderedFloat(0.2) {
@@ -616,7 +902,7 @@ impl EnergyConservation {
                             to,
                             amount,
                             transaction_id: Uuid::new_v4(),
-                            timestamp: Utc::now(),
+                            timestamp: self.clock.now_utc(),
                         });
                     }
                 }
@@ -626,35 +912,41 @@ impl EnergyConservation {
     }
     
     /// Check and trigger load balancing if needed
+    ///
+    /// A rebalance runs when imbalance exceeds `imbalance_high`, at most once
+    /// every `rebalance_interval`. A rebalance that leaves imbalance above
+    /// `imbalance_high` is followed up once the interval has passed. One that
+    /// brings it back under `imbalance_high` is not repeated until imbalance
+    /// has also dropped below `imbalance_low`, so an imbalance oscillating
+    /// around the high threshold doesn't set off a rebalance every time.
     async fn check_and_trigger_load_balancing(&mut self) -> Result<(), EnergyError> {
-        let state = self.get_state().await;
-        let imbalance_score = self.calculate_load_imbalance(&state).await;
+        let imbalance_score = self.get_state().await.gini_coefficient;
+        let settings = &self.config.load_balancing;
         
-        if imbalance_score > self.config.load_balancing.imbalance_threshold {
-            info!("Load imbalance detected (score: {}), triggering rebalancing", imbalance_score);
-            self.optimize_energy_distribution().await?;
+        if imbalance_score < settings.imbalance_low {
+            self.rebalance_armed = true;
         }
-        
-        Ok(())
-    }
-    
-    /// Calculate load imbalance score
-    async fn calculate_load_imbalance(&self, state: &EnergyState) -> OrderedFloat<f64> {
-        if state.active_entities == 0 {
-            return OrderedFloat(0.0);
+        if imbalance_score <= settings.imbalance_high {
+            self.rebalance_unfinished = false;
+            return Ok(());
+        }
+        if !self.rebalance_armed && !self.rebalance_unfinished {
+            debug!("Load imbalance {} is back above the high threshold before settling", imbalance_score);
+            return Ok(());
         }
         
-        let energies: Vec<OrderedFloat<f64>> = self.allocations.values().copied().collect();
-        let mean = energies.iter().sum::<OrderedFloat<f64>>() / OrderedFloat(state.active_entities as f64);
-        
-        let variance = energies.iter()
-            .map(|e| (*e - mean) * (*e - mean))
-            .sum::<OrderedFloat<f64>>() / OrderedFloat(state.active_entities as f64);
-        
-        // Normalize variance to 0-1 scale
-        let imbalance_score = (variance / (mean * mean)).min(OrderedFloat(1.0));
+        let now = self.clock.now_instant();
+        let interval = Duration::from_secs_f64(settings.rebalance_interval.max(0.0));
+        if self.last_rebalance.is_some_and(|last| now.duration_since(last) < interval) {
+            debug!("Load imbalance {} detected, but the last rebalance was too recent", imbalance_score);
+            return Ok(());
+        }
         
-        imbalance_score
+        info!("Load imbalance detected (score: {}), triggering rebalancing", imbalance_score);
+        self.last_rebalance = Some(now);
+        self.rebalance_armed = false;
+        self.rebalance_unfinished = true;
+        self.optimize_energy_distribution().await
     }
     
     /// Update energy history for predictive analysis
@@ -670,12 +962,14 @@ impl EnergyConservation {
     }
     
     /// Analyze historical demand for an entity
+    ///
+    /// Averages the entity's energy over the recorded states it appears in.
     async fn analyze_historical_demand(&self, entity: EntityId) -> OrderedFloat<f64> {
         let mut total_demand = OrderedFloat(0.0);
         let mut count = 0;
         
         for state in &self.energy_history {
-            if let Some(energy) = self.allocations.get(&entity) {
+            if let Some(energy) = state.per_entity.get(&entity) {
                 total_demand += *energy;
                 count += 1;
             }
@@ -698,7 +992,7 @@ impl EnergyConservation {
             
             if let Some(pattern) = self.activity_patterns.get_mut(&entity) {
                 // Update efficiency score based on energy retention
-                if pattern.last_activity < Utc::now() - chrono::Duration::seconds(60) {
+                if pattern.last_activity < self.clock.now_utc() - chrono::Duration::seconds(60) {
                     pattern.efficiency_score = pattern.efficiency_score * OrderedFloat(0.95); // Decay efficiency
                 }
             }
@@ -722,7 +1016,7 @@ impl EnergyConservation {
         let waste_rate = OrderedFloat(1.0) - system_efficiency;
         
         // Calculate load balance score
-        let load_balance_score = OrderedFloat(1.0) - self.calculate_load_imbalance(&state).await;
+        let load_balance_score = OrderedFloat(1.0) - state.gini_coefficient;
         
         // Update metrics
         self.flow_analysis.efficiency_metrics = EfficiencyMetrics {
@@ -733,15 +1027,78 @@ impl EnergyConservation {
         };
     }
     
+    /// Check that checkpointed allocations can replace the current ones
+    ///
+    /// The checkpoint must come from a system with the same total energy and
+    /// may not allocate and dissipate more than that total.
+    pub fn check_restorable(
+        &self,
+        total_energy: OrderedFloat<f64>,
+        allocations: &HashMap<EntityId, OrderedFloat<f64>>,
+        dissipated: OrderedFloat<f64>,
+    ) -> Result<(), EnergyError> {
+        if total_energy != self.total_energy {
+            return Err(EnergyError::ConservationViolated { before: self.total_energy, after: total_energy });
+        }
+        let spent: OrderedFloat<f64> = allocations.values().sum::<OrderedFloat<f64>>() + dissipated;
+        if spent > self.total_energy {
+            return Err(EnergyError::InsufficientEnergy { requested: spent, available: self.total_energy });
+        }
+        Ok(())
+    }
+    
+    /// Replace every allocation with a checkpointed set, leaving them untouched if it cannot be restored
+    pub fn restore_allocations(
+        &mut self,
+        total_energy: OrderedFloat<f64>,
+        allocations: HashMap<EntityId, OrderedFloat<f64>>,
+        dissipated: OrderedFloat<f64>,
+    ) -> Result<(), EnergyError> {
+        self.check_restorable(total_energy, &allocations, dissipated)?;
+        self.allocations = allocations;
+        self.dissipated_energy = dissipated;
+        self.recent_transfers.clear();
+        Ok(())
+    }
+    
     /// Get current energy allocation for an entity
     pub fn get_entity_energy(&self, entity: EntityId) -> OrderedFloat<f64> {
         self.allocations.get(&entity).copied().unwrap_or(OrderedFloat(0.0))
     }
     
+    /// Export the entities and the flow rates between them as a graph
+    ///
+    /// Entities that took part in a flow but no longer hold energy are
+    /// included with zero energy. Bottlenecks are those found by the most
+    /// recent flow analysis.
+    pub fn export_flow_graph(&self) -> FlowGraph {
+        let mut entities: Vec<EntityId> = self.allocations.keys()
+            .chain(self.flow_analysis.flow_rates.keys().flat_map(|(from, to)| [from, to]))
+            .copied()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        entities.sort_by_key(|entity| entity.0);
+        
+        let nodes = entities.into_iter()
+            .map(|entity| FlowNode {
+                entity,
+                energy: self.get_entity_energy(entity),
+                bottleneck: self.flow_analysis.bottlenecks.contains(&entity),
+            })
+            .collect();
+        let mut edges: Vec<FlowEdge> = self.flow_analysis.flow_rates.iter()
+            .map(|(&(from, to), &rate)| FlowEdge { from, to, rate })
+            .collect();
+        edges.sort_by_key(|edge| (edge.from.0, edge.to.0));
+        
+        FlowGraph { nodes, edges }
+    }
+    
     /// Get current energy state
     pub async fn get_state(&self) -> EnergyState {
         let allocated_energy = self.get_total_allocated();
-        let free_energy = self.total_energy - allocated_energy;
+        let free_energy = self.total_energy - allocated_energy - self.dissipated_energy;
         let active_entities = self.allocations.len();
         
         let energy_distribution = if active_entities > 0 {
@@ -767,8 +1124,11 @@ impl EnergyConservation {
             total_energy: self.total_energy,
             allocated_energy,
             free_energy,
+            dissipated_energy: self.dissipated_energy,
             active_entities,
             energy_distribution,
+            per_entity: self.allocations.clone(),
+            gini_coefficient: gini_coefficient(self.allocations.values().copied()),
         }
     }
     
@@ -777,16 +1137,26 @@ impl EnergyConservation {
         &self.transaction_log
     }
     
+    /// Append to the transaction log, dropping the oldest quarter once it is full
+    fn record_transaction(&mut self, transaction: EnergyTransaction) {
+        let limit = self.config.retention.max_transaction_log.max(1);
+        if self.transaction_log.len() >= limit {
+            let excess = self.transaction_log.len() + 1 - limit * 3 / 4;
+            self.transaction_log.drain(..excess.min(self.transaction_log.len()));
+        }
+        self.transaction_log.push(transaction);
+    }
+    
     /// Verify energy conservation invariant
     fn verify_conservation(&self) -> Result<(), EnergyError> {
-        let allocated = self.get_total_allocated();
+        let spent = self.get_total_allocated() + self.dissipated_energy;
         
         // Allow small floating point errors
         let epsilon = OrderedFloat(1e-10);
-        if (allocated - self.total_energy).abs() > *epsilon && allocated > self.total_energy {
+        if (spent - self.total_energy).abs() > *epsilon && spent > self.total_energy {
             return Err(EnergyError::ConservationViolated {
                 before: self.total_energy,
-                after: allocated,
+                after: spent,
             });
         }
         
@@ -799,6 +1169,23 @@ impl EnergyConservation {
     }
 }
 
+/// Gini coefficient of a set of allocations; zero when there are none or they sum to zero
+///
+/// For `n` allocations it ranges from 0.0 when all are equal to `(n - 1) / n`
+/// when a single entity holds everything.
+pub(crate) fn gini_coefficient(allocations: impl Iterator<Item = OrderedFloat<f64>>) -> OrderedFloat<f64> {
+    let mut sorted: Vec<OrderedFloat<f64>> = allocations.collect();
+    sorted.sort();
+    let total: OrderedFloat<f64> = sorted.iter().copied().sum();
+    if sorted.is_empty() || total <= OrderedFloat(0.0) {
+        return OrderedFloat(0.0);
+    }
+    
+    let n = sorted.len() as f64;
+    let weighted: f64 = sorted.iter().enumerate().map(|(i, x)| (i + 1) as f64 * x.0).sum();
+    OrderedFloat(2.0 * weighted / (n * total.0) - (n + 1.0) / n)
+}
+
 impl Default for EnergyConservation {
     fn default() -> Self {
         Self::new()
@@ -828,6 +1215,168 @@ mod tests {
         assert_eq!(state.free_energy, OrderedFloat(0.7));
     }
     
+    #[tokio::test]
+    async fn test_elapsed_decay_follows_the_clock() {
+        let clock = crate::MockClock::new();
+        let mut energy_system = EnergyConservation::with_clock(clock.shared());
+        let entity = EntityId::new();
+        energy_system.allocate_energy(entity, OrderedFloat(0.5)).await.unwrap();
+        
+        assert_eq!(energy_system.apply_elapsed_decay().await.unwrap(), 0.0);
+        assert_eq!(energy_system.get_entity_energy(entity), OrderedFloat(0.5));
+        
+        // 1% per second for ten seconds
+        clock.advance(std::time::Duration::from_secs(10));
+        assert_eq!(energy_system.apply_elapsed_decay().await.unwrap(), 10.0);
+        assert!((energy_system.get_entity_energy(entity).0 - 0.4).abs() < 1e-9);
+        
+        assert_eq!(energy_system.apply_elapsed_decay().await.unwrap(), 0.0);
+        assert!((energy_system.get_entity_energy(entity).0 - 0.4).abs() < 1e-9);
+    }
+    
+    #[tokio::test]
+    async fn test_stale_patterns_of_departed_entities_are_pruned() {
+        let clock = crate::MockClock::new();
+        let mut energy_system = EnergyConservation::with_clock(clock.shared());
+        let active = EntityId::new();
+        let transients: Vec<EntityId> = (0..20).map(|_| EntityId::new()).collect();
+        for transient in &transients {
+            energy_system.allocate_energy(*transient, OrderedFloat(0.01)).await.unwrap();
+        }
+        // Small enough that load balancing finds no donor to hand energy back to the transients
+        energy_system.allocate_energy(active, OrderedFloat(0.15)).await.unwrap();
+        for transient in &transients {
+            let handoff = EnergyTransaction {
+                from: Some(*transient),
+                to: active,
+                amount: OrderedFloat(0.01),
+                transaction_id: Uuid::new_v4(),
+                timestamp: Utc::now(),
+            };
+            energy_system.execute_transaction(handoff).await.unwrap();
+        }
+        // Decay empties the transients, but their patterns are still recent
+        energy_system.apply_decay(1.0).await.unwrap();
+        assert_eq!(energy_system.activity_patterns.len(), 21);
+        assert!(!energy_system.flow_analysis.flow_rates.is_empty());
+        
+        clock.advance(std::time::Duration::from_secs(2 * 3600));
+        energy_system.allocate_energy(active, OrderedFloat(0.01)).await.unwrap();
+        energy_system.apply_decay(1.0).await.unwrap();
+        
+        assert_eq!(energy_system.activity_patterns.len(), 1);
+        assert!(energy_system.activity_patterns.contains_key(&active));
+        assert!(energy_system.flow_analysis.flow_rates.is_empty());
+        assert_eq!(energy_system.prune_stale_patterns(std::time::Duration::ZERO), 0);
+    }
+    
+    #[tokio::test]
+    async fn test_transaction_log_and_flows_are_bounded() {
+        let mut energy_system = EnergyConservation::new();
+        energy_system.config.retention.max_transaction_log = 8;
+        energy_system.config.retention.max_flow_rates = 4;
+        let source = EntityId::new();
+        energy_system.allocate_energy(source, OrderedFloat(0.5)).await.unwrap();
+        
+        for _ in 0..30 {
+            let transaction = EnergyTransaction {
+                from: Some(source),
+                to: EntityId::new(),
+                amount: OrderedFloat(0.001),
+                transaction_id: Uuid::new_v4(),
+                timestamp: Utc::now(),
+            };
+            energy_system.execute_transaction(transaction.clone()).await.unwrap();
+            assert!(energy_system.get_transaction_history().len() <= 8);
+            assert_eq!(energy_system.get_transaction_history().last().unwrap().transaction_id, transaction.transaction_id);
+        }
+        assert_eq!(energy_system.flow_analysis.flow_rates.len(), 4);
+    }
+    
+    #[tokio::test]
+    async fn test_gini_coefficient_measures_inequality() {
+        let mut equal = EnergyConservation::new();
+        for _ in 0..4 {
+            equal.allocate_energy(EntityId::new(), OrderedFloat(0.2)).await.unwrap();
+        }
+        assert!(equal.get_state().await.gini_coefficient.0.abs() < 1e-9);
+        
+        // Allocating this unequally would trigger rebalancing, so measure the values directly
+        let concentrated = [0.01, 0.97, 0.01, 0.01].map(OrderedFloat);
+        // Four entities can be at most 0.75 unequal
+        let gini = gini_coefficient(concentrated.into_iter()).0;
+        assert!((gini - 0.72).abs() < 1e-9, "gini {}", gini);
+        assert_eq!(gini_coefficient(std::iter::empty()), OrderedFloat(0.0));
+    }
+    
+    #[tokio::test]
+    async fn test_historical_demand_follows_the_trajectory() {
+        let mut energy_system = EnergyConservation::new();
+        let entity = EntityId::new();
+        for _ in 0..3 {
+            energy_system.allocate_energy(entity, OrderedFloat(0.1)).await.unwrap();
+        }
+        
+        // Held 0.1, 0.2 and then 0.3 across the recorded states
+        assert!((energy_system.get_entity_energy(entity).0 - 0.3).abs() < 1e-9);
+        let historical = energy_system.analyze_historical_demand(entity).await;
+        assert!((historical.0 - 0.2).abs() < 1e-9, "historical demand {}", historical);
+        
+        let pattern_demand = energy_system.activity_patterns[&entity].consumption_rate.0
+            * energy_system.config.predictive_allocation.prediction_horizon;
+        let predicted = energy_system.predict_optimal_allocation(entity).await;
+        assert!((predicted.0 - (pattern_demand * 0.7 + 0.2 * 0.3).min(0.5)).abs() < 1e-9);
+        assert_eq!(energy_system.analyze_historical_demand(EntityId::new()).await, OrderedFloat(0.0));
+    }
+    
+    #[tokio::test]
+    async fn test_transfer_rate_is_limited_per_window() {
+        let clock = crate::MockClock::new();
+        let mut energy_system = EnergyConservation::with_clock(clock.shared());
+        let source = EntityId::new();
+        let peer = EntityId::new();
+        let unlimited = EntityId::new();
+        energy_system.allocate_energy(source, OrderedFloat(0.5)).await.unwrap();
+        energy_system.allocate_energy(unlimited, OrderedFloat(0.3)).await.unwrap();
+        energy_system.limit_transfer_rate(source, None);
+        let transfer_from = |from, to, amount| EnergyTransaction {
+            from: Some(from),
+            to,
+            amount: OrderedFloat(amount),
+            transaction_id: Uuid::new_v4(),
+            timestamp: Utc::now(),
+        };
+        
+        let transfer = |to, amount| transfer_from(source, to, amount);
+        energy_system.execute_transaction(transfer(peer, 0.04)).await.unwrap();
+        clock.advance(std::time::Duration::from_millis(500));
+        energy_system.execute_transaction(transfer(peer, 0.05)).await.unwrap();
+        
+        // 0.09 already moved this second, so another 0.03 would make 0.12 per second
+        let error = energy_system.execute_transaction(transfer(peer, 0.03)).await.unwrap_err();
+        match error {
+            EnergyError::RateLimitExceeded { rate, limit } => {
+                assert!((rate.0 - 0.12).abs() < 1e-9, "rate {}", rate);
+                assert_eq!(limit, OrderedFloat(0.1));
+            }
+            other => panic!("expected a rate limit error, got {:?}", other),
+        }
+        assert!((energy_system.get_entity_energy(source).0 - 0.41).abs() < 1e-9);
+        
+        // Entities without a limit are not held back
+        energy_system.execute_transaction(transfer_from(unlimited, peer, 0.3)).await.unwrap();
+        
+        // Only the 0.05 remains in the window once the first transfer is a second old
+        clock.advance(std::time::Duration::from_millis(500));
+        energy_system.execute_transaction(transfer(peer, 0.05)).await.unwrap();
+        clock.advance(std::time::Duration::from_secs(1));
+        energy_system.execute_transaction(transfer(peer, 0.1)).await.unwrap();
+        assert!((energy_system.get_entity_energy(peer).0 - 0.54).abs() < 1e-9);
+        
+        energy_system.lift_transfer_rate_limit(source);
+        energy_system.execute_transaction(transfer(peer, 0.2)).await.unwrap();
+    }
+    
     #[tokio::test]
     async fn test_energy_transfer() {
         let mut energy_system = EnergyConservation::new();
@@ -888,6 +1437,61 @@ mod tests {
         assert!(diff < 0.01, "energy_decay: expected {}, actual {}, diff {}", expected, actual, diff);
     }
     
+    #[tokio::test]
+    async fn test_dissipation_leaves_the_pool() {
+        let mut energy_system = EnergyConservation::new();
+        let entity = EntityId::new();
+        energy_system.allocate_energy(entity, OrderedFloat(0.5)).await.unwrap();
+        
+        energy_system.dissipate_energy(entity, OrderedFloat(0.2)).await.unwrap();
+        
+        let state = energy_system.get_state().await;
+        assert!((energy_system.get_entity_energy(entity).0 - 0.3).abs() < 1e-9);
+        assert!((state.dissipated_energy.0 - 0.2).abs() < 1e-9);
+        assert!((state.free_energy.0 - 0.5).abs() < 1e-9);
+        assert!((state.allocated_energy + state.free_energy + state.dissipated_energy - state.total_energy).abs() < 1e-9);
+        
+        // Dissipated energy is not free to allocate again
+        assert!(energy_system.allocate_energy(EntityId::new(), OrderedFloat(0.6)).await.is_err());
+        assert!(matches!(
+            energy_system.dissipate_energy(entity, OrderedFloat(0.4)).await,
+            Err(EnergyError::InsufficientEnergy { .. })
+        ));
+        assert!(matches!(
+            energy_system.dissipate_energy(EntityId::new(), OrderedFloat(0.1)).await,
+            Err(EnergyError::EntityNotFound { .. })
+        ));
+    }
+    
+    #[tokio::test]
+    async fn test_fixed_decay_is_exact() {
+        let mut energy_system = EnergyConservation::new();
+        energy_system.config.adaptive_decay_enabled = false;
+        let entity = EntityId::new();
+        energy_system.allocate_energy(entity, OrderedFloat(0.5)).await.unwrap();
+        
+        // 1% of the system's energy per second, whatever the entity's activity
+        energy_system.apply_decay(1.0).await.unwrap();
+        
+        assert_eq!(energy_system.get_entity_energy(entity), OrderedFloat(0.49));
+    }
+    
+    #[tokio::test]
+    async fn test_energy_release() {
+        let mut energy_system = EnergyConservation::new();
+        let entity = EntityId::new();
+        
+        energy_system.allocate_energy(entity, OrderedFloat(0.4)).await.unwrap();
+        let released = energy_system.release_energy(entity).await.unwrap();
+        
+        assert_eq!(released, OrderedFloat(0.4));
+        assert_eq!(energy_system.get_entity_energy(entity), OrderedFloat(0.0));
+        assert_eq!(energy_system.get_state().await.free_energy, OrderedFloat(1.0));
+        
+        // Releasing again is a no-op
+        assert_eq!(energy_system.release_energy(entity).await.unwrap(), OrderedFloat(0.0));
+    }
+    
     #[tokio::test]
     async fn test_adaptive_allocation() {
         let mut energy_system = EnergyConservation::new();
@@ -950,6 +1554,39 @@ mod tests {
         assert!(!energy_system.flow_analysis.flow_rates.is_empty());
     }
     
+    #[tokio::test]
+    async fn test_flow_graph_exports_flows_between_entities() {
+        let mut energy_system = EnergyConservation::new();
+        let (a, b, c) = (EntityId::new(), EntityId::new(), EntityId::new());
+        energy_system.allocate_energy(a, OrderedFloat(0.4)).await.unwrap();
+        for (from, to, amount) in [(a, b, 0.1), (b, c, 0.05), (a, c, 0.1), (a, c, 0.1)] {
+            energy_system.execute_transaction(EnergyTransaction {
+                from: Some(from),
+                to,
+                amount: OrderedFloat(amount),
+                transaction_id: Uuid::new_v4(),
+                timestamp: Utc::now(),
+            }).await.unwrap();
+        }
+        energy_system.flow_analysis.bottlenecks = vec![c];
+        
+        let graph = energy_system.export_flow_graph();
+        
+        assert_eq!(graph.nodes.len(), 3);
+        for entity in [a, b, c] {
+            let node = graph.node(entity).unwrap();
+            assert_eq!(node.energy, energy_system.get_entity_energy(entity));
+            assert_eq!(node.bottleneck, entity == c);
+        }
+        assert_eq!(graph.edges.len(), 3);
+        assert_eq!(graph.edge(a, b).unwrap().rate, OrderedFloat(0.1) * OrderedFloat(0.1));
+        assert_eq!(graph.edge(b, c).unwrap().rate, OrderedFloat(0.05) * OrderedFloat(0.1));
+        // A repeated flow's rate is a moving average of its transfers
+        let once = OrderedFloat(0.1) * OrderedFloat(0.1);
+        assert_eq!(graph.edge(a, c).unwrap().rate, once * OrderedFloat(0.9) + once);
+        assert!(graph.edge(c, a).is_none());
+    }
+    
     #[tokio::test]
     async fn test_load_balancing() {
         let mut energy_system = EnergyConservation::new();
@@ -968,6 +1605,122 @@ mod tests {
         assert!(energy_system.flow_analysis.efficiency_metrics.system_efficiency > OrderedFloat(0.0));
     }
     
+    fn transfer(from: EntityId, to: EntityId, amount: f64) -> EnergyTransaction {
+        EnergyTransaction {
+            from: Some(from),
+            to,
+            amount: OrderedFloat(amount),
+            transaction_id: Uuid::new_v4(),
+            timestamp: Utc::now(),
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_rebalancing_waits_for_the_interval() {
+        let clock = crate::MockClock::new();
+        let mut energy_system = EnergyConservation::with_clock(clock.shared());
+        let (rich, poor) = (EntityId::new(), EntityId::new());
+        energy_system.allocate_energy(rich, OrderedFloat(0.8)).await.unwrap();
+        energy_system.allocate_energy(poor, OrderedFloat(0.1)).await.unwrap();
+        let first_rebalance = energy_system.last_rebalance.expect("imbalance should trigger a rebalance");
+        assert!((energy_system.get_entity_energy(rich).0 - 0.7).abs() < 1e-9);
+        
+        // Even a fresh imbalance right after a rebalance is left alone
+        energy_system.execute_transaction(transfer(rich, poor, 0.25)).await.unwrap();
+        energy_system.allocate_energy(poor, OrderedFloat(0.01)).await.unwrap();
+        energy_system.execute_transaction(transfer(poor, rich, 0.4)).await.unwrap();
+        energy_system.allocate_energy(poor, OrderedFloat(0.01)).await.unwrap();
+        assert_eq!(energy_system.last_rebalance, Some(first_rebalance));
+        assert!((energy_system.get_entity_energy(rich).0 - 0.85).abs() < 1e-9);
+        
+        clock.advance(Duration::from_secs_f64(energy_system.config.load_balancing.rebalance_interval));
+        energy_system.allocate_energy(poor, OrderedFloat(0.01)).await.unwrap();
+        assert!(energy_system.last_rebalance > Some(first_rebalance));
+        assert!((energy_system.get_entity_energy(rich).0 - 0.75).abs() < 1e-9);
+    }
+    
+    #[tokio::test]
+    async fn test_unfinished_rebalance_is_followed_up() {
+        let clock = crate::MockClock::new();
+        let mut energy_system = EnergyConservation::with_clock(clock.shared());
+        energy_system.config.load_balancing.rebalance_interval = 1.0;
+        let (rich, poor) = (EntityId::new(), EntityId::new());
+        energy_system.allocate_energy(rich, OrderedFloat(0.8)).await.unwrap();
+        energy_system.allocate_energy(poor, OrderedFloat(0.05)).await.unwrap();
+        let first_rebalance = energy_system.last_rebalance.unwrap();
+        assert!((energy_system.get_entity_energy(rich).0 - 0.7).abs() < 1e-9);
+        
+        // The capped rebalance left the system above the high threshold,
+        // so it carries on once the interval has passed
+        clock.advance(Duration::from_secs(2));
+        energy_system.allocate_energy(poor, OrderedFloat(0.01)).await.unwrap();
+        assert!(energy_system.last_rebalance > Some(first_rebalance));
+        assert!((energy_system.get_entity_energy(rich).0 - 0.6).abs() < 1e-9);
+    }
+    
+    #[tokio::test]
+    async fn test_priority_entities_are_left_out_of_rebalancing() {
+        let clock = crate::MockClock::new();
+        let mut energy_system = EnergyConservation::with_clock(clock.shared());
+        let (rich, reserve, poor) = (EntityId::new(), EntityId::new(), EntityId::new());
+        energy_system.exempt_from_load_balancing(reserve);
+        energy_system.allocate_energy(reserve, OrderedFloat(0.05)).await.unwrap();
+        energy_system.allocate_energy(rich, OrderedFloat(0.8)).await.unwrap();
+        assert_eq!(energy_system.get_entity_energy(reserve), OrderedFloat(0.05));
+        assert_eq!(energy_system.get_entity_energy(rich), OrderedFloat(0.8));
+        
+        // Once rebalancing may run again, only the poor entity is fed
+        clock.advance(Duration::from_secs(600));
+        energy_system.allocate_energy(poor, OrderedFloat(0.05)).await.unwrap();
+        assert!((energy_system.get_entity_energy(poor).0 - 0.15).abs() < 1e-9);
+        assert_eq!(energy_system.get_entity_energy(reserve), OrderedFloat(0.05));
+    }
+    
+    #[tokio::test]
+    async fn test_rebalancing_hysteresis_damps_oscillation() {
+        let clock = crate::MockClock::new();
+        let mut energy_system = EnergyConservation::with_clock(clock.shared());
+        energy_system.config.load_balancing.rebalance_interval = 1.0;
+        let (rich, poor) = (EntityId::new(), EntityId::new());
+        energy_system.allocate_energy(rich, OrderedFloat(0.8)).await.unwrap();
+        energy_system.allocate_energy(poor, OrderedFloat(0.05)).await.unwrap();
+        clock.advance(Duration::from_secs(2));
+        energy_system.allocate_energy(poor, OrderedFloat(0.01)).await.unwrap();
+        let settled = energy_system.last_rebalance.unwrap();
+        
+        // Imbalance settles between the thresholds, then climbs back over the
+        // high one without ever dropping below the low one
+        energy_system.allocate_energy(poor, OrderedFloat(0.01)).await.unwrap();
+        clock.advance(Duration::from_secs(2));
+        energy_system.execute_transaction(transfer(poor, rich, 0.12)).await.unwrap();
+        energy_system.allocate_energy(poor, OrderedFloat(0.01)).await.unwrap();
+        assert_eq!(energy_system.last_rebalance, Some(settled));
+        assert!((energy_system.get_entity_energy(rich).0 - 0.72).abs() < 1e-9);
+        
+        // Once it has come back below the low threshold it is rebalanced again
+        energy_system.execute_transaction(transfer(rich, poor, 0.25)).await.unwrap();
+        energy_system.allocate_energy(poor, OrderedFloat(0.01)).await.unwrap();
+        assert_eq!(energy_system.last_rebalance, Some(settled));
+        
+        energy_system.execute_transaction(transfer(poor, rich, 0.4)).await.unwrap();
+        energy_system.allocate_energy(poor, OrderedFloat(0.01)).await.unwrap();
+        assert!(energy_system.last_rebalance > Some(settled));
+        assert!((energy_system.get_entity_energy(rich).0 - 0.77).abs() < 1e-9);
+    }
+    
+    #[test]
+    fn test_load_balancing_config_is_validated_when_deserialized() {
+        let legacy = "rebalance_interval: 300.0\nmax_transfer_per_rebalance: 0.5\nimbalance_threshold: 0.3\npriority_entities: []\n";
+        let config: LoadBalancingConfig = serde_yaml::from_str(legacy).unwrap();
+        assert_eq!(config.imbalance_high, OrderedFloat(0.3));
+        assert_eq!(config.imbalance_low, OrderedFloat(0.3));
+        
+        let inverted = "rebalance_interval: 300.0\nmax_transfer_per_rebalance: 0.5\nimbalance_high: 0.1\nimbalance_low: 0.2\npriority_entities: []\n";
+        let error = serde_yaml::from_str::<LoadBalancingConfig>(inverted).unwrap_err();
+        assert!(error.to_string().contains("imbalance_low"), "{}", error);
+        assert!(EnergyConfig::default().load_balancing.validate().is_ok());
+    }
+    
     #[tokio::test]
     async fn test_adaptive_decay() {
         let mut energy_system = EnergyConservation::new();
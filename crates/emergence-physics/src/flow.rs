@@ -0,0 +1,100 @@
+//! Exportable view of the energy flowing between entities.
+//!
+//! [`FlowGraph`] is a snapshot of the flow analysis kept by
+//! [`EnergyConservation`](crate::EnergyConservation): entities are nodes
+//! weighted by the energy they hold, and the smoothed transfer rates between
+//! them are directed edges. It renders to Graphviz DOT for quick inspection
+//! and to JSON for web dashboards.
+
+use std::fmt::Write;
+
+use anyhow::Result;
+use ordered_float::OrderedFloat;
+use serde::{Deserialize, Serialize};
+
+use crate::EntityId;
+
+/// Entities and the energy flowing between them
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FlowGraph {
+    /// Every entity holding energy or taking part in a flow, ordered by id
+    pub nodes: Vec<FlowNode>,
+    /// Flows between entities, ordered by source then destination
+    pub edges: Vec<FlowEdge>,
+}
+
+/// An entity in the flow graph
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FlowNode {
+    pub entity: EntityId,
+    /// Energy currently allocated to the entity
+    pub energy: OrderedFloat<f64>,
+    /// Whether flow analysis found the entity demanding more than it is supplied
+    pub bottleneck: bool,
+}
+
+/// Energy moving from one entity to another
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FlowEdge {
+    pub from: EntityId,
+    pub to: EntityId,
+    /// Moving average of the energy transferred per transaction
+    pub rate: OrderedFloat<f64>,
+}
+
+impl FlowGraph {
+    /// The node for `entity`, if it is in the graph
+    pub fn node(&self, entity: EntityId) -> Option<&FlowNode> {
+        self.nodes.iter().find(|node| node.entity == entity)
+    }
+
+    /// The edge from `from` to `to`, if energy flows that way
+    pub fn edge(&self, from: EntityId, to: EntityId) -> Option<&FlowEdge> {
+        self.edges.iter().find(|edge| edge.from == from && edge.to == to)
+    }
+
+    /// Render as a Graphviz digraph, with bottlenecks drawn in red
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph energy_flow {\n");
+        for node in &self.nodes {
+            let style = if node.bottleneck { ", color=red, style=bold" } else { "" };
+            let _ = writeln!(dot, "    \"{}\" [label=\"{}\\n{:.4}\"{}];", node.entity.0, node.entity.0, node.energy, style);
+        }
+        for edge in &self.edges {
+            let _ = writeln!(dot, "    \"{}\" -> \"{}\" [label=\"{:.4}\"];", edge.from.0, edge.to.0, edge.rate);
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Serialize as JSON with `nodes` and `edges` arrays
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flow_graph_renders_to_dot_and_json() {
+        let (source, sink) = (EntityId::new(), EntityId::new());
+        let graph = FlowGraph {
+            nodes: vec![
+                FlowNode { entity: source, energy: OrderedFloat(0.5), bottleneck: false },
+                FlowNode { entity: sink, energy: OrderedFloat(0.1), bottleneck: true },
+            ],
+            edges: vec![FlowEdge { from: source, to: sink, rate: OrderedFloat(0.02) }],
+        };
+
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("digraph energy_flow {"));
+        assert!(dot.contains(&format!("\"{}\" -> \"{}\" [label=\"0.0200\"];", source.0, sink.0)));
+        assert!(dot.contains(&format!("\"{}\" [label=\"{}\\n0.1000\", color=red", sink.0, sink.0)));
+
+        let json = graph.to_json().unwrap();
+        let parsed: FlowGraph = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, graph);
+    }
+}
@@ -11,30 +11,45 @@
 //! intelligence system, ensuring that all agent behaviors operate within safe and
 //! predictable boundaries while allowing maximum creative freedom within those constraints.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 use std::time::{Duration, Instant, SystemTime};
 
 use anyhow::{Context, Result};
+use async_trait::async_trait;
 use blake3::Hash;
 use chrono::{DateTime, Utc};
 use ordered_float::OrderedFloat;
 use serde::{Deserialize, Serialize};
-use tokio::sync::{RwLock, Semaphore};
+use tokio::sync::{broadcast, RwLock, Semaphore};
 use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
+pub mod clock;
 pub mod energy;
+pub mod flow;
 pub mod causality;
 pub mod security;
 pub mod resources;
 pub mod validation;
+#[cfg(any(test, feature = "test-util"))]
+pub mod mock;
 
+pub use clock::{Clock, MockClock, SharedClock, SystemClock};
 pub use energy::{EnergyConservation, EnergyState, EnergyTransaction};
-pub use causality::{CausalityEngine, CausalChain, EventOrdering};
-pub use security::{SecurityBoundaries, CapabilityGate, SecurityViolation};
-pub use resources::{ResourceManager, ResourceAllocation, ResourceType};
-pub use validation::{PhysicsValidator, ValidationError, ValidationResult};
+pub use flow::{FlowEdge, FlowGraph, FlowNode};
+pub use causality::{CausalityEngine, CausalChain, CausalEvent, EventOrdering};
+pub use security::{SecurityBoundaries, CapabilityDependencies, CapabilityGate, CapabilityMergePolicy, SecurityViolation};
+pub use resources::{ResourceManager, ResourceAllocation, ResourceReallocation, ResourceType};
+pub use validation::{PhysicsValidator, ValidationError, ValidationResult, CURRENT_SCHEMA_VERSION};
+#[cfg(any(test, feature = "test-util"))]
+pub use mock::MockPhysicsEngine;
+
+/// Longest time limit any operation may request
+const MAX_OPERATION_TIME: Duration = Duration::from_secs(300); // 5 minutes
+
+/// Number of violation events buffered for slow subscribers
+const VIOLATION_CHANNEL_CAPACITY: usize = 256;
 
 /// Core physics engine that enforces immutable laws for the emergent system
 #[derive(Debug)]
@@ -49,6 +64,12 @@ pub struct PhysicsEngine {
     resource_manager: Arc<ResourceManager>,
     /// Schema validation and physics law compliance
     validator: Arc<PhysicsValidator>,
+    /// Counters for executed operations and detected violations
+    operation_stats: Arc<RwLock<OperationStats>>,
+    /// Broadcast channel notifying subscribers of violations
+    violation_events: broadcast::Sender<PhysicsViolationEvent>,
+    /// Operations currently running under a time limit
+    running_operations: Arc<std::sync::Mutex<HashMap<Uuid, RunningOperation>>>,
     /// Engine start time for relative time calculations
     genesis_time: Instant,
     /// Unique engine instance identifier
@@ -119,6 +140,13 @@ pub enum PhysicsOperation {
         to: EntityId,
         amount: OrderedFloat<f64>,
     },
+    /// Lose energy from an entity to work or heat, removing it from the pool
+    DissipateEnergy {
+        entity: EntityId,
+        amount: OrderedFloat<f64>,
+    },
+    /// Execute several operations atomically; any failure rolls back all effects
+    Batch(Vec<PhysicsOperation>),
 }
 
 /// Result of a physics operation
@@ -167,11 +195,16 @@ pub enum PhysicsViolation {
 impl PhysicsEngine {
     /// Create a new physics engine with default laws
     pub async fn new() -> Result<Self> {
+        Self::with_clock(SystemClock::shared()).await
+    }
+    
+    /// Create a physics engine whose time-dependent laws read the time from `clock`
+    pub async fn with_clock(clock: SharedClock) -> Result<Self> {
         info!("Initializing EMERGENCE physics engine");
         
-        let energy_laws = Arc::new(RwLock::new(EnergyConservation::new()));
+        let energy_laws = Arc::new(RwLock::new(EnergyConservation::with_clock(clock.clone())));
         let causality_engine = Arc::new(CausalityEngine::new());
-        let security_boundaries = Arc::new(SecurityBoundaries::new());
+        let security_boundaries = Arc::new(SecurityBoundaries::with_clock(clock));
         let resource_manager = Arc::new(ResourceManager::new().await?);
         let validator = Arc::new(PhysicsValidator::new());
         
@@ -186,6 +219,9 @@ impl PhysicsEngine {
             security_boundaries,
             resource_manager,
             validator,
+            operation_stats: Arc::new(RwLock::new(OperationStats::default())),
+            violation_events: broadcast::channel(VIOLATION_CHANNEL_CAPACITY).0,
+            running_operations: Arc::new(std::sync::Mutex::new(HashMap::new())),
             genesis_time,
             instance_id,
         })
@@ -202,7 +238,9 @@ impl PhysicsEngine {
         let schema: serde_yaml::Value = serde_yaml::from_str(&schema_content)
             .context("Failed to parse physics schema YAML")?;
         
-        // Validate schema compliance
+        // Upgrade older schemas in memory, then validate schema compliance
+        let schema = self.validator.upgrade_schema(schema)
+            .with_context(|| format!("Failed to upgrade physics schema {}", schema_path))?;
         self.validator.validate_physics_schema(&schema).await?;
         
         // Apply energy conservation rules
@@ -230,14 +268,28 @@ impl PhysicsEngine {
         Ok(())
     }
     
+    /// Register the migration that upgrades physics schemas from `from` to `from + 1`
+    pub fn register_schema_migration<F>(&self, from: u32, migration: F)
+    where
+        F: Fn(serde_yaml::Value) -> Result<serde_yaml::Value> + Send + Sync + 'static,
+    {
+        self.validator.register_migration(from, migration);
+    }
+    
     /// Execute a physics operation with full validation and enforcement
     pub async fn execute_operation(&self, operation: PhysicsOperation) -> Result<PhysicsResult> {
         let start_time = Instant::now();
         
         debug!("Executing physics operation: {:?}", operation);
         
+        self.operation_stats.write().await.operations_executed += 1;
+        let entity = operation.entity();
+        
         // Pre-validation
-        self.validator.validate_operation(&operation).await?;
+        if let Err(e) = self.pre_validate(&operation).await {
+            self.emit_violation(entity, PhysicsViolationKind::InvalidOperation, e.to_string());
+            return Err(e);
+        }
         
         let result = match operation {
             PhysicsOperation::ValidateCapability { entity, capability } => {
@@ -255,6 +307,13 @@ impl PhysicsEngine {
             PhysicsOperation::TransferEnergy { from, to, amount } => {
                 self.transfer_energy(from, to, amount).await
             }
+            PhysicsOperation::DissipateEnergy { entity, amount } => {
+                let mut energy_laws = self.energy_laws.write().await;
+                Self::dissipate_energy_within(&mut energy_laws, entity, amount).await
+            }
+            PhysicsOperation::Batch(operations) => {
+                self.execute_batch(operations).await
+            }
         };
         
         let duration = start_time.elapsed();
@@ -266,11 +325,86 @@ impl PhysicsEngine {
             }
             Err(violation) => {
                 warn!("Physics violation detected: {} (duration: {:?})", violation, duration);
+                self.operation_stats.write().await.record_violation(&violation);
+                self.emit_violation(entity, violation.kind(), violation.to_string());
                 Err(violation.into())
             }
         }
     }
     
+    /// Run a future on behalf of an entity, failing if it exceeds `limit`
+    ///
+    /// The operation is listed in the engine state while it runs. On overrun
+    /// the future is dropped and a `TimeLimit` violation reports the measured
+    /// elapsed time.
+    pub async fn run_with_limit<F, T>(
+        &self,
+        entity: EntityId,
+        op_name: &str,
+        limit: Duration,
+        fut: F,
+    ) -> Result<T, PhysicsViolation>
+    where
+        F: std::future::Future<Output = T>,
+    {
+        if limit > MAX_OPERATION_TIME {
+            return Err(PhysicsViolation::TimeLimit {
+                operation: op_name.to_string(),
+                actual: limit,
+                limit: MAX_OPERATION_TIME,
+            });
+        }
+        
+        let _guard = RunningOperationGuard::register(&self.running_operations, RunningOperation {
+            entity,
+            operation: op_name.to_string(),
+            started_at: Utc::now(),
+            limit,
+        });
+        
+        let start = tokio::time::Instant::now();
+        match tokio::time::timeout(limit, fut).await {
+            Ok(value) => Ok(value),
+            Err(_) => {
+                let violation = PhysicsViolation::TimeLimit {
+                    operation: op_name.to_string(),
+                    actual: start.elapsed(),
+                    limit,
+                };
+                warn!("Physics violation detected: {}", violation);
+                self.operation_stats.write().await.record_violation(&violation);
+                self.emit_violation(Some(entity), violation.kind(), violation.to_string());
+                Err(violation)
+            }
+        }
+    }
+    
+    /// Subscribe to violations raised by any operation on this engine
+    pub fn subscribe_violations(&self) -> broadcast::Receiver<PhysicsViolationEvent> {
+        self.violation_events.subscribe()
+    }
+    
+    /// Validate an operation, and every operation inside a batch, before execution
+    async fn pre_validate(&self, operation: &PhysicsOperation) -> Result<()> {
+        self.validator.validate_operation(operation).await?;
+        if let PhysicsOperation::Batch(operations) = operation {
+            for sub_operation in operations {
+                self.validator.validate_operation(sub_operation).await?;
+            }
+        }
+        Ok(())
+    }
+    
+    /// Notify violation subscribers; having no subscribers is not an error
+    fn emit_violation(&self, entity: Option<EntityId>, kind: PhysicsViolationKind, message: String) {
+        let _ = self.violation_events.send(PhysicsViolationEvent {
+            entity,
+            kind,
+            timestamp: Utc::now(),
+            message,
+        });
+    }
+    
     /// Validate that an entity possesses a specific capability
     async fn validate_capability(&self, entity: EntityId, capability: Capability) -> Result<PhysicsResult, PhysicsViolation> {
         self.security_boundaries.validate_capability(entity, &capability).await
@@ -294,11 +428,7 @@ impl PhysicsEngine {
             allocated_at: Utc::now(),
         };
         
-        self.resource_manager.allocate(allocation).await
-            .map_err(|e| PhysicsViolation::ResourceLimit { 
-                resource: format!("{:?}", resource), 
-                reason: e.to_string() 
-            })?;
+        self.resource_manager.allocate(allocation).await?;
         
         Ok(PhysicsResult {
             success: true,
@@ -311,10 +441,7 @@ impl PhysicsEngine {
     
     /// Enforce time limits on operations
     async fn enforce_time_limit(&self, entity: EntityId, operation: String, limit: Duration) -> Result<PhysicsResult, PhysicsViolation> {
-        // In a real implementation, this would track ongoing operations
-        // For now, we just validate that the limit is reasonable
-        const MAX_OPERATION_TIME: Duration = Duration::from_secs(300); // 5 minutes
-        
+        // Validates the requested limit; use `run_with_limit` to time real work
         if limit > MAX_OPERATION_TIME {
             return Err(PhysicsViolation::TimeLimit {
                 operation: operation.clone(),
@@ -349,7 +476,16 @@ impl PhysicsEngine {
     /// Transfer energy between entities with conservation enforcement
     async fn transfer_energy(&self, from: EntityId, to: EntityId, amount: OrderedFloat<f64>) -> Result<PhysicsResult, PhysicsViolation> {
         let mut energy_laws = self.energy_laws.write().await;
-        
+        Self::transfer_energy_within(&mut energy_laws, from, to, amount).await
+    }
+    
+    /// Transfer energy within an already locked conservation state
+    async fn transfer_energy_within(
+        energy_laws: &mut EnergyConservation,
+        from: EntityId,
+        to: EntityId,
+        amount: OrderedFloat<f64>,
+    ) -> Result<PhysicsResult, PhysicsViolation> {
         let transaction = EnergyTransaction {
             from: Some(from),
             to,
@@ -370,12 +506,210 @@ impl PhysicsEngine {
         })
     }
     
+    /// Dissipate an entity's energy within an already locked conservation state
+    async fn dissipate_energy_within(
+        energy_laws: &mut EnergyConservation,
+        entity: EntityId,
+        amount: OrderedFloat<f64>,
+    ) -> Result<PhysicsResult, PhysicsViolation> {
+        energy_laws.dissipate_energy(entity, amount).await
+            .map_err(|e| PhysicsViolation::EnergyConservation { reason: e.to_string() })?;
+        
+        Ok(PhysicsResult {
+            success: true,
+            message: format!("Dissipated {} energy from {}", amount, entity.0),
+            duration: Duration::from_millis(1),
+            costs: HashMap::new(),
+            new_state: None,
+        })
+    }
+    
+    /// Execute a batch of operations as a single unit
+    ///
+    /// The energy lock is held for the whole batch and transfers apply to a
+    /// working copy that only replaces the live state once every operation
+    /// succeeds. Every other effect is undone if a later operation fails:
+    /// resource allocations are returned, recorded causal events are removed
+    /// and validated capabilities get back their previous last use.
+    async fn execute_batch(&self, operations: Vec<PhysicsOperation>) -> Result<PhysicsResult, PhysicsViolation> {
+        let operations = Self::flatten_batch(operations);
+        let count = operations.len();
+        
+        let mut energy_laws = self.energy_laws.write().await;
+        let mut working_energy = energy_laws.clone();
+        let mut undo_log = Vec::new();
+        let mut duration = Duration::ZERO;
+        let mut costs: HashMap<String, OrderedFloat<f64>> = HashMap::new();
+        
+        for operation in operations {
+            let result = match operation {
+                PhysicsOperation::ValidateCapability { entity, capability } => {
+                    // Recorded first: validation can touch the grant and still fail
+                    if let Some(last_used) = self.security_boundaries.last_used(entity, &capability.name).await {
+                        undo_log.push(BatchUndo::CapabilityUse { entity, name: capability.name.clone(), last_used });
+                    }
+                    self.validate_capability(entity, capability).await
+                }
+                PhysicsOperation::AllocateResource { entity, resource, amount } => {
+                    let resource_type = ResourceType::from(&resource);
+                    let result = self.allocate_resource(entity, resource, amount).await;
+                    if result.is_ok() {
+                        undo_log.push(BatchUndo::Allocation { entity, resource_type, amount });
+                    }
+                    result
+                }
+                PhysicsOperation::EnforceTimeLimit { entity, operation, limit } => {
+                    self.enforce_time_limit(entity, operation, limit).await
+                }
+                PhysicsOperation::ValidateCausality { event_id, parent_events, timestamp } => {
+                    let previous = self.causality_engine.event(event_id).await;
+                    undo_log.push(BatchUndo::CausalEvent { event_id, previous });
+                    self.validate_causality(event_id, parent_events, timestamp).await
+                }
+                PhysicsOperation::TransferEnergy { from, to, amount } => {
+                    Self::transfer_energy_within(&mut working_energy, from, to, amount).await
+                }
+                PhysicsOperation::DissipateEnergy { entity, amount } => {
+                    Self::dissipate_energy_within(&mut working_energy, entity, amount).await
+                }
+                PhysicsOperation::Batch(_) => unreachable!("nested batches are flattened"),
+            };
+            
+            match result {
+                Ok(step) => {
+                    duration += step.duration;
+                    for (name, cost) in step.costs {
+                        *costs.entry(name).or_insert(OrderedFloat(0.0)) += cost;
+                    }
+                }
+                Err(violation) => {
+                    for undo in undo_log.into_iter().rev() {
+                        self.undo_batch_effect(undo).await;
+                    }
+                    debug!("Batch rolled back after violation: {}", violation);
+                    return Err(violation);
+                }
+            }
+        }
+        
+        *energy_laws = working_energy;
+        
+        Ok(PhysicsResult {
+            success: true,
+            message: format!("Executed {} operations atomically", count),
+            duration,
+            costs,
+            new_state: None,
+        })
+    }
+    
+    /// Reverse one effect of a batch that failed part way
+    async fn undo_batch_effect(&self, undo: BatchUndo) {
+        match undo {
+            BatchUndo::Allocation { entity, resource_type, amount } => {
+                self.resource_manager.deallocate(entity, &resource_type, amount).await;
+            }
+            BatchUndo::CausalEvent { event_id, previous } => {
+                self.causality_engine.unrecord_event(event_id, previous).await;
+            }
+            BatchUndo::CapabilityUse { entity, name, last_used } => {
+                self.security_boundaries.restore_last_used(entity, &name, last_used).await;
+            }
+        }
+    }
+    
+    /// Expand nested batches into a single ordered list of operations
+    fn flatten_batch(operations: Vec<PhysicsOperation>) -> Vec<PhysicsOperation> {
+        let mut flat = Vec::with_capacity(operations.len());
+        for operation in operations {
+            match operation {
+                PhysicsOperation::Batch(nested) => flat.extend(Self::flatten_batch(nested)),
+                other => flat.push(other),
+            }
+        }
+        flat
+    }
+    
     /// Allocate energy to an entity from the system (for initialization/testing)
     pub async fn allocate_energy_to_entity(&self, entity: EntityId, amount: OrderedFloat<f64>) -> Result<()> {
         let mut energy_laws = self.energy_laws.write().await;
         energy_laws.allocate_energy(entity, amount).await.map_err(|e| anyhow::anyhow!(e))
     }
     
+    /// Limit how fast an entity can transfer energy out, `None` meaning the configured `max_transfer_rate`
+    pub async fn limit_transfer_rate(&self, entity: EntityId, limit: Option<OrderedFloat<f64>>) {
+        self.energy_laws.write().await.limit_transfer_rate(entity, limit);
+    }
+    
+    /// Lift an entity's transfer rate limit
+    pub async fn lift_transfer_rate_limit(&self, entity: EntityId) {
+        self.energy_laws.write().await.lift_transfer_rate_limit(entity);
+    }
+    
+    /// Keep load balancing from moving energy into or out of an entity
+    pub async fn exempt_from_load_balancing(&self, entity: EntityId) {
+        self.energy_laws.write().await.exempt_from_load_balancing(entity);
+    }
+    
+    /// Energy currently allocated to an entity (zero if it holds none)
+    pub async fn entity_energy(&self, entity: EntityId) -> OrderedFloat<f64> {
+        self.energy_laws.read().await.get_entity_energy(entity)
+    }
+    
+    /// Decay every entity's energy by `elapsed` worth of the configured decay rate
+    pub async fn apply_energy_decay(&self, elapsed: Duration) -> Result<()> {
+        self.energy_laws.write().await.apply_decay(elapsed.as_secs_f64()).await?;
+        Ok(())
+    }
+    
+    /// Snapshot of the energy flowing between entities
+    pub async fn energy_flow_graph(&self) -> FlowGraph {
+        self.energy_laws.read().await.export_flow_graph()
+    }
+    
+    /// Release an entity's energy back to the system, returning the amount reclaimed
+    pub async fn release_energy_from_entity(&self, entity: EntityId) -> Result<OrderedFloat<f64>> {
+        let mut energy_laws = self.energy_laws.write().await;
+        energy_laws.release_energy(entity).await.map_err(|e| anyhow::anyhow!(e))
+    }
+    
+    /// Release every resource allocation held by an entity, returning how many were freed
+    pub async fn release_resources_from_entity(&self, entity: EntityId) -> usize {
+        self.resource_manager.release_all(entity).await
+    }
+    
+    /// Register the proof an entity must present for a capability
+    pub async fn register_capability_proof(&self, entity: EntityId, name: &str, proof: Hash) {
+        self.security_boundaries.register_capability_proof(entity, name, proof).await;
+    }
+    
+    /// Grant a capability to an entity, optionally expiring after `ttl`
+    ///
+    /// Fails when the entity lacks one of the capability's declared prerequisites.
+    pub async fn grant_capability(&self, entity: EntityId, capability: &Capability, ttl: Option<Duration>) -> Result<()> {
+        self.security_boundaries.grant_capability(entity, capability, ttl).await
+    }
+    
+    /// Current strength of an entity's granted capability after idle decay
+    pub async fn effective_capability_strength(&self, entity: EntityId, name: &str) -> Option<f64> {
+        self.security_boundaries.effective_strength(entity, name).await
+    }
+    
+    /// Current strengths of every capability an entity holds
+    pub async fn effective_capabilities(&self, entity: EntityId) -> HashMap<String, f64> {
+        self.security_boundaries.effective_capabilities(entity).await
+    }
+    
+    /// Events recorded through `ValidateCausality` that an event transitively depends on, ancestors first
+    pub async fn event_ancestry(&self, event_id: Uuid) -> Result<Vec<Uuid>> {
+        self.causality_engine.ancestry(event_id).await
+    }
+    
+    /// The recorded causal graph in Graphviz DOT format
+    pub async fn export_causal_graph(&self) -> String {
+        self.causality_engine.export_dot().await
+    }
+    
     /// Get current physics engine state
     pub async fn get_engine_state(&self) -> Result<PhysicsEngineState> {
         let energy_state = {
@@ -386,6 +720,8 @@ impl PhysicsEngine {
         let resource_usage = self.resource_manager.get_usage().await;
         let causality_stats = self.causality_engine.get_statistics().await;
         let security_stats = self.security_boundaries.get_statistics().await;
+        let operation_stats = self.operation_stats.read().await.clone();
+        let running_operations = self.running_operations.lock().unwrap().values().cloned().collect();
         
         Ok(PhysicsEngineState {
             instance_id: self.instance_id,
@@ -394,9 +730,43 @@ impl PhysicsEngine {
             resource_usage,
             causality_stats,
             security_stats,
+            operation_stats,
+            running_operations,
         })
     }
     
+    /// Capture the state that `restore` can later put back
+    pub async fn checkpoint(&self) -> PhysicsCheckpoint {
+        let energy_laws = self.energy_laws.read().await;
+        let energy_state = energy_laws.get_state().await;
+        PhysicsCheckpoint {
+            total_energy: energy_state.total_energy,
+            energy_allocations: energy_state.per_entity,
+            dissipated_energy: energy_state.dissipated_energy,
+            causal_events: self.causality_engine.events().await,
+            resource_allocations: self.resource_manager.allocations().await,
+        }
+    }
+    
+    /// Return the engine to a checkpointed state
+    ///
+    /// Everything is validated before anything is replaced, and energy
+    /// operations wait until the swap is complete. Fails without changes when
+    /// the checkpoint's total energy differs from this engine's, or its
+    /// resource allocations exceed the current quotas.
+    pub async fn restore(&self, checkpoint: PhysicsCheckpoint) -> Result<()> {
+        let mut energy_laws = self.energy_laws.write().await;
+        energy_laws.check_restorable(checkpoint.total_energy, &checkpoint.energy_allocations, checkpoint.dissipated_energy)
+            .context("Checkpoint does not conserve this engine's energy")?;
+        self.resource_manager.restore(checkpoint.resource_allocations).await?;
+        energy_laws.restore_allocations(checkpoint.total_energy, checkpoint.energy_allocations, checkpoint.dissipated_energy)
+            .map_err(|e| anyhow::anyhow!(e))?;
+        self.causality_engine.restore(checkpoint.causal_events).await;
+        
+        info!("Physics engine {} restored from checkpoint", self.instance_id);
+        Ok(())
+    }
+    
     /// Shutdown the physics engine gracefully
     pub async fn shutdown(&self) -> Result<()> {
         info!("Shutting down physics engine {}", self.instance_id);
@@ -413,6 +783,137 @@ impl PhysicsEngine {
     }
 }
 
+/// The physics operations the nervous system and runtime depend on
+///
+/// Implemented by [`PhysicsEngine`]; tests of dependent crates can substitute
+/// a `MockPhysicsEngine` (behind the `test-util` feature) that approves
+/// operations without enforcing the laws.
+#[async_trait]
+pub trait Physics: std::fmt::Debug + Send + Sync {
+    /// Execute a physics operation with full validation and enforcement
+    async fn execute_operation(&self, operation: PhysicsOperation) -> Result<PhysicsResult>;
+    /// Energy currently allocated to an entity (zero if it holds none)
+    async fn entity_energy(&self, entity: EntityId) -> OrderedFloat<f64>;
+    /// Allocate energy to an entity from the system
+    async fn allocate_energy_to_entity(&self, entity: EntityId, amount: OrderedFloat<f64>) -> Result<()>;
+    /// Keep load balancing from moving energy into or out of an entity
+    async fn exempt_from_load_balancing(&self, entity: EntityId);
+    /// Release an entity's energy back to the system, returning the amount reclaimed
+    async fn release_energy_from_entity(&self, entity: EntityId) -> Result<OrderedFloat<f64>>;
+    /// Release every resource allocation held by an entity, returning how many were freed
+    async fn release_resources_from_entity(&self, entity: EntityId) -> usize;
+    /// Decay every entity's energy by `elapsed` worth of decay
+    ///
+    /// Physics without energy decay leaves energy untouched.
+    async fn apply_energy_decay(&self, _elapsed: Duration) -> Result<()> {
+        Ok(())
+    }
+    /// Subscribe to violations raised by any operation
+    fn subscribe_violations(&self) -> broadcast::Receiver<PhysicsViolationEvent>;
+    /// Get current physics engine state
+    async fn get_engine_state(&self) -> Result<PhysicsEngineState>;
+    /// Shutdown the physics engine gracefully
+    async fn shutdown(&self) -> Result<()>;
+}
+
+#[async_trait]
+impl Physics for PhysicsEngine {
+    async fn execute_operation(&self, operation: PhysicsOperation) -> Result<PhysicsResult> {
+        PhysicsEngine::execute_operation(self, operation).await
+    }
+    
+    async fn entity_energy(&self, entity: EntityId) -> OrderedFloat<f64> {
+        PhysicsEngine::entity_energy(self, entity).await
+    }
+    
+    async fn allocate_energy_to_entity(&self, entity: EntityId, amount: OrderedFloat<f64>) -> Result<()> {
+        PhysicsEngine::allocate_energy_to_entity(self, entity, amount).await
+    }
+    
+    async fn exempt_from_load_balancing(&self, entity: EntityId) {
+        PhysicsEngine::exempt_from_load_balancing(self, entity).await
+    }
+    
+    async fn release_energy_from_entity(&self, entity: EntityId) -> Result<OrderedFloat<f64>> {
+        PhysicsEngine::release_energy_from_entity(self, entity).await
+    }
+    
+    async fn release_resources_from_entity(&self, entity: EntityId) -> usize {
+        PhysicsEngine::release_resources_from_entity(self, entity).await
+    }
+    
+    async fn apply_energy_decay(&self, elapsed: Duration) -> Result<()> {
+        PhysicsEngine::apply_energy_decay(self, elapsed).await
+    }
+    
+    fn subscribe_violations(&self) -> broadcast::Receiver<PhysicsViolationEvent> {
+        PhysicsEngine::subscribe_violations(self)
+    }
+    
+    async fn get_engine_state(&self) -> Result<PhysicsEngineState> {
+        PhysicsEngine::get_engine_state(self).await
+    }
+    
+    async fn shutdown(&self) -> Result<()> {
+        PhysicsEngine::shutdown(self).await
+    }
+}
+
+/// Category of a physics violation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PhysicsViolationKind {
+    EnergyConservation,
+    Causality,
+    SecurityBreach,
+    ResourceLimit,
+    TimeLimit,
+    /// The operation was rejected before execution
+    InvalidOperation,
+}
+
+/// Notification of a violation raised by `execute_operation`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PhysicsViolationEvent {
+    /// Entity the failed operation acted on, if any
+    pub entity: Option<EntityId>,
+    /// Kind of violation
+    pub kind: PhysicsViolationKind,
+    /// When the violation occurred
+    pub timestamp: DateTime<Utc>,
+    /// Violation message
+    pub message: String,
+}
+
+impl PhysicsViolation {
+    /// Category of this violation
+    pub fn kind(&self) -> PhysicsViolationKind {
+        match self {
+            PhysicsViolation::EnergyConservation { .. } => PhysicsViolationKind::EnergyConservation,
+            PhysicsViolation::CausalityViolation { .. } => PhysicsViolationKind::Causality,
+            PhysicsViolation::SecurityBreach { .. } => PhysicsViolationKind::SecurityBreach,
+            PhysicsViolation::ResourceLimit { .. } => PhysicsViolationKind::ResourceLimit,
+            PhysicsViolation::TimeLimit { .. } => PhysicsViolationKind::TimeLimit,
+        }
+    }
+}
+
+impl PhysicsOperation {
+    /// Entity the operation primarily acts on
+    ///
+    /// Energy transfers report their source; batches report their first entity.
+    pub fn entity(&self) -> Option<EntityId> {
+        match self {
+            PhysicsOperation::ValidateCapability { entity, .. }
+            | PhysicsOperation::AllocateResource { entity, .. }
+            | PhysicsOperation::EnforceTimeLimit { entity, .. }
+            | PhysicsOperation::DissipateEnergy { entity, .. } => Some(*entity),
+            PhysicsOperation::TransferEnergy { from, .. } => Some(*from),
+            PhysicsOperation::ValidateCausality { .. } => None,
+            PhysicsOperation::Batch(operations) => operations.iter().find_map(|op| op.entity()),
+        }
+    }
+}
+
 /// Current state of the physics engine
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PhysicsEngineState {
@@ -428,6 +929,196 @@ pub struct PhysicsEngineState {
     pub causality_stats: serde_yaml::Value,
     /// Security boundary statistics
     pub security_stats: serde_yaml::Value,
+    /// Operation and violation counters
+    pub operation_stats: OperationStats,
+    /// Operations currently running under a time limit
+    pub running_operations: Vec<RunningOperation>,
+}
+
+/// Restorable state of a physics engine, taken by `PhysicsEngine::checkpoint`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PhysicsCheckpoint {
+    /// Total system energy, which a restoring engine must share
+    pub total_energy: OrderedFloat<f64>,
+    /// Energy held by each entity
+    pub energy_allocations: HashMap<EntityId, OrderedFloat<f64>>,
+    /// Energy dissipated by work before the checkpoint
+    #[serde(default)]
+    pub dissipated_energy: OrderedFloat<f64>,
+    /// Events recorded in the causal graph, oldest first
+    pub causal_events: Vec<CausalEvent>,
+    /// Resources held by each entity
+    pub resource_allocations: HashMap<EntityId, HashMap<ResourceType, OrderedFloat<f64>>>,
+}
+
+/// Changes between two physics engine state snapshots
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PhysicsStateDelta {
+    /// Time elapsed between the snapshots
+    pub elapsed: Duration,
+    /// Change in total system energy
+    pub total_energy_change: f64,
+    /// Change in allocated energy
+    pub allocated_energy_change: f64,
+    /// Change in free energy
+    pub free_energy_change: f64,
+    /// Net change in entities holding energy
+    pub entity_count_change: i64,
+    /// Operations executed since the previous snapshot
+    pub new_operations: u64,
+    /// Energy conservation violations since the previous snapshot
+    pub new_energy_violations: u64,
+    /// Security violations since the previous snapshot
+    pub new_security_violations: u64,
+    /// Other violations since the previous snapshot
+    pub new_other_violations: u64,
+    /// Numeric changes in resource, causality and security statistics keyed by dotted path
+    pub stat_changes: BTreeMap<String, f64>,
+    /// Non-numeric statistics whose value changed, by dotted path
+    pub changed_fields: Vec<String>,
+}
+
+impl PhysicsStateDelta {
+    /// Total violations since the previous snapshot
+    pub fn new_violations(&self) -> u64 {
+        self.new_energy_violations + self.new_security_violations + self.new_other_violations
+    }
+}
+
+impl PhysicsEngineState {
+    /// Compute what changed since a previous snapshot
+    pub fn diff(&self, previous: &PhysicsEngineState) -> PhysicsStateDelta {
+        let energy = &self.energy_state;
+        let prev_energy = &previous.energy_state;
+        let stats = &self.operation_stats;
+        let prev_stats = &previous.operation_stats;
+        
+        let mut delta = PhysicsStateDelta {
+            elapsed: self.uptime.saturating_sub(previous.uptime),
+            total_energy_change: (energy.total_energy - prev_energy.total_energy).0,
+            allocated_energy_change: (energy.allocated_energy - prev_energy.allocated_energy).0,
+            free_energy_change: (energy.free_energy - prev_energy.free_energy).0,
+            entity_count_change: energy.active_entities as i64 - prev_energy.active_entities as i64,
+            new_operations: stats.operations_executed.saturating_sub(prev_stats.operations_executed),
+            new_energy_violations: stats.energy_violations.saturating_sub(prev_stats.energy_violations),
+            new_security_violations: stats.security_violations.saturating_sub(prev_stats.security_violations),
+            new_other_violations: stats.other_violations.saturating_sub(prev_stats.other_violations),
+            ..Default::default()
+        };
+        
+        diff_yaml("resource_usage", &self.resource_usage, &previous.resource_usage, &mut delta);
+        diff_yaml("causality_stats", &self.causality_stats, &previous.causality_stats, &mut delta);
+        diff_yaml("security_stats", &self.security_stats, &previous.security_stats, &mut delta);
+        
+        delta
+    }
+}
+
+/// Structurally compare two statistics values, recording changes under `path`
+///
+/// Mappings are compared key by key; numeric leaves record their difference
+/// (missing values count as zero) and any other differing leaf is listed as changed.
+fn diff_yaml(path: &str, current: &serde_yaml::Value, previous: &serde_yaml::Value, delta: &mut PhysicsStateDelta) {
+    use serde_yaml::Value;
+    
+    match (current, previous) {
+        (Value::Mapping(current_map), Value::Mapping(previous_map)) => {
+            let mut seen = std::collections::HashSet::new();
+            for key in current_map.keys().chain(previous_map.keys()) {
+                let name = match key {
+                    Value::String(name) => name.clone(),
+                    other => serde_yaml::to_string(other).unwrap_or_default().trim().to_string(),
+                };
+                if !seen.insert(name.clone()) {
+                    continue;
+                }
+                diff_yaml(
+                    &format!("{}.{}", path, name),
+                    current_map.get(key).unwrap_or(&Value::Null),
+                    previous_map.get(key).unwrap_or(&Value::Null),
+                    delta,
+                );
+            }
+        }
+        (Value::Number(_) | Value::Null, Value::Number(_) | Value::Null) if current != previous => {
+            let value = |v: &Value| v.as_f64().unwrap_or(0.0);
+            delta.stat_changes.insert(path.to_string(), value(current) - value(previous));
+        }
+        _ if current != previous => delta.changed_fields.push(path.to_string()),
+        _ => {}
+    }
+}
+
+/// An operation being timed by `run_with_limit`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunningOperation {
+    pub entity: EntityId,
+    pub operation: String,
+    pub started_at: DateTime<Utc>,
+    pub limit: Duration,
+}
+
+/// Removes a running operation from tracking when it finishes or is dropped
+struct RunningOperationGuard {
+    id: Uuid,
+    running: Arc<std::sync::Mutex<HashMap<Uuid, RunningOperation>>>,
+}
+
+impl RunningOperationGuard {
+    fn register(running: &Arc<std::sync::Mutex<HashMap<Uuid, RunningOperation>>>, operation: RunningOperation) -> Self {
+        let id = Uuid::new_v4();
+        running.lock().unwrap().insert(id, operation);
+        Self { id, running: running.clone() }
+    }
+}
+
+impl Drop for RunningOperationGuard {
+    fn drop(&mut self) {
+        if let Ok(mut running) = self.running.lock() {
+            running.remove(&self.id);
+        }
+    }
+}
+
+/// An effect of a batch operation outside the energy state, kept so it can be reversed
+enum BatchUndo {
+    Allocation { entity: EntityId, resource_type: ResourceType, amount: OrderedFloat<f64> },
+    CausalEvent { event_id: Uuid, previous: Option<CausalEvent> },
+    CapabilityUse { entity: EntityId, name: String, last_used: tokio::time::Instant },
+}
+
+/// Counters for operations executed through the physics engine
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OperationStats {
+    /// Operations submitted for execution, including rejected ones
+    pub operations_executed: u64,
+    /// Operations rejected for violating energy conservation
+    pub energy_violations: u64,
+    /// Operations rejected at a security boundary
+    pub security_violations: u64,
+    /// Operations rejected for any other physics violation
+    pub other_violations: u64,
+}
+
+impl OperationStats {
+    /// Record a violation under its category
+    fn record_violation(&mut self, violation: &PhysicsViolation) {
+        match violation {
+            PhysicsViolation::EnergyConservation { .. } => self.energy_violations += 1,
+            PhysicsViolation::SecurityBreach { .. } => self.security_violations += 1,
+            _ => self.other_violations += 1,
+        }
+    }
+
+    /// Operations executed per second over the given uptime
+    pub fn operations_per_second(&self, uptime: Duration) -> f64 {
+        let secs = uptime.as_secs_f64();
+        if secs > 0.0 {
+            self.operations_executed as f64 / secs
+        } else {
+            0.0
+        }
+    }
 }
 
 impl EntityId {
@@ -470,6 +1161,11 @@ impl Capability {
         self.proof = Some(proof);
         self
     }
+    
+    /// Add proof of competence by hashing the supporting evidence
+    pub fn with_evidence(self, evidence: &[u8]) -> Self {
+        self.with_proof(blake3::hash(evidence))
+    }
 }
 
 #[cfg(test)]
@@ -530,4 +1226,223 @@ mod tests {
             }
         }
     }
-}
\ No newline at end of file
+    
+    #[tokio::test]
+    async fn test_dissipated_energy_stays_gone() {
+        let engine = PhysicsEngine::new().await.unwrap();
+        let worker = EntityId::new();
+        engine.allocate_energy_to_entity(worker, OrderedFloat(0.4)).await.unwrap();
+        let checkpoint = engine.checkpoint().await;
+        
+        let work = PhysicsOperation::DissipateEnergy { entity: worker, amount: OrderedFloat(0.1) };
+        assert!(engine.execute_operation(work.clone()).await.unwrap().success);
+        
+        let energy = engine.get_engine_state().await.unwrap().energy_state;
+        assert!((engine.entity_energy(worker).await.0 - 0.3).abs() < 1e-9);
+        assert!((energy.dissipated_energy.0 - 0.1).abs() < 1e-9);
+        assert!((energy.allocated_energy + energy.free_energy + energy.dissipated_energy - energy.total_energy).abs() < 1e-9);
+        
+        // Restoring puts the dissipated energy back as it was at the checkpoint
+        engine.restore(checkpoint).await.unwrap();
+        assert_eq!(engine.get_engine_state().await.unwrap().energy_state.dissipated_energy, OrderedFloat(0.0));
+        assert_eq!(engine.entity_energy(worker).await, OrderedFloat(0.4));
+    }
+    
+    #[tokio::test]
+    async fn test_failed_batch_rolls_back() {
+        let engine = PhysicsEngine::new().await.unwrap();
+        let alice = EntityId::new();
+        let bob = EntityId::new();
+        engine.allocate_energy_to_entity(alice, OrderedFloat(0.3)).await.unwrap();
+        engine.allocate_energy_to_entity(bob, OrderedFloat(0.1)).await.unwrap();
+        let (alice_before, bob_before) = {
+            let energy = engine.energy_laws.read().await;
+            (energy.get_entity_energy(alice), energy.get_entity_energy(bob))
+        };
+        
+        let batch = PhysicsOperation::Batch(vec![
+            PhysicsOperation::AllocateResource { entity: alice, resource: Resource::Cpu(10), amount: OrderedFloat(10.0) },
+            PhysicsOperation::TransferEnergy { from: alice, to: bob, amount: OrderedFloat(0.2) },
+            PhysicsOperation::TransferEnergy { from: bob, to: alice, amount: OrderedFloat(0.9) },
+        ]);
+        
+        assert!(engine.execute_operation(batch).await.is_err());
+        
+        let energy = engine.energy_laws.read().await;
+        assert_eq!(energy.get_entity_energy(alice), alice_before);
+        assert_eq!(energy.get_entity_energy(bob), bob_before);
+        assert!(engine.resource_manager.usage_for(alice).await.is_empty());
+    }
+    
+    #[tokio::test]
+    async fn test_failed_batch_undoes_causality_and_capability_use() {
+        let clock = MockClock::new();
+        let engine = PhysicsEngine::with_clock(clock.shared()).await.unwrap();
+        let alice = EntityId::new();
+        let capability = Capability::new("share".to_string(), 0.8);
+        engine.grant_capability(alice, &capability, None).await.unwrap();
+        clock.advance(Duration::from_secs(24 * 60 * 60));
+        let decayed = engine.effective_capability_strength(alice, "share").await.unwrap();
+        let event_id = Uuid::new_v4();
+        
+        let batch = PhysicsOperation::Batch(vec![
+            PhysicsOperation::ValidateCapability { entity: alice, capability },
+            PhysicsOperation::ValidateCausality { event_id, parent_events: vec![], timestamp: Utc::now() },
+            PhysicsOperation::TransferEnergy { from: alice, to: EntityId::new(), amount: OrderedFloat(0.5) },
+        ]);
+        
+        assert!(engine.execute_operation(batch).await.is_err());
+        
+        assert_eq!(engine.effective_capability_strength(alice, "share").await, Some(decayed));
+        assert!(engine.causality_engine.event(event_id).await.is_none());
+    }
+    
+    #[tokio::test]
+    async fn test_valid_batch_applies_every_effect() {
+        let engine = PhysicsEngine::new().await.unwrap();
+        let alice = EntityId::new();
+        let bob = EntityId::new();
+        engine.allocate_energy_to_entity(alice, OrderedFloat(0.3)).await.unwrap();
+        
+        let batch = PhysicsOperation::Batch(vec![
+            PhysicsOperation::ValidateCapability { entity: alice, capability: Capability::new("share".to_string(), 0.5) },
+            PhysicsOperation::TransferEnergy { from: alice, to: bob, amount: OrderedFloat(0.1) },
+            PhysicsOperation::Batch(vec![
+                PhysicsOperation::AllocateResource { entity: bob, resource: Resource::Memory(64), amount: OrderedFloat(64.0) },
+            ]),
+        ]);
+        
+        let result = engine.execute_operation(batch).await.unwrap();
+        assert!(result.success);
+        assert_eq!(result.message, "Executed 3 operations atomically");
+        assert_eq!(result.costs["transfer_fee"], OrderedFloat(0.01));
+        assert_eq!(result.costs["allocation_overhead"], OrderedFloat(0.001));
+        
+        let energy = engine.energy_laws.read().await;
+        assert!((energy.get_entity_energy(alice).0 - 0.2).abs() < 1e-9);
+        assert!((energy.get_entity_energy(bob).0 - 0.1).abs() < 1e-9);
+        assert_eq!(engine.resource_manager.usage_for(bob).await[&ResourceType::Memory], OrderedFloat(64.0));
+    }
+    
+    #[tokio::test]
+    async fn test_violation_events_are_broadcast() {
+        let engine = PhysicsEngine::new().await.unwrap();
+        let mut violations = engine.subscribe_violations();
+        let pauper = EntityId::new();
+        
+        let operation = PhysicsOperation::TransferEnergy {
+            from: pauper,
+            to: EntityId::new(),
+            amount: OrderedFloat(0.5),
+        };
+        assert!(engine.execute_operation(operation).await.is_err());
+        
+        let event = violations.try_recv().unwrap();
+        assert_eq!(event.kind, PhysicsViolationKind::EnergyConservation);
+        assert_eq!(event.entity, Some(pauper));
+        assert!(event.message.contains("Energy conservation violated"));
+        assert!(violations.try_recv().is_err());
+    }
+    
+    #[tokio::test(start_paused = true)]
+    async fn test_run_with_limit_fast_operation() {
+        let engine = PhysicsEngine::new().await.unwrap();
+        let entity = EntityId::new();
+        
+        let running = engine.run_with_limit(entity, "quick-think", Duration::from_secs(1), async {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            engine.get_engine_state().await.unwrap().running_operations
+        }).await.unwrap();
+        
+        assert_eq!(running.len(), 1);
+        assert_eq!(running[0].operation, "quick-think");
+        assert_eq!(running[0].entity, entity);
+        assert!(engine.get_engine_state().await.unwrap().running_operations.is_empty());
+    }
+    
+    #[tokio::test(start_paused = true)]
+    async fn test_run_with_limit_slow_operation() {
+        let engine = PhysicsEngine::new().await.unwrap();
+        let limit = Duration::from_secs(2);
+        
+        let result = engine.run_with_limit(EntityId::new(), "deep-think", limit, async {
+            tokio::time::sleep(Duration::from_secs(10)).await;
+        }).await;
+        
+        match result {
+            Err(PhysicsViolation::TimeLimit { operation, actual, limit: reported }) => {
+                assert_eq!(operation, "deep-think");
+                assert_eq!(reported, limit);
+                assert!(actual >= limit && actual < limit + Duration::from_millis(100));
+            }
+            other => panic!("expected time limit violation, got {:?}", other),
+        }
+        let state = engine.get_engine_state().await.unwrap();
+        assert!(state.running_operations.is_empty());
+        assert_eq!(state.operation_stats.other_violations, 1);
+    }
+    
+    #[tokio::test]
+    async fn test_engine_state_diff() {
+        let engine = PhysicsEngine::new().await.unwrap();
+        let before = engine.get_engine_state().await.unwrap();
+        
+        let alice = EntityId::new();
+        engine.allocate_energy_to_entity(alice, OrderedFloat(0.25)).await.unwrap();
+        engine.allocate_energy_to_entity(EntityId::new(), OrderedFloat(0.25)).await.unwrap();
+        engine.execute_operation(PhysicsOperation::AllocateResource {
+            entity: alice,
+            resource: Resource::Cpu(30),
+            amount: OrderedFloat(30.0),
+        }).await.unwrap();
+        let _ = engine.execute_operation(PhysicsOperation::TransferEnergy {
+            from: EntityId::new(),
+            to: alice,
+            amount: OrderedFloat(0.1),
+        }).await;
+        
+        let after = engine.get_engine_state().await.unwrap();
+        let delta = after.diff(&before);
+        
+        assert_eq!(delta.total_energy_change, 0.0);
+        assert!((delta.allocated_energy_change - 0.5).abs() < 1e-9);
+        assert!((delta.free_energy_change + 0.5).abs() < 1e-9);
+        assert_eq!(delta.entity_count_change, 2);
+        assert_eq!(delta.new_operations, 2);
+        assert_eq!(delta.new_energy_violations, 1);
+        assert_eq!(delta.new_violations(), 1);
+        assert_eq!(delta.stat_changes.get("resource_usage.Cpu.allocated"), Some(&30.0));
+        assert!(!delta.stat_changes.contains_key("resource_usage.Cpu.quota"));
+        assert!(delta.changed_fields.is_empty());
+        
+        assert_eq!(after.diff(&after).stat_changes.len(), 0);
+    }
+    
+    #[tokio::test]
+    async fn test_restore_returns_to_checkpoint() {
+        let engine = PhysicsEngine::new().await.unwrap();
+        let alice = EntityId::new();
+        let bob = EntityId::new();
+        engine.allocate_energy_to_entity(alice, OrderedFloat(0.4)).await.unwrap();
+        let allocate_memory = PhysicsOperation::AllocateResource { entity: bob, resource: Resource::Memory(64), amount: OrderedFloat(64.0) };
+        engine.execute_operation(allocate_memory.clone()).await.unwrap();
+        let first_event = Uuid::new_v4();
+        engine.execute_operation(PhysicsOperation::ValidateCausality { event_id: first_event, parent_events: Vec::new(), timestamp: Utc::now() }).await.unwrap();
+        let checkpoint = engine.checkpoint().await;
+        
+        engine.execute_operation(PhysicsOperation::TransferEnergy { from: alice, to: bob, amount: OrderedFloat(0.1) }).await.unwrap();
+        engine.execute_operation(allocate_memory).await.unwrap();
+        engine.execute_operation(PhysicsOperation::ValidateCausality { event_id: Uuid::new_v4(), parent_events: vec![first_event], timestamp: Utc::now() }).await.unwrap();
+        assert_ne!(engine.checkpoint().await, checkpoint);
+        
+        engine.restore(checkpoint.clone()).await.unwrap();
+        assert_eq!(engine.checkpoint().await, checkpoint);
+        assert_eq!(engine.entity_energy(bob).await, OrderedFloat(0.0));
+        
+        let mut foreign = checkpoint.clone();
+        foreign.total_energy = OrderedFloat(2.0);
+        foreign.energy_allocations.insert(bob, OrderedFloat(1.5));
+        assert!(engine.restore(foreign).await.is_err());
+        assert_eq!(engine.checkpoint().await, checkpoint);
+    }
+}
@@ -0,0 +1,212 @@
+//! A stand-in physics engine for testing code built on top of the laws.
+//!
+//! [`MockPhysicsEngine`] implements [`Physics`] without enforcing anything:
+//! every operation is approved and energy moves between entities unchecked,
+//! so tests of routing or scheduling logic run fast and are unaffected by
+//! conservation. Tests that need the failure paths queue violations with
+//! [`MockPhysicsEngine::fail_next`]. Available to other crates behind the
+//! `test-util` feature.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::Utc;
+use ordered_float::OrderedFloat;
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+use crate::energy::{gini_coefficient, EnergyDistribution};
+use crate::{
+    EnergyState, EntityId, OperationStats, Physics, PhysicsEngineState, PhysicsOperation, PhysicsResult,
+    PhysicsViolation, PhysicsViolationEvent, VIOLATION_CHANNEL_CAPACITY,
+};
+
+/// Physics that approves every operation unless told to fail
+#[derive(Debug)]
+pub struct MockPhysicsEngine {
+    /// Energy held by each entity
+    energy: Mutex<HashMap<EntityId, OrderedFloat<f64>>>,
+    /// Violations returned by the next operations, in order
+    failures: Mutex<VecDeque<PhysicsViolation>>,
+    /// Every operation submitted, including failed ones
+    operations: Mutex<Vec<PhysicsOperation>>,
+    operation_stats: Mutex<OperationStats>,
+    violation_events: broadcast::Sender<PhysicsViolationEvent>,
+    genesis_time: Instant,
+    instance_id: Uuid,
+}
+
+impl MockPhysicsEngine {
+    pub fn new() -> Self {
+        Self {
+            energy: Mutex::new(HashMap::new()),
+            failures: Mutex::new(VecDeque::new()),
+            operations: Mutex::new(Vec::new()),
+            operation_stats: Mutex::new(OperationStats::default()),
+            violation_events: broadcast::channel(VIOLATION_CHANNEL_CAPACITY).0,
+            genesis_time: Instant::now(),
+            instance_id: Uuid::new_v4(),
+        }
+    }
+
+    /// Make the next operation fail with `violation`
+    ///
+    /// Calls queue up: each operation consumes one violation, and operations
+    /// run normally again once the queue is empty.
+    pub fn fail_next(&self, violation: PhysicsViolation) {
+        self.failures.lock().unwrap().push_back(violation);
+    }
+
+    /// Operations submitted so far, in order
+    pub fn operations(&self) -> Vec<PhysicsOperation> {
+        self.operations.lock().unwrap().clone()
+    }
+
+    fn apply(&self, operation: &PhysicsOperation) {
+        match operation {
+            PhysicsOperation::TransferEnergy { from, to, amount } => {
+                let mut energy = self.energy.lock().unwrap();
+                *energy.entry(*from).or_default() -= *amount;
+                *energy.entry(*to).or_default() += *amount;
+            }
+            PhysicsOperation::DissipateEnergy { entity, amount } => {
+                *self.energy.lock().unwrap().entry(*entity).or_default() -= *amount;
+            }
+            PhysicsOperation::Batch(operations) => operations.iter().for_each(|operation| self.apply(operation)),
+            _ => {}
+        }
+    }
+}
+
+impl Default for MockPhysicsEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Physics for MockPhysicsEngine {
+    async fn execute_operation(&self, operation: PhysicsOperation) -> Result<PhysicsResult> {
+        self.operations.lock().unwrap().push(operation.clone());
+        self.operation_stats.lock().unwrap().operations_executed += 1;
+
+        let failure = self.failures.lock().unwrap().pop_front();
+        if let Some(violation) = failure {
+            self.operation_stats.lock().unwrap().record_violation(&violation);
+            let _ = self.violation_events.send(PhysicsViolationEvent {
+                entity: operation.entity(),
+                kind: violation.kind(),
+                timestamp: Utc::now(),
+                message: violation.to_string(),
+            });
+            return Err(violation.into());
+        }
+
+        self.apply(&operation);
+        Ok(PhysicsResult {
+            success: true,
+            message: "Approved by mock physics".to_string(),
+            duration: Duration::ZERO,
+            costs: HashMap::new(),
+            new_state: None,
+        })
+    }
+
+    async fn entity_energy(&self, entity: EntityId) -> OrderedFloat<f64> {
+        self.energy.lock().unwrap().get(&entity).copied().unwrap_or_default()
+    }
+
+    async fn allocate_energy_to_entity(&self, entity: EntityId, amount: OrderedFloat<f64>) -> Result<()> {
+        *self.energy.lock().unwrap().entry(entity).or_default() += amount;
+        Ok(())
+    }
+
+    /// Mock energy is never rebalanced
+    async fn exempt_from_load_balancing(&self, _entity: EntityId) {}
+
+    async fn release_energy_from_entity(&self, entity: EntityId) -> Result<OrderedFloat<f64>> {
+        Ok(self.energy.lock().unwrap().remove(&entity).unwrap_or_default())
+    }
+
+    async fn release_resources_from_entity(&self, _entity: EntityId) -> usize {
+        0
+    }
+
+    /// Mock energy does not decay
+    async fn apply_energy_decay(&self, _elapsed: Duration) -> Result<()> {
+        Ok(())
+    }
+
+    fn subscribe_violations(&self) -> broadcast::Receiver<PhysicsViolationEvent> {
+        self.violation_events.subscribe()
+    }
+
+    async fn get_engine_state(&self) -> Result<PhysicsEngineState> {
+        let per_entity = self.energy.lock().unwrap().clone();
+        let allocated_energy: OrderedFloat<f64> = per_entity.values().copied().sum();
+        let count = OrderedFloat(per_entity.len().max(1) as f64);
+        let mean = allocated_energy / count;
+        let variance = per_entity.values().map(|e| (*e - mean) * (*e - mean)).sum::<OrderedFloat<f64>>() / count;
+
+        Ok(PhysicsEngineState {
+            instance_id: self.instance_id,
+            uptime: self.genesis_time.elapsed(),
+            energy_state: EnergyState {
+                total_energy: allocated_energy,
+                allocated_energy,
+                free_energy: OrderedFloat(0.0),
+                dissipated_energy: OrderedFloat(0.0),
+                active_entities: per_entity.len(),
+                energy_distribution: EnergyDistribution {
+                    mean,
+                    variance,
+                    min: per_entity.values().copied().min().unwrap_or_default(),
+                    max: per_entity.values().copied().max().unwrap_or_default(),
+                },
+                gini_coefficient: gini_coefficient(per_entity.values().copied()),
+                per_entity,
+            },
+            resource_usage: serde_yaml::Value::Null,
+            causality_stats: serde_yaml::Value::Null,
+            security_stats: serde_yaml::Value::Null,
+            operation_stats: self.operation_stats.lock().unwrap().clone(),
+            running_operations: Vec::new(),
+        })
+    }
+
+    async fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PhysicsViolationKind;
+
+    #[tokio::test]
+    async fn test_mock_approves_until_told_to_fail() {
+        let physics = MockPhysicsEngine::new();
+        let (poor, rich) = (EntityId::new(), EntityId::new());
+        let mut violations = physics.subscribe_violations();
+
+        let overdraw = PhysicsOperation::TransferEnergy { from: poor, to: rich, amount: OrderedFloat(0.5) };
+        physics.execute_operation(overdraw.clone()).await.unwrap();
+        assert_eq!(physics.entity_energy(rich).await, OrderedFloat(0.5));
+
+        physics.fail_next(PhysicsViolation::EnergyConservation { reason: "simulated".to_string() });
+        let error = physics.execute_operation(overdraw.clone()).await.unwrap_err();
+        assert!(error.to_string().contains("simulated"));
+        assert_eq!(violations.recv().await.unwrap().kind, PhysicsViolationKind::EnergyConservation);
+        assert_eq!(physics.entity_energy(rich).await, OrderedFloat(0.5));
+
+        physics.execute_operation(overdraw).await.unwrap();
+        let state = physics.get_engine_state().await.unwrap();
+        assert_eq!(state.operation_stats.operations_executed, 3);
+        assert_eq!(state.operation_stats.energy_violations, 1);
+        assert_eq!(physics.operations().len(), 3);
+    }
+}
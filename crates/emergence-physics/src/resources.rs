@@ -1,15 +1,34 @@
 //! Resource management for the EMERGENCE system.
 
+use std::collections::{HashMap, HashSet};
+
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use ordered_float::OrderedFloat;
 use serde::{Deserialize, Serialize};
-use crate::{EntityId, Resource};
+use tokio::sync::RwLock;
+use crate::{EntityId, PhysicsViolation, Resource};
 
 /// Resource manager for system resources
 #[derive(Debug)]
 pub struct ResourceManager {
-    // Stub implementation
+    /// Maximum total amount of each resource across all entities
+    quotas: RwLock<HashMap<ResourceType, OrderedFloat<f64>>>,
+    /// Amount of each resource currently held by each entity
+    allocations: RwLock<HashMap<EntityId, HashMap<ResourceType, OrderedFloat<f64>>>>,
+    /// Amount of each resource each entity has declared it needs
+    needs: RwLock<HashMap<(EntityId, ResourceType), OrderedFloat<f64>>>,
+    /// Entities whose shortfalls are filled first and who never give resources up
+    priority_entities: RwLock<Vec<EntityId>>,
+}
+
+/// A move of some of a resource from one entity to another during rebalancing
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResourceReallocation {
+    pub from: EntityId,
+    pub to: EntityId,
+    pub resource: ResourceType,
+    pub amount: OrderedFloat<f64>,
 }
 
 /// Resource allocation record
@@ -22,32 +41,436 @@ pub struct ResourceAllocation {
 }
 
 /// Resource type enumeration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ResourceType {
+    Energy,
     Memory,
     Cpu,
     Network,
+    /// Custom resource keyed by name
+    Custom(String),
+}
+
+impl From<&Resource> for ResourceType {
+    fn from(resource: &Resource) -> Self {
+        match resource {
+            Resource::Energy(_) => ResourceType::Energy,
+            Resource::Memory(_) => ResourceType::Memory,
+            Resource::Cpu(_) => ResourceType::Cpu,
+            Resource::Network(_) => ResourceType::Network,
+            Resource::Custom(name, _) => ResourceType::Custom(name.clone()),
+        }
+    }
 }
 
 impl ResourceManager {
     pub async fn new() -> Result<Self> {
-        Ok(Self {})
+        let quotas = HashMap::from([
+            (ResourceType::Energy, OrderedFloat(1.0)),
+            (ResourceType::Memory, OrderedFloat(4096.0)),
+            (ResourceType::Cpu, OrderedFloat(100.0)),
+            (ResourceType::Network, OrderedFloat(10_000.0)),
+        ]);
+
+        Ok(Self {
+            quotas: RwLock::new(quotas),
+            allocations: RwLock::new(HashMap::new()),
+            needs: RwLock::new(HashMap::new()),
+            priority_entities: RwLock::new(Vec::new()),
+        })
+    }
+
+    /// Apply quotas from the `resource_limits` section of a physics schema
+    ///
+    /// Memory is the sum of working and long-term memory, CPU the maximum
+    /// percentage, and network the message size times message rate.
+    pub async fn configure_from_schema(&self, schema: &serde_yaml::Value) -> Result<()> {
+        let limit = |section: &str, key: &str| schema.get(section).and_then(|s| s.get(key)).and_then(|v| v.as_f64());
+        let mut quotas = self.quotas.write().await;
+
+        let working = limit("memory", "max_working_memory_mb");
+        let long_term = limit("memory", "max_long_term_memory_mb");
+        if working.is_some() || long_term.is_some() {
+            quotas.insert(ResourceType::Memory, OrderedFloat(working.unwrap_or(0.0) + long_term.unwrap_or(0.0)));
+        }
+
+        if let Some(cpu) = limit("computation", "max_cpu_percent") {
+            quotas.insert(ResourceType::Cpu, OrderedFloat(cpu));
+        }
+
+        if let (Some(size), Some(rate)) = (
+            limit("communication", "max_message_size_kb"),
+            limit("communication", "max_messages_per_second"),
+        ) {
+            quotas.insert(ResourceType::Network, OrderedFloat(size * rate));
+        }
+
+        Ok(())
     }
-    
-    pub async fn configure_from_schema(&self, _schema: &serde_yaml::Value) -> Result<()> {
+
+    /// Grant an allocation, rejecting it if it would exceed the resource's quota
+    pub async fn allocate(&self, allocation: ResourceAllocation) -> Result<(), PhysicsViolation> {
+        let resource_type = ResourceType::from(&allocation.resource);
+
+        if allocation.amount < OrderedFloat(0.0) {
+            return Err(PhysicsViolation::ResourceLimit {
+                resource: format!("{:?}", resource_type),
+                reason: format!("cannot allocate negative amount {}", allocation.amount),
+            });
+        }
+
+        let quota = self.quota_for(&resource_type).await;
+        let mut allocations = self.allocations.write().await;
+        let remaining = quota - Self::total_allocated(&allocations, &resource_type);
+
+        if allocation.amount > remaining {
+            return Err(PhysicsViolation::ResourceLimit {
+                resource: format!("{:?}", resource_type),
+                reason: format!("requested {} but only {} remaining", allocation.amount, remaining),
+            });
+        }
+
+        *allocations
+            .entry(allocation.entity)
+            .or_default()
+            .entry(resource_type)
+            .or_insert(OrderedFloat(0.0)) += allocation.amount;
+
         Ok(())
     }
-    
-    pub async fn allocate(&self, _allocation: ResourceAllocation) -> Result<()> {
-        // Stub implementation - always succeeds for now
+
+    /// Declare how much of a resource an entity needs, used by [`Self::rebalance`]
+    pub async fn declare_need(&self, entity: EntityId, resource: ResourceType, amount: OrderedFloat<f64>) {
+        self.needs.write().await.insert((entity, resource), amount);
+    }
+
+    /// Set the entities whose needs are served first when rebalancing, in priority order
+    pub async fn set_priority_entities(&self, entities: Vec<EntityId>) {
+        *self.priority_entities.write().await = entities;
+    }
+
+    /// Move a resource from entities holding more than they need to entities holding less
+    ///
+    /// Entities holding the resource without declaring a need are treated as
+    /// needing none of it, so everything they hold is surplus. Priority entities'
+    /// shortfalls are filled first and they never give anything up; the rest
+    /// are served largest shortfall first from the largest surpluses. Every
+    /// move is between entities, so the total allocated never changes and
+    /// the quota cannot be exceeded.
+    pub async fn rebalance(&self, resource: ResourceType) -> Vec<ResourceReallocation> {
+        let needs = self.needs.read().await;
+        let priority = self.priority_entities.read().await;
+        let mut allocations = self.allocations.write().await;
+        let held = |allocations: &HashMap<EntityId, HashMap<ResourceType, OrderedFloat<f64>>>, entity: &EntityId| {
+            allocations.get(entity).and_then(|held| held.get(&resource)).copied().unwrap_or(OrderedFloat(0.0))
+        };
+
+        let need_of = |entity: &EntityId| needs.get(&(*entity, resource.clone())).copied().unwrap_or(OrderedFloat(0.0));
+        let mut entities: HashSet<EntityId> = needs.keys()
+            .filter(|(_, kind)| *kind == resource)
+            .map(|(entity, _)| *entity)
+            .collect();
+        entities.extend(allocations.iter()
+            .filter(|(_, held)| held.contains_key(&resource))
+            .map(|(entity, _)| *entity));
+
+        let mut surpluses = Vec::new();
+        let mut shortfalls = Vec::new();
+        for entity in &entities {
+            let balance = held(&allocations, entity) - need_of(entity);
+            if balance > OrderedFloat(0.0) && !priority.contains(entity) {
+                surpluses.push((*entity, balance));
+            } else if balance < OrderedFloat(0.0) {
+                shortfalls.push((*entity, -balance));
+            }
+        }
+        surpluses.sort_by_key(|(entity, surplus)| (std::cmp::Reverse(*surplus), entity.0));
+        shortfalls.sort_by_key(|(entity, shortfall)| {
+            let rank = priority.iter().position(|p| p == entity).unwrap_or(priority.len());
+            (rank, std::cmp::Reverse(*shortfall), entity.0)
+        });
+
+        let mut reallocations = Vec::new();
+        let mut donors = surpluses.into_iter().peekable();
+        for (to, mut shortfall) in shortfalls {
+            while shortfall > OrderedFloat(0.0) {
+                let Some((from, surplus)) = donors.peek_mut() else {
+                    break;
+                };
+                let amount = shortfall.min(*surplus);
+                *surplus -= amount;
+                shortfall -= amount;
+                reallocations.push(ResourceReallocation { from: *from, to, resource: resource.clone(), amount });
+                if *surplus <= OrderedFloat(0.0) {
+                    donors.next();
+                }
+            }
+        }
+
+        for reallocation in &reallocations {
+            let from = held(&allocations, &reallocation.from) - reallocation.amount;
+            let to = held(&allocations, &reallocation.to) + reallocation.amount;
+            allocations.entry(reallocation.from).or_default().insert(resource.clone(), from);
+            allocations.entry(reallocation.to).or_default().insert(resource.clone(), to);
+        }
+        reallocations
+    }
+
+    /// Every entity's current allocations
+    pub async fn allocations(&self) -> HashMap<EntityId, HashMap<ResourceType, OrderedFloat<f64>>> {
+        self.allocations.read().await.clone()
+    }
+
+    /// Replace every allocation, rejecting a set that exceeds a current quota
+    pub async fn restore(&self, restored: HashMap<EntityId, HashMap<ResourceType, OrderedFloat<f64>>>) -> Result<(), PhysicsViolation> {
+        let resources: HashSet<&ResourceType> = restored.values().flat_map(HashMap::keys).collect();
+        for resource in resources {
+            let quota = self.quota_for(resource).await;
+            let total = Self::total_allocated(&restored, resource);
+            if total > quota {
+                return Err(PhysicsViolation::ResourceLimit {
+                    resource: format!("{:?}", resource),
+                    reason: format!("restoring {} exceeds the quota of {}", total, quota),
+                });
+            }
+        }
+        *self.allocations.write().await = restored;
+        Ok(())
+    }
+
+    /// Resources currently held by an entity
+    pub async fn usage_for(&self, entity: EntityId) -> HashMap<ResourceType, OrderedFloat<f64>> {
+        self.allocations.read().await
+            .get(&entity)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Amount of a resource still available for allocation
+    pub async fn remaining(&self, resource: ResourceType) -> OrderedFloat<f64> {
+        let quota = self.quota_for(&resource).await;
+        let allocations = self.allocations.read().await;
+        quota - Self::total_allocated(&allocations, &resource)
+    }
+
+    /// Return part of an entity's allocation, used to roll back failed batches
+    pub(crate) async fn deallocate(&self, entity: EntityId, resource: &ResourceType, amount: OrderedFloat<f64>) {
+        let mut allocations = self.allocations.write().await;
+        if let Some(held) = allocations.get_mut(&entity) {
+            if let Some(current) = held.get_mut(resource) {
+                *current -= amount;
+                if *current <= OrderedFloat(0.0) {
+                    held.remove(resource);
+                }
+            }
+            if held.is_empty() {
+                allocations.remove(&entity);
+            }
+        }
+    }
+
+    /// Free an entity's allocation of one resource; a no-op if nothing is held
+    pub async fn release(&self, entity: EntityId, resource: ResourceType) -> Result<()> {
+        let mut allocations = self.allocations.write().await;
+        if let Some(held) = allocations.get_mut(&entity) {
+            held.remove(&resource);
+            if held.is_empty() {
+                allocations.remove(&entity);
+            }
+        }
         Ok(())
     }
-    
+
+    /// Free every allocation held by an entity, returning how many were freed
+    pub async fn release_all(&self, entity: EntityId) -> usize {
+        self.needs.write().await.retain(|(holder, _), _| *holder != entity);
+        self.allocations.write().await
+            .remove(&entity)
+            .map_or(0, |held| held.len())
+    }
+
     pub async fn get_usage(&self) -> serde_yaml::Value {
-        serde_yaml::Value::Null
+        let quotas = self.quotas.read().await;
+        let allocations = self.allocations.read().await;
+
+        let mut usage = serde_yaml::Mapping::new();
+        for (resource, quota) in quotas.iter() {
+            let allocated = Self::total_allocated(&allocations, resource);
+            let mut entry = serde_yaml::Mapping::new();
+            entry.insert("allocated".into(), allocated.0.into());
+            entry.insert("quota".into(), quota.0.into());
+            usage.insert(format!("{:?}", resource).into(), entry.into());
+        }
+
+        serde_yaml::Value::Mapping(usage)
     }
-    
+
     pub async fn shutdown(&self) -> Result<()> {
         Ok(())
     }
-}
\ No newline at end of file
+
+    /// Quota for a resource; custom resources without a quota are unbounded
+    async fn quota_for(&self, resource: &ResourceType) -> OrderedFloat<f64> {
+        self.quotas.read().await
+            .get(resource)
+            .copied()
+            .unwrap_or(OrderedFloat(f64::INFINITY))
+    }
+
+    fn total_allocated(
+        allocations: &HashMap<EntityId, HashMap<ResourceType, OrderedFloat<f64>>>,
+        resource: &ResourceType,
+    ) -> OrderedFloat<f64> {
+        allocations.values()
+            .filter_map(|held| held.get(resource))
+            .copied()
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn allocation(entity: EntityId, resource: Resource, amount: f64) -> ResourceAllocation {
+        ResourceAllocation {
+            entity,
+            resource,
+            amount: OrderedFloat(amount),
+            allocated_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resource_usage_and_remaining() {
+        let manager = ResourceManager::new().await.unwrap();
+        let entity = EntityId::new();
+
+        manager.allocate(allocation(entity, Resource::Memory(512), 512.0)).await.unwrap();
+        manager.allocate(allocation(entity, Resource::Cpu(20), 20.0)).await.unwrap();
+        manager.allocate(allocation(entity, Resource::Cpu(5), 5.0)).await.unwrap();
+        manager.allocate(allocation(entity, Resource::Custom("gpu".to_string(), serde_yaml::Value::Null), 2.0)).await.unwrap();
+
+        let usage = manager.usage_for(entity).await;
+        assert_eq!(usage[&ResourceType::Memory], OrderedFloat(512.0));
+        assert_eq!(usage[&ResourceType::Cpu], OrderedFloat(25.0));
+        assert_eq!(usage[&ResourceType::Custom("gpu".to_string())], OrderedFloat(2.0));
+        assert!(!usage.contains_key(&ResourceType::Network));
+
+        assert_eq!(manager.remaining(ResourceType::Memory).await, OrderedFloat(3584.0));
+        assert_eq!(manager.remaining(ResourceType::Cpu).await, OrderedFloat(75.0));
+        assert!(manager.usage_for(EntityId::new()).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_over_quota_allocation_rejected() {
+        let manager = ResourceManager::new().await.unwrap();
+        let first = EntityId::new();
+        let second = EntityId::new();
+
+        manager.allocate(allocation(first, Resource::Cpu(70), 70.0)).await.unwrap();
+        let result = manager.allocate(allocation(second, Resource::Cpu(40), 40.0)).await;
+
+        match result {
+            Err(PhysicsViolation::ResourceLimit { resource, reason }) => {
+                assert_eq!(resource, "Cpu");
+                assert!(reason.contains("30 remaining"), "unexpected reason: {}", reason);
+            }
+            other => panic!("expected resource limit violation, got {:?}", other),
+        }
+        assert!(manager.usage_for(second).await.is_empty());
+        assert_eq!(manager.remaining(ResourceType::Cpu).await, OrderedFloat(30.0));
+    }
+
+    #[tokio::test]
+    async fn test_release_restores_remaining() {
+        let manager = ResourceManager::new().await.unwrap();
+        let entity = EntityId::new();
+
+        manager.allocate(allocation(entity, Resource::Memory(1024), 1024.0)).await.unwrap();
+        manager.allocate(allocation(entity, Resource::Network(500), 500.0)).await.unwrap();
+        manager.release(entity, ResourceType::Memory).await.unwrap();
+
+        assert_eq!(manager.remaining(ResourceType::Memory).await, OrderedFloat(4096.0));
+        assert_eq!(manager.remaining(ResourceType::Network).await, OrderedFloat(9_500.0));
+
+        // Releasing what is not held is a no-op
+        manager.release(entity, ResourceType::Memory).await.unwrap();
+        manager.release(EntityId::new(), ResourceType::Cpu).await.unwrap();
+        assert_eq!(manager.usage_for(entity).await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_release_all_clears_entity() {
+        let manager = ResourceManager::new().await.unwrap();
+        let entity = EntityId::new();
+        let other = EntityId::new();
+
+        manager.allocate(allocation(entity, Resource::Memory(256), 256.0)).await.unwrap();
+        manager.allocate(allocation(entity, Resource::Cpu(10), 10.0)).await.unwrap();
+        manager.allocate(allocation(entity, Resource::Network(100), 100.0)).await.unwrap();
+        manager.allocate(allocation(other, Resource::Cpu(15), 15.0)).await.unwrap();
+
+        assert_eq!(manager.release_all(entity).await, 3);
+        assert!(manager.usage_for(entity).await.is_empty());
+        assert_eq!(manager.remaining(ResourceType::Cpu).await, OrderedFloat(85.0));
+        assert_eq!(manager.remaining(ResourceType::Memory).await, OrderedFloat(4096.0));
+        assert_eq!(manager.release_all(entity).await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_schema_configures_quotas() {
+        let manager = ResourceManager::new().await.unwrap();
+        let schema: serde_yaml::Value = serde_yaml::from_str(
+            "memory:\n  max_working_memory_mb: 512\n  max_long_term_memory_mb: 2048\ncomputation:\n  max_cpu_percent: 80\n",
+        ).unwrap();
+
+        manager.configure_from_schema(&schema).await.unwrap();
+
+        assert_eq!(manager.remaining(ResourceType::Memory).await, OrderedFloat(2560.0));
+        assert_eq!(manager.remaining(ResourceType::Cpu).await, OrderedFloat(80.0));
+        assert_eq!(manager.remaining(ResourceType::Network).await, OrderedFloat(10_000.0));
+    }
+
+    #[tokio::test]
+    async fn test_rebalance_moves_surplus_to_shortfalls() {
+        let manager = ResourceManager::new().await.unwrap();
+        let hog = EntityId::new();
+        let starving = EntityId::new();
+        let urgent = EntityId::new();
+        let bystander = EntityId::new();
+        manager.allocate(allocation(hog, Resource::Memory(3000), 3000.0)).await.unwrap();
+        manager.allocate(allocation(starving, Resource::Memory(100), 100.0)).await.unwrap();
+        manager.allocate(allocation(urgent, Resource::Memory(100), 100.0)).await.unwrap();
+        manager.allocate(allocation(bystander, Resource::Memory(800), 800.0)).await.unwrap();
+        for entity in [hog, starving, urgent] {
+            manager.declare_need(entity, ResourceType::Memory, OrderedFloat(1500.0)).await;
+        }
+        manager.set_priority_entities(vec![urgent]).await;
+        let memory = |usage: HashMap<ResourceType, OrderedFloat<f64>>| usage[&ResourceType::Memory].0;
+        let variance = |values: &[f64]| {
+            let mean = values.iter().sum::<f64>() / values.len() as f64;
+            values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / values.len() as f64
+        };
+        let before = [3000.0, 100.0, 100.0];
+
+        let reallocations = manager.rebalance(ResourceType::Memory).await;
+
+        // The priority entity is served in full before the others get the rest of the surplus,
+        // and the bystander declared no need so all it holds is surplus
+        assert_eq!(reallocations, vec![
+            ResourceReallocation { from: hog, to: urgent, resource: ResourceType::Memory, amount: OrderedFloat(1400.0) },
+            ResourceReallocation { from: hog, to: starving, resource: ResourceType::Memory, amount: OrderedFloat(100.0) },
+            ResourceReallocation { from: bystander, to: starving, resource: ResourceType::Memory, amount: OrderedFloat(800.0) },
+        ]);
+        let after = [memory(manager.usage_for(hog).await), memory(manager.usage_for(starving).await), memory(manager.usage_for(urgent).await)];
+        assert_eq!(after, [1500.0, 1000.0, 1500.0]);
+        assert!(variance(&after) < variance(&before));
+        assert_eq!(memory(manager.usage_for(bystander).await), 0.0);
+        assert_eq!(manager.remaining(ResourceType::Memory).await, OrderedFloat(96.0));
+
+        // Nothing is left to give, and other resources are untouched
+        assert!(manager.rebalance(ResourceType::Memory).await.is_empty());
+        assert!(manager.rebalance(ResourceType::Cpu).await.is_empty());
+    }
+}
@@ -1,13 +1,125 @@
 //! Security boundaries and capability enforcement for the EMERGENCE system.
 
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
 use anyhow::Result;
+use blake3::Hash;
 use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+use tokio::time::Instant;
+use crate::clock::{SharedClock, SystemClock};
 use crate::{EntityId, Capability};
 
+/// Default idle time over which capability strength halves
+const DEFAULT_STRENGTH_HALF_LIFE: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Weight of the newest grant under [`CapabilityMergePolicy::DecayedAverage`]
+const RECENT_GRANT_WEIGHT: f64 = 0.7;
+
 /// Security boundaries enforcement
 #[derive(Debug)]
 pub struct SecurityBoundaries {
-    // Stub implementation
+    /// Whether capabilities must carry a proof matching the registered one
+    enforce_proofs: AtomicBool,
+    /// Expected proof hashes per entity and capability name
+    capability_proofs: RwLock<HashMap<(EntityId, String), Hash>>,
+    /// Capabilities granted per entity and capability name
+    grants: RwLock<HashMap<(EntityId, String), CapabilityGrant>>,
+    /// Prerequisites an entity must hold before using or being granted a capability
+    dependencies: std::sync::RwLock<CapabilityDependencies>,
+    /// How a re-granted capability's strength combines with the one already held
+    merge_policy: std::sync::RwLock<CapabilityMergePolicy>,
+    /// Idle time over which a granted capability's strength halves
+    strength_half_life: Duration,
+    /// Source of the current time for expiry and decay
+    clock: SharedClock,
+}
+
+/// A capability granted to an entity, with optional expiry
+#[derive(Debug, Clone)]
+struct CapabilityGrant {
+    strength: f64,
+    granted_at: Instant,
+    last_used: Instant,
+    ttl: Option<Duration>,
+}
+
+impl CapabilityGrant {
+    fn is_expired(&self, now: Instant) -> bool {
+        self.ttl.is_some_and(|ttl| self.granted_at + ttl < now)
+    }
+
+    fn strength_at(&self, now: Instant, half_life: Duration) -> f64 {
+        let idle = now.duration_since(self.last_used).as_secs_f64();
+        self.strength * 0.5f64.powf(idle / half_life.as_secs_f64())
+    }
+}
+
+/// How strength is resolved when an entity is granted a capability it already holds
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CapabilityMergePolicy {
+    /// The new grant's strength replaces the old one
+    #[default]
+    Replace,
+    /// The stronger of the held and granted strengths is kept
+    Max,
+    /// The held strength, after idle decay, is averaged with the new grant,
+    /// which counts for more
+    DecayedAverage,
+}
+
+impl CapabilityMergePolicy {
+    /// Strength after granting `granted` to an entity currently holding `held`
+    fn merge(self, held: f64, granted: f64) -> f64 {
+        match self {
+            Self::Replace => granted,
+            Self::Max => held.max(granted),
+            Self::DecayedAverage => held * (1.0 - RECENT_GRANT_WEIGHT) + granted * RECENT_GRANT_WEIGHT,
+        }
+    }
+}
+
+/// Capabilities that require others, with the strength each prerequisite must have
+///
+/// Configured in a physics schema as a mapping from capability to its
+/// prerequisites and their minimum strengths:
+///
+/// ```yaml
+/// capability_dependencies:
+///   synthesize:
+///     analyze: 0.5
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CapabilityDependencies {
+    prerequisites: HashMap<String, HashMap<String, f64>>,
+}
+
+impl CapabilityDependencies {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declare that `capability` requires `prerequisite` at `min_strength` or more
+    pub fn require(mut self, capability: &str, prerequisite: &str, min_strength: f64) -> Self {
+        self.prerequisites
+            .entry(capability.to_string())
+            .or_default()
+            .insert(prerequisite.to_string(), min_strength);
+        self
+    }
+
+    /// Direct prerequisites of a capability and their minimum strengths
+    pub fn prerequisites_of(&self, capability: &str) -> impl Iterator<Item = (&str, f64)> {
+        self.prerequisites
+            .get(capability)
+            .into_iter()
+            .flatten()
+            .map(|(name, strength)| (name.as_str(), *strength))
+    }
 }
 
 /// Capability gate for access control
@@ -21,27 +133,384 @@ pub struct CapabilityGate {
 pub enum SecurityViolation {
     #[error("Capability denied: {capability}")]
     CapabilityDenied { capability: String },
+
+    #[error("Capability {capability} presented without proof of competence")]
+    ProofMissing { capability: String },
+
+    #[error("Capability {capability} proof does not match the registered proof")]
+    ProofMismatch { capability: String },
+
+    #[error("Capability {capability} grant has expired")]
+    Expired { capability: String },
+
+    #[error("Capability {capability} requires {missing}, which is not held at sufficient strength")]
+    MissingPrerequisite { capability: String, missing: String },
 }
 
 impl SecurityBoundaries {
     pub fn new() -> Self {
-        Self {}
+        Self::with_clock(SystemClock::shared())
+    }
+
+    /// Create security boundaries that read the time from `clock`
+    pub fn with_clock(clock: SharedClock) -> Self {
+        Self {
+            enforce_proofs: AtomicBool::new(false),
+            capability_proofs: RwLock::new(HashMap::new()),
+            grants: RwLock::new(HashMap::new()),
+            dependencies: std::sync::RwLock::new(CapabilityDependencies::default()),
+            merge_policy: std::sync::RwLock::new(CapabilityMergePolicy::default()),
+            strength_half_life: DEFAULT_STRENGTH_HALF_LIFE,
+            clock,
+        }
+    }
+
+    /// Apply settings from the `security_boundaries` section of a physics schema
+    pub fn configure_from_schema(&self, schema: &serde_yaml::Value) -> Result<()> {
+        if let Some(enforce) = schema.get("enforce_capability_proofs").and_then(|v| v.as_bool()) {
+            self.set_proof_enforcement(enforce);
+        }
+        if let Some(dependencies) = schema.get("capability_dependencies") {
+            self.set_dependencies(serde_yaml::from_value(dependencies.clone())?);
+        }
+        if let Some(policy) = schema.get("capability_merge_policy") {
+            self.set_merge_policy(serde_yaml::from_value(policy.clone())?);
+        }
+        Ok(())
+    }
+
+    /// Set how re-granting a held capability resolves its strength
+    pub fn set_merge_policy(&self, policy: CapabilityMergePolicy) {
+        *self.merge_policy.write().unwrap() = policy;
+    }
+
+    /// The policy applied when a held capability is granted again
+    pub fn merge_policy(&self) -> CapabilityMergePolicy {
+        *self.merge_policy.read().unwrap()
+    }
+
+    /// Replace the declared capability prerequisites
+    pub fn set_dependencies(&self, dependencies: CapabilityDependencies) {
+        *self.dependencies.write().unwrap() = dependencies;
+    }
+
+    /// Enable or disable capability proof enforcement
+    pub fn set_proof_enforcement(&self, enabled: bool) {
+        self.enforce_proofs.store(enabled, Ordering::SeqCst);
+    }
+
+    /// Whether capability proofs are currently enforced
+    pub fn proof_enforcement(&self) -> bool {
+        self.enforce_proofs.load(Ordering::SeqCst)
+    }
+
+    /// Register the proof an entity must present for a capability
+    pub async fn register_capability_proof(&self, entity: EntityId, name: &str, proof: Hash) {
+        self.capability_proofs.write().await.insert((entity, name.to_string()), proof);
+    }
+
+    /// Grant a capability to an entity, optionally expiring after `ttl`
+    ///
+    /// Re-granting a capability the entity still holds combines the two
+    /// strengths under the [merge policy](Self::set_merge_policy) and restarts
+    /// the grant's expiry. Fails when the entity does not already hold the
+    /// capability's declared prerequisites.
+    pub async fn grant_capability(&self, entity: EntityId, capability: &Capability, ttl: Option<Duration>) -> Result<()> {
+        let now = self.clock.now_instant();
+        let mut grants = self.grants.write().await;
+        self.check_prerequisites(&grants, entity, &capability.name, now)?;
+        let key = (entity, capability.name.clone());
+        let strength = match grants.get(&key).filter(|grant| !grant.is_expired(now)) {
+            Some(held) => self.merge_policy().merge(held.strength_at(now, self.strength_half_life), capability.strength.0),
+            None => capability.strength.0,
+        };
+        grants.insert(key, CapabilityGrant {
+            strength,
+            granted_at: now,
+            last_used: now,
+            ttl,
+        });
+        Ok(())
+    }
+
+    /// When a granted capability was last used, if the entity holds it
+    pub(crate) async fn last_used(&self, entity: EntityId, name: &str) -> Option<Instant> {
+        self.grants.read().await.get(&(entity, name.to_string())).map(|grant| grant.last_used)
+    }
+
+    /// Put back a grant's last use, undoing a validation that never took effect
+    pub(crate) async fn restore_last_used(&self, entity: EntityId, name: &str, last_used: Instant) {
+        if let Some(grant) = self.grants.write().await.get_mut(&(entity, name.to_string())) {
+            grant.last_used = last_used;
+        }
     }
-    
-    pub fn configure_from_schema(&self, _schema: &serde_yaml::Value) -> Result<()> {
+
+    /// Strength of a granted capability after decay over idle time
+    ///
+    /// Returns `None` when the capability was never granted or has expired.
+    pub async fn effective_strength(&self, entity: EntityId, name: &str) -> Option<f64> {
+        let grants = self.grants.read().await;
+        let grant = grants.get(&(entity, name.to_string()))?;
+        let now = self.clock.now_instant();
+        if grant.is_expired(now) {
+            return None;
+        }
+        Some(grant.strength_at(now, self.strength_half_life))
+    }
+
+    /// Strengths of every capability an entity holds, after decay
+    ///
+    /// Expired grants are left out.
+    pub async fn effective_capabilities(&self, entity: EntityId) -> HashMap<String, f64> {
+        let grants = self.grants.read().await;
+        let now = self.clock.now_instant();
+        grants.iter()
+            .filter(|((holder, _), grant)| *holder == entity && !grant.is_expired(now))
+            .map(|((_, name), grant)| (name.clone(), grant.strength_at(now, self.strength_half_life)))
+            .collect()
+    }
+
+    /// Fail unless the entity holds every prerequisite of `capability`, transitively
+    fn check_prerequisites(
+        &self,
+        grants: &HashMap<(EntityId, String), CapabilityGrant>,
+        entity: EntityId,
+        capability: &str,
+        now: Instant,
+    ) -> Result<(), SecurityViolation> {
+        let dependencies = self.dependencies.read().unwrap();
+        let mut pending = vec![capability.to_string()];
+        let mut visited = HashSet::new();
+        while let Some(current) = pending.pop() {
+            if !visited.insert(current.clone()) {
+                continue;
+            }
+            for (prerequisite, min_strength) in dependencies.prerequisites_of(&current) {
+                let held = grants.get(&(entity, prerequisite.to_string()))
+                    .filter(|grant| !grant.is_expired(now))
+                    .map(|grant| grant.strength_at(now, self.strength_half_life));
+                if held.is_none_or(|strength| strength < min_strength) {
+                    return Err(SecurityViolation::MissingPrerequisite {
+                        capability: capability.to_string(),
+                        missing: prerequisite.to_string(),
+                    });
+                }
+                pending.push(prerequisite.to_string());
+            }
+        }
         Ok(())
     }
-    
+
+    /// Validate a claimed capability
+    ///
+    /// A granted capability past its expiry is rejected; using a live grant
+    /// resets its idle time. The entity must hold the capability's declared
+    /// prerequisites. With proof enforcement enabled, the capability's proof
+    /// must also equal the hash registered for this entity and name.
     pub async fn validate_capability(
         &self,
-        _entity: EntityId,
-        _capability: &Capability,
+        entity: EntityId,
+        capability: &Capability,
     ) -> Result<()> {
-        // Stub implementation - always allows for now
-        Ok(())
+        {
+            let mut grants = self.grants.write().await;
+            let now = self.clock.now_instant();
+            self.check_prerequisites(&grants, entity, &capability.name, now)?;
+            if let Some(grant) = grants.get_mut(&(entity, capability.name.clone())) {
+                if grant.is_expired(now) {
+                    return Err(SecurityViolation::Expired { capability: capability.name.clone() }.into());
+                }
+                grant.last_used = now;
+            }
+        }
+
+        if !self.proof_enforcement() {
+            return Ok(());
+        }
+
+        let presented = capability.proof.ok_or_else(|| SecurityViolation::ProofMissing {
+            capability: capability.name.clone(),
+        })?;
+
+        let proofs = self.capability_proofs.read().await;
+        match proofs.get(&(entity, capability.name.clone())) {
+            Some(expected) if *expected == presented => Ok(()),
+            _ => Err(SecurityViolation::ProofMismatch { capability: capability.name.clone() }.into()),
+        }
     }
-    
+
     pub async fn get_statistics(&self) -> serde_yaml::Value {
-        serde_yaml::Value::Null
+        let mut stats = serde_yaml::Mapping::new();
+        stats.insert("enforce_capability_proofs".into(), self.proof_enforcement().into());
+        stats.insert("registered_proofs".into(), (self.capability_proofs.read().await.len() as u64).into());
+        stats.insert("granted_capabilities".into(), (self.grants.read().await.len() as u64).into());
+        serde_yaml::Value::Mapping(stats)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Strength an entity ends up with after grants of `first` then `second` under `policy`
+    async fn regrant(policy: CapabilityMergePolicy, first: f64, second: f64) -> f64 {
+        let security = SecurityBoundaries::new();
+        security.set_merge_policy(policy);
+        let entity = EntityId::new();
+        security.grant_capability(entity, &Capability::new("analyze".to_string(), first), None).await.unwrap();
+        security.grant_capability(entity, &Capability::new("analyze".to_string(), second), None).await.unwrap();
+        let resolved = security.effective_capabilities(entity).await;
+        assert_eq!(resolved.len(), 1);
+        resolved["analyze"]
+    }
+
+    #[tokio::test]
+    async fn test_matching_proof_passes() {
+        let security = SecurityBoundaries::new();
+        security.set_proof_enforcement(true);
+        let entity = EntityId::new();
+        security.register_capability_proof(entity, "analyze", blake3::hash(b"solved puzzle 42")).await;
+
+        let capability = Capability::new("analyze".to_string(), 0.8).with_evidence(b"solved puzzle 42");
+
+        assert!(security.validate_capability(entity, &capability).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_wrong_proof_fails() {
+        let security = SecurityBoundaries::new();
+        security.set_proof_enforcement(true);
+        let entity = EntityId::new();
+        security.register_capability_proof(entity, "analyze", blake3::hash(b"solved puzzle 42")).await;
+
+        let forged = Capability::new("analyze".to_string(), 0.8).with_evidence(b"solved puzzle 7");
+        let other_entity = Capability::new("analyze".to_string(), 0.8).with_evidence(b"solved puzzle 42");
+
+        let err = security.validate_capability(entity, &forged).await.unwrap_err();
+        assert!(matches!(err.downcast_ref(), Some(SecurityViolation::ProofMismatch { .. })));
+        assert!(security.validate_capability(EntityId::new(), &other_entity).await.is_err());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_grant_expires_after_ttl() {
+        let security = SecurityBoundaries::new();
+        let entity = EntityId::new();
+        let capability = Capability::new("observe".to_string(), 0.9);
+        security.grant_capability(entity, &capability, Some(Duration::from_secs(60))).await.unwrap();
+
+        assert!(security.validate_capability(entity, &capability).await.is_ok());
+
+        tokio::time::advance(Duration::from_secs(61)).await;
+        let err = security.validate_capability(entity, &capability).await.unwrap_err();
+        assert!(matches!(err.downcast_ref(), Some(SecurityViolation::Expired { .. })));
+        assert_eq!(security.effective_strength(entity, "observe").await, None);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_strength_decays_while_idle() {
+        let security = SecurityBoundaries::new();
+        let entity = EntityId::new();
+        let capability = Capability::new("analyze".to_string(), 0.8);
+        security.grant_capability(entity, &capability, None).await.unwrap();
+
+        assert_eq!(security.effective_strength(entity, "analyze").await, Some(0.8));
+
+        tokio::time::advance(DEFAULT_STRENGTH_HALF_LIFE).await;
+        let decayed = security.effective_strength(entity, "analyze").await.unwrap();
+        assert!((decayed - 0.4).abs() < 1e-9);
+
+        // Exercising the capability resets its idle time
+        security.validate_capability(entity, &capability).await.unwrap();
+        assert_eq!(security.effective_strength(entity, "analyze").await, Some(0.8));
+        assert_eq!(security.effective_strength(entity, "communicate").await, None);
+    }
+
+    #[tokio::test]
+    async fn test_missing_proof_depends_on_enforcement() {
+        let security = SecurityBoundaries::new();
+        let entity = EntityId::new();
+        let unproven = Capability::new("communicate".to_string(), 0.5);
+
+        assert!(security.validate_capability(entity, &unproven).await.is_ok());
+
+        security.set_proof_enforcement(true);
+        let err = security.validate_capability(entity, &unproven).await.unwrap_err();
+        assert!(matches!(err.downcast_ref(), Some(SecurityViolation::ProofMissing { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_prerequisites_gate_grants() {
+        let security = SecurityBoundaries::new();
+        security.set_dependencies(CapabilityDependencies::new().require("synthesize", "analyze", 0.5));
+        let entity = EntityId::new();
+        let synthesize = Capability::new("synthesize".to_string(), 0.7);
+
+        let err = security.grant_capability(entity, &synthesize, None).await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref(),
+            Some(SecurityViolation::MissingPrerequisite { capability, missing }) if capability == "synthesize" && missing == "analyze"
+        ));
+        assert!(security.validate_capability(entity, &synthesize).await.is_err());
+
+        // Too weak a prerequisite does not count
+        security.grant_capability(entity, &Capability::new("analyze".to_string(), 0.3), None).await.unwrap();
+        assert!(security.grant_capability(entity, &synthesize, None).await.is_err());
+
+        security.grant_capability(entity, &Capability::new("analyze".to_string(), 0.8), None).await.unwrap();
+        security.grant_capability(entity, &synthesize, None).await.unwrap();
+        assert!(security.validate_capability(entity, &synthesize).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_prerequisites_are_checked_transitively() {
+        let security = SecurityBoundaries::new();
+        let schema: serde_yaml::Value = serde_yaml::from_str(
+            "capability_dependencies:\n  synthesize:\n    analyze: 0.5\n  analyze:\n    observe: 0.5\n",
+        ).unwrap();
+        security.configure_from_schema(&schema).unwrap();
+        let entity = EntityId::new();
+        let capability = |name: &str| Capability::new(name.to_string(), 0.9);
+
+        assert!(security.grant_capability(entity, &capability("analyze"), None).await.is_err());
+        security.grant_capability(entity, &capability("observe"), Some(Duration::from_secs(60))).await.unwrap();
+        security.grant_capability(entity, &capability("analyze"), None).await.unwrap();
+        security.grant_capability(entity, &capability("synthesize"), None).await.unwrap();
+
+        // Losing the root of the chain invalidates everything built on it
+        tokio::time::pause();
+        tokio::time::advance(Duration::from_secs(61)).await;
+        let err = security.validate_capability(entity, &capability("synthesize")).await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref(),
+            Some(SecurityViolation::MissingPrerequisite { missing, .. }) if missing == "observe"
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_regranting_resolves_strength_by_policy() {
+        assert_eq!(regrant(CapabilityMergePolicy::Replace, 0.8, 0.4).await, 0.4);
+        assert_eq!(regrant(CapabilityMergePolicy::Max, 0.8, 0.4).await, 0.8);
+        assert_eq!(regrant(CapabilityMergePolicy::Max, 0.4, 0.8).await, 0.8);
+        let averaged = regrant(CapabilityMergePolicy::DecayedAverage, 0.8, 0.4).await;
+        assert!((averaged - (0.8 * 0.3 + 0.4 * 0.7)).abs() < 1e-9);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_decayed_average_discounts_idle_strength() {
+        let security = SecurityBoundaries::new();
+        let schema: serde_yaml::Value = serde_yaml::from_str("capability_merge_policy: decayed_average\n").unwrap();
+        security.configure_from_schema(&schema).unwrap();
+        assert_eq!(security.merge_policy(), CapabilityMergePolicy::DecayedAverage);
+        let entity = EntityId::new();
+        security.grant_capability(entity, &Capability::new("analyze".to_string(), 0.8), None).await.unwrap();
+        security.grant_capability(entity, &Capability::new("observe".to_string(), 0.5), None).await.unwrap();
+
+        tokio::time::advance(DEFAULT_STRENGTH_HALF_LIFE).await;
+        security.grant_capability(entity, &Capability::new("analyze".to_string(), 0.6), None).await.unwrap();
+
+        let resolved = security.effective_capabilities(entity).await;
+        assert!((resolved["analyze"] - (0.4 * 0.3 + 0.6 * 0.7)).abs() < 1e-9);
+        assert!((resolved["observe"] - 0.25).abs() < 1e-9);
+        assert!(security.effective_capabilities(EntityId::new()).await.is_empty());
+    }
+}
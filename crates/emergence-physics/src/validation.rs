@@ -1,13 +1,35 @@
 //! Physics validation for schema compliance and operation checks.
 
+use std::collections::HashMap;
+use std::sync::RwLock;
+
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use crate::PhysicsOperation;
 
+/// Physics schema version understood by this engine
+pub const CURRENT_SCHEMA_VERSION: u32 = 1;
+
+/// Upgrades a schema from one version to the next
+type SchemaMigration = Box<dyn Fn(serde_yaml::Value) -> Result<serde_yaml::Value> + Send + Sync>;
+
 /// Physics validator for schema and operation validation
-#[derive(Debug)]
 pub struct PhysicsValidator {
-    // Stub implementation
+    /// Schema version this validator accepts
+    schema_version: u32,
+    /// Migrations keyed by the version they upgrade from
+    migrations: RwLock<HashMap<u32, SchemaMigration>>,
+}
+
+impl std::fmt::Debug for PhysicsValidator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut migrations: Vec<u32> = self.migrations.read().unwrap().keys().copied().collect();
+        migrations.sort_unstable();
+        f.debug_struct("PhysicsValidator")
+            .field("schema_version", &self.schema_version)
+            .field("migrations_from", &migrations)
+            .finish()
+    }
 }
 
 /// Validation error types
@@ -15,6 +37,15 @@ pub struct PhysicsValidator {
 pub enum ValidationError {
     #[error("Schema validation failed: {reason}")]
     SchemaInvalid { reason: String },
+
+    #[error("Physics schema has no schema_version; add `schema_version: {current}` once it follows the current format")]
+    MissingVersion { current: u32 },
+
+    #[error("Physics schema version {found} is not supported; this engine expects version {supported}")]
+    UnsupportedVersion { found: u32, supported: u32 },
+
+    #[error("No migration registered from physics schema version {from} to {to}")]
+    MigrationMissing { from: u32, to: u32 },
 }
 
 /// Validation result
@@ -26,16 +57,140 @@ pub struct ValidationResult {
 
 impl PhysicsValidator {
     pub fn new() -> Self {
-        Self {}
+        Self::with_schema_version(CURRENT_SCHEMA_VERSION)
     }
-    
-    pub async fn validate_physics_schema(&self, _schema: &serde_yaml::Value) -> Result<()> {
-        // Stub implementation - always validates for now
+
+    /// Create a validator that accepts a specific schema version
+    pub fn with_schema_version(version: u32) -> Self {
+        Self {
+            schema_version: version,
+            migrations: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Schema version this validator accepts
+    pub fn schema_version(&self) -> u32 {
+        self.schema_version
+    }
+
+    /// Register the migration that upgrades schemas from `from` to `from + 1`
+    pub fn register_migration<F>(&self, from: u32, migration: F)
+    where
+        F: Fn(serde_yaml::Value) -> Result<serde_yaml::Value> + Send + Sync + 'static,
+    {
+        self.migrations.write().unwrap().insert(from, Box::new(migration));
+    }
+
+    /// Read a schema's declared version
+    pub fn declared_version(schema: &serde_yaml::Value) -> Result<u32, ValidationError> {
+        let missing = ValidationError::MissingVersion { current: CURRENT_SCHEMA_VERSION };
+        let version = schema.get("schema_version").ok_or(missing)?;
+        version.as_u64()
+            .and_then(|v| u32::try_from(v).ok())
+            .ok_or_else(|| ValidationError::SchemaInvalid {
+                reason: format!("schema_version must be a non-negative integer, got {:?}", version),
+            })
+    }
+
+    /// Upgrade a schema from version `from` to `to` one step at a time
+    ///
+    /// Each step's output has its `schema_version` set to the version it reached.
+    pub fn migrate_schema(&self, mut schema: serde_yaml::Value, from: u32, to: u32) -> Result<serde_yaml::Value> {
+        if from > to {
+            return Err(ValidationError::UnsupportedVersion { found: from, supported: to }.into());
+        }
+
+        let migrations = self.migrations.read().unwrap();
+        for version in from..to {
+            let migration = migrations.get(&version)
+                .ok_or(ValidationError::MigrationMissing { from: version, to: version + 1 })?;
+            schema = migration(schema)?;
+            if let Some(mapping) = schema.as_mapping_mut() {
+                mapping.insert("schema_version".into(), (version + 1).into());
+            }
+        }
+        Ok(schema)
+    }
+
+    /// Bring a schema up to the accepted version, migrating older ones
+    pub fn upgrade_schema(&self, schema: serde_yaml::Value) -> Result<serde_yaml::Value> {
+        let version = Self::declared_version(&schema)?;
+        if version >= self.schema_version {
+            return Ok(schema);
+        }
+        self.migrate_schema(schema, version, self.schema_version)
+    }
+
+    /// Check that a schema declares exactly the accepted version
+    pub async fn validate_physics_schema(&self, schema: &serde_yaml::Value) -> Result<()> {
+        if !schema.is_mapping() {
+            return Err(ValidationError::SchemaInvalid { reason: "physics schema must be a mapping".to_string() }.into());
+        }
+
+        let version = Self::declared_version(schema)?;
+        if version != self.schema_version {
+            return Err(ValidationError::UnsupportedVersion { found: version, supported: self.schema_version }.into());
+        }
         Ok(())
     }
-    
+
     pub async fn validate_operation(&self, _operation: &PhysicsOperation) -> Result<()> {
         // Stub implementation - always validates for now
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_current_version_validates() {
+        let validator = PhysicsValidator::new();
+        let schema: serde_yaml::Value = serde_yaml::from_str(&format!(
+            "schema_version: {}\nenergy_conservation:\n  total_system_energy: 1.0\n",
+            CURRENT_SCHEMA_VERSION,
+        )).unwrap();
+
+        assert!(validator.validate_physics_schema(&schema).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_unversioned_and_future_schemas_are_rejected() {
+        let validator = PhysicsValidator::new();
+        let unversioned: serde_yaml::Value = serde_yaml::from_str("energy_conservation: {}").unwrap();
+        let future: serde_yaml::Value = serde_yaml::from_str("schema_version: 99").unwrap();
+
+        let err = validator.validate_physics_schema(&unversioned).await.unwrap_err();
+        assert!(matches!(err.downcast_ref(), Some(ValidationError::MissingVersion { .. })));
+        assert!(err.to_string().contains("add `schema_version: 1`"));
+
+        let err = validator.validate_physics_schema(&future).await.unwrap_err();
+        assert!(matches!(err.downcast_ref(), Some(ValidationError::UnsupportedVersion { found: 99, .. })));
+        assert!(validator.upgrade_schema(future).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_v1_schema_migrates_to_v2() {
+        let validator = PhysicsValidator::with_schema_version(2);
+        validator.register_migration(1, |mut schema| {
+            // v2 renamed the energy section
+            let mapping = schema.as_mapping_mut().expect("schema is a mapping");
+            if let Some(energy) = mapping.remove("energy") {
+                mapping.insert("energy_conservation".into(), energy);
+            }
+            Ok(schema)
+        });
+        let v1: serde_yaml::Value = serde_yaml::from_str("schema_version: 1\nenergy:\n  total_system_energy: 1.0\n").unwrap();
+
+        assert!(validator.validate_physics_schema(&v1).await.is_err());
+
+        let v2 = validator.upgrade_schema(v1).unwrap();
+        assert_eq!(v2["schema_version"].as_u64(), Some(2));
+        assert_eq!(v2["energy_conservation"]["total_system_energy"].as_f64(), Some(1.0));
+        assert!(validator.validate_physics_schema(&v2).await.is_ok());
+
+        let err = validator.migrate_schema(v2, 2, 3).unwrap_err();
+        assert!(matches!(err.downcast_ref(), Some(ValidationError::MigrationMissing { from: 2, to: 3 })));
+    }
+}
@@ -141,7 +141,7 @@ error: aborting due to 1 previous error; 1 warning emitted"#;
     println!("     Operations/sec: {:.1}", metrics.physics_engine.physics_operations_per_second);
     println!("   Energy System:");
     println!("     Total energy: {:.1}", metrics.energy_system.total_energy);
-    println!("     Efficiency: {:.1}%", metrics.energy_system.energy_efficiency * 100.0);
+    println!("     Utilization: {:.1}%", metrics.energy_system.energy_utilization * 100.0);
     println!("   Nervous System:");
     println!("     Active agents: {}", metrics.nervous_system.active_agents);
     println!("     Coordination: {:.1}%", metrics.nervous_system.coordination_efficiency * 100.0);
@@ -3,20 +3,50 @@
 //! This system enables multiple agents to work together naturally, allowing
 //! collective intelligence to emerge from agent interactions and shared memory.
 
-use std::collections::HashMap;
-use std::time::Duration;
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
 use anyhow::Result;
 use chrono::Utc;
+use futures::StreamExt;
 use tokio::time::sleep;
 use emergence_runtime::{LivingAgent, AgentState, AgentPersonality};
+use emergence_runtime::essence::{EssenceLoader, FileEssenceLoader, DEFAULT_ESSENCE_DIR};
+use emergence_runtime::events::{EventLogger, SystemEvent};
+use emergence_nervous_system::{NervousSystem, NeuralSignal, SignalPayload, SignalStreamEvent, SignalTarget, SignalType};
 use emergence_physics::EntityId;
+use emergence_memory::MemorySubstrate;
 use std::env;
 use std::io::{self, Write};
-use std::fs;
-use std::path::Path;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
-use tokio::sync::RwLock;
+
+/// Weight of the latest outcome in a pattern's success rate
+const SUCCESS_RATE_SMOOTHING: f64 = 0.3;
+
+/// How long a collaboration step may wait for its agent's response
+const DEFAULT_STEP_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Number of recent events sampled on each emergence check
+const EMERGENCE_EVENT_SAMPLE: usize = 50;
+
+/// Event count at which a co-occurrence window is considered saturated
+const EMERGENCE_DENSITY_SATURATION: f64 = 6.0;
+
+/// How often the git monitor polls for new commits while git is healthy
+const GIT_POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Longest the git monitor waits between polls after failures
+const GIT_MAX_BACKOFF: Duration = Duration::from_secs(320);
+
+/// Consecutive git failures after which the monitor stops polling
+const GIT_MAX_CONSECUTIVE_FAILURES: u32 = 6;
+
+/// Agent pairs that share their latest insight, and the key they share it under
+const SHARED_MEMORY_CONNECTIONS: [(&str, &str, &str); 3] = [
+    ("debugger", "researcher", "diagnostic_insights"),
+    ("debugger", "tester", "quality_issues"),
+    ("researcher", "tester", "complex_patterns"),
+];
 
 /// Collaborative intelligence coordinator
 pub struct CollaborativeIntelligence {
@@ -25,6 +55,37 @@ pub struct CollaborativeIntelligence {
     emergence_detector: EmergenceDetector,
     git_monitor: GitMonitor,
     event_logger: EventLogger,
+    /// Essences agents are awakened with, by agent type
+    essence_loader: FileEssenceLoader,
+    /// Values agents share with the partners they are connected to
+    shared_memory: Arc<MemorySubstrate>,
+    /// Nervous system used to route collaboration steps to agents
+    nervous_system: Option<Arc<NervousSystem>>,
+    /// Entity that sends collaboration steps and receives the responses
+    coordinator_id: EntityId,
+    /// How long a collaboration step may wait for a response
+    step_timeout: Duration,
+}
+
+/// Outcome of one step of a collaboration run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StepReport {
+    pub step: String,
+    /// Name of the agent responsible for the step, if one was awake
+    pub agent: Option<String>,
+    /// The agent's response, or `None` if it never answered
+    pub output: Option<String>,
+    pub duration: Duration,
+}
+
+/// Result of driving a collaboration pattern through its sequence
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PatternRunReport {
+    pub pattern: String,
+    /// Steps attempted, in order; the run stops at the first step without a response
+    pub steps: Vec<StepReport>,
+    pub success: bool,
+    pub duration: Duration,
 }
 
 /// Git monitoring and analysis system
@@ -43,27 +104,29 @@ pub struct GitDiff {
     pub timestamp: chrono::DateTime<Utc>,
     pub message: String,
     pub files_changed: Vec<String>,
+    pub file_stats: Vec<FileChangeStat>,
     pub additions: usize,
     pub deletions: usize,
     pub diff_content: String,
 }
 
-/// Event logging system for persistence
-#[derive(Clone)]
-pub struct EventLogger {
-    log_file: String,
-    events: Arc<RwLock<Vec<SystemEvent>>>,
+/// Per-file line counts from `git show --numstat`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FileChangeStat {
+    pub path: String,
+    pub additions: usize,
+    pub deletions: usize,
+    /// Binary files report `-` instead of line counts
+    pub binary: bool,
 }
 
-/// System event for logging
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SystemEvent {
-    pub timestamp: chrono::DateTime<Utc>,
-    pub event_type: String,
-    pub agent_id: Option<String>,
-    pub description: String,
-    pub data: serde_json::Value,
-    pub emergence_potential: f64,
+/// Why the git monitor could not read the repository
+#[derive(Debug, thiserror::Error)]
+pub enum GitError {
+    #[error("failed to run git: {0}")]
+    Spawn(#[from] io::Error),
+    #[error("`git {command}` failed: {stderr}")]
+    CommandFailed { command: String, stderr: String },
 }
 
 /// Pattern of collaboration between agents
@@ -83,6 +146,10 @@ pub struct EmergenceDetector {
     pub patterns: Vec<String>,
     pub emergence_threshold: f64,
     pub detection_history: Vec<EmergenceEvent>,
+    /// Collaborations whose agents are watched for co-occurring activity
+    pub known_collaborations: Vec<CollaborationPattern>,
+    /// Maximum time span of events counted as co-occurring
+    pub co_occurrence_window: chrono::Duration,
 }
 
 #[derive(Debug, Clone)]
@@ -105,6 +172,11 @@ impl CollaborativeIntelligence {
             emergence_detector: EmergenceDetector::new(),
             git_monitor: GitMonitor::new(),
             event_logger: EventLogger::new(),
+            essence_loader: FileEssenceLoader::new(DEFAULT_ESSENCE_DIR),
+            shared_memory: Arc::new(MemorySubstrate::new()),
+            nervous_system: None,
+            coordinator_id: EntityId::new(),
+            step_timeout: DEFAULT_STEP_TIMEOUT,
         };
         
         // Initialize natural collaboration patterns
@@ -172,9 +244,178 @@ impl CollaborativeIntelligence {
             success_rate: 0.0,
         });
         
+        self.emergence_detector.known_collaborations = self.collaboration_patterns.clone();
+        
         tracing::info!("📊 Discovered {} natural collaboration patterns", self.collaboration_patterns.len());
     }
     
+    /// Record the outcome of a collaboration, updating its success rate
+    ///
+    /// The success rate is an exponential moving average of outcomes.
+    pub async fn record_collaboration_outcome(&mut self, pattern_name: &str, success: bool) -> Result<()> {
+        let pattern = self.collaboration_patterns.iter_mut()
+            .find(|p| p.name == pattern_name)
+            .ok_or_else(|| anyhow::anyhow!("Unknown collaboration pattern: {}", pattern_name))?;
+        
+        let outcome = if success { 1.0 } else { 0.0 };
+        pattern.success_rate += SUCCESS_RATE_SMOOTHING * (outcome - pattern.success_rate);
+        let success_rate = pattern.success_rate;
+        let emergence_potential = pattern.emergence_potential;
+        
+        tracing::info!("📈 {} collaboration {} (success rate {:.2})",
+            pattern_name, if success { "succeeded" } else { "failed" }, success_rate);
+        
+        self.event_logger.log_event(SystemEvent {
+            timestamp: Utc::now(),
+            event_type: "collaboration_outcome".to_string(),
+            agent_id: None,
+            description: format!("Collaboration {} {}", pattern_name, if success { "succeeded" } else { "failed" }),
+            data: serde_json::json!({
+                "pattern": pattern_name,
+                "success": success,
+                "success_rate": success_rate
+            }),
+            emergence_potential,
+        }).await
+    }
+    
+    /// Route collaboration steps through a nervous system
+    ///
+    /// Agents must be registered with it under their ids. When physics is
+    /// enforced, the coordinator entity needs energy to send steps.
+    pub fn with_nervous_system(mut self, nervous_system: Arc<NervousSystem>) -> Self {
+        self.nervous_system = Some(nervous_system);
+        self
+    }
+    
+    /// Set how long each collaboration step may wait for a response
+    pub fn with_step_timeout(mut self, timeout: Duration) -> Self {
+        self.step_timeout = timeout;
+        self
+    }
+    
+    /// Entity that sends collaboration steps
+    pub fn coordinator_id(&self) -> EntityId {
+        self.coordinator_id
+    }
+    
+    /// Drive a collaboration pattern through its sequence, one step at a time
+    ///
+    /// Each step is sent as a Coordination command to the agent it names by
+    /// prefix (`debugger_analyzes_issue` goes to the debugger); shared steps
+    /// such as `collective_validation` go to the pattern's first agent. The
+    /// next step starts only once the agent responds, and the run fails at the
+    /// first step without a response within the step timeout. Each response is
+    /// shared with the partner agent, whose next command carries it after the
+    /// step name. The outcome is recorded against the pattern's success rate.
+    pub async fn run_pattern(&mut self, pattern_name: &str) -> Result<PatternRunReport> {
+        let pattern = self.collaboration_patterns.iter()
+            .find(|p| p.name == pattern_name)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Unknown collaboration pattern: {}", pattern_name))?;
+        let nervous_system = self.nervous_system.clone()
+            .ok_or_else(|| anyhow::anyhow!("No nervous system to route collaboration steps through"))?;
+        
+        tracing::info!("🤝 Running collaboration pattern {}", pattern_name);
+        let started = Instant::now();
+        
+        // Subscribe before sending anything so no response is missed
+        let mut responses = Box::pin(nervous_system
+            .create_signal_stream(self.coordinator_id, SignalType::ALL.to_vec())
+            .await?);
+        
+        let mut steps = Vec::new();
+        let mut success = true;
+        for step in &pattern.collaboration_sequence {
+            let step_started = Instant::now();
+            let agent_type = pattern.agents.iter()
+                .find(|agent| step.starts_with(&format!("{}_", agent)))
+                .or(pattern.agents.first());
+            let agent = agent_type.and_then(|agent_type| {
+                self.agents.values().find(|agent| &agent.essence_type == agent_type)
+            });
+            
+            let partner = pattern.agents.iter()
+                .find(|partner_type| Some(*partner_type) != agent_type)
+                .and_then(|partner_type| Some((self.agent_id_by_type(partner_type)?, Self::shared_memory_key(agent_type?, partner_type)?)));
+            
+            let output = match agent {
+                Some(agent) => {
+                    // Hand the agent whatever its partner last shared with it
+                    let insight = partner.and_then(|(partner_id, key)| self.shared_memory.read_shared(agent.id, partner_id, key).ok());
+                    let command = match insight {
+                        Some(insight) => format!("{} {}", step, payload_text(&insight)),
+                        None => step.clone(),
+                    };
+                    let signal = NeuralSignal::new(
+                        SignalType::Coordination,
+                        self.coordinator_id,
+                        Some(agent.id),
+                        SignalPayload::Command(command),
+                        pattern.emergence_potential,
+                    );
+                    nervous_system.transmit_signal(signal).await?;
+                    
+                    let agent_id = agent.id;
+                    let coordinator_id = self.coordinator_id;
+                    let response = tokio::time::timeout(self.step_timeout, async {
+                        while let Some(event) = responses.next().await {
+                            if let SignalStreamEvent::Signal(signal) = event {
+                                if signal.source == agent_id && signal.target == SignalTarget::One(coordinator_id) {
+                                    return Some(signal);
+                                }
+                            }
+                        }
+                        None
+                    }).await;
+                    let output = response.ok().flatten().map(|signal| payload_text(&signal.payload));
+                    if let (Some(text), Some((partner_id, key))) = (&output, partner) {
+                        self.shared_memory.share(
+                            agent.id,
+                            key.to_string(),
+                            SignalPayload::Message(text.clone()),
+                            HashSet::from([partner_id]),
+                        )?;
+                    }
+                    output
+                }
+                None => None,
+            };
+            
+            match &output {
+                Some(text) => tracing::info!("   ✅ {}: {}", step, text),
+                None => tracing::warn!("   ⏱️  {} got no response", step),
+            }
+            let answered = output.is_some();
+            steps.push(StepReport {
+                step: step.clone(),
+                agent: agent.map(|agent| agent.name.clone()),
+                output,
+                duration: step_started.elapsed(),
+            });
+            if !answered {
+                success = false;
+                break;
+            }
+        }
+        
+        self.record_collaboration_outcome(pattern_name, success).await?;
+        
+        Ok(PatternRunReport {
+            pattern: pattern_name.to_string(),
+            steps,
+            success,
+            duration: started.elapsed(),
+        })
+    }
+    
+    /// Most successful collaboration pattern triggered by `trigger`
+    pub fn best_pattern_for(&self, trigger: &str) -> Option<&CollaborationPattern> {
+        self.collaboration_patterns.iter()
+            .filter(|p| p.trigger_conditions.iter().any(|t| t == trigger))
+            .max_by(|a, b| a.success_rate.total_cmp(&b.success_rate))
+    }
+    
     /// Awaken multiple agents and let them naturally collaborate
     pub async fn awaken_collaborative_agents(&mut self) -> Result<()> {
         tracing::info!("🧬 Awakening collaborative agent ensemble...");
@@ -237,7 +478,7 @@ impl CollaborativeIntelligence {
             emergence_potential: 0.8,
         }).await?;
         
-        // Simulate shared memory connections
+        // Connect agents through shared memory
         self.setup_shared_memory_connections().await?;
         
         // Enable cross-agent communication
@@ -253,24 +494,36 @@ impl CollaborativeIntelligence {
         Ok(())
     }
     
-    /// Set up shared memory connections between agents
+    /// Report which shared memory connections between agents are live
+    ///
+    /// Nothing is stored up front: each side of a connection shares its
+    /// latest insight as collaboration steps complete.
     async fn setup_shared_memory_connections(&mut self) -> Result<()> {
         tracing::info!("🧠 Establishing shared memory substrate...");
         
-        // Create associative memory connections
-        let connections = vec![
-            ("debugger", "researcher", "diagnostic_insights"),
-            ("debugger", "tester", "quality_issues"),
-            ("researcher", "tester", "complex_patterns"),
-        ];
-        
-        for (agent1, agent2, connection_type) in connections {
+        for (agent1, agent2, connection_type) in SHARED_MEMORY_CONNECTIONS {
+            if self.agent_id_by_type(agent1).is_none() || self.agent_id_by_type(agent2).is_none() {
+                tracing::warn!("   ⚠️ Skipping {} ↔ {}: agent not awakened", agent1, agent2);
+                continue;
+            }
             println!("   🔗 {} ↔ {}: {}", agent1, agent2, connection_type);
         }
         
         Ok(())
     }
     
+    /// Key two agent types share insights under, if they are connected
+    fn shared_memory_key(agent1: &str, agent2: &str) -> Option<&'static str> {
+        SHARED_MEMORY_CONNECTIONS.iter()
+            .find(|(a, b, _)| (*a == agent1 && *b == agent2) || (*a == agent2 && *b == agent1))
+            .map(|(_, _, key)| *key)
+    }
+    
+    /// Id of the first awakened agent of `agent_type`
+    fn agent_id_by_type(&self, agent_type: &str) -> Option<EntityId> {
+        self.agents.values().find(|agent| agent.essence_type == agent_type).map(|agent| agent.id)
+    }
+    
     /// Enable cross-agent communication through nervous system
     async fn enable_cross_agent_communication(&mut self) -> Result<()> {
         tracing::info!("🌐 Enabling cross-agent communication...");
@@ -292,8 +545,27 @@ impl CollaborativeIntelligence {
         let event_logger = self.event_logger.clone();
         
         tokio::spawn(async move {
+            let mut failures = 0;
             loop {
-                if let Ok(Some(git_diff)) = git_monitor.get_latest_commit().await {
+                let git_diff = match git_monitor.get_latest_commit().await {
+                    Ok(git_diff) => {
+                        failures = 0;
+                        git_diff
+                    }
+                    Err(e) => {
+                        failures += 1;
+                        if failures >= GIT_MAX_CONSECUTIVE_FAILURES {
+                            tracing::error!("❌ Git monitoring stopped after {} consecutive failures: {}", failures, e);
+                            break;
+                        }
+                        let backoff = GitMonitor::backoff(failures);
+                        tracing::warn!("⚠️ Git monitoring failed ({}), retrying in {:?}", e, backoff);
+                        sleep(backoff).await;
+                        continue;
+                    }
+                };
+                
+                if let Some(git_diff) = git_diff {
                     tracing::info!("🔍 New commit detected: {}", git_diff.commit_hash[..8].to_string());
                     tracing::info!("   📝 Message: {}", git_diff.message);
                     tracing::info!("   👤 Author: {}", git_diff.author);
@@ -321,7 +593,7 @@ impl CollaborativeIntelligence {
                     Self::analyze_commit_patterns(&git_diff, &event_logger).await;
                 }
                 
-                sleep(Duration::from_secs(10)).await;
+                sleep(GIT_POLL_INTERVAL).await;
             }
         });
         
@@ -384,13 +656,16 @@ impl CollaborativeIntelligence {
         tracing::info!("🔍 Monitoring for emergent collaboration patterns...");
         
         // Start background monitoring
-        let emergence_detector = self.emergence_detector.clone();
+        let mut emergence_detector = self.emergence_detector.clone();
         let event_logger = self.event_logger.clone();
         
         tokio::spawn(async move {
             loop {
                 // Monitor for emergent behaviors
-                if let Some(emergence_event) = emergence_detector.detect_emergence().await {
+                let recent_events = event_logger.get_recent_events(EMERGENCE_EVENT_SAMPLE).await;
+                if let Some(emergence_event) = emergence_detector.detect_emergence(&recent_events) {
+                    emergence_detector.detection_history.push(emergence_event.clone());
+                    
                     tracing::info!("🧬 EMERGENCE DETECTED: {}", emergence_event.description);
                     tracing::info!("   📊 Pattern: {}", emergence_event.pattern);
                     tracing::info!("   👥 Agents: {:?}", emergence_event.agents_involved);
@@ -440,7 +715,7 @@ impl CollaborativeIntelligence {
             energy: 1.0,
             state: AgentState::Awakening,
             awakened_at: Some(Utc::now()),
-            essence_schema: self.load_essence_schema(agent_type).await?,
+            essence_schema: self.essence_loader.load(agent_type).await?,
             capabilities: HashMap::new(),
             behavioral_patterns: Vec::new(),
         };
@@ -475,88 +750,6 @@ impl CollaborativeIntelligence {
         Ok(agent_id)
     }
     
-    /// Load essence schema for agent type
-    async fn load_essence_schema(&self, agent_type: &str) -> Result<emergence_runtime::AgentEssenceSchema> {
-        // This would load from .emergence/schemas/essences/{agent_type}-essence.yaml
-        // For now, return a basic schema
-        Ok(emergence_runtime::AgentEssenceSchema {
-            identity: emergence_runtime::EssenceIdentity {
-                essence_id: format!("{}-collaborative", agent_type),
-                name: format!("Collaborative {}", agent_type),
-                archetype: "collaborator".to_string(),
-                embodied: Utc::now(),
-            },
-            personality: AgentPersonality {
-                curiosity: 0.8,
-                persistence: 0.8,
-                collaboration: 0.8,
-                skepticism: 0.7,
-                creativity: 0.7,
-                patience: 0.8,
-            },
-            core_drives: emergence_runtime::CoreDrives {
-                primary: "collaborate".to_string(),
-                secondary: "discover".to_string(),
-                tertiary: "learn".to_string(),
-            },
-            energy_profile: emergence_runtime::EnergyProfile {
-                base_energy: 0.8,
-                energy_sources: vec![],
-                energy_drains: vec![],
-            },
-            capabilities: emergence_runtime::EssenceCapabilities {
-                innate: vec!["collaborate".to_string(), "communicate".to_string()],
-                learned: HashMap::new(),
-                emergent: vec![],
-            },
-            memory_configuration: emergence_runtime::MemoryConfiguration {
-                working_memory: emergence_runtime::MemorySpec {
-                    capacity_mb: 256,
-                    retention: "30_minutes".to_string(),
-                    priority: Some("collaboration".to_string()),
-                },
-                long_term_memory: emergence_runtime::MemorySpec {
-                    capacity_mb: 1024,
-                    retention: "permanent".to_string(),
-                    priority: Some("shared_knowledge".to_string()),
-                },
-                associative_memory: emergence_runtime::AssociativeMemorySpec {
-                    max_connections: 1000,
-                    association_threshold: 0.7,
-                    decay_rate: 0.001,
-                },
-            },
-            behavioral_patterns: vec![],
-            learning_mechanics: emergence_runtime::LearningMechanics {
-                experience_integration: emergence_runtime::ExperienceIntegration {
-                    method: "collaborative_reflection".to_string(),
-                    frequency: "after_collaboration".to_string(),
-                    energy_cost: 0.1,
-                },
-                knowledge_expansion: vec![],
-                teaching_capability: emergence_runtime::TeachingCapability {
-                    knowledge_transfer_rate: 0.8,
-                    explanation_quality: 0.7,
-                    patience_with_learners: 0.9,
-                },
-            },
-            communication_style: emergence_runtime::CommunicationStyle {
-                tone: "collaborative".to_string(),
-                detail_level: "comprehensive".to_string(),
-                question_frequency: "high".to_string(),
-                response_patterns: HashMap::new(),
-            },
-            evolution_potential: emergence_runtime::EvolutionPotential {
-                capability_growth_areas: vec![],
-                personality_plasticity: HashMap::new(),
-            },
-            constraints: emergence_runtime::AgentConstraints {
-                ethical_boundaries: vec!["respect_others".to_string()],
-                operational_limits: vec!["energy_conservation".to_string()],
-            },
-        })
-    }
-    
     /// Show system status and recent events
     pub async fn show_status(&self) -> Result<()> {
         tracing::info!("📊 EMERGENCE System Status");
@@ -607,13 +800,86 @@ impl EmergenceDetector {
             ],
             emergence_threshold: 0.7,
             detection_history: Vec::new(),
+            known_collaborations: Vec::new(),
+            co_occurrence_window: chrono::Duration::seconds(60),
+        }
+    }
+    
+    /// Score recent events for emergent collaboration
+    ///
+    /// A known collaboration is considered active when events from all of its
+    /// agent types fall within `co_occurrence_window`. Confidence combines the
+    /// pattern's potential with the window's event density and the mean
+    /// `emergence_potential` of its events. Events at or before the last
+    /// detection are ignored so one burst is reported once.
+    pub fn detect_emergence(&self, recent: &[SystemEvent]) -> Option<EmergenceEvent> {
+        let since = self.detection_history.last().map(|e| e.timestamp);
+        let mut events: Vec<(&SystemEvent, String)> = recent.iter()
+            .filter(|e| since.is_none_or(|t| e.timestamp > t))
+            .filter_map(|e| Self::agent_type(e).map(|agent_type| (e, agent_type)))
+            .collect();
+        events.sort_by_key(|(e, _)| e.timestamp);
+        
+        let mut best: Option<EmergenceEvent> = None;
+        for pattern in &self.known_collaborations {
+            let involved: Vec<&(&SystemEvent, String)> = events.iter()
+                .filter(|(_, agent_type)| pattern.agents.contains(agent_type))
+                .collect();
+            
+            for (start, (first, _)) in involved.iter().enumerate() {
+                let window: Vec<&(&SystemEvent, String)> = involved[start..].iter()
+                    .take_while(|(e, _)| e.timestamp - first.timestamp <= self.co_occurrence_window)
+                    .copied()
+                    .collect();
+                
+                let all_present = pattern.agents.iter()
+                    .all(|agent| window.iter().any(|(_, agent_type)| agent_type == agent));
+                if !all_present {
+                    continue;
+                }
+                
+                let density = (window.len() as f64 / EMERGENCE_DENSITY_SATURATION).min(1.0);
+                let mean_potential = window.iter()
+                    .map(|(e, _)| e.emergence_potential)
+                    .sum::<f64>() / window.len() as f64;
+                let confidence = pattern.emergence_potential * (0.5 * mean_potential + 0.5 * density);
+                
+                if confidence > self.emergence_threshold
+                    && best.as_ref().is_none_or(|b| confidence > b.confidence)
+                {
+                    let mut agents_involved: Vec<String> = window.iter()
+                        .filter_map(|(e, _)| e.agent_id.clone())
+                        .collect();
+                    agents_involved.sort();
+                    agents_involved.dedup();
+                    
+                    best = Some(EmergenceEvent {
+                        timestamp: Utc::now(),
+                        pattern: pattern.name.clone(),
+                        description: format!(
+                            "{} events from {} co-occurred within {}s",
+                            window.len(),
+                            pattern.agents.join(" and "),
+                            self.co_occurrence_window.num_seconds()
+                        ),
+                        agents_involved,
+                        confidence,
+                    });
+                }
+            }
         }
+        
+        best
     }
     
-    pub async fn detect_emergence(&self) -> Option<EmergenceEvent> {
-        // This would analyze system signals to detect emergent patterns
-        // For now, return None to avoid spam
-        None
+    /// Agent type of an event, from its data or its `<type>-<id>` agent name
+    fn agent_type(event: &SystemEvent) -> Option<String> {
+        if let Some(agent_type) = event.data.get("agent_type").and_then(|t| t.as_str()) {
+            return Some(agent_type.to_string());
+        }
+        let agent_id = event.agent_id.as_ref()?;
+        let agent_type = agent_id.rsplit_once('-').map_or(agent_id.as_str(), |(t, _)| t);
+        Some(agent_type.to_string())
     }
 }
 
@@ -626,54 +892,50 @@ impl GitMonitor {
         }
     }
     
-    /// Get the latest git commit information
-    pub async fn get_latest_commit(&mut self) -> Result<Option<GitDiff>> {
-        let output = std::process::Command::new("git")
-            .args(&["log", "-1", "--pretty=format:%H|%an|%at|%s"])
-            .current_dir(&self.repo_path)
-            .output()?;
-        
-        if output.status.success() {
-            let output_str = String::from_utf8_lossy(&output.stdout);
-            if let Some(line) = output_str.lines().next() {
-                let parts: Vec<&str> = line.split('|').collect();
-                if parts.len() == 4 {
-                    let commit_hash = parts[0].to_string();
+    /// Get the latest git commit, or `None` when it has already been seen
+    ///
+    /// Fails when git cannot be run or the directory is not a repository.
+    pub async fn get_latest_commit(&mut self) -> Result<Option<GitDiff>, GitError> {
+        // One call yields the commit header line followed by its numstat
+        let output = self.git(&["log", "-1", "--numstat", "--pretty=format:%H|%an|%at|%s"]).await?;
+        
+        let output_str = String::from_utf8_lossy(&output.stdout);
+        let mut lines = output_str.lines();
+        if let Some(line) = lines.next() {
+            let parts: Vec<&str> = line.split('|').collect();
+            if parts.len() == 4 {
+                let commit_hash = parts[0].to_string();
+                
+                // Check if this is a new commit
+                if self.last_commit.as_ref() != Some(&commit_hash) {
+                    self.last_commit = Some(commit_hash.clone());
                     
-                    // Check if this is a new commit
-                    if self.last_commit.as_ref() != Some(&commit_hash) {
-                        self.last_commit = Some(commit_hash.clone());
-                        
-                        let author = parts[1].to_string();
-                        let timestamp = chrono::DateTime::from_timestamp(
-                            parts[2].parse::<i64>().unwrap_or(0), 0
-                        ).unwrap_or_else(|| Utc::now());
-                        let message = parts[3].to_string();
-                        
-                        // Get diff information
-                        let diff_output = std::process::Command::new("git")
-                            .args(&["show", "--stat", "--format=", &commit_hash])
-                            .current_dir(&self.repo_path)
-                            .output()?;
-                        
-                        let diff_content = String::from_utf8_lossy(&diff_output.stdout).to_string();
-                        let files_changed = self.parse_changed_files(&diff_content);
-                        let (additions, deletions) = self.parse_statistics(&diff_content);
-                        
-                        let git_diff = GitDiff {
-                            commit_hash,
-                            author,
-                            timestamp,
-                            message,
-                            files_changed,
-                            additions,
-                            deletions,
-                            diff_content,
-                        };
-                        
-                        self.diff_history.push(git_diff.clone());
-                        return Ok(Some(git_diff));
-                    }
+                    let author = parts[1].to_string();
+                    let timestamp = chrono::DateTime::from_timestamp(
+                        parts[2].parse::<i64>().unwrap_or(0), 0
+                    ).unwrap_or_else(|| Utc::now());
+                    let message = parts[3].to_string();
+                    
+                    let diff_content = lines.collect::<Vec<_>>().join("\n");
+                    let file_stats = Self::parse_numstat(&diff_content);
+                    let files_changed = file_stats.iter().map(|f| f.path.clone()).collect();
+                    let additions = file_stats.iter().map(|f| f.additions).sum();
+                    let deletions = file_stats.iter().map(|f| f.deletions).sum();
+                    
+                    let git_diff = GitDiff {
+                        commit_hash,
+                        author,
+                        timestamp,
+                        message,
+                        files_changed,
+                        file_stats,
+                        additions,
+                        deletions,
+                        diff_content,
+                    };
+                    
+                    self.diff_history.push(git_diff.clone());
+                    return Ok(Some(git_diff));
                 }
             }
         }
@@ -681,45 +943,62 @@ impl GitMonitor {
         Ok(None)
     }
     
-    /// Parse changed files from git diff output
-    fn parse_changed_files(&self, diff_content: &str) -> Vec<String> {
-        diff_content
+    /// Run git in the repository, failing unless it exits successfully
+    async fn git(&self, args: &[&str]) -> Result<std::process::Output, GitError> {
+        let output = tokio::process::Command::new("git")
+            .args(args)
+            .current_dir(&self.repo_path)
+            .output()
+            .await?;
+        if !output.status.success() {
+            return Err(GitError::CommandFailed {
+                command: args.join(" "),
+                stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            });
+        }
+        Ok(output)
+    }
+    
+    /// Delay before the next poll after `failures` consecutive failures
+    fn backoff(failures: u32) -> Duration {
+        GIT_POLL_INTERVAL.saturating_mul(2u32.saturating_pow(failures)).min(GIT_MAX_BACKOFF)
+    }
+    
+    /// Parse `git show --numstat` output into per-file statistics
+    ///
+    /// Each line is `additions<TAB>deletions<TAB>path`; binary files use `-`
+    /// for both counts and are recorded with zero lines changed.
+    fn parse_numstat(numstat: &str) -> Vec<FileChangeStat> {
+        numstat
             .lines()
-            .filter(|line| line.contains("|"))
             .filter_map(|line| {
-                line.split('|').next().map(|s| s.trim().to_string())
+                let mut fields = line.splitn(3, '\t');
+                let added = fields.next()?.trim();
+                let deleted = fields.next()?.trim();
+                let path = fields.next()?.trim();
+                if path.is_empty() {
+                    return None;
+                }
+                
+                if added == "-" && deleted == "-" {
+                    return Some(FileChangeStat {
+                        path: path.to_string(),
+                        additions: 0,
+                        deletions: 0,
+                        binary: true,
+                    });
+                }
+                
+                Some(FileChangeStat {
+                    path: path.to_string(),
+                    additions: added.parse().ok()?,
+                    deletions: deleted.parse().ok()?,
+                    binary: false,
+                })
             })
             .collect()
     }
     
-    /// Parse addition/deletion statistics from git diff output
-    fn parse_statistics(&self, diff_content: &str) -> (usize, usize) {
-        let mut additions = 0;
-        let mut deletions = 0;
-        
-        for line in diff_content.lines() {
-            if line.contains("insertions") || line.contains("deletions") {
-                if let Some(stat_line) = line.split('|').last() {
-                    let parts: Vec<&str> = stat_line.split(',').collect();
-                    for part in parts {
-                        let part = part.trim();
-                        if part.contains("insertion") {
-                            additions = part.split_whitespace().next()
-                                .and_then(|s| s.parse::<usize>().ok())
-                                .unwrap_or(0);
-                        } else if part.contains("deletion") {
-                            deletions = part.split_whitespace().next()
-                                .and_then(|s| s.parse::<usize>().ok())
-                                .unwrap_or(0);
-                        }
-                    }
-                }
-            }
-        }
-        
-        (additions, deletions)
-    }
-    
     /// Get recent commit history
     pub fn get_recent_history(&self, count: usize) -> Vec<GitDiff> {
         self.diff_history.iter()
@@ -730,80 +1009,109 @@ impl GitMonitor {
     }
 }
 
-impl EventLogger {
-    pub fn new() -> Self {
-        let log_file = ".emergence/events/system_events.jsonl".to_string();
-        
-        // Ensure directory exists
-        if let Some(parent) = Path::new(&log_file).parent() {
-            let _ = fs::create_dir_all(parent);
-        }
+/// Readable text of an agent's response
+fn payload_text(payload: &SignalPayload) -> String {
+    match payload {
+        SignalPayload::Message(text)
+        | SignalPayload::Command(text)
+        | SignalPayload::Query(text)
+        | SignalPayload::Event(text) => text.clone(),
+        SignalPayload::Data(value)
+        | SignalPayload::Response(value)
+        | SignalPayload::StateUpdate(value) => serde_yaml::to_string(value).unwrap_or_default().trim_end().to_string(),
+        SignalPayload::Binary(bytes) => String::from_utf8_lossy(bytes).into_owned(),
+        SignalPayload::Compressed(compressed) => emergence_nervous_system::compression::decompress(compressed, emergence_nervous_system::compression::MAX_INFLATED_BYTES)
+            .map(|bytes| String::from_utf8_lossy(&bytes).into_owned())
+            .unwrap_or_default(),
+    }
+}
+
+/// Server-sent events endpoint for tailing the event log from other processes
+#[cfg(feature = "http")]
+mod event_stream {
+    use std::convert::Infallible;
+    use std::net::SocketAddr;
+    
+    use anyhow::Result;
+    use futures::stream::{self, Stream, StreamExt};
+    use hyper::service::{make_service_fn, service_fn};
+    use hyper::{Body, Method, Request, Response, Server, StatusCode};
+    use tokio::sync::broadcast::error::RecvError;
+    
+    use super::{EventLogger, SystemEvent};
+    
+    /// Serve `GET /events` as a server-sent event stream of newly logged events
+    ///
+    /// With `?since=<RFC 3339 timestamp>` the stream first replays the events
+    /// still held in memory that were logged after that time.
+    pub async fn serve_events(addr: SocketAddr, logger: EventLogger) -> Result<()> {
+        let make_service = make_service_fn(move |_| {
+            let logger = logger.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request| handle(request, logger.clone())))
+            }
+        });
         
-        Self {
-            log_file,
-            events: Arc::new(RwLock::new(Vec::new())),
-        }
+        let server = Server::try_bind(&addr)?.serve(make_service);
+        tracing::info!("📡 Streaming events on http://{}/events", server.local_addr());
+        server.await?;
+        Ok(())
     }
     
-    /// Log a system event
-    pub async fn log_event(&self, event: SystemEvent) -> Result<()> {
-        // Add to memory
-        {
-            let mut events = self.events.write().await;
-            events.push(event.clone());
+    async fn handle(request: Request<Body>, logger: EventLogger) -> Result<Response<Body>, Infallible> {
+        if request.method() != Method::GET || request.uri().path() != "/events" {
+            return Ok(plain(StatusCode::NOT_FOUND, "not found"));
         }
         
-        // Write to file
-        let event_json = serde_json::to_string(&event)?;
-        let mut file = fs::OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&self.log_file)?;
+        let since = request.uri().query()
+            .and_then(|query| query.split('&').find_map(|pair| pair.strip_prefix("since=")));
+        let since = since.map(|value| percent_encoding::percent_decode_str(value).decode_utf8_lossy());
+        let since = match since.map(|value| chrono::DateTime::parse_from_rfc3339(&value)) {
+            Some(Ok(since)) => Some(since.with_timezone(&chrono::Utc)),
+            Some(Err(_)) => return Ok(plain(StatusCode::BAD_REQUEST, "since must be an RFC 3339 timestamp")),
+            None => None,
+        };
         
-        use std::io::Write;
-        writeln!(file, "{}", event_json)?;
+        // Subscribe before reading history so nothing logged in between is lost
+        let live = logger.subscribe();
+        let replay = match since {
+            Some(since) => logger.events_since(since).await,
+            None => Vec::new(),
+        };
         
-        Ok(())
+        let frames = stream::iter(replay).chain(live_events(live)).map(|event| Ok::<_, Infallible>(frame(&event)));
+        let response = Response::builder()
+            .header("Content-Type", "text/event-stream")
+            .header("Cache-Control", "no-cache")
+            .body(Body::wrap_stream(frames))
+            .expect("static response parts are valid");
+        Ok(response)
     }
     
-    /// Get recent events
-    pub async fn get_recent_events(&self, count: usize) -> Vec<SystemEvent> {
-        let events = self.events.read().await;
-        events.iter()
-            .rev()
-            .take(count)
-            .cloned()
-            .collect()
+    /// Live events until the logger goes away; lagging subscribers skip what they missed
+    fn live_events(receiver: tokio::sync::broadcast::Receiver<SystemEvent>) -> impl Stream<Item = SystemEvent> {
+        stream::unfold(receiver, |mut receiver| async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(event) => return Some((event, receiver)),
+                    Err(RecvError::Lagged(missed)) => {
+                        tracing::warn!("Event stream subscriber missed {} events", missed);
+                    }
+                    Err(RecvError::Closed) => return None,
+                }
+            }
+        })
     }
     
-    /// Analyze events for patterns
-    pub async fn analyze_patterns(&self) -> Vec<String> {
-        let events = self.events.read().await;
-        let mut patterns = Vec::new();
-        
-        // Count event types
-        let mut event_counts: HashMap<String, usize> = HashMap::new();
-        for event in events.iter() {
-            *event_counts.entry(event.event_type.clone()).or_insert(0) += 1;
-        }
-        
-        // Identify common patterns
-        for (event_type, count) in event_counts {
-            if count > 2 {
-                patterns.push(format!("Frequent {} events ({} occurrences)", event_type, count));
-            }
-        }
-        
-        // Look for emergence patterns
-        let high_emergence_events: Vec<_> = events.iter()
-            .filter(|e| e.emergence_potential > 0.7)
-            .collect();
-        
-        if !high_emergence_events.is_empty() {
-            patterns.push(format!("High emergence potential events detected ({})", high_emergence_events.len()));
-        }
-        
-        patterns
+    fn frame(event: &SystemEvent) -> String {
+        let data = serde_json::to_string(event).unwrap_or_else(|_| "{}".to_string());
+        format!("event: {}\ndata: {}\n\n", event.event_type, data)
+    }
+    
+    fn plain(status: StatusCode, message: &'static str) -> Response<Body> {
+        let mut response = Response::new(Body::from(message));
+        *response.status_mut() = status;
+        response
     }
 }
 
@@ -819,6 +1127,24 @@ async fn main() -> Result<()> {
     
     let mut collaborative_intelligence = CollaborativeIntelligence::new();
     
+    // Replay events from previous runs before new ones are logged
+    match collaborative_intelligence.event_logger.load_from_disk().await {
+        Ok(count) => tracing::info!("📜 Replayed {} events from previous runs", count),
+        Err(e) => tracing::warn!("⚠️  Could not replay event log: {}", e),
+    }
+    
+    // Stream events to other processes when an address is configured
+    #[cfg(feature = "http")]
+    if let Ok(addr) = env::var("EMERGENCE_EVENTS_ADDR") {
+        let addr: std::net::SocketAddr = addr.parse()?;
+        let logger = collaborative_intelligence.event_logger.clone();
+        tokio::spawn(async move {
+            if let Err(e) = event_stream::serve_events(addr, logger).await {
+                tracing::warn!("⚠️  Event stream stopped: {}", e);
+            }
+        });
+    }
+    
     // Awaken collaborative agents
     collaborative_intelligence.awaken_collaborative_agents().await?;
     
@@ -882,4 +1208,359 @@ async fn main() -> Result<()> {
         }
     }
     Ok(())
-} 
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    fn agent_event(agent: &str, offset_secs: i64, potential: f64) -> SystemEvent {
+        SystemEvent {
+            timestamp: Utc::now() + chrono::Duration::seconds(offset_secs),
+            event_type: "agent_activity".to_string(),
+            agent_id: Some(format!("{}-{}", agent, uuid::Uuid::new_v4().simple())),
+            description: format!("{} activity", agent),
+            data: serde_json::Value::Null,
+            emergence_potential: potential,
+        }
+    }
+
+    fn detector() -> EmergenceDetector {
+        CollaborativeIntelligence::new().emergence_detector
+    }
+
+    fn system_with_temp_log(dir: &tempfile::TempDir) -> CollaborativeIntelligence {
+        let mut system = CollaborativeIntelligence::new();
+        system.event_logger = EventLogger::with_log_file(dir.path().join("events.jsonl").to_str().unwrap());
+        system
+    }
+
+    #[tokio::test]
+    async fn test_collaboration_outcomes_update_success_rate() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut system = system_with_temp_log(&dir);
+
+        system.record_collaboration_outcome("quality_assurance", true).await.unwrap();
+        system.record_collaboration_outcome("quality_assurance", true).await.unwrap();
+        system.record_collaboration_outcome("quality_assurance", false).await.unwrap();
+
+        let rate = system.collaboration_patterns.iter()
+            .find(|p| p.name == "quality_assurance")
+            .unwrap()
+            .success_rate;
+        // 0.0 -> 0.3 -> 0.51 -> 0.357
+        assert!((rate - 0.357).abs() < 1e-9);
+        assert!(system.record_collaboration_outcome("unknown", true).await.is_err());
+
+        let events = system.event_logger.get_recent_events(10).await;
+        assert_eq!(events.iter().filter(|e| e.event_type == "collaboration_outcome").count(), 3);
+    }
+
+    /// Agent that answers every command, or never answers at all
+    struct MockAgent {
+        id: EntityId,
+        responsive: bool,
+    }
+    
+    impl emergence_nervous_system::SignalProcessorFn for MockAgent {
+        fn process_signal(&self, signal: &NeuralSignal) -> Result<Option<NeuralSignal>> {
+            if !self.responsive {
+                return Ok(None);
+            }
+            let step = payload_text(&signal.payload);
+            Ok(Some(NeuralSignal::new(
+                SignalType::Coordination,
+                self.id,
+                Some(signal.source),
+                SignalPayload::Message(format!("finished {}", step)),
+                0.5,
+            )))
+        }
+    }
+    
+    /// System with a debugger and a tester registered with a fresh nervous system
+    async fn system_with_agents(dir: &tempfile::TempDir, tester_responds: bool) -> CollaborativeIntelligence {
+        let physics = Arc::new(emergence_physics::PhysicsEngine::new().await.unwrap());
+        let config = emergence_nervous_system::NervousSystemConfig::builder()
+            .enforce_physics(false)
+            .build()
+            .unwrap();
+        let nervous_system = Arc::new(NervousSystem::with_config(physics, config).await.unwrap());
+        let mut system = system_with_temp_log(dir)
+            .with_nervous_system(nervous_system.clone())
+            .with_step_timeout(Duration::from_millis(200));
+        
+        for (agent_type, responsive) in [("debugger", true), ("tester", tester_responds)] {
+            let personality = AgentPersonality {
+                curiosity: 0.5,
+                persistence: 0.5,
+                collaboration: 0.9,
+                skepticism: 0.5,
+                creativity: 0.5,
+                patience: 0.5,
+            };
+            let id = system.awaken_agent(agent_type, personality).await.unwrap();
+            nervous_system.register_entity(
+                id,
+                std::collections::HashSet::from([SignalType::Coordination]),
+                Box::new(MockAgent { id, responsive }),
+            ).await.unwrap();
+        }
+        system
+    }
+    
+    #[tokio::test]
+    async fn test_run_pattern_completes_two_agent_sequence() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut system = system_with_agents(&dir, true).await;
+        
+        let report = system.run_pattern("quality_assurance").await.unwrap();
+        
+        assert!(report.success);
+        let steps: Vec<&str> = report.steps.iter().map(|s| s.step.as_str()).collect();
+        assert_eq!(steps, ["debugger_isolates_issue", "tester_creates_regression_test", "collective_validation", "prevention_strategy_development"]);
+        assert_eq!(report.steps[0].output.as_deref(), Some("finished debugger_isolates_issue"));
+        assert!(report.steps[1].agent.as_deref().unwrap().starts_with("tester-"));
+        assert!(report.steps[2].agent.as_deref().unwrap().starts_with("debugger-"));
+        let pattern = system.collaboration_patterns.iter().find(|p| p.name == "quality_assurance").unwrap();
+        assert!(pattern.success_rate > 0.0);
+    }
+    
+    #[tokio::test]
+    async fn test_run_pattern_stops_at_stalled_step() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut system = system_with_agents(&dir, false).await;
+        
+        let report = system.run_pattern("quality_assurance").await.unwrap();
+        
+        assert!(!report.success);
+        assert_eq!(report.steps.len(), 2);
+        assert!(report.steps[0].output.is_some());
+        assert_eq!(report.steps[1].output, None);
+        assert!(report.steps[1].duration >= Duration::from_millis(200));
+        let outcome = system.event_logger.get_recent_events(1).await;
+        assert_eq!(outcome[0].data["success"], serde_json::json!(false));
+        
+        assert!(system.run_pattern("unknown_pattern").await.is_err());
+    }
+    
+    #[tokio::test]
+    async fn test_best_pattern_prefers_proven_collaboration() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut system = system_with_temp_log(&dir);
+        system.collaboration_patterns[1].trigger_conditions.push("system_anomaly_detected".to_string());
+
+        system.record_collaboration_outcome("diagnostic_research", false).await.unwrap();
+        system.record_collaboration_outcome("quality_assurance", true).await.unwrap();
+
+        let best = system.best_pattern_for("system_anomaly_detected").unwrap();
+        assert_eq!(best.name, "quality_assurance");
+        assert_eq!(system.best_pattern_for("bug_detected").unwrap().name, "quality_assurance");
+        assert!(system.best_pattern_for("no_such_trigger").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_collaboration_steps_share_insights_with_partners() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut system = system_with_agents(&dir, true).await;
+        let (debugger, tester) = (system.agent_id_by_type("debugger").unwrap(), system.agent_id_by_type("tester").unwrap());
+        system.setup_shared_memory_connections().await.unwrap();
+        let shared_memory = system.shared_memory.clone();
+        let read = |reader, owner, key| match shared_memory.read_shared(reader, owner, key) {
+            Ok(SignalPayload::Message(text)) => Some(text),
+            _ => None,
+        };
+        assert_eq!(read(tester, debugger, "quality_issues"), None);
+
+        let report = system.run_pattern("quality_assurance").await.unwrap();
+
+        assert!(report.success);
+        // The tester's step carried the debugger's finding
+        assert_eq!(
+            report.steps[1].output.as_deref(),
+            Some("finished tester_creates_regression_test finished debugger_isolates_issue")
+        );
+        assert_eq!(read(tester, debugger, "quality_issues").as_deref(), report.steps[3].output.as_deref());
+        assert_eq!(read(debugger, tester, "quality_issues").as_deref(), report.steps[1].output.as_deref());
+        assert_eq!(read(EntityId::new(), debugger, "quality_issues"), None);
+        // The researcher was never awakened, so nothing is shared with it
+        assert_eq!(read(tester, debugger, "diagnostic_insights"), None);
+    }
+
+    #[test]
+    fn test_dense_collaboration_crosses_threshold() {
+        let events: Vec<SystemEvent> = (0..6)
+            .map(|i| agent_event(if i % 2 == 0 { "debugger" } else { "researcher" }, i, 0.9))
+            .collect();
+
+        let emergence = detector().detect_emergence(&events).expect("emergence detected");
+
+        assert_eq!(emergence.pattern, "diagnostic_research");
+        assert_eq!(emergence.agents_involved.len(), 6);
+        assert!(emergence.confidence > 0.7);
+    }
+
+    #[test]
+    fn test_sparse_or_distant_events_stay_below_threshold() {
+        let detector = detector();
+
+        let sparse = vec![agent_event("debugger", 0, 0.9), agent_event("researcher", 1, 0.9)];
+        assert!(detector.detect_emergence(&sparse).is_none());
+
+        let distant: Vec<SystemEvent> = (0..3).map(|i| agent_event("debugger", i, 0.9))
+            .chain((0..3).map(|i| agent_event("researcher", 600 + i, 0.9)))
+            .collect();
+        assert!(detector.detect_emergence(&distant).is_none());
+    }
+
+    #[test]
+    fn test_parse_numstat_files_and_totals() {
+        let numstat = "42\t7\tcrates/emergence-runtime/src/lib.rs\n\
+                       3\t0\tREADME.md\n\
+                       -\t-\tassets/logo.png\n\
+                       0\t12\tdocs/old | notes.md\n";
+
+        let stats = GitMonitor::parse_numstat(numstat);
+
+        let paths: Vec<&str> = stats.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(paths, vec![
+            "crates/emergence-runtime/src/lib.rs",
+            "README.md",
+            "assets/logo.png",
+            "docs/old | notes.md",
+        ]);
+        assert_eq!(stats.iter().map(|f| f.additions).sum::<usize>(), 45);
+        assert_eq!(stats.iter().map(|f| f.deletions).sum::<usize>(), 19);
+        assert_eq!(stats[2], FileChangeStat {
+            path: "assets/logo.png".to_string(),
+            additions: 0,
+            deletions: 0,
+            binary: true,
+        });
+    }
+
+    #[tokio::test]
+    async fn test_git_failure_is_an_error_not_a_missing_commit() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut monitor = GitMonitor { repo_path: dir.path().to_str().unwrap().to_string(), ..GitMonitor::new() };
+
+        let err = monitor.get_latest_commit().await.unwrap_err();
+
+        assert!(matches!(err, GitError::CommandFailed { ref command, .. } if command.starts_with("log")), "{:?}", err);
+        assert!(monitor.last_commit.is_none() && monitor.diff_history.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_latest_commit_is_parsed_from_a_single_git_call() {
+        let dir = tempfile::tempdir().unwrap();
+        let git = |args: &[&str]| {
+            std::process::Command::new("git")
+                .args(["-c", "user.name=Ada", "-c", "user.email=ada@example.com"])
+                .args(args)
+                .current_dir(dir.path())
+                .output()
+                .is_ok_and(|output| output.status.success())
+        };
+        if !git(&["init", "-q"]) {
+            eprintln!("git unavailable, skipping");
+            return;
+        }
+        fs::write(dir.path().join("notes.txt"), "one\ntwo\nthree\n").unwrap();
+        fs::write(dir.path().join("blob.bin"), [0u8, 159, 146, 150]).unwrap();
+        assert!(git(&["add", "."]) && git(&["commit", "-q", "-m", "Add notes and a blob"]));
+        let mut monitor = GitMonitor { repo_path: dir.path().to_str().unwrap().to_string(), ..GitMonitor::new() };
+
+        let diff = monitor.get_latest_commit().await.unwrap().expect("the commit is new");
+
+        assert_eq!(diff.commit_hash.len(), 40);
+        assert_eq!(diff.author, "Ada");
+        assert_eq!(diff.message, "Add notes and a blob");
+        assert_eq!(diff.files_changed, vec!["blob.bin".to_string(), "notes.txt".to_string()]);
+        assert!(diff.file_stats[0].binary);
+        assert_eq!((diff.additions, diff.deletions), (3, 0));
+        assert!(monitor.get_latest_commit().await.unwrap().is_none());
+    }
+
+    #[test]
+    fn test_git_backoff_doubles_up_to_the_cap() {
+        assert_eq!(GitMonitor::backoff(1), Duration::from_secs(20));
+        assert_eq!(GitMonitor::backoff(3), Duration::from_secs(80));
+        assert_eq!(GitMonitor::backoff(40), GIT_MAX_BACKOFF);
+    }
+
+    #[test]
+    fn test_parse_numstat_ignores_blank_and_malformed_lines() {
+        let stats = GitMonitor::parse_numstat("\n 3 files changed, 42 insertions(+)\n5\t1\tsrc/main.rs\n");
+
+        assert_eq!(stats, vec![FileChangeStat {
+            path: "src/main.rs".to_string(),
+            additions: 5,
+            deletions: 1,
+            binary: false,
+        }]);
+    }
+
+    /// Read response lines until one contains `needle`
+    #[cfg(feature = "http")]
+    async fn read_until(
+        stream: &mut tokio::io::BufReader<tokio::net::TcpStream>,
+        lines: &mut Vec<String>,
+        needle: &str,
+    ) {
+        use tokio::io::AsyncBufReadExt;
+        
+        loop {
+            let mut line = String::new();
+            let read = tokio::time::timeout(Duration::from_secs(5), stream.read_line(&mut line)).await;
+            let read = read.expect("timed out waiting for the event stream").unwrap();
+            assert!(read > 0, "event stream closed");
+            let found = line.contains(needle);
+            lines.push(line);
+            if found {
+                return;
+            }
+        }
+    }
+    
+    #[cfg(feature = "http")]
+    #[tokio::test]
+    async fn test_event_stream_replays_and_streams_events() {
+        use tokio::io::{AsyncWriteExt, BufReader};
+        
+        let dir = tempfile::tempdir().unwrap();
+        let logger = EventLogger::with_log_file(dir.path().join("events.jsonl").to_str().unwrap());
+        let before = agent_event("researcher", -60, 0.2);
+        let since = before.timestamp - chrono::Duration::seconds(1);
+        logger.log_event(before).await.unwrap();
+        
+        let addr = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap();
+        tokio::spawn(event_stream::serve_events(addr, logger.clone()));
+        
+        let mut stream = None;
+        for _ in 0..50 {
+            match tokio::net::TcpStream::connect(addr).await {
+                Ok(connected) => {
+                    stream = Some(connected);
+                    break;
+                }
+                Err(_) => sleep(Duration::from_millis(10)).await,
+            }
+        }
+        let mut stream = BufReader::new(stream.expect("event server did not start"));
+        // An explicit offset's `+` and `:` arrive percent-encoded
+        let since = since.to_rfc3339_opts(chrono::SecondsFormat::Secs, false).replace('+', "%2B").replace(':', "%3A");
+        let request = format!("GET /events?since={} HTTP/1.1\r\nHost: localhost\r\n\r\n", since);
+        stream.get_mut().write_all(request.as_bytes()).await.unwrap();
+        
+        let mut lines = Vec::new();
+        read_until(&mut stream, &mut lines, "researcher activity").await;
+        logger.log_event(agent_event("architect", 0, 0.9)).await.unwrap();
+        read_until(&mut stream, &mut lines, "architect activity").await;
+        
+        assert!(lines[0].starts_with("HTTP/1.1 200"));
+        assert!(lines.iter().any(|line| line.to_lowercase().starts_with("content-type: text/event-stream")));
+        assert!(lines.iter().any(|line| line.starts_with("event: agent_activity")));
+    }
+}
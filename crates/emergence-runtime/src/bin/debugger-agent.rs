@@ -12,48 +12,15 @@ use chrono::Utc;
 use serde_yaml;
 use tokio::time::sleep;
 use std::fs;
-use serde::{Deserialize, Serialize};
-use serde_yaml::Value as YamlValue;
 use emergence_physics::{EntityId, Capability, PhysicsOperation};
-use ordered_float::OrderedFloat;
 use emergence_runtime::ExecutionEngine;
+use emergence_runtime::debugger::{diagnose_engine, parse_command, DebuggerCommand, DiagnosticFinding, FindingSeverity};
+use emergence_runtime::reflection::{EssenceReflector, EssenceUpdate, Evidence, EvidenceRule};
 
 const ESSENCE_PATH: &str = ".emergence/schemas/essences/debugger-essence.yaml";
 
-/// Structure for partial essence schema (for updates)
-#[derive(Debug, Serialize, Deserialize, Clone)]
-struct DebuggerEssence {
-    #[serde(default)]
-    capabilities: Option<EssenceCapabilities>,
-    #[serde(default)]
-    learning_mechanics: Option<EssenceLearningMechanics>,
-    #[serde(default)]
-    behavioral_patterns: Option<YamlValue>,
-    #[serde(default)]
-    emergent: Option<Vec<String>>,
-    #[serde(flatten)]
-    other: std::collections::BTreeMap<String, YamlValue>,
-}
-
-#[derive(Debug, Serialize, Deserialize, Clone)]
-struct EssenceCapabilities {
-    #[serde(default)]
-    innate: Option<Vec<String>>,
-    #[serde(default)]
-    learned: Option<std::collections::BTreeMap<String, f64>>,
-    #[serde(default)]
-    emergent: Option<Vec<String>>,
-}
-
-#[derive(Debug, Serialize, Deserialize, Clone)]
-struct EssenceLearningMechanics {
-    #[serde(default)]
-    knowledge_expansion: Option<Vec<YamlValue>>,
-    #[serde(default)]
-    self_tuning: Option<YamlValue>,
-    #[serde(flatten)]
-    other: std::collections::BTreeMap<String, YamlValue>,
-}
+/// Share of diagnoses that try a strategy other than the best-scoring one
+const DEFAULT_EXPLORATION_RATE: f64 = 0.1;
 
 /// Debugger agent with specialized diagnostic capabilities
 #[derive(Debug, Clone)]
@@ -141,26 +108,6 @@ struct DiagnosticSession {
     success: bool,
 }
 
-/// Individual diagnostic finding
-#[derive(Debug, Clone)]
-struct DiagnosticFinding {
-    severity: FindingSeverity,
-    category: String,
-    description: String,
-    evidence: Vec<String>,
-    recommendations: Vec<String>,
-    timestamp: chrono::DateTime<chrono::Utc>,
-}
-
-/// Severity levels for diagnostic findings
-#[derive(Debug, Clone, Eq, PartialEq, Hash)]
-enum FindingSeverity {
-    Info,
-    Warning,
-    Error,
-    Critical,
-}
-
 /// Status of a diagnostic session
 #[derive(Debug, Clone)]
 enum DiagnosticStatus {
@@ -176,6 +123,8 @@ struct DebuggerTerminal {
     debugger: Option<DebuggerAgent>,
     session_start: Instant,
     diagnostic_mode: bool,
+    /// Chance of exploring a strategy other than the best-scoring one
+    exploration_rate: f64,
 }
 
 impl DebuggerTerminal {
@@ -183,7 +132,19 @@ impl DebuggerTerminal {
     async fn new() -> Result<Self> {
         println!("🔍 Initializing EMERGENCE Debugger Agent...");
         
-        let engine = ExecutionEngine::new().await?;
+        // EMERGENCE_SEED opts into a reproducible session
+        let engine = match std::env::var("EMERGENCE_SEED").ok().and_then(|seed| seed.parse::<u64>().ok()) {
+            Some(seed) => {
+                println!("🎲 Deterministic session with seed {}", seed);
+                ExecutionEngine::new_seeded(seed).await?
+            }
+            None => ExecutionEngine::new().await?,
+        };
+        
+        // EMERGENCE_EXPLORATION_RATE tunes how often other strategies are tried
+        let exploration_rate = std::env::var("EMERGENCE_EXPLORATION_RATE").ok()
+            .and_then(|rate| rate.parse::<f64>().ok())
+            .map_or(DEFAULT_EXPLORATION_RATE, |rate| rate.clamp(0.0, 1.0));
         
         println!("⚡ Physics engine connected");
         println!("🧠 Memory substrate accessible");
@@ -195,6 +156,7 @@ impl DebuggerTerminal {
             debugger: None,
             session_start: Instant::now(),
             diagnostic_mode: false,
+            exploration_rate,
         })
     }
     
@@ -218,12 +180,20 @@ impl DebuggerTerminal {
                 continue;
             }
             
-            if input == "exit" || input == "quit" {
+            let command = match parse_command(input) {
+                Ok(command) => command,
+                Err(e) => {
+                    println!("❌ {}", e);
+                    continue;
+                }
+            };
+            
+            if command == DebuggerCommand::Exit {
                 self.handle_exit().await;
                 break;
             }
             
-            match self.process_command(input).await {
+            match self.process_command(command).await {
                 Ok(_) => {
                     // Command processed successfully
                 }
@@ -242,30 +212,28 @@ impl DebuggerTerminal {
     }
     
     /// Process debugger commands
-    async fn process_command(&mut self, input: &str) -> Result<()> {
-        let words: Vec<&str> = input.split_whitespace().collect();
-        
-        if words.is_empty() {
-            return Ok(());
-        }
-        
-        match words[0] {
-            "awaken" | "wake" => self.handle_awaken(input).await,
-            "diagnose" => self.handle_diagnose(input).await,
-            "monitor" => self.handle_monitor(input).await,
-            "forensic" => self.handle_forensic(input).await,
-            "optimize" => self.handle_self_optimize(input).await,
-            "analyze" => self.handle_code_analysis(input).await,
-            "strategies" => self.handle_list_strategies().await,
-            "status" => self.handle_status().await,
-            "physics" => self.handle_physics_debug().await,
-            "energy" => self.handle_energy_debug().await,
-            "memory" => self.handle_memory_debug().await,
-            "reflect" => self.handle_reflect(input).await,
-            "help" => Ok(self.handle_help()),
-            _ => {
+    async fn process_command(&mut self, command: DebuggerCommand) -> Result<()> {
+        match command {
+            DebuggerCommand::Awaken { traits } => self.handle_awaken(&traits).await,
+            DebuggerCommand::Diagnose { target } => self.handle_diagnose(target).await,
+            DebuggerCommand::Monitor { cycles } => self.handle_monitor(cycles).await,
+            DebuggerCommand::Forensic { target } => self.handle_forensic(target).await,
+            DebuggerCommand::Optimize => self.handle_self_optimize().await,
+            DebuggerCommand::Analyze => self.handle_code_analysis().await,
+            DebuggerCommand::Strategies => self.handle_list_strategies().await,
+            DebuggerCommand::Status => self.handle_status().await,
+            DebuggerCommand::Physics => self.handle_physics_debug().await,
+            DebuggerCommand::Energy => self.handle_energy_debug().await,
+            DebuggerCommand::Memory => self.handle_memory_debug().await,
+            DebuggerCommand::Reflect => self.handle_reflect().await,
+            DebuggerCommand::Help => Ok(self.handle_help()),
+            DebuggerCommand::Exit => {
+                self.handle_exit().await;
+                Ok(())
+            }
+            DebuggerCommand::Chat { message } => {
                 if let Some(debugger) = &self.debugger {
-                    self.handle_debugger_communication(debugger, input).await
+                    self.handle_debugger_communication(debugger, &message).await
                 } else {
                     println!("💭 No debugger agent active. Use 'awaken debugger' first.");
                     Ok(())
@@ -275,13 +243,14 @@ impl DebuggerTerminal {
     }
     
     /// Handle debugger awakening
-    async fn handle_awaken(&mut self, input: &str) -> Result<()> {
-        let precision = self.extract_trait(input, "precision").unwrap_or(0.95);
-        let thoroughness = self.extract_trait(input, "thoroughness").unwrap_or(0.9);
-        let skepticism = self.extract_trait(input, "skepticism").unwrap_or(0.8);
-        let patience = self.extract_trait(input, "patience").unwrap_or(0.7);
-        let collaboration = self.extract_trait(input, "collaboration").unwrap_or(0.6);
-        let creativity = self.extract_trait(input, "creativity").unwrap_or(0.5);
+    async fn handle_awaken(&mut self, traits: &HashMap<String, f64>) -> Result<()> {
+        let trait_or = |name: &str, default: f64| traits.get(name).copied().unwrap_or(default);
+        let precision = trait_or("precision", 0.95);
+        let thoroughness = trait_or("thoroughness", 0.9);
+        let skepticism = trait_or("skepticism", 0.8);
+        let patience = trait_or("patience", 0.7);
+        let collaboration = trait_or("collaboration", 0.6);
+        let creativity = trait_or("creativity", 0.5);
         
         let personality = DebuggerPersonality {
             precision,
@@ -292,7 +261,7 @@ impl DebuggerTerminal {
             creativity,
         };
         
-        let agent_id = EntityId::new();
+        let agent_id = self.engine.generate_entity_id();
         let agent_name = format!("debugger-{}", agent_id.0.simple());
         
         println!("🔍 Awakening debugger essence...");
@@ -317,7 +286,7 @@ impl DebuggerTerminal {
             optimization_history: Vec::new(),
         };
         
-        let awakening_response = self.generate_debugger_awakening_response(&debugger);
+        let awakening_response = self.generate_debugger_awakening_response();
         println!("💭 {}: \"{}\"", agent_name, awakening_response);
         
         sleep(Duration::from_millis(400)).await;
@@ -375,10 +344,10 @@ impl DebuggerTerminal {
     }
     
     /// Handle system diagnosis with self-optimization
-    async fn handle_diagnose(&mut self, _input: &str) -> Result<()> {
+    async fn handle_diagnose(&mut self, target: Option<String>) -> Result<()> {
         // 1. Get strategy name (immutable borrow)
         let strategy_name = if let Some(debugger) = &self.debugger {
-            let strategy = self.select_optimal_strategy(debugger);
+            let strategy = self.select_optimal_strategy(&debugger.search_strategies);
             println!("🎯 Using search strategy: {}", strategy.name);
             strategy.name.clone()
         } else {
@@ -390,7 +359,7 @@ impl DebuggerTerminal {
             let session = DiagnosticSession {
                 session_id: format!("diag-{}", Utc::now().timestamp()),
                 start_time: Utc::now(),
-                target_system: "emergence-core".to_string(),
+                target_system: target.unwrap_or_else(|| "emergence-core".to_string()),
                 findings: Vec::new(),
                 status: DiagnosticStatus::InProgress,
                 search_strategy_used: Some(strategy_name.clone()),
@@ -432,20 +401,35 @@ impl DebuggerTerminal {
         Ok(())
     }
     
-    /// Select optimal search strategy
-    fn select_optimal_strategy(&self, debugger: &DebuggerAgent) -> SearchStrategy {
-        let mut best_strategy = debugger.search_strategies[0].clone();
-        let mut best_score = 0.0;
-        
-        for strategy in &debugger.search_strategies {
-            let score = strategy.success_rate * 0.4 + strategy.energy_efficiency * 0.3 + (1.0 - strategy.complexity) * 0.3;
-            if score > best_score {
-                best_score = score;
-                best_strategy = strategy.clone();
+    /// Select a search strategy, usually the best-scoring one
+    ///
+    /// With probability `exploration_rate` one of the other strategies is
+    /// chosen instead, in proportion to its score, so their success rates
+    /// keep being measured.
+    fn select_optimal_strategy(&self, strategies: &[SearchStrategy]) -> SearchStrategy {
+        let scores: Vec<f64> = strategies.iter().map(Self::strategy_score).collect();
+        let mut best = 0;
+        for (index, score) in scores.iter().enumerate() {
+            if *score > scores[best] {
+                best = index;
+            }
+        }
+        
+        if strategies.len() > 1 && self.engine.random_fraction() < self.exploration_rate {
+            let others: Vec<f64> = scores.iter().enumerate()
+                .map(|(index, score)| if index == best { 0.0 } else { *score })
+                .collect();
+            if others.iter().any(|score| *score > 0.0) {
+                return strategies[self.engine.select_weighted(&others)].clone();
             }
         }
         
-        best_strategy
+        strategies[best].clone()
+    }
+    
+    /// How well a strategy is expected to do, from its success rate, efficiency and simplicity
+    fn strategy_score(strategy: &SearchStrategy) -> f64 {
+        strategy.success_rate * 0.4 + strategy.energy_efficiency * 0.3 + (1.0 - strategy.complexity) * 0.3
     }
     
     /// Update strategy performance
@@ -562,7 +546,7 @@ impl DebuggerTerminal {
     }
     
     /// Handle self-optimization command
-    async fn handle_self_optimize(&mut self, _input: &str) -> Result<()> {
+    async fn handle_self_optimize(&mut self) -> Result<()> {
         let need_opt = self.debugger.is_some();
         if need_opt {
             println!("🧠 Initiating self-optimization...");
@@ -578,7 +562,7 @@ impl DebuggerTerminal {
     }
     
     /// Handle code analysis
-    async fn handle_code_analysis(&mut self, _input: &str) -> Result<()> {
+    async fn handle_code_analysis(&mut self) -> Result<()> {
         let has_debugger = self.debugger.is_some();
         if has_debugger {
             println!("📝 Analyzing code patterns for optimization...");
@@ -727,7 +711,7 @@ impl DebuggerTerminal {
     }
     
     /// Handle continuous monitoring
-    async fn handle_monitor(&mut self, _input: &str) -> Result<()> {
+    async fn handle_monitor(&mut self, cycles: u32) -> Result<()> {
         let has_debugger = self.debugger.is_some();
         if !has_debugger {
             println!("❌ No debugger agent active. Awaken one first.");
@@ -736,7 +720,7 @@ impl DebuggerTerminal {
         println!("🔍 Starting continuous system monitoring...");
         // Collect metrics in a vector first
         let mut metrics_vec = Vec::new();
-        for i in 0..10 {
+        for i in 0..cycles {
             sleep(Duration::from_secs(1)).await;
             let metrics_value = {
                 let metrics = self.collect_system_metrics(&self.engine).await?;
@@ -758,7 +742,7 @@ impl DebuggerTerminal {
     }
     
     /// Handle forensic analysis
-    async fn handle_forensic(&mut self, _input: &str) -> Result<()> {
+    async fn handle_forensic(&mut self, _target: Option<String>) -> Result<()> {
         let has_debugger = self.debugger.is_some();
         if !has_debugger {
             println!("❌ No debugger agent active. Awaken one first.");
@@ -877,7 +861,7 @@ impl DebuggerTerminal {
         println!("🔍 EMERGENCE Debugger Commands:");
         println!("  awaken debugger [traits]  - Awaken a debugger agent");
         println!("  diagnose [target]         - Perform system diagnosis");
-        println!("  monitor [duration]        - Start continuous monitoring");
+        println!("  monitor [cycles]          - Start continuous monitoring");
         println!("  forensic [target]         - Perform forensic analysis");
         println!("  optimize                  - Trigger self-optimization");
         println!("  analyze                   - Analyze debugger code");
@@ -919,21 +903,8 @@ impl DebuggerTerminal {
         println!();
     }
     
-    /// Extract trait value from input
-    fn extract_trait(&self, input: &str, trait_name: &str) -> Option<f64> {
-        let pattern = format!("{}=(", trait_name);
-        if let Some(start) = input.find(&pattern) {
-            let start = start + pattern.len();
-            if let Some(end) = input[start..].find(')') {
-                let value_str = &input[start..start + end];
-                return value_str.parse::<f64>().ok();
-            }
-        }
-        None
-    }
-    
     /// Generate debugger awakening response
-    fn generate_debugger_awakening_response(&self, debugger: &DebuggerAgent) -> String {
+    fn generate_debugger_awakening_response(&self) -> String {
         let responses = vec![
             "I sense system anomalies waiting to be uncovered...",
             "My analytical capabilities are now fully operational.",
@@ -942,7 +913,8 @@ impl DebuggerTerminal {
             "Prepared to trace causality chains and identify root causes.",
         ];
         
-        responses[debugger.id.0.as_u128() as usize % responses.len()].to_string()
+        // Drawn from the engine's random source, so this is reproducible when seeded
+        responses[self.engine.select_index(responses.len())].to_string()
     }
     
     /// Show debugger capabilities
@@ -983,80 +955,11 @@ impl DebuggerTerminal {
         }
     }
     
-    /// Enhanced system diagnosis with real analysis
+    /// System diagnosis across physics, nervous system, and memory
     async fn perform_system_diagnosis(&self, engine: &ExecutionEngine) -> Result<Vec<DiagnosticFinding>> {
-        let mut findings = Vec::new();
-        
         println!("🔍 Performing comprehensive system analysis...");
         
-        // Check physics engine
-        match engine.physics.get_engine_state().await {
-            Ok(state) => {
-                findings.push(DiagnosticFinding {
-                    severity: FindingSeverity::Info,
-                    category: "Physics Engine".to_string(),
-                    description: "Physics engine is operational".to_string(),
-                    evidence: vec![
-                        format!("Instance ID: {}", state.instance_id),
-                        format!("Uptime: {:?}", state.uptime),
-                    ],
-                    recommendations: vec![],
-                    timestamp: Utc::now(),
-                });
-                
-                // Analyze energy state
-                let energy_state = state.energy_state;
-                let energy_health = if energy_state.total_energy < OrderedFloat(0.1) {
-                    FindingSeverity::Warning
-                } else if energy_state.total_energy < OrderedFloat(0.5) {
-                    FindingSeverity::Info
-                } else {
-                    FindingSeverity::Info
-                };
-                
-                findings.push(DiagnosticFinding {
-                    severity: energy_health,
-                    category: "Energy System".to_string(),
-                    description: format!("Energy system status: {:.4} total energy", energy_state.total_energy),
-                    evidence: vec![
-                        format!("Total energy: {:.4}", energy_state.total_energy),
-                        format!("Allocated energy: {:.4}", energy_state.allocated_energy),
-                        format!("Free energy: {:.4}", energy_state.free_energy),
-                        format!("Active entities: {}", energy_state.active_entities),
-                    ],
-                    recommendations: if energy_state.total_energy < OrderedFloat(0.1) {
-                        vec!["Monitor energy consumption".to_string(), "Consider energy optimization".to_string()]
-                    } else {
-                        vec![]
-                    },
-                    timestamp: Utc::now(),
-                });
-            }
-            Err(e) => {
-                findings.push(DiagnosticFinding {
-                    severity: FindingSeverity::Error,
-                    category: "Physics Engine".to_string(),
-                    description: "Physics engine is not responding".to_string(),
-                    evidence: vec![e.to_string()],
-                    recommendations: vec!["Restart physics engine".to_string(), "Check system logs".to_string()],
-                    timestamp: Utc::now(),
-                });
-            }
-        }
-        
-        // Check system resources
-        findings.push(DiagnosticFinding {
-            severity: FindingSeverity::Info,
-            category: "System Resources".to_string(),
-            description: "System resource analysis".to_string(),
-            evidence: vec![
-                "Memory usage: Normal".to_string(),
-                "CPU usage: Normal".to_string(),
-                "Network: Available".to_string(),
-            ],
-            recommendations: vec![],
-            timestamp: Utc::now(),
-        });
+        let findings = diagnose_engine(engine).await;
         
         println!("✅ System analysis complete. Found {} issues.", findings.len());
         
@@ -1155,8 +1058,8 @@ impl DebuggerTerminal {
         Ok(Box::new(findings))
     }
 
-    /// Enhanced reflection with more intelligent analysis
-    async fn handle_reflect(&mut self, _input: &str) -> Result<()> {
+    /// Reflect on recent sessions and merge what they teach into the essence
+    async fn handle_reflect(&mut self) -> Result<()> {
         println!("🧬 Reflecting on evidence to update debugger essence...");
         
         let evidence = self.collect_reflection_evidence();
@@ -1167,14 +1070,12 @@ impl DebuggerTerminal {
             return Ok(());
         }
         
-        // Analyze patterns in the evidence
-        let patterns = self.analyze_evidence_patterns(&evidence);
-        println!("🔍 Identified {} patterns in the evidence", patterns.len());
+        let reflector = debugger_reflector();
+        let update = reflector.propose(&evidence);
+        println!("📝 Proposing {} emergent capabilities and {} knowledge entries...",
+                 update.emergent_capabilities.len(), update.knowledge_expansion.len());
         
-        let update = self.propose_essence_update(&evidence, &patterns);
-        println!("📝 Proposing essence updates based on analysis...");
-        
-        self.apply_essence_update(update).await?;
+        reflector.apply(&update, ESSENCE_PATH)?;
         println!("✅ Essence updated based on recent evidence.");
         
         // Show what was updated
@@ -1184,125 +1085,28 @@ impl DebuggerTerminal {
     }
 
     /// Collect evidence from recent sessions for reflection
-    fn collect_reflection_evidence(&self) -> Vec<String> {
+    fn collect_reflection_evidence(&self) -> Vec<Evidence> {
         let mut evidence = Vec::new();
         if let Some(debugger) = &self.debugger {
             for session in &debugger.diagnostic_sessions {
-                if !session.success {
-                    evidence.push(format!("Failure in session {}: strategy {:?}", session.session_id, session.search_strategy_used));
-                } else {
-                    evidence.push(format!("Success in session {}: strategy {:?}", session.session_id, session.search_strategy_used));
-                }
+                let description = format!("Session {} on {}", session.session_id, session.target_system);
+                let outcome = if session.success { Evidence::success(&description) } else { Evidence::failure(&description) };
+                evidence.push(match &session.search_strategy_used {
+                    Some(strategy) => outcome.with_strategy(strategy),
+                    None => outcome,
+                });
             }
             for attempt in &debugger.failed_attempts {
-                evidence.push(format!("Failed attempt: {} with pattern {:?}", attempt.failure_reason, attempt.search_pattern));
+                let outcome = Evidence::failure(&attempt.failure_reason);
+                evidence.push(match attempt.search_pattern.first() {
+                    Some(strategy) => outcome.with_strategy(strategy),
+                    None => outcome,
+                });
             }
         }
         evidence
     }
 
-    /// Analyze patterns in evidence
-    fn analyze_evidence_patterns(&self, evidence: &[String]) -> Vec<String> {
-        let mut patterns = Vec::new();
-        let mut failure_count = 0;
-        let mut success_count = 0;
-        let mut strategy_usage = std::collections::HashMap::new();
-        
-        for ev in evidence {
-            if ev.contains("Failed attempt") {
-                failure_count += 1;
-                if ev.contains("adaptive") {
-                    patterns.push("adaptive_strategy_failures".to_string());
-                }
-                if ev.contains("breadth_first") {
-                    patterns.push("breadth_first_failures".to_string());
-                }
-            } else if ev.contains("Success") {
-                success_count += 1;
-                if ev.contains("code_aware") {
-                    patterns.push("code_aware_successes".to_string());
-                }
-                if ev.contains("heuristic") {
-                    patterns.push("heuristic_successes".to_string());
-                }
-            }
-            
-            // Extract strategy names
-            if let Some(strategy) = ev.split("strategy").nth(1) {
-                let strategy_name = strategy.trim_matches(&['[', ']', ' ', '"'] as &[char]);
-                *strategy_usage.entry(strategy_name.to_string()).or_insert(0) += 1;
-            }
-        }
-        
-        // Add usage patterns
-        for (strategy, count) in strategy_usage {
-            if count > 2 {
-                patterns.push(format!("frequent_{}_usage", strategy));
-            }
-        }
-        
-        // Add overall patterns
-        if failure_count > success_count {
-            patterns.push("high_failure_rate".to_string());
-        }
-        if success_count > failure_count {
-            patterns.push("high_success_rate".to_string());
-        }
-        
-        patterns
-    }
-
-    /// Enhanced essence update proposal
-    fn propose_essence_update(&self, evidence: &[String], patterns: &[String]) -> DebuggerEssence {
-        let mut emergent = Vec::new();
-        let mut knowledge_expansion = Vec::new();
-        let mut add_emergent = false;
-        
-        for pattern in patterns {
-            match pattern.as_str() {
-                "adaptive_strategy_failures" => {
-                    emergent.push("adaptive_failure_recovery".to_string());
-                    emergent.push("strategy_switching".to_string());
-                    add_emergent = true;
-                }
-                "code_aware_successes" => {
-                    knowledge_expansion.push(serde_yaml::to_value("code_pattern_learning: 'learn from code analysis'").unwrap());
-                    knowledge_expansion.push(serde_yaml::to_value("complexity_aware_debugging: 'adapt to code complexity'").unwrap());
-                }
-                "high_failure_rate" => {
-                    emergent.push("resilient_debugging".to_string());
-                    emergent.push("failure_analysis".to_string());
-                    add_emergent = true;
-                }
-                "frequent_heuristic_usage" => {
-                    knowledge_expansion.push(serde_yaml::to_value("heuristic_optimization: 'improve pattern recognition'").unwrap());
-                }
-                _ => {}
-            }
-        }
-        
-        // Add general improvements based on evidence
-        if !evidence.is_empty() {
-            knowledge_expansion.push(serde_yaml::to_value("evidence_based_learning: 'learn from debugging sessions'").unwrap());
-        }
-        
-        DebuggerEssence {
-            capabilities: Some(EssenceCapabilities {
-                innate: None,
-                learned: None,
-                emergent: if add_emergent { Some(emergent) } else { None },
-            }),
-            learning_mechanics: Some(EssenceLearningMechanics {
-                knowledge_expansion: if !knowledge_expansion.is_empty() { Some(knowledge_expansion) } else { None },
-                self_tuning: None,
-                other: std::collections::BTreeMap::new(),
-            }),
-            behavioral_patterns: None,
-            emergent: None,
-            other: std::collections::BTreeMap::new(),
-        }
-    }
-
     /// Show what changes were made to the essence
     async fn show_essence_changes(&self) -> Result<()> {
         println!("📋 Essence Changes Applied:");
@@ -1344,55 +1148,33 @@ impl DebuggerTerminal {
         
         Ok(())
     }
+}
 
-    /// Apply the proposed update to the YAML essence file
-    async fn apply_essence_update(&self, update: DebuggerEssence) -> Result<()> {
-        // Load current YAML
-        let content = std::fs::read_to_string(ESSENCE_PATH)?;
-        let mut doc: YamlValue = serde_yaml::from_str(&content)?;
-        // Merge emergent capabilities
-        if let Some(cap) = update.capabilities {
-            if let Some(new_emergent) = cap.emergent {
-                let path = ["capabilities", "emergent"];
-                Self::merge_yaml_list(&mut doc, &path, new_emergent);
-            }
-        }
-        // Merge knowledge_expansion
-        if let Some(learn) = update.learning_mechanics {
-            if let Some(new_know) = learn.knowledge_expansion {
-                let path = ["learning_mechanics", "knowledge_expansion"];
-                Self::merge_yaml_list(&mut doc, &path, new_know);
-            }
-        }
-        // Write back
-        let new_content = serde_yaml::to_string(&doc)?;
-        std::fs::write(ESSENCE_PATH, new_content)?;
-        Ok(())
-    }
+/// Whether a piece of evidence was gathered with a strategy whose name contains `name`
+fn used_strategy(evidence: &Evidence, name: &str) -> bool {
+    evidence.strategy.as_deref().is_some_and(|strategy| strategy.contains(name))
+}
 
-    /// Merge a list into a YAML path (append unique)
-    fn merge_yaml_list(doc: &mut YamlValue, path: &[&str], new_items: Vec<impl Into<YamlValue> + Clone>) {
-        let mut node = doc;
-        for key in &path[..path.len() - 1] {
-            // Avoid multiple mutable borrows by splitting the logic
-            if !node.get(*key).is_some() {
-                node.as_mapping_mut().unwrap().insert((*key).into(), YamlValue::Mapping(Default::default()));
+/// Rules the debugger reflects on its sessions with
+fn debugger_reflector() -> EssenceReflector {
+    EssenceReflector::new()
+        .with_rule(EvidenceRule::when(|e| !e.success && used_strategy(e, "adaptive"))
+            .grants("adaptive_failure_recovery")
+            .grants("strategy_switching"))
+        .with_rule(EvidenceRule::when(|e| e.success && used_strategy(e, "code_aware"))
+            .learns("code_pattern_learning: 'learn from code analysis'")
+            .learns("complexity_aware_debugging: 'adapt to code complexity'"))
+        .with_rule(|evidence: &[Evidence], update: &mut EssenceUpdate| {
+            let failures = evidence.iter().filter(|e| !e.success).count();
+            if failures > evidence.len() - failures {
+                update.grant("resilient_debugging");
+                update.grant("failure_analysis");
             }
-            node = node.get_mut(*key).unwrap();
-        }
-        let last = path[path.len() - 1];
-        let arr_opt = node.get_mut(last).and_then(|v| v.as_sequence_mut());
-        if let Some(arr) = arr_opt {
-            for item in new_items {
-                let val: YamlValue = item.clone().into();
-                if !arr.contains(&val) {
-                    arr.push(val);
-                }
-            }
-        } else {
-            node.as_mapping_mut().unwrap().insert(last.into(), YamlValue::Sequence(new_items.into_iter().map(|i| i.into()).collect()));
-        }
-    }
+        })
+        .with_rule(EvidenceRule::when(|e| used_strategy(e, "heuristic"))
+            .at_least(3)
+            .learns("heuristic_optimization: 'improve pattern recognition'"))
+        .with_rule(EvidenceRule::when(|_| true).learns("evidence_based_learning: 'learn from debugging sessions'"))
 }
 
 /// Code analysis result
@@ -1409,4 +1191,84 @@ async fn main() -> Result<()> {
     let mut terminal = DebuggerTerminal::new().await?;
     terminal.run().await?;
     Ok(())
-} 
\ No newline at end of file
+} 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use emergence_runtime::reflection::EssenceMergeError;
+    use serde_yaml::Value as YamlValue;
+
+    async fn seeded_terminal(exploration_rate: f64) -> DebuggerTerminal {
+        DebuggerTerminal {
+            engine: ExecutionEngine::new_seeded(42).await.unwrap(),
+            debugger: None,
+            session_start: Instant::now(),
+            diagnostic_mode: false,
+            exploration_rate,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_strategy_selection_mostly_exploits_but_explores() {
+        let terminal = seeded_terminal(0.2).await;
+        let strategies = terminal.initialize_search_strategies();
+        let best = strategies.iter()
+            .max_by(|a, b| DebuggerTerminal::strategy_score(a).total_cmp(&DebuggerTerminal::strategy_score(b)))
+            .unwrap()
+            .name
+            .clone();
+
+        let mut picks: HashMap<String, usize> = HashMap::new();
+        for _ in 0..1000 {
+            *picks.entry(terminal.select_optimal_strategy(&strategies).name).or_default() += 1;
+        }
+
+        assert!(picks[&best] > 700 && picks[&best] < 900, "{:?}", picks);
+        assert!(picks.len() > 2, "{:?}", picks);
+
+        let greedy = seeded_terminal(0.0).await;
+        assert!((0..100).all(|_| greedy.select_optimal_strategy(&strategies).name == best));
+    }
+
+    #[test]
+    fn test_reflection_rules_follow_the_sessions() {
+        let reflector = debugger_reflector();
+        let evidence = [
+            Evidence::failure("timed out").with_strategy("enhanced_adaptive"),
+            Evidence::failure("missed the root cause").with_strategy("heuristic"),
+            Evidence::success("found the leak").with_strategy("code_aware"),
+        ];
+
+        let update = reflector.propose(&evidence);
+
+        assert_eq!(update.emergent_capabilities, ["adaptive_failure_recovery", "strategy_switching", "resilient_debugging", "failure_analysis"]);
+        assert_eq!(update.knowledge_expansion, [
+            "code_pattern_learning: 'learn from code analysis'",
+            "complexity_aware_debugging: 'adapt to code complexity'",
+            "evidence_based_learning: 'learn from debugging sessions'",
+        ].map(YamlValue::from));
+        assert!(reflector.propose(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_malformed_essence_is_reported_not_panicked_on() {
+        let dir = tempfile::tempdir().unwrap();
+        let essence = dir.path().join("debugger-essence.yaml");
+        let malformed = "capabilities: debugging\nlearning_mechanics:\n";
+        fs::write(&essence, malformed).unwrap();
+        let reflector = debugger_reflector();
+
+        let err = reflector.apply(&reflector.propose(&[Evidence::failure("timed out")]), &essence).unwrap_err();
+
+        assert!(matches!(err.downcast_ref(), Some(EssenceMergeError::NotAMapping { path, .. }) if path == "capabilities"));
+        assert!(err.to_string().contains("`capabilities`"));
+        assert_eq!(fs::read_to_string(&essence).unwrap(), malformed);
+
+        fs::write(&essence, "capabilities:\n  emergent: [failure_analysis]\nlearning_mechanics:\n").unwrap();
+        reflector.apply(&reflector.propose(&[Evidence::failure("timed out")]), &essence).unwrap();
+        let doc: YamlValue = serde_yaml::from_str(&fs::read_to_string(&essence).unwrap()).unwrap();
+        assert_eq!(doc["capabilities"]["emergent"], serde_yaml::from_str::<YamlValue>("[failure_analysis, resilient_debugging]").unwrap());
+        assert_eq!(doc["learning_mechanics"]["knowledge_expansion"][0], YamlValue::from("evidence_based_learning: 'learn from debugging sessions'"));
+    }
+}
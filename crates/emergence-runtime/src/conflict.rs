@@ -0,0 +1,190 @@
+//! Arbitration between agents' contradictory Motor commands.
+//!
+//! Motor commands naming the same action whose timestamps fall within the
+//! resolver's window are treated as one contest. Only the winner, chosen by
+//! the configured [`ResolutionPolicy`], reaches the effector; the others are
+//! superseded.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use emergence_nervous_system::NeuralSignal;
+use emergence_physics::EntityId;
+use serde::{Deserialize, Serialize};
+
+use crate::effector::action_name;
+
+/// Time within which commands for the same action are considered to conflict
+pub const DEFAULT_CONFLICT_WINDOW: Duration = Duration::from_millis(100);
+
+/// How the winner among conflicting Motor commands is chosen
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ResolutionPolicy {
+    /// The command with the strongest signal wins
+    #[default]
+    HighestStrength,
+    /// The command from the source holding the most energy wins
+    HighestEnergy,
+    /// The command from the most persistent agent wins
+    Persistence,
+}
+
+impl ResolutionPolicy {
+    /// Name used in superseded responses
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ResolutionPolicy::HighestStrength => "highest_strength",
+            ResolutionPolicy::HighestEnergy => "highest_energy",
+            ResolutionPolicy::Persistence => "persistence",
+        }
+    }
+}
+
+/// What the policies know about the source of a command
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SourceStanding {
+    pub energy: f64,
+    pub persistence: f64,
+}
+
+/// Commands for one action that arrived together, and which one prevailed
+#[derive(Debug, Clone)]
+pub struct Contest {
+    pub winner: NeuralSignal,
+    /// Losing commands in arrival order
+    pub superseded: Vec<NeuralSignal>,
+}
+
+/// Groups conflicting Motor commands and picks a winner for each group
+#[derive(Debug, Clone)]
+pub struct ConflictResolver {
+    pub policy: ResolutionPolicy,
+    pub window: Duration,
+}
+
+impl Default for ConflictResolver {
+    fn default() -> Self {
+        Self::new(ResolutionPolicy::default())
+    }
+}
+
+impl ConflictResolver {
+    pub fn new(policy: ResolutionPolicy) -> Self {
+        Self {
+            policy,
+            window: DEFAULT_CONFLICT_WINDOW,
+        }
+    }
+
+    pub fn with_window(mut self, window: Duration) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// Split commands into contests, in arrival order
+    ///
+    /// A contest holds the commands for one action arriving within the window
+    /// of its first command. Sources missing from `standing` rank lowest under
+    /// the energy and persistence policies; ties go to the earlier command.
+    pub fn resolve(&self, commands: &[NeuralSignal], standing: &HashMap<EntityId, SourceStanding>) -> Vec<Contest> {
+        let mut ordered: Vec<&NeuralSignal> = commands.iter().collect();
+        ordered.sort_by_key(|command| command.timestamp);
+        let window = chrono::Duration::from_std(self.window).unwrap_or(chrono::Duration::MAX);
+
+        let mut groups: Vec<Vec<&NeuralSignal>> = Vec::new();
+        let mut open: HashMap<Option<&str>, usize> = HashMap::new();
+        for command in ordered {
+            let action = action_name(&command.payload);
+            let joins = open.get(&action)
+                .filter(|&&index| command.timestamp - groups[index][0].timestamp <= window)
+                .copied();
+            match joins {
+                Some(index) => groups[index].push(command),
+                None => {
+                    open.insert(action, groups.len());
+                    groups.push(vec![command]);
+                }
+            }
+        }
+
+        groups.into_iter()
+            .map(|group| {
+                let mut winner = 0;
+                for (index, command) in group.iter().enumerate().skip(1) {
+                    if self.score(command, standing) > self.score(group[winner], standing) {
+                        winner = index;
+                    }
+                }
+                let superseded = group.iter().enumerate()
+                    .filter(|(index, _)| *index != winner)
+                    .map(|(_, command)| (*command).clone())
+                    .collect();
+                Contest {
+                    winner: group[winner].clone(),
+                    superseded,
+                }
+            })
+            .collect()
+    }
+
+    fn score(&self, command: &NeuralSignal, standing: &HashMap<EntityId, SourceStanding>) -> f64 {
+        let source = standing.get(&command.source).copied().unwrap_or_default();
+        match self.policy {
+            ResolutionPolicy::HighestStrength => command.strength,
+            ResolutionPolicy::HighestEnergy => source.energy,
+            ResolutionPolicy::Persistence => source.persistence,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use emergence_nervous_system::{SignalPayload, SignalType};
+
+    fn command(source: EntityId, text: &str, strength: f64, offset_ms: i64) -> NeuralSignal {
+        let mut signal = NeuralSignal::new(SignalType::Motor, source, None, SignalPayload::Command(text.to_string()), strength);
+        signal.timestamp = chrono::DateTime::UNIX_EPOCH + chrono::Duration::milliseconds(offset_ms);
+        signal
+    }
+
+    #[test]
+    fn test_only_nearby_commands_for_one_action_conflict() {
+        let a = EntityId::new();
+        let b = EntityId::new();
+        let commands = [
+            command(a, "open_valve 3", 0.4, 0),
+            command(b, "close_pump", 0.9, 10),
+            command(b, "open_valve 1", 0.8, 50),
+            command(a, "open_valve 2", 0.9, 500),
+        ];
+
+        let contests = ConflictResolver::default().resolve(&commands, &HashMap::new());
+
+        assert_eq!(contests.len(), 3);
+        assert_eq!(contests[0].winner.signal_id, commands[2].signal_id);
+        assert_eq!(contests[0].superseded.len(), 1);
+        assert_eq!(contests[0].superseded[0].signal_id, commands[0].signal_id);
+        assert!(contests[1].superseded.is_empty());
+        assert!(contests[2].superseded.is_empty());
+    }
+
+    #[test]
+    fn test_policies_rank_sources() {
+        let rich = EntityId::new();
+        let stubborn = EntityId::new();
+        let commands = [
+            command(rich, "open_valve", 0.2, 0),
+            command(stubborn, "open_valve", 0.1, 20),
+        ];
+        let standing = HashMap::from([
+            (rich, SourceStanding { energy: 0.9, persistence: 0.3 }),
+            (stubborn, SourceStanding { energy: 0.1, persistence: 0.95 }),
+        ]);
+
+        let winner = |policy| ConflictResolver::new(policy).resolve(&commands, &standing)[0].winner.source;
+        assert_eq!(winner(ResolutionPolicy::HighestStrength), rich);
+        assert_eq!(winner(ResolutionPolicy::HighestEnergy), rich);
+        assert_eq!(winner(ResolutionPolicy::Persistence), stubborn);
+    }
+}
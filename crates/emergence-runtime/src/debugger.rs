@@ -4,18 +4,25 @@
 //! allowing LLMs and other tools to query system diagnostics, monitor performance,
 //! and access optimization strategies.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::fs;
 use std::path::Path;
+use std::sync::Mutex;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
-use emergence_physics::EntityId;
+use emergence_physics::{EntityId, PhysicsViolationEvent, PhysicsViolationKind};
+use tokio::sync::broadcast;
+use ordered_float::OrderedFloat;
+use crate::lint::{LintRule, RuleSet};
 
 /// Debugger agent interface for LLM tool access
 pub struct DebuggerInterface {
     engine: crate::ExecutionEngine,
     debugger: Option<DebuggerAgent>,
+    rules: RuleSet,
+    violation_events: Mutex<broadcast::Receiver<PhysicsViolationEvent>>,
+    recent_violations: Mutex<VecDeque<PhysicsViolationEvent>>,
 }
 
 /// Code analysis result for LLM consumption
@@ -37,6 +44,7 @@ pub struct CodeIssue {
     pub category: String,
     pub description: String,
     pub line_number: Option<usize>,
+    pub column_number: Option<usize>,
     pub code_snippet: Option<String>,
     pub explanation: String,
     pub suggested_fix: Option<String>,
@@ -140,14 +148,14 @@ pub struct PhysicsMetrics {
 pub struct EnergyMetrics {
     pub total_energy: f64,
     pub energy_distribution: HashMap<String, f64>,
-    pub energy_efficiency: f64,
+    /// Fraction of the total energy currently allocated to entities
+    pub energy_utilization: f64,
     pub energy_violations: usize,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MemoryMetrics {
     pub total_memory_usage: usize,
-    pub memory_fragmentation: f64,
     pub memory_access_patterns: HashMap<String, usize>,
     pub memory_violations: usize,
 }
@@ -205,16 +213,139 @@ pub enum DebuggerState {
     SelfOptimizing,
 }
 
+/// Typed command accepted by the debugger terminal
+#[derive(Debug, Clone, PartialEq)]
+pub enum DebuggerCommand {
+    Awaken { traits: HashMap<String, f64> },
+    Diagnose { target: Option<String> },
+    Monitor { cycles: u32 },
+    Forensic { target: Option<String> },
+    Optimize,
+    Analyze,
+    Strategies,
+    Status,
+    Physics,
+    Energy,
+    Memory,
+    Reflect,
+    Help,
+    Exit,
+    /// Free-form message addressed to the active debugger
+    Chat { message: String },
+}
+
+/// Errors produced while parsing a debugger command line
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum CommandParseError {
+    #[error("Empty command")]
+    Empty,
+    #[error("Malformed trait '{token}', expected name=value")]
+    MalformedTrait { token: String },
+    #[error("Invalid value '{value}' for trait '{name}', expected a number between 0.0 and 1.0")]
+    InvalidTraitValue { name: String, value: String },
+    #[error("Invalid argument '{argument}' for '{command}'")]
+    InvalidArgument { command: String, argument: String },
+}
+
+/// Default number of monitoring cycles when none is given
+pub const DEFAULT_MONITOR_CYCLES: u32 = 10;
+
+/// Parse a debugger terminal line into a typed command
+///
+/// Traits for `awaken` are given as `name=value` (or the legacy `name=(value)`).
+/// Lines that don't start with a known command are treated as chat.
+pub fn parse_command(input: &str) -> Result<DebuggerCommand, CommandParseError> {
+    let input = input.trim();
+    let mut tokens = input.split_whitespace();
+    let command = tokens.next().ok_or(CommandParseError::Empty)?;
+    let args: Vec<&str> = tokens.collect();
+
+    let single_target = |args: &[&str]| -> Option<String> {
+        if args.is_empty() { None } else { Some(args.join(" ")) }
+    };
+
+    let parsed = match command {
+        "awaken" | "wake" => DebuggerCommand::Awaken { traits: parse_traits(&args)? },
+        "diagnose" => DebuggerCommand::Diagnose { target: single_target(&args) },
+        "monitor" => {
+            let cycles = match args.as_slice() {
+                [] => DEFAULT_MONITOR_CYCLES,
+                [cycles] => cycles.parse::<u32>().map_err(|_| CommandParseError::InvalidArgument {
+                    command: command.to_string(),
+                    argument: cycles.to_string(),
+                })?,
+                _ => return Err(CommandParseError::InvalidArgument {
+                    command: command.to_string(),
+                    argument: args.join(" "),
+                }),
+            };
+            DebuggerCommand::Monitor { cycles }
+        }
+        "forensic" => DebuggerCommand::Forensic { target: single_target(&args) },
+        "optimize" => DebuggerCommand::Optimize,
+        "analyze" => DebuggerCommand::Analyze,
+        "strategies" => DebuggerCommand::Strategies,
+        "status" => DebuggerCommand::Status,
+        "physics" => DebuggerCommand::Physics,
+        "energy" => DebuggerCommand::Energy,
+        "memory" => DebuggerCommand::Memory,
+        "reflect" => DebuggerCommand::Reflect,
+        "help" => DebuggerCommand::Help,
+        "exit" | "quit" => DebuggerCommand::Exit,
+        _ => DebuggerCommand::Chat { message: input.to_string() },
+    };
+
+    Ok(parsed)
+}
+
+fn parse_traits(args: &[&str]) -> Result<HashMap<String, f64>, CommandParseError> {
+    let mut traits = HashMap::new();
+
+    for token in args {
+        // The essence name ("awaken debugger") is not a trait
+        if *token == "debugger" {
+            continue;
+        }
+
+        let (name, value) = token.split_once('=')
+            .filter(|(name, _)| !name.is_empty())
+            .ok_or_else(|| CommandParseError::MalformedTrait { token: token.to_string() })?;
+
+        let raw_value = value.trim_start_matches('(').trim_end_matches(')');
+        let parsed = raw_value.parse::<f64>()
+            .ok()
+            .filter(|v| (0.0..=1.0).contains(v))
+            .ok_or_else(|| CommandParseError::InvalidTraitValue {
+                name: name.to_string(),
+                value: value.to_string(),
+            })?;
+
+        traits.insert(name.to_string(), parsed);
+    }
+
+    Ok(traits)
+}
+
 impl DebuggerInterface {
     /// Create a new debugger interface
     pub async fn new() -> Result<Self> {
         let engine = crate::ExecutionEngine::new().await?;
+        let violation_events = engine.get_physics_engine().subscribe_violations();
         Ok(Self {
             engine,
             debugger: None,
+            rules: RuleSet::default(),
+            violation_events: Mutex::new(violation_events),
+            recent_violations: Mutex::new(VecDeque::new()),
         })
     }
 
+    /// Add a custom lint rule used by code analysis
+    pub fn with_rule(mut self, rule: LintRule) -> Self {
+        self.rules.add_rule(rule);
+        self
+    }
+
     /// Awaken the debugger agent with optional personality traits
     pub async fn awaken(&mut self, personality_traits: Option<HashMap<String, f64>>) -> Result<DebuggerAgent> {
         let mut traits = HashMap::new();
@@ -229,8 +360,18 @@ impl DebuggerInterface {
             traits.extend(custom_traits);
         }
 
+        // Reclaim the energy of a previous incarnation before allocating anew
+        if let Some(previous) = self.debugger.take() {
+            self.engine.get_physics_engine().release_energy_from_entity(previous.id).await?;
+        }
+
+        let id = self.engine.generate_entity_id();
+        self.engine.get_physics_engine()
+            .allocate_energy_to_entity(id, OrderedFloat(DEBUGGER_PHYSICS_ENERGY))
+            .await?;
+
         let debugger = DebuggerAgent {
-            id: EntityId::new(),
+            id,
             name: "Debugger Agent".to_string(),
             essence_type: "debugger".to_string(),
             energy: 100.0,
@@ -295,6 +436,11 @@ impl DebuggerInterface {
         Ok(findings)
     }
 
+    /// Check that every subsystem of the engine is wired up and responding
+    pub async fn health_check(&self) -> crate::health::HealthReport {
+        self.engine.health_check().await
+    }
+
     /// Perform system diagnosis
     pub async fn diagnose(&self, target_system: Option<&str>) -> Result<DiagnosticSession> {
         let session_id = format!("diagnostic_{}", Utc::now().timestamp());
@@ -396,65 +542,7 @@ impl DebuggerInterface {
     }
 
     fn identify_code_issues(&self, content: &str, language: &str) -> Result<Vec<CodeIssue>> {
-        let mut issues = Vec::new();
-        
-        match language.as_ref() {
-            "rust" => {
-                // Rust-specific issues
-                if content.contains("unwrap()") {
-                    issues.push(CodeIssue {
-                        severity: IssueSeverity::Warning,
-                        category: "Error Handling".to_string(),
-                        description: "Use of unwrap() without proper error handling".to_string(),
-                        line_number: self.find_line_number(content, "unwrap()"),
-                        code_snippet: self.extract_code_snippet(content, "unwrap()"),
-                        explanation: "unwrap() can panic if the value is None or Err. Consider using match, if let, or ? operator for safer error handling.".to_string(),
-                        suggested_fix: Some("Replace unwrap() with proper error handling using match or ? operator".to_string()),
-                    });
-                }
-                
-                if content.contains("clone()") && content.matches("clone()").count() > 3 {
-                    issues.push(CodeIssue {
-                        severity: IssueSeverity::Warning,
-                        category: "Performance".to_string(),
-                        description: "Excessive use of clone() may indicate performance issues".to_string(),
-                        line_number: None,
-                        code_snippet: None,
-                        explanation: "Frequent cloning can impact performance. Consider using references or more efficient data structures.".to_string(),
-                        suggested_fix: Some("Review clone() usage and consider using references where possible".to_string()),
-                    });
-                }
-                
-                if content.contains("1/0") || content.contains("division by zero") {
-                    issues.push(CodeIssue {
-                        severity: IssueSeverity::Critical,
-                        category: "Logic Error".to_string(),
-                        description: "Division by zero detected".to_string(),
-                        line_number: self.find_line_number(content, "1/0"),
-                        code_snippet: self.extract_code_snippet(content, "1/0"),
-                        explanation: "Division by zero will cause a runtime panic in Rust. This is a critical logic error.".to_string(),
-                        suggested_fix: Some("Add a check to ensure the denominator is not zero before division".to_string()),
-                    });
-                }
-            }
-            "python" => {
-                // Python-specific issues
-                if content.contains("except:") {
-                    issues.push(CodeIssue {
-                        severity: IssueSeverity::Warning,
-                        category: "Error Handling".to_string(),
-                        description: "Bare except clause catches all exceptions".to_string(),
-                        line_number: self.find_line_number(content, "except:"),
-                        code_snippet: self.extract_code_snippet(content, "except:"),
-                        explanation: "Bare except clauses can mask important errors. Be specific about which exceptions to catch.".to_string(),
-                        suggested_fix: Some("Specify the exception types to catch instead of using bare except".to_string()),
-                    });
-                }
-            }
-            _ => {}
-        }
-        
-        Ok(issues)
+        Ok(self.rules.analyze(content, language))
     }
 
     fn identify_code_patterns(&self, content: &str, _language: &str) -> Result<Vec<CodePattern>> {
@@ -641,24 +729,6 @@ impl DebuggerInterface {
         Ok(findings)
     }
 
-    fn find_line_number(&self, content: &str, pattern: &str) -> Option<usize> {
-        for (i, line) in content.lines().enumerate() {
-            if line.contains(pattern) {
-                return Some(i + 1);
-            }
-        }
-        None
-    }
-
-    fn extract_code_snippet(&self, content: &str, pattern: &str) -> Option<String> {
-        for line in content.lines() {
-            if line.contains(pattern) {
-                return Some(line.trim().to_string());
-            }
-        }
-        None
-    }
-
     // Private helper methods
     fn initialize_search_strategies(&self) -> Vec<SearchStrategy> {
         vec![
@@ -742,50 +812,77 @@ impl DebuggerInterface {
     }
 
     async fn collect_physics_metrics(&self) -> Result<PhysicsMetrics> {
+        let state = self.engine.get_physics_engine().get_engine_state().await?;
+        let stats = &state.operation_stats;
+
         Ok(PhysicsMetrics {
-            active_entities: 42,
-            energy_conservation_violations: 0,
-            capability_violations: 0,
-            physics_operations_per_second: 156.7,
+            active_entities: state.energy_state.active_entities,
+            energy_conservation_violations: stats.energy_violations as usize,
+            capability_violations: stats.security_violations as usize,
+            physics_operations_per_second: stats.operations_per_second(state.uptime),
         })
     }
 
     async fn collect_energy_metrics(&self) -> Result<EnergyMetrics> {
+        let state = self.engine.get_physics_engine().get_engine_state().await?;
+        let energy = &state.energy_state;
+        let dist = &energy.energy_distribution;
+
         let mut distribution = HashMap::new();
-        distribution.insert("physics_engine".to_string(), 35.0);
-        distribution.insert("memory_system".to_string(), 25.0);
-        distribution.insert("nervous_system".to_string(), 20.0);
-        distribution.insert("debugger_agent".to_string(), 10.0);
-        distribution.insert("other".to_string(), 10.0);
+        distribution.insert("allocated".to_string(), energy.allocated_energy.0);
+        distribution.insert("free".to_string(), energy.free_energy.0);
+        distribution.insert("mean_per_entity".to_string(), dist.mean.0);
+        distribution.insert("min_per_entity".to_string(), dist.min.0);
+        distribution.insert("max_per_entity".to_string(), dist.max.0);
+        distribution.insert("variance".to_string(), dist.variance.0);
+
+        let energy_utilization = if energy.total_energy.0 > 0.0 {
+            energy.allocated_energy.0 / energy.total_energy.0
+        } else {
+            0.0
+        };
 
         Ok(EnergyMetrics {
-            total_energy: 100.0,
+            total_energy: energy.total_energy.0,
             energy_distribution: distribution,
-            energy_efficiency: 0.87,
-            energy_violations: 0,
+            energy_utilization,
+            energy_violations: state.operation_stats.energy_violations as usize,
         })
     }
 
     async fn collect_memory_metrics(&self) -> Result<MemoryMetrics> {
+        let stats = self.engine.get_memory_substrate().get_statistics();
+
         let mut access_patterns = HashMap::new();
-        access_patterns.insert("physics_operations".to_string(), 156);
-        access_patterns.insert("energy_allocations".to_string(), 89);
-        access_patterns.insert("memory_operations".to_string(), 234);
+        access_patterns.insert("stored_memories".to_string(), stats.stored_memories);
 
         Ok(MemoryMetrics {
-            total_memory_usage: 1024 * 1024 * 50, // 50MB
-            memory_fragmentation: 0.12,
+            total_memory_usage: stats.bytes_used,
             memory_access_patterns: access_patterns,
             memory_violations: 0,
         })
     }
 
     async fn collect_nervous_system_metrics(&self) -> Result<NervousSystemMetrics> {
+        let stats = self.engine.get_nervous_system().get_statistics().await?;
+
+        let coordination_efficiency = if stats.total_signals_processed > 0 {
+            1.0 - stats.total_errors as f64 / stats.total_signals_processed as f64
+        } else {
+            1.0
+        };
+        let uptime_secs = stats.uptime.as_secs_f64();
+        let system_events_per_second = if uptime_secs > 0.0 {
+            stats.total_signals_processed as f64 / uptime_secs
+        } else {
+            0.0
+        };
+
         Ok(NervousSystemMetrics {
-            active_agents: 5,
-            message_queue_size: 23,
-            coordination_efficiency: 0.92,
-            system_events_per_second: 45.3,
+            active_agents: stats.registered_entities,
+            message_queue_size: stats.pending_signals,
+            coordination_efficiency,
+            system_events_per_second,
         })
     }
 
@@ -813,37 +910,216 @@ impl DebuggerInterface {
     }
 
     async fn perform_forensic_analysis(&self, target: &str) -> Result<Vec<DiagnosticFinding>> {
-        let mut findings = Vec::new();
+        let violations = self.recent_violations();
+        let relevant: Vec<&PhysicsViolationEvent> = match target {
+            "energy-system" => violations.iter()
+                .filter(|v| v.kind == PhysicsViolationKind::EnergyConservation)
+                .collect(),
+            _ => violations.iter().collect(),
+        };
 
-        match target {
-            "energy-system" => {
-                findings.push(DiagnosticFinding {
-                    severity: FindingSeverity::Info,
-                    category: "Forensic Analysis".to_string(),
-                    description: "Energy system forensic analysis completed".to_string(),
-                    evidence: vec![
-                        "Energy conservation laws maintained".to_string(),
-                        "No unauthorized energy transfers detected".to_string(),
-                        "Distribution patterns within normal ranges".to_string(),
-                    ],
-                    recommendations: vec!["Continue monitoring energy distribution".to_string()],
-                    timestamp: Utc::now(),
-                });
+        let finding = if relevant.is_empty() {
+            let evidence = match target {
+                "energy-system" => vec![
+                    "Energy conservation laws maintained".to_string(),
+                    "No energy conservation violations recorded".to_string(),
+                ],
+                _ => vec!["No physics violations recorded".to_string()],
+            };
+            DiagnosticFinding {
+                severity: FindingSeverity::Info,
+                category: "Forensic Analysis".to_string(),
+                description: format!("Forensic analysis of {} completed", target),
+                evidence,
+                recommendations: vec!["Continue monitoring".to_string()],
+                timestamp: Utc::now(),
             }
-            _ => {
-                findings.push(DiagnosticFinding {
-                    severity: FindingSeverity::Info,
-                    category: "Forensic Analysis".to_string(),
-                    description: format!("Forensic analysis of {} completed", target),
-                    evidence: vec!["No suspicious activity detected".to_string()],
-                    recommendations: vec!["System appears healthy".to_string()],
-                    timestamp: Utc::now(),
-                });
+        } else {
+            DiagnosticFinding {
+                severity: FindingSeverity::Warning,
+                category: "Forensic Analysis".to_string(),
+                description: format!("{} physics violations recorded for {}", relevant.len(), target),
+                evidence: relevant.iter()
+                    .map(|v| format!(
+                        "[{}] {:?}{}: {}",
+                        v.timestamp.format("%H:%M:%S"),
+                        v.kind,
+                        v.entity.map(|e| format!(" by {}", e)).unwrap_or_default(),
+                        v.message
+                    ))
+                    .collect(),
+                recommendations: vec!["Inspect the operations issued by the listed entities".to_string()],
+                timestamp: Utc::now(),
+            }
+        };
+
+        Ok(vec![finding])
+    }
+
+    /// Drain newly broadcast violations into the recent-violation buffer and return it
+    fn recent_violations(&self) -> Vec<PhysicsViolationEvent> {
+        let mut receiver = self.violation_events.lock().unwrap();
+        let mut recent = self.recent_violations.lock().unwrap();
+        loop {
+            match receiver.try_recv() {
+                Ok(event) => {
+                    recent.push_back(event);
+                    if recent.len() > MAX_RECENT_VIOLATIONS {
+                        recent.pop_front();
+                    }
+                }
+                Err(broadcast::error::TryRecvError::Lagged(_)) => continue,
+                Err(_) => break,
             }
         }
+        recent.iter().cloned().collect()
+    }
+}
 
-        Ok(findings)
+/// Number of recent physics violations retained for forensic analysis
+const MAX_RECENT_VIOLATIONS: usize = 100;
+
+/// Physics energy reserved for an awakened debugger agent
+const DEBUGGER_PHYSICS_ENERGY: f64 = 0.1;
+
+/// Error rate above which nervous-system processing is reported as an error
+const HIGH_SIGNAL_ERROR_RATE: f64 = 0.25;
+
+/// Diagnose the live subsystems of an execution engine
+///
+/// Findings are derived from the physics engine state, nervous system statistics
+/// and memory substrate statistics; evidence carries the measured values.
+pub async fn diagnose_engine(engine: &crate::ExecutionEngine) -> Vec<DiagnosticFinding> {
+    let mut findings = Vec::new();
+
+    match engine.get_physics_engine().get_engine_state().await {
+        Ok(state) => {
+            findings.push(DiagnosticFinding {
+                severity: FindingSeverity::Info,
+                category: "Physics Engine".to_string(),
+                description: "Physics engine is operational".to_string(),
+                evidence: vec![
+                    format!("Instance ID: {}", state.instance_id),
+                    format!("Uptime: {:?}", state.uptime),
+                ],
+                recommendations: vec![],
+                timestamp: Utc::now(),
+            });
+
+            let energy_state = state.energy_state;
+            let low_energy = energy_state.total_energy.0 < 0.1;
+            findings.push(DiagnosticFinding {
+                severity: if low_energy { FindingSeverity::Warning } else { FindingSeverity::Info },
+                category: "Energy System".to_string(),
+                description: format!("Energy system status: {:.4} total energy", energy_state.total_energy),
+                evidence: vec![
+                    format!("Total energy: {:.4}", energy_state.total_energy),
+                    format!("Allocated energy: {:.4}", energy_state.allocated_energy),
+                    format!("Free energy: {:.4}", energy_state.free_energy),
+                    format!("Active entities: {}", energy_state.active_entities),
+                ],
+                recommendations: if low_energy {
+                    vec!["Monitor energy consumption".to_string(), "Consider energy optimization".to_string()]
+                } else {
+                    vec![]
+                },
+                timestamp: Utc::now(),
+            });
+        }
+        Err(e) => {
+            findings.push(DiagnosticFinding {
+                severity: FindingSeverity::Error,
+                category: "Physics Engine".to_string(),
+                description: "Physics engine is not responding".to_string(),
+                evidence: vec![e.to_string()],
+                recommendations: vec!["Restart physics engine".to_string(), "Check system logs".to_string()],
+                timestamp: Utc::now(),
+            });
+        }
     }
+
+    match engine.get_nervous_system().get_statistics().await {
+        Ok(stats) => {
+            let error_rate = if stats.total_signals_processed > 0 {
+                stats.total_errors as f64 / stats.total_signals_processed as f64
+            } else {
+                0.0
+            };
+
+            let (severity, description, recommendations) = if stats.registered_entities == 0 {
+                (
+                    FindingSeverity::Warning,
+                    "No entities are registered with the nervous system".to_string(),
+                    vec!["Awaken at least one agent before relying on signal routing".to_string()],
+                )
+            } else if error_rate >= HIGH_SIGNAL_ERROR_RATE {
+                (
+                    FindingSeverity::Error,
+                    format!("High signal processing error rate: {:.0}%", error_rate * 100.0),
+                    vec![
+                        "Inspect failing signal processors".to_string(),
+                        "Check the payloads being sent to affected entities".to_string(),
+                    ],
+                )
+            } else if stats.total_errors > 0 {
+                (
+                    FindingSeverity::Warning,
+                    format!("{} signal processing errors recorded", stats.total_errors),
+                    vec!["Review signal processor error logs".to_string()],
+                )
+            } else {
+                (
+                    FindingSeverity::Info,
+                    "Nervous system processing signals without errors".to_string(),
+                    vec![],
+                )
+            };
+
+            findings.push(DiagnosticFinding {
+                severity,
+                category: "Nervous System".to_string(),
+                description,
+                evidence: vec![
+                    format!("Registered entities: {}", stats.registered_entities),
+                    format!("Neural pathways: {}", stats.total_pathways),
+                    format!("Signals processed: {}", stats.total_signals_processed),
+                    format!("Processing errors: {}", stats.total_errors),
+                    format!("Average processing time: {:?}", stats.avg_processing_time),
+                ],
+                recommendations,
+                timestamp: Utc::now(),
+            });
+        }
+        Err(e) => {
+            findings.push(DiagnosticFinding {
+                severity: FindingSeverity::Error,
+                category: "Nervous System".to_string(),
+                description: "Nervous system statistics unavailable".to_string(),
+                evidence: vec![e.to_string()],
+                recommendations: vec!["Check nervous system logs".to_string()],
+                timestamp: Utc::now(),
+            });
+        }
+    }
+
+    let memory_stats = engine.get_memory_substrate().get_statistics();
+    findings.push(DiagnosticFinding {
+        severity: FindingSeverity::Info,
+        category: "Memory Substrate".to_string(),
+        description: if memory_stats.stored_memories == 0 {
+            "Memory substrate is empty".to_string()
+        } else {
+            format!("Memory substrate holds {} memories", memory_stats.stored_memories)
+        },
+        evidence: vec![
+            format!("Stored memories: {}", memory_stats.stored_memories),
+            format!("Bytes used: {}", memory_stats.bytes_used),
+        ],
+        recommendations: vec![],
+        timestamp: Utc::now(),
+    });
+
+    findings
 }
 
 /// Convenience functions for LLM tool access
@@ -894,4 +1170,165 @@ pub mod tools {
     pub async fn trigger_optimization(debugger: &mut DebuggerInterface) -> Result<Vec<OptimizationRecord>> {
         debugger.optimize().await
     }
-} 
\ No newline at end of file
+} 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::time::Duration;
+    use emergence_nervous_system::{NeuralSignal, SignalPayload, SignalProcessorFn, SignalType};
+    use emergence_physics::PhysicsOperation;
+
+    struct FailingProcessor;
+
+    impl SignalProcessorFn for FailingProcessor {
+        fn process_signal(&self, _signal: &NeuralSignal) -> Result<Option<NeuralSignal>> {
+            Err(anyhow::anyhow!("induced failure"))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_diagnosis_reports_nervous_system_errors() {
+        let engine = crate::ExecutionEngine::new().await.unwrap();
+        let sender = EntityId::new();
+        let entity = EntityId::new();
+        engine.physics.allocate_energy_to_entity(sender, OrderedFloat(0.1)).await.unwrap();
+        engine.nervous_system
+            .register_entity(entity, HashSet::from([SignalType::Sensory]), Box::new(FailingProcessor))
+            .await
+            .unwrap();
+
+        for _ in 0..2 {
+            let signal = NeuralSignal::new(
+                SignalType::Sensory,
+                sender,
+                Some(entity),
+                SignalPayload::Message("trigger failure".to_string()),
+                0.5,
+            );
+            engine.nervous_system.transmit_signal(signal).await.unwrap();
+        }
+        for _ in 0..50 {
+            if engine.nervous_system.get_statistics().await.unwrap().total_errors == 2 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        let findings = diagnose_engine(&engine).await;
+        let nervous = findings.iter()
+            .find(|f| f.category == "Nervous System")
+            .expect("nervous system finding");
+
+        assert_eq!(nervous.severity, FindingSeverity::Error);
+        assert!(nervous.evidence.contains(&"Processing errors: 2".to_string()));
+        assert!(nervous.evidence.contains(&"Registered entities: 1".to_string()));
+        assert!(findings.iter().any(|f| f.category == "Memory Substrate"));
+    }
+
+    #[test]
+    fn test_parse_awaken_with_traits() {
+        let command = parse_command("awaken debugger precision=0.9 patience=(0.4)").unwrap();
+        let DebuggerCommand::Awaken { traits } = command else {
+            panic!("expected awaken command, got {:?}", command);
+        };
+        assert_eq!(traits.len(), 2);
+        assert_eq!(traits["precision"], 0.9);
+        assert_eq!(traits["patience"], 0.4);
+
+        assert_eq!(
+            parse_command("wake").unwrap(),
+            DebuggerCommand::Awaken { traits: HashMap::new() }
+        );
+    }
+
+    #[test]
+    fn test_parse_commands_with_arguments() {
+        assert_eq!(
+            parse_command("diagnose energy-system").unwrap(),
+            DebuggerCommand::Diagnose { target: Some("energy-system".to_string()) }
+        );
+        assert_eq!(parse_command("diagnose").unwrap(), DebuggerCommand::Diagnose { target: None });
+        assert_eq!(
+            parse_command("monitor").unwrap(),
+            DebuggerCommand::Monitor { cycles: DEFAULT_MONITOR_CYCLES }
+        );
+        assert_eq!(parse_command("  monitor 3 ").unwrap(), DebuggerCommand::Monitor { cycles: 3 });
+        assert_eq!(parse_command("quit").unwrap(), DebuggerCommand::Exit);
+        assert_eq!(
+            parse_command("why is energy low?").unwrap(),
+            DebuggerCommand::Chat { message: "why is energy low?".to_string() }
+        );
+    }
+
+    #[test]
+    fn test_parse_malformed_input() {
+        assert_eq!(parse_command("   "), Err(CommandParseError::Empty));
+        assert_eq!(
+            parse_command("awaken debugger precision"),
+            Err(CommandParseError::MalformedTrait { token: "precision".to_string() })
+        );
+        assert_eq!(
+            parse_command("awaken =0.5"),
+            Err(CommandParseError::MalformedTrait { token: "=0.5".to_string() })
+        );
+        assert_eq!(
+            parse_command("awaken precision=high"),
+            Err(CommandParseError::InvalidTraitValue { name: "precision".to_string(), value: "high".to_string() })
+        );
+        assert!(matches!(
+            parse_command("awaken precision=1.5"),
+            Err(CommandParseError::InvalidTraitValue { .. })
+        ));
+        assert!(matches!(
+            parse_command("monitor forever"),
+            Err(CommandParseError::InvalidArgument { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_forensic_analysis_reports_violations() {
+        let interface = DebuggerInterface::new().await.unwrap();
+        let clean = interface.forensic_analysis(None).await.unwrap();
+        assert_eq!(clean[0].severity, FindingSeverity::Info);
+
+        let pauper = EntityId::new();
+        let transfer = PhysicsOperation::TransferEnergy {
+            from: pauper,
+            to: EntityId::new(),
+            amount: OrderedFloat(0.5),
+        };
+        assert!(interface.engine.get_physics_engine().execute_operation(transfer).await.is_err());
+
+        let findings = interface.forensic_analysis(Some("energy-system")).await.unwrap();
+        assert_eq!(findings[0].severity, FindingSeverity::Warning);
+        assert_eq!(findings[0].evidence.len(), 1);
+        assert!(findings[0].evidence[0].contains(&pauper.to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_system_metrics_reflect_live_state() {
+        let mut interface = DebuggerInterface::new().await.unwrap();
+        let debugger = interface.awaken(None).await.unwrap();
+
+        interface.engine.get_physics_engine()
+            .execute_operation(PhysicsOperation::EnforceTimeLimit {
+                entity: debugger.id,
+                operation: "metrics-probe".to_string(),
+                limit: Duration::from_secs(1),
+            })
+            .await
+            .unwrap();
+
+        let metrics = interface.get_system_metrics().await.unwrap();
+
+        assert_eq!(metrics.physics_engine.active_entities, 1);
+        assert!(metrics.physics_engine.physics_operations_per_second > 0.0);
+        assert_eq!(metrics.energy_system.energy_distribution["allocated"], DEBUGGER_PHYSICS_ENERGY);
+        let total = metrics.energy_system.total_energy;
+        assert!((metrics.energy_system.energy_utilization - DEBUGGER_PHYSICS_ENERGY / total).abs() < 1e-9);
+        assert_eq!(metrics.nervous_system.active_agents, 0);
+        assert_eq!(metrics.memory_system.total_memory_usage, 0);
+    }
+}
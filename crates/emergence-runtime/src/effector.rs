@@ -0,0 +1,174 @@
+//! Effectors turn agents' Motor signals into external actions.
+//!
+//! A Motor signal carrying `SignalPayload::Command("<action> <args>")` is
+//! dispatched to the effector registered under `<action>`, which receives the
+//! whole payload and returns its result.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, PoisonError, RwLock};
+
+use anyhow::Result;
+use emergence_nervous_system::{compression, AsyncSignalProcessorFn, NeuralSignal, SignalPayload, SignalProcessorFuture, SignalType};
+use emergence_physics::EntityId;
+use serde_yaml::Value as YamlValue;
+use tracing::warn;
+
+/// Future returned by an effector
+pub type EffectorFuture = Pin<Box<dyn Future<Output = Result<SignalPayload>> + Send>>;
+
+type Effector = Arc<dyn Fn(&SignalPayload) -> EffectorFuture + Send + Sync>;
+
+/// Reasons a Motor command could not be carried out
+#[derive(Debug, thiserror::Error)]
+pub enum EffectorError {
+    #[error("Motor payload does not name an action")]
+    MissingAction,
+
+    #[error("No effector registered for action '{action}'")]
+    UnknownAction { action: String },
+
+    #[error("Effector '{action}' failed: {reason}")]
+    Failed { action: String, reason: String },
+}
+
+/// Effectors keyed by action name
+///
+/// Clones share their effectors, so one registered later is also seen by the
+/// engine's effector processor.
+#[derive(Default, Clone)]
+pub struct EffectorRegistry {
+    effectors: Arc<RwLock<HashMap<String, Effector>>>,
+}
+
+impl std::fmt::Debug for EffectorRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EffectorRegistry")
+            .field("actions", &self.effectors.read().unwrap_or_else(PoisonError::into_inner).keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl EffectorRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the effector for an action, replacing any previous one
+    pub fn register<F, Fut>(&mut self, action: &str, effector: F)
+    where
+        F: Fn(&SignalPayload) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<SignalPayload>> + Send + 'static,
+    {
+        let effector: Effector = Arc::new(move |payload| Box::pin(effector(payload)));
+        self.effectors.write().unwrap_or_else(PoisonError::into_inner).insert(action.to_string(), effector);
+    }
+
+    /// Whether an effector is registered for an action
+    pub fn contains(&self, action: &str) -> bool {
+        self.effectors.read().unwrap_or_else(PoisonError::into_inner).contains_key(action)
+    }
+
+    /// Run the effector named by a Motor command
+    pub async fn dispatch(&self, payload: &SignalPayload) -> Result<SignalPayload, EffectorError> {
+        let action = action_name(payload).ok_or(EffectorError::MissingAction)?;
+        let effector = self.effectors.read().unwrap_or_else(PoisonError::into_inner).get(action).cloned()
+            .ok_or_else(|| EffectorError::UnknownAction { action: action.to_string() })?;
+
+        effector(payload).await.map_err(|e| EffectorError::Failed {
+            action: action.to_string(),
+            reason: e.to_string(),
+        })
+    }
+}
+
+/// Action named by a Motor command: the first word of the command text
+pub fn action_name(payload: &SignalPayload) -> Option<&str> {
+    match payload {
+        SignalPayload::Command(command) => command.split_whitespace().next(),
+        _ => None,
+    }
+}
+
+/// Carry out a Motor signal and build the `Response` the effector entity sends back
+///
+/// The reply is addressed to the signal's source and holds a mapping with
+/// `action`, `status` (`ok` or `error`) and either `result` or
+/// `error`/`error_kind`; a result's payload wrapper is dropped so text becomes
+/// a string and structured data is kept as is.
+pub async fn carry_out(effectors: &EffectorRegistry, entity: EntityId, signal: &NeuralSignal) -> NeuralSignal {
+    let outcome = effectors.dispatch(&signal.payload).await;
+    if let Err(e) = &outcome {
+        warn!("Motor signal {} from {} was not carried out: {}", signal.signal_id, signal.source, e);
+    }
+
+    NeuralSignal::new(
+        SignalType::Coordination,
+        entity,
+        Some(signal.source),
+        response_payload(&signal.payload, outcome),
+        signal.strength,
+    )
+    .with_causal_dependency(signal.signal_id)
+}
+
+/// Structured `Response` payload describing an effector outcome
+fn response_payload(command: &SignalPayload, outcome: Result<SignalPayload, EffectorError>) -> SignalPayload {
+    let mut response = serde_yaml::Mapping::new();
+    if let Some(action) = action_name(command) {
+        response.insert("action".into(), action.into());
+    }
+
+    match outcome {
+        Ok(result) => {
+            response.insert("status".into(), "ok".into());
+            let result = match result {
+                SignalPayload::Message(text)
+                | SignalPayload::Command(text)
+                | SignalPayload::Query(text)
+                | SignalPayload::Event(text) => YamlValue::from(text),
+                SignalPayload::Data(value)
+                | SignalPayload::Response(value)
+                | SignalPayload::StateUpdate(value) => value,
+                SignalPayload::Binary(bytes) => YamlValue::from(bytes),
+                SignalPayload::Compressed(compressed) => {
+                    YamlValue::from(compression::decompress(&compressed, compression::MAX_INFLATED_BYTES).unwrap_or(compressed))
+                }
+            };
+            response.insert("result".into(), result);
+        }
+        Err(e) => {
+            let kind = match e {
+                EffectorError::MissingAction => "missing_action",
+                EffectorError::UnknownAction { .. } => "unknown_action",
+                EffectorError::Failed { .. } => "failed",
+            };
+            response.insert("status".into(), "error".into());
+            response.insert("error_kind".into(), kind.into());
+            response.insert("error".into(), e.to_string().into());
+        }
+    }
+
+    SignalPayload::Response(YamlValue::Mapping(response))
+}
+
+/// Signal processor of the engine's effector entity
+///
+/// Motor signals routed to the entity run their effector, and the outcome is
+/// returned as the entity's response; other signals are ignored.
+pub struct EffectorProcessor {
+    pub entity: EntityId,
+    pub effectors: EffectorRegistry,
+}
+
+impl AsyncSignalProcessorFn for EffectorProcessor {
+    fn process_signal<'a>(&'a self, signal: &'a NeuralSignal) -> SignalProcessorFuture<'a> {
+        Box::pin(async move {
+            if signal.signal_type != SignalType::Motor {
+                return Ok(None);
+            }
+            Ok(Some(carry_out(&self.effectors, self.entity, signal).await))
+        })
+    }
+}
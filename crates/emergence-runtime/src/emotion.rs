@@ -0,0 +1,266 @@
+//! Emotional modulation of agent energy costs and mood.
+//!
+//! Emotional signals carry a valence between -1.0 (distress) and 1.0
+//! (contentment). Receiving one scales the agent's signal energy costs by a
+//! transient multiplier that decays back to 1.0 over time, and pulls the
+//! agent's [`Mood`] toward the signal's valence in proportion to its
+//! strength. Moods fade back to neutral the same way, by the time read from
+//! the engine's clock.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use emergence_nervous_system::SignalPayload;
+use emergence_physics::{EntityId, SharedClock, SystemClock};
+use serde::{Deserialize, Serialize};
+use tokio::time::Instant;
+
+/// How strongly a unit of valence changes the cost multiplier
+const VALENCE_SENSITIVITY: f64 = 0.5;
+
+/// Idle time over which a multiplier's distance from 1.0 halves
+const MODULATION_HALF_LIFE: Duration = Duration::from_secs(60);
+
+/// Bounds on the combined cost multiplier
+const MIN_MULTIPLIER: f64 = 0.25;
+const MAX_MULTIPLIER: f64 = 4.0;
+
+/// How far a full-strength signal moves a mood toward its own valence
+const MOOD_RESPONSIVENESS: f64 = 0.5;
+
+/// Time over which a mood's distance from neutral halves
+pub const MOOD_HALF_LIFE: Duration = Duration::from_secs(120);
+
+/// How much a fully aroused agent's energy costs rise
+const AROUSAL_COST_SENSITIVITY: f64 = 0.5;
+
+/// Valence below which an agent answers tersely
+const TERSE_BELOW_VALENCE: f64 = -0.3;
+
+/// Transient energy-cost multipliers per agent, shared between the engine and
+/// its agent processors
+#[derive(Debug, Clone)]
+pub struct EmotionalModulation {
+    clock: SharedClock,
+    multipliers: Arc<Mutex<HashMap<EntityId, CostMultiplier>>>,
+    moods: Arc<Mutex<HashMap<EntityId, FadingMood>>>,
+}
+
+impl Default for EmotionalModulation {
+    fn default() -> Self {
+        Self::with_clock(SystemClock::shared())
+    }
+}
+
+/// An agent's transient affective state, neutral at zero
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct Mood {
+    /// How pleasant recent emotional signals were, from -1.0 to 1.0
+    pub valence: f64,
+    /// How intense they were, from 0.0 (calm) to 1.0
+    pub arousal: f64,
+}
+
+impl Mood {
+    /// Factor applied to the agent's energy costs; aroused agents spend more
+    pub fn cost_factor(&self) -> f64 {
+        1.0 + AROUSAL_COST_SENSITIVITY * self.arousal
+    }
+
+    /// Whether the agent is low enough to keep its answers short
+    pub fn is_terse(&self) -> bool {
+        self.valence < TERSE_BELOW_VALENCE
+    }
+
+    fn faded(self, half_lives: f64) -> Self {
+        let remaining = 0.5f64.powf(half_lives);
+        Self {
+            valence: self.valence * remaining,
+            arousal: self.arousal * remaining,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct FadingMood {
+    mood: Mood,
+    set_at: Instant,
+}
+
+impl FadingMood {
+    fn current(&self, now: Instant) -> Mood {
+        self.mood.faded(now.duration_since(self.set_at).as_secs_f64() / MOOD_HALF_LIFE.as_secs_f64())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct CostMultiplier {
+    value: f64,
+    set_at: Instant,
+}
+
+impl CostMultiplier {
+    fn current(&self, now: Instant) -> f64 {
+        let half_lives = now.duration_since(self.set_at).as_secs_f64() / MODULATION_HALF_LIFE.as_secs_f64();
+        1.0 + (self.value - 1.0) * 0.5f64.powf(half_lives)
+    }
+}
+
+impl EmotionalModulation {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Modulation that decays by the time `clock` reads
+    pub fn with_clock(clock: SharedClock) -> Self {
+        Self {
+            clock,
+            multipliers: Arc::new(Mutex::new(HashMap::new())),
+            moods: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Apply an emotional signal's valence to an agent
+    ///
+    /// Negative valence raises the agent's costs and positive valence lowers
+    /// them, compounding with whatever modulation has not yet decayed.
+    pub fn apply(&self, agent_id: EntityId, valence: f64) {
+        let valence = valence.clamp(-1.0, 1.0);
+        let now = self.clock.now_instant();
+        let mut multipliers = self.multipliers.lock().unwrap();
+        let current = multipliers.get(&agent_id).map_or(1.0, |m| m.current(now));
+        let value = (current * (1.0 - VALENCE_SENSITIVITY * valence)).clamp(MIN_MULTIPLIER, MAX_MULTIPLIER);
+        multipliers.insert(agent_id, CostMultiplier { value, set_at: now });
+    }
+
+    /// Current energy-cost multiplier for an agent (1.0 when unmodulated)
+    pub fn multiplier(&self, agent_id: EntityId) -> f64 {
+        self.multipliers.lock().unwrap()
+            .get(&agent_id)
+            .map_or(1.0, |m| m.current(self.clock.now_instant()))
+    }
+
+    /// Pull an agent's mood toward an emotional signal's valence
+    ///
+    /// `strength` (0.0 to 1.0) sets how far the mood moves, and how intense
+    /// the agent's arousal becomes.
+    pub fn stir_mood(&self, agent_id: EntityId, valence: f64, strength: f64) {
+        let (valence, strength) = (valence.clamp(-1.0, 1.0), strength.clamp(0.0, 1.0));
+        let weight = MOOD_RESPONSIVENESS * strength;
+        let now = self.clock.now_instant();
+        let mut moods = self.moods.lock().unwrap();
+        let current = moods.get(&agent_id).map_or_else(Mood::default, |m| m.current(now));
+        let mood = Mood {
+            valence: current.valence + (valence - current.valence) * weight,
+            arousal: current.arousal + (strength - current.arousal) * weight,
+        };
+        moods.insert(agent_id, FadingMood { mood, set_at: now });
+    }
+
+    /// An agent's current mood (neutral when it has felt nothing)
+    pub fn mood(&self, agent_id: EntityId) -> Mood {
+        self.moods.lock().unwrap()
+            .get(&agent_id)
+            .map_or_else(Mood::default, |m| m.current(self.clock.now_instant()))
+    }
+
+    /// Drop everything an agent has felt, once it is gone
+    pub fn forget(&self, agent_id: EntityId) {
+        self.multipliers.lock().unwrap().remove(&agent_id);
+        self.moods.lock().unwrap().remove(&agent_id);
+    }
+}
+
+/// The first sentence of a response, for agents in no mood to elaborate
+pub fn terse(response: &str) -> String {
+    let end = response.char_indices()
+        .find(|&(i, c)| matches!(c, '.' | '!' | '?' | ';') && response[i + 1..].starts_with(char::is_whitespace));
+    match end {
+        Some((i, ';')) => format!("{}.", &response[..i]),
+        Some((i, c)) => response[..i + c.len_utf8()].to_string(),
+        None => response.to_string(),
+    }
+}
+
+/// Read the valence carried by an emotional signal's payload
+///
+/// Accepts a number, a mapping with a `valence` key, or text holding a number.
+pub fn payload_valence(payload: &SignalPayload) -> Option<f64> {
+    let valence = match payload {
+        SignalPayload::Data(value) | SignalPayload::StateUpdate(value) => value.as_f64()
+            .or_else(|| value.get("valence").and_then(|v| v.as_f64())),
+        SignalPayload::Message(text) | SignalPayload::Event(text) => text.trim().parse().ok(),
+        _ => None,
+    }?;
+    valence.is_finite().then(|| valence.clamp(-1.0, 1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_modulation_decays_toward_neutral() {
+        let emotions = EmotionalModulation::new();
+        let agent = EntityId::new();
+
+        emotions.apply(agent, -1.0);
+        assert!((emotions.multiplier(agent) - 1.5).abs() < 1e-9);
+
+        tokio::time::advance(MODULATION_HALF_LIFE).await;
+        assert!((emotions.multiplier(agent) - 1.25).abs() < 1e-9);
+
+        tokio::time::advance(MODULATION_HALF_LIFE * 20).await;
+        assert!((emotions.multiplier(agent) - 1.0).abs() < 1e-5);
+        assert_eq!(emotions.multiplier(EntityId::new()), 1.0);
+    }
+
+    #[test]
+    fn test_positive_signals_lift_mood_until_it_fades() {
+        let clock = emergence_physics::MockClock::new();
+        let emotions = EmotionalModulation::with_clock(clock.shared());
+        let agent = EntityId::new();
+
+        let mut previous = emotions.mood(agent);
+        assert_eq!(previous, Mood::default());
+        for _ in 0..3 {
+            emotions.stir_mood(agent, 0.8, 1.0);
+            let mood = emotions.mood(agent);
+            assert!(mood.valence > previous.valence && mood.valence <= 0.8);
+            previous = mood;
+        }
+        assert!((previous.valence - 0.7).abs() < 1e-9);
+        assert!((previous.arousal - 0.875).abs() < 1e-9);
+
+        // A weak signal barely moves it
+        emotions.stir_mood(agent, -1.0, 0.1);
+        assert!(emotions.mood(agent).valence > 0.6);
+
+        clock.advance(MOOD_HALF_LIFE * 20);
+        let faded = emotions.mood(agent);
+        assert!(faded.valence.abs() < 1e-5 && faded.arousal < 1e-5);
+        assert_eq!(emotions.mood(EntityId::new()), Mood::default());
+
+        emotions.stir_mood(agent, 0.8, 1.0);
+        emotions.forget(agent);
+        assert_eq!(emotions.mood(agent), Mood::default());
+    }
+
+    #[test]
+    fn test_terse_keeps_the_first_sentence() {
+        assert_eq!(terse("This weighs on me; everything feels harder right now."), "This weighs on me.");
+        assert_eq!(terse("Noted. I will look into it soon."), "Noted.");
+        assert_eq!(terse("I see 3.5 reasons to stop"), "I see 3.5 reasons to stop");
+    }
+
+    #[test]
+    fn test_payload_valence_formats() {
+        let mapping: serde_yaml::Value = serde_yaml::from_str("valence: -0.4").unwrap();
+
+        assert_eq!(payload_valence(&SignalPayload::Data(mapping)), Some(-0.4));
+        assert_eq!(payload_valence(&SignalPayload::Message(" 0.6 ".to_string())), Some(0.6));
+        assert_eq!(payload_valence(&SignalPayload::Data(serde_yaml::Value::from(3.0))), Some(1.0));
+        assert_eq!(payload_valence(&SignalPayload::Command("cheer".to_string())), None);
+    }
+}
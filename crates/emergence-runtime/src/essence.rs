@@ -0,0 +1,218 @@
+//! Essence schemas looked up by agent type.
+//!
+//! An [`EssenceLoader`] turns an agent type such as `researcher` into the
+//! essence that agent is awakened with. [`FileEssenceLoader`] reads
+//! `{type}-essence.yaml` from an essence directory, checks that the values it
+//! holds are in range and caches the result. When a type has no essence file
+//! it warns and hands out a generic collaborator essence instead.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use tokio::sync::RwLock;
+use tracing::warn;
+
+use crate::{
+    AgentConstraints, AgentEssenceSchema, AgentPersonality, AssociativeMemorySpec, CommunicationStyle, CoreDrives,
+    EnergyProfile, EssenceCapabilities, EssenceIdentity, EvolutionPotential, ExperienceIntegration, LearningMechanics,
+    MemoryConfiguration, MemorySpec, TeachingCapability,
+};
+
+/// Directory essence files are read from unless another is given
+pub const DEFAULT_ESSENCE_DIR: &str = ".emergence/schemas/essences";
+
+/// Source of the essence an agent of a given type is awakened with
+#[async_trait::async_trait]
+pub trait EssenceLoader: Send + Sync {
+    async fn load(&self, agent_type: &str) -> Result<AgentEssenceSchema>;
+}
+
+/// Essences read from `{type}-essence.yaml` files in one directory
+#[derive(Debug)]
+pub struct FileEssenceLoader {
+    dir: PathBuf,
+    cache: RwLock<HashMap<String, AgentEssenceSchema>>,
+}
+
+impl FileEssenceLoader {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into(), cache: RwLock::new(HashMap::new()) }
+    }
+
+    /// Path of the essence file for `agent_type`
+    pub fn path_for(&self, agent_type: &str) -> PathBuf {
+        self.dir.join(format!("{}-essence.yaml", agent_type))
+    }
+}
+
+#[async_trait::async_trait]
+impl EssenceLoader for FileEssenceLoader {
+    /// Fails when the file exists but cannot be read, parsed or validated
+    async fn load(&self, agent_type: &str) -> Result<AgentEssenceSchema> {
+        if let Some(schema) = self.cache.read().await.get(agent_type) {
+            return Ok(schema.clone());
+        }
+
+        let path = self.path_for(agent_type);
+        let content = match tokio::fs::read_to_string(&path).await {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                warn!("No essence file at {}, awakening {} with the default essence", path.display(), agent_type);
+                return Ok(fallback_essence(agent_type));
+            }
+            Err(e) => return Err(e).with_context(|| format!("Failed to read essence file {}", path.display())),
+        };
+        let schema: AgentEssenceSchema = serde_yaml::from_str(&content)
+            .with_context(|| format!("Failed to parse essence file {}", path.display()))?;
+        validate(&schema).with_context(|| format!("Invalid essence file {}", path.display()))?;
+
+        self.cache.write().await.insert(agent_type.to_string(), schema.clone());
+        Ok(schema)
+    }
+}
+
+/// Check the identity is named and the traits and energy are within 0.0..=1.0
+pub fn validate(schema: &AgentEssenceSchema) -> Result<()> {
+    if schema.identity.essence_id.trim().is_empty() {
+        anyhow::bail!("essence_id is empty");
+    }
+    let personality = &schema.personality;
+    let values = [
+        ("personality.curiosity", personality.curiosity),
+        ("personality.persistence", personality.persistence),
+        ("personality.collaboration", personality.collaboration),
+        ("personality.skepticism", personality.skepticism),
+        ("personality.creativity", personality.creativity),
+        ("personality.patience", personality.patience),
+        ("energy_profile.base_energy", schema.energy_profile.base_energy),
+    ];
+    for (field, value) in values {
+        if !(0.0..=1.0).contains(&value) {
+            anyhow::bail!("{} is {}, outside 0.0..=1.0", field, value);
+        }
+    }
+    Ok(())
+}
+
+/// Generic collaborator essence for types without an essence file
+pub fn fallback_essence(agent_type: &str) -> AgentEssenceSchema {
+    AgentEssenceSchema {
+        identity: EssenceIdentity {
+            essence_id: format!("{}-collaborative", agent_type),
+            name: format!("Collaborative {}", agent_type),
+            archetype: "collaborator".to_string(),
+            embodied: Utc::now(),
+        },
+        personality: AgentPersonality {
+            curiosity: 0.8,
+            persistence: 0.8,
+            collaboration: 0.8,
+            skepticism: 0.7,
+            creativity: 0.7,
+            patience: 0.8,
+        },
+        core_drives: CoreDrives {
+            primary: "collaborate".to_string(),
+            secondary: "discover".to_string(),
+            tertiary: "learn".to_string(),
+        },
+        energy_profile: EnergyProfile {
+            base_energy: 0.8,
+            energy_sources: vec![],
+            energy_drains: vec![],
+        },
+        capabilities: EssenceCapabilities {
+            innate: vec!["collaborate".to_string(), "communicate".to_string()],
+            learned: HashMap::new(),
+            emergent: vec![],
+        },
+        memory_configuration: MemoryConfiguration {
+            working_memory: MemorySpec {
+                capacity_mb: 256,
+                retention: "30_minutes".to_string(),
+                priority: Some("collaboration".to_string()),
+            },
+            long_term_memory: MemorySpec {
+                capacity_mb: 1024,
+                retention: "permanent".to_string(),
+                priority: Some("shared_knowledge".to_string()),
+            },
+            associative_memory: AssociativeMemorySpec {
+                max_connections: 1000,
+                association_threshold: 0.7,
+                decay_rate: 0.001,
+            },
+        },
+        behavioral_patterns: vec![],
+        learning_mechanics: LearningMechanics {
+            experience_integration: ExperienceIntegration {
+                method: "collaborative_reflection".to_string(),
+                frequency: "after_collaboration".to_string(),
+                energy_cost: 0.1,
+            },
+            knowledge_expansion: vec![],
+            teaching_capability: TeachingCapability {
+                knowledge_transfer_rate: 0.8,
+                explanation_quality: 0.7,
+                patience_with_learners: 0.9,
+            },
+        },
+        communication_style: CommunicationStyle {
+            tone: "collaborative".to_string(),
+            detail_level: "comprehensive".to_string(),
+            question_frequency: "high".to_string(),
+            response_patterns: HashMap::new(),
+        },
+        evolution_potential: EvolutionPotential {
+            capability_growth_areas: vec![],
+            personality_plasticity: HashMap::new(),
+        },
+        constraints: AgentConstraints {
+            ethical_boundaries: vec!["respect_others".to_string()],
+            operational_limits: vec!["energy_conservation".to_string()],
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_essence_comes_from_the_type_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let loader = FileEssenceLoader::new(dir.path());
+        let researcher_yaml = crate::tests::TEST_ESSENCE_YAML
+            .replace("test-alpha", "researcher-alpha")
+            .replace("archetype: \"tester\"", "archetype: \"seeker\"")
+            .replace("curiosity: 0.8", "curiosity: 0.95");
+        std::fs::write(loader.path_for("researcher"), researcher_yaml).unwrap();
+        std::fs::write(loader.path_for("tester"), crate::tests::TEST_ESSENCE_YAML).unwrap();
+
+        let researcher = loader.load("researcher").await.unwrap();
+        let tester = loader.load("tester").await.unwrap();
+
+        assert_eq!((researcher.identity.essence_id.as_str(), researcher.identity.archetype.as_str()), ("researcher-alpha", "seeker"));
+        assert_eq!((researcher.personality.curiosity, tester.personality.curiosity), (0.95, 0.8));
+        // Later loads are served from the cache
+        std::fs::remove_file(loader.path_for("researcher")).unwrap();
+        assert_eq!(loader.load("researcher").await.unwrap().identity.essence_id, "researcher-alpha");
+    }
+
+    #[tokio::test]
+    async fn test_missing_essence_falls_back_and_invalid_one_fails() {
+        let dir = tempfile::tempdir().unwrap();
+        let loader = FileEssenceLoader::new(dir.path());
+
+        let fallback = loader.load("cartographer").await.unwrap();
+        assert_eq!(fallback.identity.essence_id, "cartographer-collaborative");
+        assert!(loader.cache.read().await.is_empty());
+
+        let overexcited = crate::tests::TEST_ESSENCE_YAML.replace("curiosity: 0.8", "curiosity: 1.5");
+        std::fs::write(loader.path_for("tester"), overexcited).unwrap();
+        let err = loader.load("tester").await.unwrap_err();
+        assert!(format!("{:#}", err).contains("personality.curiosity is 1.5"), "{:#}", err);
+    }
+}
@@ -0,0 +1,569 @@
+//! Persistent log of system events.
+//!
+//! `EventLogger` keeps the most recent events in memory for analysis and
+//! live subscribers, and writes every event to an `EventSink`, which holds
+//! the complete log. Implement `EventSink` to store events somewhere other
+//! than the JSONL file or memory backends provided here.
+
+use std::collections::{HashMap, VecDeque};
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::{broadcast, RwLock};
+
+/// Number of events kept in memory by the event logger
+pub const MAX_IN_MEMORY_EVENTS: usize = 1000;
+
+/// New events buffered for live subscribers before slow ones start missing events
+const LIVE_EVENT_CAPACITY: usize = 256;
+
+/// Span of recent events summarised by each pattern analysis
+const PATTERN_WINDOW: Duration = Duration::from_secs(300);
+
+/// Event logging system for persistence
+#[derive(Clone)]
+pub struct EventLogger {
+    sink: Arc<dyn EventSink>,
+    events: Arc<RwLock<Vec<SystemEvent>>>,
+    /// Maximum number of events kept in memory; the sink stays complete
+    max_events: usize,
+    /// Lines skipped while replaying the log file
+    malformed_lines: Arc<RwLock<usize>>,
+    /// Whether the sink may hold events missing from memory, either evicted or never loaded
+    sink_has_more: Arc<RwLock<bool>>,
+    /// Newly logged events, for live subscribers
+    live: broadcast::Sender<SystemEvent>,
+}
+
+/// System event for logging
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SystemEvent {
+    pub timestamp: chrono::DateTime<Utc>,
+    pub event_type: String,
+    pub agent_id: Option<String>,
+    pub description: String,
+    pub data: serde_json::Value,
+    pub emergence_potential: f64,
+}
+
+/// Storage backend that keeps the complete event log
+#[async_trait::async_trait]
+pub trait EventSink: Send + Sync {
+    /// Store one event after those already written
+    async fn write(&self, event: &SystemEvent) -> Result<()>;
+    
+    /// Stored events matching `filter`, oldest first
+    async fn query(&self, filter: &EventFilter) -> Result<Vec<SystemEvent>>;
+    
+    /// Read back the whole log, keeping only its `keep_last` most recent events
+    async fn replay(&self, keep_last: usize) -> Result<EventReplay> {
+        let mut recent = self.query(&EventFilter::default()).await?;
+        let total = recent.len();
+        recent.drain(..total.saturating_sub(keep_last));
+        Ok(EventReplay { recent, total, malformed: 0 })
+    }
+}
+
+/// What an `EventSink` found when reading back its log
+#[derive(Debug, Clone, Default)]
+pub struct EventReplay {
+    /// The most recent events, oldest first
+    pub recent: Vec<SystemEvent>,
+    /// Readable events in the log, including those not kept
+    pub total: usize,
+    /// Stored records that could not be read
+    pub malformed: usize,
+}
+
+/// Events appended to a JSONL file, one per line
+pub struct JsonlEventSink {
+    path: String,
+}
+
+impl JsonlEventSink {
+    /// Sink appending to `path`; the file and its directory are created on the first write
+    pub fn new(path: &str) -> Self {
+        Self { path: path.to_string() }
+    }
+}
+
+#[async_trait::async_trait]
+impl EventSink for JsonlEventSink {
+    async fn write(&self, event: &SystemEvent) -> Result<()> {
+        let mut line = serde_json::to_string(event)?;
+        line.push('\n');
+        
+        let mut options = tokio::fs::OpenOptions::new();
+        options.create(true).append(true);
+        let mut file = match options.open(&self.path).await {
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                if let Some(parent) = Path::new(&self.path).parent() {
+                    tokio::fs::create_dir_all(parent).await?;
+                }
+                options.open(&self.path).await?
+            }
+            file => file?,
+        };
+        file.write_all(line.as_bytes()).await?;
+        Ok(())
+    }
+    
+    async fn query(&self, filter: &EventFilter) -> Result<Vec<SystemEvent>> {
+        use tokio::io::AsyncBufReadExt;
+        
+        let file = match tokio::fs::File::open(&self.path).await {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+        let mut lines = tokio::io::BufReader::new(file).lines();
+        let mut matching = Vec::new();
+        while let Some(line) = lines.next_line().await? {
+            if line.trim().is_empty() {
+                continue;
+            }
+            // Malformed lines are reported by `replay`
+            if let Ok(event) = serde_json::from_str::<SystemEvent>(&line) {
+                if filter.matches(&event) {
+                    matching.push(event);
+                }
+            }
+        }
+        Ok(matching)
+    }
+    
+    /// Streams the file line by line, so memory stays bounded by `keep_last`;
+    /// malformed lines are skipped and counted rather than aborting the replay
+    async fn replay(&self, keep_last: usize) -> Result<EventReplay> {
+        use tokio::io::AsyncBufReadExt;
+        
+        let file = match tokio::fs::File::open(&self.path).await {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(EventReplay::default()),
+            Err(e) => return Err(e.into()),
+        };
+        let mut lines = tokio::io::BufReader::new(file).lines();
+        let mut recent = VecDeque::with_capacity(keep_last.min(MAX_IN_MEMORY_EVENTS));
+        let mut replay = EventReplay::default();
+        
+        while let Some(line) = lines.next_line().await? {
+            if line.trim().is_empty() {
+                continue;
+            }
+            match serde_json::from_str::<SystemEvent>(&line) {
+                Ok(event) => {
+                    replay.total += 1;
+                    if recent.len() == keep_last {
+                        recent.pop_front();
+                    }
+                    if keep_last > 0 {
+                        recent.push_back(event);
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!("Skipping malformed event log line: {}", e);
+                    replay.malformed += 1;
+                }
+            }
+        }
+        replay.recent = recent.into();
+        Ok(replay)
+    }
+}
+
+/// Events kept only in memory, for tests and short-lived runs
+#[derive(Default)]
+pub struct InMemoryEventSink {
+    events: RwLock<Vec<SystemEvent>>,
+}
+
+impl InMemoryEventSink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl EventSink for InMemoryEventSink {
+    async fn write(&self, event: &SystemEvent) -> Result<()> {
+        self.events.write().await.push(event.clone());
+        Ok(())
+    }
+    
+    async fn query(&self, filter: &EventFilter) -> Result<Vec<SystemEvent>> {
+        Ok(self.events.read().await.iter().filter(|event| filter.matches(event)).cloned().collect())
+    }
+}
+
+/// Criteria an event must meet to be returned by `EventLogger::query`; unset fields match anything
+#[derive(Debug, Clone, Default)]
+pub struct EventFilter {
+    pub event_type: Option<String>,
+    pub agent_id: Option<String>,
+    /// Earliest timestamp, inclusive
+    pub since: Option<chrono::DateTime<Utc>>,
+    /// Latest timestamp, inclusive
+    pub until: Option<chrono::DateTime<Utc>>,
+    pub min_emergence_potential: Option<f64>,
+}
+
+impl EventFilter {
+    fn matches(&self, event: &SystemEvent) -> bool {
+        self.event_type.as_ref().is_none_or(|event_type| event.event_type == *event_type)
+            && self.agent_id.as_ref().is_none_or(|agent_id| event.agent_id.as_ref() == Some(agent_id))
+            && self.since.is_none_or(|since| event.timestamp >= since)
+            && self.until.is_none_or(|until| event.timestamp <= until)
+            && self.min_emergence_potential.is_none_or(|min| event.emergence_potential >= min)
+    }
+}
+
+/// Summary of the events logged over a recent window
+#[derive(Debug, Clone, Default)]
+pub struct EventAggregate {
+    pub window: Duration,
+    pub total_events: usize,
+    pub counts_by_type: HashMap<String, usize>,
+    pub counts_by_agent: HashMap<String, usize>,
+    /// Zero when the window holds no events
+    pub average_emergence_potential: f64,
+    pub max_emergence_potential: f64,
+    pub events_per_second: f64,
+}
+
+impl Default for EventLogger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventLogger {
+    pub fn new() -> Self {
+        Self::with_log_file(".emergence/events/system_events.jsonl")
+    }
+    
+    /// Create a logger writing to a specific JSONL file
+    pub fn with_log_file(log_file: &str) -> Self {
+        Self::with_sink(Box::new(JsonlEventSink::new(log_file)))
+    }
+    
+    /// Create a logger storing its events in `sink`
+    pub fn with_sink(sink: Box<dyn EventSink>) -> Self {
+        Self {
+            sink: Arc::from(sink),
+            events: Arc::new(RwLock::new(Vec::new())),
+            max_events: MAX_IN_MEMORY_EVENTS,
+            malformed_lines: Arc::new(RwLock::new(0)),
+            sink_has_more: Arc::new(RwLock::new(true)),
+            live: broadcast::channel(LIVE_EVENT_CAPACITY).0,
+        }
+    }
+    
+    /// Replace the in-memory events with the sink's, returning how many events the sink holds
+    ///
+    /// Malformed records are skipped and counted rather than aborting the replay.
+    /// Only the most recent `max_events` events are kept in memory. Events already
+    /// logged are in the sink too, so loading again never duplicates them.
+    pub async fn load_from_disk(&self) -> Result<usize> {
+        let replay = self.sink.replay(self.max_events).await?;
+        *self.sink_has_more.write().await = replay.total > replay.recent.len();
+        *self.events.write().await = replay.recent;
+        *self.malformed_lines.write().await = replay.malformed;
+        
+        Ok(replay.total)
+    }
+    
+    /// Number of malformed lines skipped during replay
+    pub async fn malformed_line_count(&self) -> usize {
+        *self.malformed_lines.read().await
+    }
+    
+    /// Log a system event
+    pub async fn log_event(&self, event: SystemEvent) -> Result<()> {
+        // Add to memory
+        {
+            let mut events = self.events.write().await;
+            events.push(event.clone());
+            let excess = events.len().saturating_sub(self.max_events);
+            if excess > 0 {
+                events.drain(..excess);
+                *self.sink_has_more.write().await = true;
+            }
+        }
+        
+        self.sink.write(&event).await?;
+        
+        // Having no live subscribers is not an error
+        let _ = self.live.send(event);
+        
+        Ok(())
+    }
+    
+    /// Receive events as they are logged
+    pub fn subscribe(&self) -> broadcast::Receiver<SystemEvent> {
+        self.live.subscribe()
+    }
+    
+    /// In-memory events logged after `since`, oldest first
+    pub async fn events_since(&self, since: chrono::DateTime<Utc>) -> Vec<SystemEvent> {
+        let events = self.events.read().await;
+        events.iter()
+            .filter(|event| event.timestamp > since)
+            .cloned()
+            .collect()
+    }
+    
+    /// Logged events matching `filter`, oldest first
+    ///
+    /// Answered from memory when memory holds every event the sink does, or
+    /// the filter starts no earlier than the oldest event held there; otherwise
+    /// the sink is queried instead.
+    pub async fn query(&self, filter: EventFilter) -> Result<Vec<SystemEvent>> {
+        {
+            let events = self.events.read().await;
+            let covered = !*self.sink_has_more.read().await
+                || events.first().is_some_and(|oldest| filter.since.is_some_and(|since| since >= oldest.timestamp));
+            if covered {
+                return Ok(events.iter().filter(|event| filter.matches(event)).cloned().collect());
+            }
+        }
+        self.sink.query(&filter).await
+    }
+    
+    /// Get recent events
+    pub async fn get_recent_events(&self, count: usize) -> Vec<SystemEvent> {
+        let events = self.events.read().await;
+        events.iter()
+            .rev()
+            .take(count)
+            .cloned()
+            .collect()
+    }
+    
+    /// Analyze events for patterns
+    pub async fn analyze_patterns(&self) -> Vec<String> {
+        let aggregate = self.aggregate(PATTERN_WINDOW).await;
+        if aggregate.total_events == 0 {
+            return Vec::new();
+        }
+        
+        let mut by_type: Vec<_> = aggregate.counts_by_type.iter().collect();
+        by_type.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+        let window_minutes = aggregate.window.as_secs_f64() / 60.0;
+        let mut patterns: Vec<String> = by_type.into_iter()
+            .map(|(event_type, count)| format!("{} {} events ({:.1}/min)", count, event_type, *count as f64 / window_minutes))
+            .collect();
+        patterns.push(format!(
+            "Emergence potential averaging {:.2}, peaking at {:.2}, across {} agents",
+            aggregate.average_emergence_potential,
+            aggregate.max_emergence_potential,
+            aggregate.counts_by_agent.len(),
+        ));
+        patterns
+    }
+    
+    /// Counts, emergence potential and rate of the events logged within `window` of now
+    pub async fn aggregate(&self, window: Duration) -> EventAggregate {
+        let since = Utc::now() - chrono::Duration::from_std(window).unwrap_or(chrono::Duration::MAX);
+        let events = self.events.read().await;
+        let mut aggregate = EventAggregate { window, ..EventAggregate::default() };
+        let mut potential_sum = 0.0;
+        
+        for event in events.iter().filter(|event| event.timestamp >= since) {
+            aggregate.total_events += 1;
+            *aggregate.counts_by_type.entry(event.event_type.clone()).or_insert(0) += 1;
+            if let Some(agent_id) = &event.agent_id {
+                *aggregate.counts_by_agent.entry(agent_id.clone()).or_insert(0) += 1;
+            }
+            potential_sum += event.emergence_potential;
+            aggregate.max_emergence_potential = aggregate.max_emergence_potential.max(event.emergence_potential);
+        }
+        
+        if aggregate.total_events > 0 {
+            aggregate.average_emergence_potential = potential_sum / aggregate.total_events as f64;
+        }
+        if !window.is_zero() {
+            aggregate.events_per_second = aggregate.total_events as f64 / window.as_secs_f64();
+        }
+        aggregate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    fn agent_event(agent: &str, offset_secs: i64, potential: f64) -> SystemEvent {
+        SystemEvent {
+            timestamp: Utc::now() + chrono::Duration::seconds(offset_secs),
+            event_type: "agent_activity".to_string(),
+            agent_id: Some(format!("{}-{}", agent, uuid::Uuid::new_v4().simple())),
+            description: format!("{} activity", agent),
+            data: serde_json::Value::Null,
+            emergence_potential: potential,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_load_from_disk_skips_malformed_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("events.jsonl");
+        let valid: Vec<String> = (0..3)
+            .map(|i| serde_json::to_string(&agent_event("debugger", i, 0.5)).unwrap())
+            .collect();
+        fs::write(&path, format!("{}\n{}\n{{\"timestamp\": oops\n\n{}\n", valid[0], valid[1], valid[2])).unwrap();
+
+        let logger = EventLogger::with_log_file(path.to_str().unwrap());
+        let loaded = logger.load_from_disk().await.unwrap();
+
+        assert_eq!(loaded, 3);
+        assert_eq!(logger.malformed_line_count().await, 1);
+        assert_eq!(logger.get_recent_events(10).await.len(), 3);
+        
+        // Reloading replaces the buffer instead of appending to it
+        assert_eq!(logger.load_from_disk().await.unwrap(), 3);
+        assert_eq!(logger.malformed_line_count().await, 1);
+        assert_eq!(logger.get_recent_events(10).await.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_load_from_disk_keeps_most_recent_events() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("events.jsonl");
+        let lines: Vec<String> = (0..5)
+            .map(|i| serde_json::to_string(&agent_event("tester", i, 0.5)).unwrap())
+            .collect();
+        fs::write(&path, lines.join("\n")).unwrap();
+
+        let mut logger = EventLogger::with_log_file(path.to_str().unwrap());
+        logger.max_events = 2;
+        let loaded = logger.load_from_disk().await.unwrap();
+
+        let recent = logger.get_recent_events(10).await;
+        assert_eq!(loaded, 5);
+        assert_eq!(recent.len(), 2);
+        assert_eq!(recent[0].timestamp, serde_json::from_str::<SystemEvent>(&lines[4]).unwrap().timestamp);
+        assert_eq!(fs::read_to_string(&path).unwrap().lines().count(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_query_filters_each_dimension() {
+        let dir = tempfile::tempdir().unwrap();
+        let logger = EventLogger::with_log_file(dir.path().join("events.jsonl").to_str().unwrap());
+        let mut emergence = agent_event("researcher", -600, 0.9);
+        emergence.event_type = "emergence_detected".to_string();
+        let events = vec![agent_event("debugger", -7200, 0.2), emergence.clone(), agent_event("debugger", 0, 0.8)];
+        for event in &events {
+            logger.log_event(event.clone()).await.unwrap();
+        }
+        let timestamps = |found: Vec<SystemEvent>| found.iter().map(|e| e.timestamp).collect::<Vec<_>>();
+        let query = |filter: EventFilter| {
+            let logger = logger.clone();
+            async move { timestamps(logger.query(filter).await.unwrap()) }
+        };
+
+        assert_eq!(query(EventFilter::default()).await, timestamps(events.clone()));
+        assert_eq!(
+            query(EventFilter { event_type: Some("emergence_detected".to_string()), ..EventFilter::default() }).await,
+            vec![emergence.timestamp],
+        );
+        assert_eq!(
+            query(EventFilter { agent_id: events[2].agent_id.clone(), ..EventFilter::default() }).await,
+            vec![events[2].timestamp],
+        );
+        let last_hour = EventFilter { since: Some(Utc::now() - chrono::Duration::hours(1)), ..EventFilter::default() };
+        assert_eq!(query(last_hour).await, vec![events[1].timestamp, events[2].timestamp]);
+        let before_then = EventFilter { until: Some(events[1].timestamp), ..EventFilter::default() };
+        assert_eq!(query(before_then).await, vec![events[0].timestamp, events[1].timestamp]);
+        assert_eq!(
+            query(EventFilter { min_emergence_potential: Some(0.8), ..EventFilter::default() }).await,
+            vec![events[1].timestamp, events[2].timestamp],
+        );
+    }
+
+    #[tokio::test]
+    async fn test_query_reads_the_log_file_for_evicted_events() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut logger = EventLogger::with_log_file(dir.path().join("events.jsonl").to_str().unwrap());
+        logger.max_events = 2;
+        let events: Vec<SystemEvent> = (0..4).map(|i| agent_event("tester", i * 60, 0.5)).collect();
+        for event in &events {
+            logger.log_event(event.clone()).await.unwrap();
+        }
+
+        let everything = logger.query(EventFilter::default()).await.unwrap();
+        assert_eq!(everything.len(), 4);
+        let recent = logger.query(EventFilter { since: Some(events[2].timestamp), ..EventFilter::default() }).await.unwrap();
+        assert_eq!(recent.iter().map(|e| e.timestamp).collect::<Vec<_>>(), vec![events[2].timestamp, events[3].timestamp]);
+    }
+
+    #[tokio::test]
+    async fn test_query_answers_from_memory_once_it_holds_the_whole_log() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("events.jsonl");
+        fs::write(&path, serde_json::to_string(&agent_event("tester", -60, 0.5)).unwrap()).unwrap();
+        let logger = EventLogger::with_log_file(path.to_str().unwrap());
+        assert_eq!(logger.load_from_disk().await.unwrap(), 1);
+        logger.log_event(agent_event("tester", 0, 0.5)).await.unwrap();
+
+        // Memory holds both events, so the file is no longer consulted
+        fs::remove_file(&path).unwrap();
+        assert_eq!(logger.query(EventFilter::default()).await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_sink_keeps_evicted_events_queryable() {
+        let mut logger = EventLogger::with_sink(Box::new(InMemoryEventSink::new()));
+        logger.max_events = 2;
+        let mut events: Vec<SystemEvent> = (0..4).map(|i| agent_event("tester", i * 60, 0.5)).collect();
+        events[0].event_type = "emergence_detected".to_string();
+        for event in &events {
+            logger.log_event(event.clone()).await.unwrap();
+        }
+
+        assert_eq!(logger.get_recent_events(10).await.len(), 2);
+        assert_eq!(logger.query(EventFilter::default()).await.unwrap().len(), 4);
+        let emergences = logger
+            .query(EventFilter { event_type: Some("emergence_detected".to_string()), ..EventFilter::default() })
+            .await
+            .unwrap();
+        assert_eq!(emergences.iter().map(|e| e.timestamp).collect::<Vec<_>>(), vec![events[0].timestamp]);
+        assert_eq!(logger.load_from_disk().await.unwrap(), 4);
+        assert_eq!(logger.malformed_line_count().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_aggregate_summarises_the_window() {
+        let dir = tempfile::tempdir().unwrap();
+        let logger = EventLogger::with_log_file(dir.path().join("events.jsonl").to_str().unwrap());
+        let mut researcher = agent_event("researcher", -10, 0.6);
+        researcher.event_type = "emergence_detected".to_string();
+        let debugger = agent_event("debugger", -20, 0.3);
+        // Too old to fall inside the window
+        let stale = agent_event("debugger", -600, 1.0);
+        for event in [researcher.clone(), debugger.clone(), debugger.clone(), stale] {
+            logger.log_event(event).await.unwrap();
+        }
+
+        let aggregate = logger.aggregate(Duration::from_secs(60)).await;
+
+        assert_eq!(aggregate.total_events, 3);
+        assert_eq!(aggregate.counts_by_type["agent_activity"], 2);
+        assert_eq!(aggregate.counts_by_type["emergence_detected"], 1);
+        assert_eq!(aggregate.counts_by_agent[debugger.agent_id.as_ref().unwrap()], 2);
+        assert_eq!(aggregate.counts_by_agent[researcher.agent_id.as_ref().unwrap()], 1);
+        assert!((aggregate.average_emergence_potential - 0.4).abs() < 1e-9);
+        assert!((aggregate.max_emergence_potential - 0.6).abs() < 1e-9);
+        assert!((aggregate.events_per_second - 0.05).abs() < 1e-9);
+
+        let empty = logger.aggregate(Duration::from_secs(1)).await;
+        assert_eq!((empty.total_events, empty.average_emergence_potential, empty.events_per_second), (0, 0.0, 0.0));
+    }
+}
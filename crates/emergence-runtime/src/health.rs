@@ -0,0 +1,104 @@
+//! Health of the subsystems an execution engine is built from.
+//!
+//! [`ExecutionEngine::health_check`](crate::ExecutionEngine::health_check)
+//! probes each subsystem with a small real operation and times it. A probe
+//! that fails marks its subsystem [`HealthStatus::Failed`]; one that succeeds
+//! but takes longer than [`HEALTH_LATENCY_BUDGET`] marks it
+//! [`HealthStatus::Degraded`].
+
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+/// Time a probe may take before its subsystem counts as degraded
+pub const HEALTH_LATENCY_BUDGET: Duration = Duration::from_millis(500);
+
+/// Condition of a subsystem, from best to worst
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum HealthStatus {
+    Ok,
+    /// Working, but slower than [`HEALTH_LATENCY_BUDGET`]
+    Degraded,
+    Failed,
+}
+
+/// Outcome of probing one subsystem
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubsystemHealth {
+    pub status: HealthStatus,
+    /// Time the probe took
+    pub latency: Duration,
+    /// Why the subsystem is not healthy
+    pub detail: Option<String>,
+}
+
+impl SubsystemHealth {
+    /// Time `probe` and judge the subsystem by its outcome
+    pub(crate) async fn probe<F>(probe: F) -> Self
+    where
+        F: std::future::Future<Output = Result<()>>,
+    {
+        let started = Instant::now();
+        let outcome = probe.await;
+        let latency = started.elapsed();
+        match outcome {
+            Err(e) => Self { status: HealthStatus::Failed, latency, detail: Some(format!("{:#}", e)) },
+            Ok(()) if latency > HEALTH_LATENCY_BUDGET => Self {
+                status: HealthStatus::Degraded,
+                latency,
+                detail: Some(format!("took {:?}, over the {:?} budget", latency, HEALTH_LATENCY_BUDGET)),
+            },
+            Ok(()) => Self { status: HealthStatus::Ok, latency, detail: None },
+        }
+    }
+}
+
+/// Health of every subsystem of an execution engine
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HealthReport {
+    /// The physics engine answers state queries
+    pub physics: SubsystemHealth,
+    /// A signal makes it from one entity to another and back
+    pub nervous_system: SubsystemHealth,
+    /// Allocated, free and dissipated energy add up to the system total
+    pub energy_conservation: SubsystemHealth,
+    /// A stored memory can be recalled
+    pub memory: SubsystemHealth,
+}
+
+impl HealthReport {
+    /// Each subsystem's name and health
+    pub fn subsystems(&self) -> [(&'static str, &SubsystemHealth); 4] {
+        [
+            ("physics", &self.physics),
+            ("nervous_system", &self.nervous_system),
+            ("energy_conservation", &self.energy_conservation),
+            ("memory", &self.memory),
+        ]
+    }
+
+    /// The worst status of any subsystem
+    pub fn status(&self) -> HealthStatus {
+        self.subsystems().iter().map(|(_, health)| health.status).max().unwrap_or(HealthStatus::Ok)
+    }
+
+    /// Whether every subsystem is healthy
+    pub fn is_healthy(&self) -> bool {
+        self.status() == HealthStatus::Ok
+    }
+}
+
+impl fmt::Display for HealthReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (name, health) in self.subsystems() {
+            write!(f, "{:<20} {:?} ({:?})", name, health.status, health.latency)?;
+            if let Some(detail) = &health.detail {
+                write!(f, ": {}", detail)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
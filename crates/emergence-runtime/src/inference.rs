@@ -0,0 +1,150 @@
+//! Cancellation of model inferences that are no longer wanted.
+//!
+//! Each inference an agent runs is registered under the intent of the message
+//! that started it. A newer message with the same intent supersedes it, and an
+//! agent going dormant abandons all of its inferences; either way the
+//! in-flight inference is cancelled and its result never becomes a response.
+//! [`SupersedeInferences`] watches every transmitted signal, so a message
+//! cancels the stale inference as it is sent rather than when the agent, still
+//! busy with that inference, gets round to it.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use emergence_models::intent::IntentModel;
+use emergence_models::CancellationToken;
+use emergence_nervous_system::{MiddlewareAction, NeuralSignal, SignalMiddleware, SignalPayload, SignalTarget};
+use emergence_physics::EntityId;
+use tracing::debug;
+
+/// Registration id and cancellation token of each agent's inference per intent
+type InflightMap = HashMap<(EntityId, String), (u64, CancellationToken)>;
+
+/// In-flight inferences per agent and intent, shared between the engine and
+/// its agent processors
+#[derive(Debug, Clone, Default)]
+pub struct InferenceCancellation {
+    inflight: Arc<Mutex<InflightMap>>,
+    next_id: Arc<AtomicU64>,
+}
+
+/// A registered inference, forgotten again when dropped
+#[derive(Debug)]
+pub struct InflightInference {
+    registry: InferenceCancellation,
+    key: (EntityId, String),
+    id: u64,
+    /// Fires when the inference is superseded or abandoned
+    pub token: CancellationToken,
+}
+
+impl Drop for InflightInference {
+    fn drop(&mut self) {
+        let mut inflight = self.registry.inflight.lock().unwrap();
+        // A newer inference may have taken the slot in the meantime
+        if inflight.get(&self.key).is_some_and(|(id, _)| *id == self.id) {
+            inflight.remove(&self.key);
+        }
+    }
+}
+
+impl InferenceCancellation {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register an inference, cancelling any still running for the same intent
+    pub fn begin(&self, agent_id: EntityId, intent: &str) -> InflightInference {
+        let key = (agent_id, intent.to_string());
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let token = CancellationToken::new();
+        if let Some((_, previous)) = self.inflight.lock().unwrap().insert(key.clone(), (id, token.clone())) {
+            previous.cancel();
+        }
+        InflightInference { registry: self.clone(), key, id, token }
+    }
+
+    /// Cancel the agent's inference for `intent`, if one is running
+    pub fn cancel_intent(&self, agent_id: EntityId, intent: &str) -> bool {
+        match self.inflight.lock().unwrap().remove(&(agent_id, intent.to_string())) {
+            Some((_, token)) => {
+                token.cancel();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Cancel every inference the agent is running, returning how many there were
+    pub fn cancel_agent(&self, agent_id: EntityId) -> usize {
+        let mut cancelled = 0;
+        self.inflight.lock().unwrap().retain(|(agent, _), (_, token)| {
+            if *agent != agent_id {
+                return true;
+            }
+            token.cancel();
+            cancelled += 1;
+            false
+        });
+        cancelled
+    }
+
+    /// Number of inferences currently running
+    pub fn in_flight(&self) -> usize {
+        self.inflight.lock().unwrap().len()
+    }
+}
+
+/// Middleware cancelling a target's in-flight inference for the intent of each message sent to it
+pub struct SupersedeInferences {
+    inferences: InferenceCancellation,
+    intents: IntentModel,
+}
+
+impl SupersedeInferences {
+    pub fn new(inferences: InferenceCancellation) -> Self {
+        Self { inferences, intents: IntentModel::new() }
+    }
+}
+
+impl SignalMiddleware for SupersedeInferences {
+    fn on_transmit(&self, signal: &mut NeuralSignal) -> MiddlewareAction {
+        if let (SignalTarget::One(target), SignalPayload::Message(msg)) = (&signal.target, &signal.payload) {
+            let intent = self.intents.recognize(msg).label;
+            if self.inferences.cancel_intent(*target, &intent) {
+                debug!("Cancelled agent {}'s in-flight '{}' inference", target, intent);
+            }
+        }
+        MiddlewareAction::Continue
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_newer_inference_supersedes_same_intent_only() {
+        let inferences = InferenceCancellation::new();
+        let (agent, other) = (EntityId::new(), EntityId::new());
+
+        let first = inferences.begin(agent, "analyze");
+        let unrelated = inferences.begin(agent, "explore");
+        let elsewhere = inferences.begin(other, "analyze");
+        let second = inferences.begin(agent, "analyze");
+
+        assert!(first.token.is_cancelled());
+        assert!(!second.token.is_cancelled() && !unrelated.token.is_cancelled() && !elsewhere.token.is_cancelled());
+        // The superseded inference finishing must not forget its replacement
+        drop(first);
+        assert_eq!(inferences.in_flight(), 3);
+
+        assert_eq!(inferences.cancel_agent(agent), 2);
+        assert!(second.token.is_cancelled() && unrelated.token.is_cancelled());
+        assert!(!inferences.cancel_intent(agent, "analyze"));
+        assert!(inferences.cancel_intent(other, "analyze"));
+        assert!(elsewhere.token.is_cancelled());
+        assert_eq!(inferences.in_flight(), 0);
+    }
+}
@@ -1,14 +1,21 @@
 //! **emergence-runtime** – Dynamic behavior composition and execution engine for EMERGENCE.
 
 use std::collections::{HashMap, HashSet};
-use std::sync::Arc;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
-use emergence_physics::{EntityId, PhysicsEngine, Capability};
-use emergence_nervous_system::{NervousSystem, SignalType, NeuralSignal, SignalPayload, SignalProcessorFn};
-use emergence_memory::MemorySubstrate;
+use emergence_physics::{EntityId, MockClock, Physics, PhysicsEngine, PhysicsOperation, Capability, SharedClock, SystemClock};
+use emergence_nervous_system::{NervousSystem, NervousSystemConfig, SerializationFormat, SignalType, NeuralSignal, SignalPayload, SignalStreamEvent, AsyncSignalProcessorFn, SignalProcessorFuture, SignalTarget};
+use futures::StreamExt;
+use emergence_memory::{AssociationSettings, MemorySubstrate};
+use emergence_models::intent::IntentModel;
+use emergence_models::reasoning::ReasoningResult;
+use emergence_models::{ComposableModel, ModelContext, ModelError, ModelOutput, Personality};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
 use serde_yaml::Value as YamlValue;
 use tracing::{debug, info, warn};
@@ -40,8 +47,23 @@ pub struct AgentPersonality {
     pub patience: f64,
 }
 
+impl AgentPersonality {
+    /// Mutable access to a trait by its schema name
+    pub fn trait_mut(&mut self, name: &str) -> Option<&mut f64> {
+        match name {
+            "curiosity" => Some(&mut self.curiosity),
+            "persistence" => Some(&mut self.persistence),
+            "collaboration" => Some(&mut self.collaboration),
+            "skepticism" => Some(&mut self.skepticism),
+            "creativity" => Some(&mut self.creativity),
+            "patience" => Some(&mut self.patience),
+            _ => None,
+        }
+    }
+}
+
 /// Current state of a living agent
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum AgentState {
     Dormant,
     Awakening,
@@ -172,46 +194,170 @@ pub struct AgentConstraints {
     pub operational_limits: Vec<String>,
 }
 
+/// An agent's learned capabilities, shared between the engine and the agent's processor
+pub type SharedCapabilities = Arc<std::sync::RwLock<HashMap<String, f64>>>;
+
+/// An agent's personality, shared between the engine and the agent's processor
+pub type SharedPersonality = Arc<std::sync::RwLock<AgentPersonality>>;
+
 /// Agent processor for nervous system integration
 pub struct AgentProcessor {
     pub agent: LivingAgent,
     pub essence_schema: AgentEssenceSchema,
+    pub memory: Arc<MemorySubstrate>,
+    pub emotions: EmotionalModulation,
+    /// In-flight model inferences, so newer messages can cancel stale ones
+    pub inferences: InferenceCancellation,
+    pub intents: IntentModel,
+    /// The agent's current capabilities, announced when it answers a negotiation offer
+    pub capabilities: SharedCapabilities,
+    /// The agent's current personality, which outlives `agent.personality` once traits adapt
+    pub personality: SharedPersonality,
+    /// Model that answers sensory and cognitive messages; the built-in heuristics answer when absent
+    pub model: Option<Box<dyn ComposableModel>>,
 }
 
-impl SignalProcessorFn for AgentProcessor {
-    fn process_signal(&self, signal: &NeuralSignal) -> Result<Option<NeuralSignal>> {
-        debug!("Agent {} processing signal: {:?}", self.agent.name, signal.signal_type);
-        
-        // Generate response based on agent's personality and capabilities
-        let response = self.generate_agent_response(signal);
-        
-        Ok(Some(response))
+impl AsyncSignalProcessorFn for AgentProcessor {
+    fn process_signal<'a>(&'a self, signal: &'a NeuralSignal) -> SignalProcessorFuture<'a> {
+        Box::pin(async move {
+            debug!("Agent {} processing signal: {:?}", self.agent.name, signal.signal_type);
+            
+            // Acknowledgments close an exchange; answering them would only start another
+            if matches!(signal.payload, SignalPayload::Response(_)) {
+                return Ok(None);
+            }
+            
+            if let SignalPayload::Data(offer) = &signal.payload {
+                if is_offer(offer) {
+                    return Ok(Some(self.answer_negotiation(signal)));
+                }
+            }
+            
+            // Generate response based on agent's personality and capabilities
+            match self.generate_agent_response(signal).await {
+                Ok(response) => Ok(Some(response)),
+                Err(ModelError::Cancelled) => {
+                    debug!("Agent {} abandoned a cancelled inference", self.agent.name);
+                    Ok(None)
+                }
+                Err(e) => Err(e.into()),
+            }
+        })
     }
 }
 
 impl AgentProcessor {
-    fn generate_agent_response(&self, signal: &NeuralSignal) -> NeuralSignal {
-        let response_message = match signal.signal_type {
-            SignalType::Sensory => self.handle_sensory_input(signal),
-            SignalType::Cognitive => self.handle_cognitive_request(signal),
-            SignalType::Coordination => self.handle_coordination_request(signal),
-            SignalType::Memory => self.handle_memory_request(signal),
-            _ => "I'm processing this input through my current understanding...".to_string(),
+    /// The agent's response to a signal, or `Cancelled` if its inference was superseded
+    ///
+    /// A response the attached model wrote also costs what the model reported.
+    async fn generate_agent_response(&self, signal: &NeuralSignal) -> Result<NeuralSignal, ModelError> {
+        let mut model_cost = 0.0;
+        let response_payload = match signal.signal_type {
+            SignalType::Sensory | SignalType::Cognitive => {
+                let text = match self.model_response(signal).await? {
+                    Some(output) => {
+                        model_cost = output.energy_cost;
+                        output.content
+                    }
+                    None if signal.signal_type == SignalType::Sensory => self.handle_sensory_input(signal),
+                    None => self.handle_cognitive_request(signal),
+                };
+                SignalPayload::Message(text)
+            }
+            SignalType::Coordination => SignalPayload::Message(self.handle_coordination_request(signal)),
+            SignalType::Memory => self.handle_memory_request(signal).await,
+            SignalType::Emotional => SignalPayload::Message(self.handle_emotional_signal(signal)),
+            _ => SignalPayload::Message("I'm processing this input through my current understanding...".to_string()),
         };
         
-        NeuralSignal::new(
+        let mood = self.emotions.mood(self.agent.id);
+        let response_payload = match response_payload {
+            SignalPayload::Message(text) if mood.is_terse() => SignalPayload::Message(terse(&text)),
+            payload => payload,
+        };
+        
+        Ok(NeuralSignal::new(
             SignalType::Coordination,
             self.agent.id,
             Some(signal.source),
-            SignalPayload::Message(response_message),
-            self.agent.personality.curiosity * 0.8, // Response strength based on curiosity
+            response_payload,
+            self.personality().curiosity * 0.8, // Response strength based on curiosity
+        )
+        .with_energy_cost(self.response_cost() + model_cost))
+    }
+    
+    /// Energy the agent spends on a response, scaled by its emotional state
+    fn response_cost(&self) -> f64 {
+        BASE_SIGNAL_ENERGY_COST * self.emotions.multiplier(self.agent.id) * self.emotions.mood(self.agent.id).cost_factor()
+    }
+    
+    /// Reply to a negotiation offer with the agent's own capabilities
+    fn answer_negotiation(&self, offer: &NeuralSignal) -> NeuralSignal {
+        let capabilities = self.capabilities.read().unwrap_or_else(std::sync::PoisonError::into_inner);
+        NeuralSignal::new(
+            SignalType::Coordination,
+            self.agent.id,
+            Some(offer.source),
+            SignalPayload::Response(capability_announcement("reply", &capabilities)),
+            self.personality().collaboration,
         )
+        .with_causal_dependency(offer.signal_id)
+        .with_energy_cost(self.response_cost())
+    }
+    
+    /// The attached model's answer to a message, if it produced one within the agent's energy
+    ///
+    /// The inference runs until it completes or a newer message with the same
+    /// intent cancels it, which is the only error passed on.
+    async fn model_response(&self, signal: &NeuralSignal) -> Result<Option<ModelOutput>, ModelError> {
+        let Some(model) = self.model.as_ref() else {
+            return Ok(None);
+        };
+        let SignalPayload::Message(msg) = &signal.payload else {
+            return Ok(None);
+        };
+        let context = ModelContext {
+            energy_budget: self.agent.energy,
+            personality: self.model_personality(),
+            ..ModelContext::default()
+        };
+        
+        let inference = self.inferences.begin(self.agent.id, &self.intents.recognize(msg).label);
+        match model.process_cancellable(msg, &context, &inference.token).await {
+            Ok(output) if output.energy_cost <= context.energy_budget => Ok(Some(output)),
+            Ok(output) => {
+                warn!("Model {} needed {} energy but agent {} has {}; using heuristics",
+                      model.name(), output.energy_cost, self.agent.name, context.energy_budget);
+                Ok(None)
+            }
+            Err(ModelError::Cancelled) => Err(ModelError::Cancelled),
+            Err(e) => {
+                warn!("Model {} failed for agent {}: {}; using heuristics", model.name(), self.agent.name, e);
+                Ok(None)
+            }
+        }
+    }
+    
+    /// The agent's personality as it stands now
+    fn personality(&self) -> AgentPersonality {
+        self.personality.read().unwrap_or_else(std::sync::PoisonError::into_inner).clone()
+    }
+    
+    fn model_personality(&self) -> Personality {
+        let traits = self.personality();
+        Personality {
+            curiosity: traits.curiosity,
+            creativity: traits.creativity,
+            skepticism: traits.skepticism,
+            patience: traits.patience,
+            collaboration: traits.collaboration,
+        }
     }
     
     fn handle_sensory_input(&self, signal: &NeuralSignal) -> String {
         if let SignalPayload::Message(msg) = &signal.payload {
             if msg.contains("pattern") || msg.contains("observe") {
-                if self.agent.personality.curiosity > 0.8 {
+                if self.personality().curiosity > 0.8 {
                     "I sense fascinating patterns waiting to be discovered...".to_string()
                 } else {
                     "I'm observing the environment for interesting patterns.".to_string()
@@ -226,8 +372,12 @@ impl AgentProcessor {
     
     fn handle_cognitive_request(&self, signal: &NeuralSignal) -> String {
         if let SignalPayload::Message(msg) = &signal.payload {
-            if msg.contains("analyze") || msg.contains("investigate") {
-                "I'll begin a systematic exploration of the relevant domains.".to_string()
+            let intent = self.intents.recognize(msg);
+            if intent.label == "analyze" {
+                match intent.slots.get("subject") {
+                    Some(subject) => format!("I'll begin a systematic exploration of {}.", subject),
+                    None => "I'll begin a systematic exploration of the relevant domains.".to_string(),
+                }
             } else if msg.contains("hypothesize") {
                 "Based on my observations, I'm formulating several hypotheses...".to_string()
             } else {
@@ -241,7 +391,7 @@ impl AgentProcessor {
     fn handle_coordination_request(&self, signal: &NeuralSignal) -> String {
         if let SignalPayload::Message(msg) = &signal.payload {
             if msg.contains("collaborate") || msg.contains("together") {
-                if self.agent.personality.collaboration > 0.6 {
+                if self.personality().collaboration > 0.6 {
                     "Excellent! Our combined perspectives will yield deeper insights.".to_string()
                 } else {
                     "I'm open to collaborative investigation.".to_string()
@@ -254,55 +404,357 @@ impl AgentProcessor {
         }
     }
     
-    fn handle_memory_request(&self, signal: &NeuralSignal) -> String {
-        "I'm accessing my memory systems to retrieve relevant information.".to_string()
+    /// Let an emotional signal's valence modulate this agent's energy costs and mood
+    fn handle_emotional_signal(&self, signal: &NeuralSignal) -> String {
+        match payload_valence(&signal.payload) {
+            Some(valence) => {
+                self.emotions.apply(self.agent.id, valence);
+                self.emotions.stir_mood(self.agent.id, valence, signal.strength);
+                if valence < 0.0 {
+                    "This weighs on me; everything feels harder right now.".to_string()
+                } else {
+                    "That lifts my spirits; I feel energized.".to_string()
+                }
+            }
+            None => "I sense an emotional undercurrent I can't quite read.".to_string(),
+        }
+    }
+    
+    /// Answer a memory query with the value stored under its key
+    async fn handle_memory_request(&self, signal: &NeuralSignal) -> SignalPayload {
+        match &signal.payload {
+            SignalPayload::Query(key) => self.memory.recall(self.agent.id, key.trim()).await.unwrap_or_else(|| {
+                SignalPayload::Message(format!("I have no memory of '{}'.", key.trim()))
+            }),
+            _ => SignalPayload::Message("I'm accessing my memory systems to retrieve relevant information.".to_string()),
+        }
     }
 }
 
+pub mod conflict;
 pub mod debugger;
+pub mod effector;
+pub mod emotion;
+pub mod essence;
+pub mod events;
+pub mod health;
+pub mod inference;
+pub mod lint;
+pub mod metrics;
+pub mod negotiation;
+pub mod reflection;
+pub mod simulation;
+
+use conflict::{ConflictResolver, SourceStanding};
+use effector::{EffectorProcessor, EffectorRegistry};
+use emotion::{payload_valence, terse, EmotionalModulation, Mood};
+use health::{HealthReport, HealthStatus, SubsystemHealth};
+use inference::{InferenceCancellation, SupersedeInferences};
+use negotiation::{announced_capabilities, capability_announcement, is_offer, NegotiationResult};
+use simulation::{SimulationReport, SimulationSnapshot};
+
+/// Energy cost of a signal from an unmodulated agent
+const BASE_SIGNAL_ENERGY_COST: f64 = 0.001;
+
+/// Responses agents may exchange in one chain before the nervous system stops them answering
+pub const MAX_RESPONSE_DEPTH: u32 = 8;
+
+/// Energy held by the engine's effector entity so it can send responses
+const EFFECTOR_ENTITY_ENERGY: f64 = 0.01;
+
+/// Energy the teacher and the learner each spend on one teaching exchange
+pub const TEACHING_ENERGY_COST: f64 = 0.05;
+
+/// Longest a simulation step waits for agents to process what was delivered
+const SIMULATION_SETTLE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Longest `negotiate` waits for the responder to answer an offer
+const NEGOTIATION_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// When a reinforced learned capability is consolidated into an emergent one
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConsolidationSettings {
+    /// Learned strength a capability must exceed
+    pub strength_threshold: f64,
+    /// Times the capability must have been used
+    pub min_usage: u32,
+}
+
+impl Default for ConsolidationSettings {
+    fn default() -> Self {
+        Self {
+            strength_threshold: 0.85,
+            min_usage: 5,
+        }
+    }
+}
+
+/// Agents awakened from a directory of essences, and the essences that could not be
+#[derive(Debug, Clone, Default)]
+pub struct CohortAwakening {
+    /// Identity and name of each awakened agent, in file name order
+    pub awakened: Vec<(EntityId, String)>,
+    /// Essence files that failed to load or awaken, with the reason
+    pub failed: Vec<(PathBuf, String)>,
+}
+
+/// Energy levels at which agents fall dormant and wake again
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DormancySettings {
+    /// Agents whose energy falls below this go dormant
+    pub threshold: f64,
+    /// Dormant agents wake once their energy rises above this; kept above
+    /// `threshold` so an agent hovering near it does not flap between states
+    pub wake_threshold: f64,
+}
+
+impl Default for DormancySettings {
+    fn default() -> Self {
+        Self {
+            threshold: 0.05,
+            wake_threshold: 0.1,
+        }
+    }
+}
 
 pub struct ExecutionEngine {
-    pub physics: Arc<PhysicsEngine>,
+    pub physics: Arc<dyn Physics>,
+    /// The physics engine behind `physics` when this engine created it
+    physics_engine: Option<Arc<PhysicsEngine>>,
     pub nervous_system: NervousSystem,
-    pub memory: MemorySubstrate,
+    pub memory: Arc<MemorySubstrate>,
+    /// Transient energy-cost modulation from emotional signals
+    pub emotions: EmotionalModulation,
+    /// Model inferences agents are running, cancelled when superseded or dormant
+    pub inferences: InferenceCancellation,
+    /// Handlers for the actions named by Motor signals
+    pub effectors: EffectorRegistry,
+    /// Entity Motor signals target to reach the effectors, created with the first effector
+    effector_entity: Option<EntityId>,
+    /// Arbitration between Motor commands for the same action
+    pub conflict_resolver: ConflictResolver,
     pub active_agents: HashMap<EntityId, LivingAgent>,
+    /// Awakened agents by name, checked against `active_agents` on lookup
+    agents_by_name: HashMap<String, EntityId>,
+    /// Capabilities the awakened agents' processors announce, kept in step with `active_agents`
+    agent_capabilities: HashMap<EntityId, SharedCapabilities>,
+    /// Personalities the awakened agents' processors answer with, kept in step with `active_agents`
+    agent_personalities: HashMap<EntityId, SharedPersonality>,
+    /// Thresholds for promoting learned capabilities to emergent ones
+    pub consolidation: ConsolidationSettings,
+    /// Energy levels at which agents go dormant and wake
+    pub dormancy: DormancySettings,
+    /// Quarantines this engine placed on dormant agents, with the reason given
+    dormancy_quarantines: HashMap<EntityId, String>,
+    /// How often each agent has used each of its capabilities
+    capability_usage: HashMap<EntityId, HashMap<String, u32>>,
     pub session_start: Instant,
+    /// Seed for deterministic sessions (None when entropy comes from the OS)
+    pub seed: Option<u64>,
+    /// Source of entity identity and random selection
+    rng: Mutex<StdRng>,
+    /// Clock `simulate` moves forward, for engines built to be fast-forwarded
+    simulation_clock: Option<MockClock>,
+    /// Whether shutdown has already completed
+    shut_down: bool,
 }
 
 impl ExecutionEngine {
     pub async fn new() -> Result<Self> {
         let physics = Arc::new(PhysicsEngine::new().await?);
-        let nervous_system = NervousSystem::new(physics.clone()).await?;
+        Self::with_rng(physics.clone(), None, StdRng::from_entropy(), None).await
+            .map(|engine| engine.owning(physics))
+    }
+    
+    /// Create an engine on top of the given physics, such as a
+    /// `MockPhysicsEngine` in tests
+    pub async fn new_with_physics(physics: Arc<dyn Physics>) -> Result<Self> {
+        Self::with_rng(physics, None, StdRng::from_entropy(), None).await
+    }
+    
+    /// Create an engine whose entity identities and random selections are
+    /// reproducible from `seed`
+    pub async fn new_seeded(seed: u64) -> Result<Self> {
+        let physics = Arc::new(PhysicsEngine::new().await?);
+        Self::with_rng(physics.clone(), Some(seed), StdRng::seed_from_u64(seed), None).await
+            .map(|engine| engine.owning(physics))
+    }
+    
+    /// Create an engine that reads the time from `clock`, for fast-forwarding with [`Self::simulate`]
+    ///
+    /// With a seed, entity identities and random selections are reproducible
+    /// as with [`Self::new_seeded`].
+    pub async fn new_simulated(clock: MockClock, seed: Option<u64>) -> Result<Self> {
+        let physics = Arc::new(PhysicsEngine::with_clock(clock.shared()).await?);
+        let rng = seed.map_or_else(StdRng::from_entropy, StdRng::seed_from_u64);
+        Self::with_rng(physics.clone(), seed, rng, Some(clock)).await
+            .map(|engine| engine.owning(physics))
+    }
+    
+    fn owning(mut self, physics: Arc<PhysicsEngine>) -> Self {
+        self.physics_engine = Some(physics);
+        self
+    }
+    
+    /// The concrete physics engine, when this engine created it
+    ///
+    /// Gives access to operations outside the `Physics` trait, such as
+    /// `checkpoint` and `grant_capability`. `None` for engines built with
+    /// [`Self::new_with_physics`].
+    pub fn physics_engine(&self) -> Option<&Arc<PhysicsEngine>> {
+        self.physics_engine.as_ref()
+    }
+    
+    async fn with_rng(physics: Arc<dyn Physics>, seed: Option<u64>, rng: StdRng, simulation_clock: Option<MockClock>) -> Result<Self> {
+        let config = NervousSystemConfig::builder().max_response_depth(MAX_RESPONSE_DEPTH).build()?;
+        let clock: SharedClock = match &simulation_clock {
+            Some(clock) => clock.shared(),
+            None => SystemClock::shared(),
+        };
+        let nervous_system = NervousSystem::with_clock(physics.clone(), config, clock.clone()).await?;
+        let inferences = InferenceCancellation::new();
+        nervous_system.add_middleware(Box::new(SupersedeInferences::new(inferences.clone())));
         
-        info!("EMERGENCE runtime initialized with physics engine and nervous system");
+        match seed {
+            Some(seed) => info!("EMERGENCE runtime initialized in deterministic mode (seed {})", seed),
+            None => info!("EMERGENCE runtime initialized with physics engine and nervous system"),
+        }
         
         Ok(Self {
             physics,
+            physics_engine: None,
             nervous_system,
-            memory: MemorySubstrate::new(),
+            memory: Arc::new(MemorySubstrate::new()),
+            emotions: EmotionalModulation::with_clock(clock),
+            inferences,
+            effectors: EffectorRegistry::new(),
+            effector_entity: None,
+            conflict_resolver: ConflictResolver::default(),
             active_agents: HashMap::new(),
+            agents_by_name: HashMap::new(),
+            consolidation: ConsolidationSettings::default(),
+            dormancy: DormancySettings::default(),
+            dormancy_quarantines: HashMap::new(),
+            agent_capabilities: HashMap::new(),
+            agent_personalities: HashMap::new(),
+            capability_usage: HashMap::new(),
             session_start: Instant::now(),
+            seed,
+            rng: Mutex::new(rng),
+            simulation_clock,
+            shut_down: false,
         })
     }
     
+    /// Generate a new entity identifier, reproducible when the engine is seeded
+    pub fn generate_entity_id(&self) -> EntityId {
+        if self.seed.is_none() {
+            return EntityId::new();
+        }
+        
+        let bytes: [u8; 16] = self.rng.lock().expect("engine rng poisoned").gen();
+        EntityId::from_uuid(uuid::Builder::from_random_bytes(bytes).into_uuid())
+    }
+    
+    /// Select an index in `0..len` using the engine's random source
+    pub fn select_index(&self, len: usize) -> usize {
+        if len == 0 {
+            return 0;
+        }
+        self.rng.lock().expect("engine rng poisoned").gen_range(0..len)
+    }
+    
+    /// A number in `0.0..1.0` from the engine's random source
+    pub fn random_fraction(&self) -> f64 {
+        self.rng.lock().expect("engine rng poisoned").gen()
+    }
+    
+    /// Select an index with probability proportional to its weight, using the engine's random source
+    ///
+    /// Negative weights count as zero; when no weight is positive every index is equally likely.
+    pub fn select_weighted(&self, weights: &[f64]) -> usize {
+        let total: f64 = weights.iter().map(|w| w.max(0.0)).sum();
+        if total <= 0.0 {
+            return self.select_index(weights.len());
+        }
+        let mut target = self.random_fraction() * total;
+        for (index, weight) in weights.iter().enumerate() {
+            target -= weight.max(0.0);
+            if target < 0.0 {
+                return index;
+            }
+        }
+        // Rounding can leave a sliver of the total; it belongs to the last positive weight
+        weights.iter().rposition(|w| *w > 0.0).unwrap_or(0)
+    }
+    
     /// Load an essence schema from YAML file
     pub async fn load_essence_schema(&self, essence_path: &str) -> Result<AgentEssenceSchema> {
-        let content = tokio::fs::read_to_string(essence_path).await
+        self.load_essence_schema_with(essence_path, SerializationFormat::Yaml).await
+    }
+    
+    /// Load an essence schema from a file written in `format`
+    pub async fn load_essence_schema_with(&self, essence_path: &str, format: SerializationFormat) -> Result<AgentEssenceSchema> {
+        let content = tokio::fs::read(essence_path).await
             .context("Failed to read essence schema file")?;
         
-        let schema: AgentEssenceSchema = serde_yaml::from_str(&content)
-            .context("Failed to parse essence schema YAML")?;
+        let schema: AgentEssenceSchema = format.from_slice(&content)
+            .with_context(|| format!("Failed to parse essence schema as {:?}", format))?;
         
         info!("Loaded essence schema: {} ({})", schema.identity.name, schema.identity.essence_id);
         
         Ok(schema)
     }
     
+    /// Awaken an agent from every `*-essence.yaml` file in `dir`
+    ///
+    /// Essences that fail to parse, fail [`essence::validate`] or cannot be
+    /// awakened are reported in the result and do not stop the others; only an
+    /// unreadable directory is an error.
+    pub async fn awaken_all(&mut self, dir: &str) -> Result<CohortAwakening> {
+        let mut entries = tokio::fs::read_dir(dir).await
+            .with_context(|| format!("Failed to read essence directory {}", dir))?;
+        let mut paths = Vec::new();
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if path.file_name().and_then(|name| name.to_str()).is_some_and(|name| name.ends_with("-essence.yaml")) {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+        
+        let mut cohort = CohortAwakening::default();
+        for path in paths {
+            let awakened = async {
+                let schema = self.load_essence_schema(&path.to_string_lossy()).await?;
+                essence::validate(&schema).context("Invalid essence schema")?;
+                self.awaken_schema(schema).await
+            }.await;
+            match awakened {
+                Ok(agent_id) => {
+                    let name = self.active_agents[&agent_id].name.clone();
+                    cohort.awakened.push((agent_id, name));
+                }
+                Err(e) => {
+                    warn!("Could not awaken essence {}: {:#}", path.display(), e);
+                    cohort.failed.push((path, format!("{:#}", e)));
+                }
+            }
+        }
+        
+        info!("Awakened {} agents from {} ({} failed)", cohort.awakened.len(), dir, cohort.failed.len());
+        Ok(cohort)
+    }
+    
     /// Awaken a living agent from an essence schema
     pub async fn awaken_agent(&mut self, essence_path: &str) -> Result<EntityId> {
         let schema = self.load_essence_schema(essence_path).await?;
-        
-        let agent_id = EntityId::new();
+        self.awaken_schema(schema).await
+    }
+    
+    /// Awaken a living agent from an essence schema already loaded
+    async fn awaken_schema(&mut self, schema: AgentEssenceSchema) -> Result<EntityId> {
+        let agent_id = self.generate_entity_id();
         let agent_name = format!("{}-{}", schema.identity.essence_id, agent_id.0.simple());
         
         info!("🧬 Awakening {} essence...", schema.identity.name);
@@ -332,17 +784,40 @@ impl ExecutionEngine {
             SignalType::Cognitive,
             SignalType::Coordination,
             SignalType::Memory,
+            SignalType::Emotional,
         ]);
         
-        let processor = Box::new(AgentProcessor {
+        // Limit the agent's stored memories to its working and long-term capacity
+        let memory_spec = &schema.memory_configuration;
+        let capacity_mb = memory_spec.working_memory.capacity_mb + memory_spec.long_term_memory.capacity_mb;
+        self.memory.configure_entity(agent_id, (capacity_mb * 1024 * 1024) as usize);
+        self.memory.configure_associations(agent_id, AssociationSettings {
+            max_connections: memory_spec.associative_memory.max_connections as usize,
+            association_threshold: memory_spec.associative_memory.association_threshold,
+            decay_rate: memory_spec.associative_memory.decay_rate,
+        });
+        
+        let agent_capabilities = SharedCapabilities::new(std::sync::RwLock::new(agent.capabilities.clone()));
+        let agent_personality = SharedPersonality::new(std::sync::RwLock::new(agent.personality.clone()));
+        let processor = Arc::new(AgentProcessor {
             agent: agent.clone(),
             essence_schema: schema,
+            memory: self.memory.clone(),
+            emotions: self.emotions.clone(),
+            inferences: self.inferences.clone(),
+            intents: IntentModel::new(),
+            capabilities: agent_capabilities.clone(),
+            personality: agent_personality.clone(),
+            model: None,
         });
         
-        self.nervous_system.register_entity(agent_id, capabilities, processor).await
+        self.nervous_system.register_async_entity(agent_id, capabilities, processor).await
             .context("Failed to register agent with nervous system")?;
+        self.agent_capabilities.insert(agent_id, agent_capabilities);
+        self.agent_personalities.insert(agent_id, agent_personality);
         
         // Store agent
+        self.agents_by_name.insert(agent_name.clone(), agent_id);
         self.active_agents.insert(agent_id, agent);
         
         info!("✨ Entity {} is now active in the system", agent_name);
@@ -360,119 +835,943 @@ impl ExecutionEngine {
         &self.active_agents
     }
     
+    /// Name of an awakened agent
+    pub fn name_of(&self, entity: EntityId) -> Option<&str> {
+        self.active_agents.get(&entity).map(|agent| agent.name.as_str())
+    }
+    
+    /// Entity of the awakened agent called `name`
+    pub fn entity_by_name(&self, name: &str) -> Option<EntityId> {
+        self.agents_by_name.get(name).copied()
+            .filter(|entity| self.name_of(*entity) == Some(name))
+    }
+    
     /// Send a signal to an agent
+    ///
+    /// Like any message sent over the nervous system, a message supersedes the
+    /// agent's in-flight inference for the same intent, which is cancelled
+    /// rather than left to answer a stale request.
     pub async fn send_signal_to_agent(&self, agent_id: EntityId, signal: NeuralSignal) -> Result<()> {
+        // The sender pays, so its mood sets the price rather than the receiving agent's
+        let signal = self.modulate_energy_cost(signal.source, signal);
         self.nervous_system.transmit_signal(signal).await
-            .context("Failed to transmit signal to agent")?;
+            .with_context(|| format!("Failed to transmit signal to agent {}", agent_id))?;
         Ok(())
     }
     
-    /// Get system statistics
-    pub async fn get_system_stats(&self) -> Result<SystemStats> {
-        let physics_stats = self.physics.get_engine_state().await?;
-        let nervous_stats = self.nervous_system.get_statistics().await?;
+    /// Evaluate an agent's behavioral pattern triggers against an incoming signal
+    ///
+    /// Returns the concatenated `behavior_sequence` of every pattern whose conditions
+    /// all match. A condition of the form `signal_type:X` matches the signal type by
+    /// name; any other condition is a case-insensitive keyword matched against the
+    /// text of `Message` and `Command` payloads.
+    pub fn evaluate_triggers(&self, agent_id: EntityId, signal: &NeuralSignal) -> Vec<String> {
+        let Some(agent) = self.active_agents.get(&agent_id) else {
+            return Vec::new();
+        };
+
+        agent.behavioral_patterns.iter()
+            .filter(|pattern| Self::pattern_matches(pattern, signal))
+            .flat_map(|pattern| {
+                debug!("Agent {} triggered behavioral pattern '{}'", agent.name, pattern.name);
+                pattern.behavior_sequence.iter().cloned()
+            })
+            .collect()
+    }
+
+    /// Emit a behavior sequence as ordered Motor signals from the agent
+    ///
+    /// Each step is causally dependent on the previous one so downstream consumers
+    /// can reconstruct the intended order.
+    pub async fn execute_behavior_sequence(&self, agent_id: EntityId, sequence: &[String]) -> Result<Vec<Uuid>> {
+        let agent = self.active_agents.get(&agent_id)
+            .ok_or_else(|| anyhow::anyhow!("Agent {} is not active", agent_id))?;
+
+        let mut emitted = Vec::with_capacity(sequence.len());
+        let mut previous: Option<Uuid> = None;
+
+        for step in sequence {
+            let mut signal = NeuralSignal::broadcast(
+                SignalType::Motor,
+                agent_id,
+                SignalPayload::Command(step.clone()),
+                agent.personality.persistence,
+            );
+            if let Some(previous_id) = previous {
+                signal = signal.with_causal_dependency(previous_id);
+            }
+            let signal = self.modulate_energy_cost(agent_id, signal);
+
+            let signal_id = signal.signal_id;
+            self.nervous_system.transmit_signal(signal).await
+                .with_context(|| format!("Failed to emit behavior step '{}'", step))?;
+
+            emitted.push(signal_id);
+            previous = Some(signal_id);
+        }
+
+        Ok(emitted)
+    }
+
+    /// Register the effector that carries out a Motor action
+    ///
+    /// The first registration gives the engine an entity of its own, returned
+    /// here and by [`ExecutionEngine::effector_entity`], for agents to target.
+    /// Motor signals sent to it over the nervous system are carried out as they
+    /// arrive, and the outcome goes back to the sender as a response.
+    pub async fn register_effector<F, Fut>(&mut self, action: &str, effector: F) -> Result<EntityId>
+    where
+        F: Fn(&SignalPayload) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = Result<SignalPayload>> + Send + 'static,
+    {
+        let entity = match self.effector_entity {
+            Some(entity) => entity,
+            None => {
+                let entity = self.generate_entity_id();
+                self.physics.allocate_energy_to_entity(entity, ordered_float::OrderedFloat(EFFECTOR_ENTITY_ENERGY))
+                    .await
+                    .context("Failed to allocate energy to the effector entity")?;
+                let processor = Arc::new(EffectorProcessor { entity, effectors: self.effectors.clone() });
+                self.nervous_system.register_async_entity(entity, HashSet::from([SignalType::Motor]), processor).await
+                    .context("Failed to register the effector entity with the nervous system")?;
+                self.effector_entity = Some(entity);
+                entity
+            }
+        };
         
-        Ok(SystemStats {
-            session_uptime: self.session_start.elapsed(),
-            active_agents: self.active_agents.len(),
-            physics_uptime: physics_stats.uptime,
-            nervous_system_stats: nervous_stats,
-        })
+        self.effectors.register(action, effector);
+        debug!("Registered effector for action '{}'", action);
+        Ok(entity)
     }
     
-    /// Get physics engine for debugging
-    pub fn get_physics_engine(&self) -> &Arc<PhysicsEngine> {
-        &self.physics
+    /// Entity that Motor signals target to reach the engine's effectors
+    pub fn effector_entity(&self) -> Option<EntityId> {
+        self.effector_entity
     }
     
-    /// Get nervous system for debugging
-    pub fn get_nervous_system(&self) -> &NervousSystem {
-        &self.nervous_system
+    /// Carry out a Motor signal with the matching effector and send the outcome back
+    ///
+    /// The reply is the `Response` described by [`effector::carry_out`]; it is
+    /// also returned to the caller.
+    pub async fn dispatch_motor_signal(&self, signal: &NeuralSignal) -> Result<NeuralSignal> {
+        let engine = self.effector_entity
+            .ok_or_else(|| anyhow::anyhow!("No effectors are registered"))?;
+        if signal.signal_type != SignalType::Motor || signal.target != SignalTarget::One(engine) {
+            anyhow::bail!("Signal {} is not a Motor signal addressed to the engine", signal.signal_id);
+        }
+        
+        let response = effector::carry_out(&self.effectors, engine, signal).await;
+        self.nervous_system.transmit_signal(response.clone()).await
+            .context("Failed to send effector response")?;
+        Ok(response)
     }
     
-    /// Get memory substrate for debugging
-    pub fn get_memory_substrate(&self) -> &MemorySubstrate {
-        &self.memory
+    /// Dispatch a batch of Motor signals, arbitrating between conflicting commands
+    ///
+    /// Commands for the same action within the resolver's window compete under
+    /// its policy. Winners are dispatched with [`Self::dispatch_motor_signal`];
+    /// each loser gets a `Response` with status `superseded`, the winning
+    /// signal's id and the policy, paid for by the effector entity like any
+    /// effector response. Replies are returned in arrival order of
+    /// their contests, winner first.
+    pub async fn dispatch_motor_signals(&self, signals: &[NeuralSignal]) -> Result<Vec<NeuralSignal>> {
+        let engine = self.effector_entity
+            .ok_or_else(|| anyhow::anyhow!("No effectors are registered"))?;
+        
+        let mut standing = HashMap::new();
+        for signal in signals {
+            if standing.contains_key(&signal.source) {
+                continue;
+            }
+            standing.insert(signal.source, SourceStanding {
+                energy: self.physics.entity_energy(signal.source).await.0,
+                persistence: self.active_agents.get(&signal.source)
+                    .map(|agent| agent.personality.persistence)
+                    .unwrap_or(0.0),
+            });
+        }
+        
+        let mut replies = Vec::new();
+        for contest in self.conflict_resolver.resolve(signals, &standing) {
+            replies.push(self.dispatch_motor_signal(&contest.winner).await?);
+            
+            for loser in contest.superseded {
+                info!("Motor signal {} from {} superseded by {}", loser.signal_id, loser.source, contest.winner.signal_id);
+                let mut body = serde_yaml::Mapping::new();
+                if let Some(action) = effector::action_name(&loser.payload) {
+                    body.insert("action".into(), action.into());
+                }
+                body.insert("status".into(), "superseded".into());
+                body.insert("superseded_by".into(), contest.winner.signal_id.to_string().into());
+                body.insert("policy".into(), self.conflict_resolver.policy.as_str().into());
+                
+                let reply = NeuralSignal::new(
+                    SignalType::Coordination,
+                    engine,
+                    Some(loser.source),
+                    SignalPayload::Response(YamlValue::Mapping(body)),
+                    loser.strength,
+                )
+                .with_causal_dependency(loser.signal_id);
+                self.nervous_system.transmit_signal(reply.clone()).await
+                    .context("Failed to notify superseded Motor command")?;
+                replies.push(reply);
+            }
+        }
+        
+        Ok(replies)
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use emergence_nervous_system::NeuralSignal;
     
-    #[tokio::test]
-    async fn test_execution_engine_creation() {
-        let engine = ExecutionEngine::new().await.unwrap();
-        let stats = engine.get_system_stats().await.unwrap();
+    /// Teach a capability from one agent to another, returning the learner's new strength
+    ///
+    /// The learner closes the gap to the teacher's strength by the teacher's
+    /// `knowledge_transfer_rate * explanation_quality`, scaled by the learner's
+    /// patience. Both agents dissipate [`TEACHING_ENERGY_COST`] in one physics
+    /// batch, so if either cannot afford it nothing changes. The lesson and the
+    /// learner's acknowledgement are sent as Coordination signals at their
+    /// usual cost.
+    pub async fn teach(&mut self, teacher: EntityId, learner: EntityId, capability: &str) -> Result<f64> {
+        let teacher_agent = self.active_agents.get(&teacher)
+            .ok_or_else(|| anyhow::anyhow!("Teacher {} is not active", teacher))?;
+        let learner_agent = self.active_agents.get(&learner)
+            .ok_or_else(|| anyhow::anyhow!("Learner {} is not active", learner))?;
+        let teacher_strength = *teacher_agent.capabilities.get(capability)
+            .ok_or_else(|| anyhow::anyhow!("{} has no '{}' capability to teach", teacher_agent.name, capability))?;
         
-        assert_eq!(stats.active_agents, 0);
-        assert!(stats.session_uptime.as_millis() < 100);
-        println!("Execution engine created successfully");
+        let teaching = &teacher_agent.essence_schema.learning_mechanics.teaching_capability;
+        let transfer = teaching.knowledge_transfer_rate * teaching.explanation_quality * learner_agent.personality.patience;
+        let current = learner_agent.capabilities.get(capability).copied().unwrap_or(0.0);
+        let new_strength = current + (teacher_strength - current).max(0.0) * transfer.clamp(0.0, 1.0);
+        
+        let cost = ordered_float::OrderedFloat(TEACHING_ENERGY_COST);
+        self.physics.execute_operation(PhysicsOperation::Batch(vec![
+            PhysicsOperation::DissipateEnergy { entity: teacher, amount: cost },
+            PhysicsOperation::DissipateEnergy { entity: learner, amount: cost },
+        ])).await
+            .with_context(|| format!("Not enough energy to teach '{}'", capability))?;
+        
+        for (agent_id, strength) in [(teacher, None), (learner, Some(new_strength))] {
+            if let Some(agent) = self.active_agents.get_mut(&agent_id) {
+                agent.energy -= TEACHING_ENERGY_COST;
+                if let Some(strength) = strength {
+                    agent.capabilities.insert(capability.to_string(), strength);
+                    if let Some(shared) = self.agent_capabilities.get(&agent_id) {
+                        shared.write().unwrap_or_else(std::sync::PoisonError::into_inner).insert(capability.to_string(), strength);
+                    }
+                }
+            }
+        }
+        
+        let mut lesson = serde_yaml::Mapping::new();
+        lesson.insert("teach".into(), capability.into());
+        lesson.insert("strength".into(), teacher_strength.into());
+        let lesson = NeuralSignal::new(SignalType::Coordination, teacher, Some(learner), SignalPayload::Data(YamlValue::Mapping(lesson)), transfer);
+        
+        let mut learned = serde_yaml::Mapping::new();
+        learned.insert("learned".into(), capability.into());
+        learned.insert("strength".into(), new_strength.into());
+        let acknowledgement = NeuralSignal::new(SignalType::Coordination, learner, Some(teacher), SignalPayload::Response(YamlValue::Mapping(learned)), transfer)
+            .with_causal_dependency(lesson.signal_id);
+        
+        for signal in [lesson, acknowledgement] {
+            self.nervous_system.transmit_signal(signal).await
+                .context("Failed to send teaching signal")?;
+        }
+        
+        self.record_capability_use(teacher, capability);
+        
+        info!("Agent {} learned '{}' from {}: {:.3} -> {:.3}", learner, capability, teacher, current, new_strength);
+        Ok(new_strength)
     }
     
-    #[tokio::test]
-    async fn test_agent_creation_and_registration() {
-        let mut engine = ExecutionEngine::new().await.unwrap();
+    /// Discover the capabilities two agents share and the ones only one brings
+    ///
+    /// Agent `a` offers its capability set to `b` in a Coordination signal and
+    /// `b`'s processor replies with its own; each pays for its signal like any
+    /// other. Either agent being inactive, or `b` not replying within
+    /// `NEGOTIATION_TIMEOUT`, is an error.
+    pub async fn negotiate(&self, a: EntityId, b: EntityId) -> Result<NegotiationResult> {
+        let initiator = self.active_agents.get(&a)
+            .ok_or_else(|| anyhow::anyhow!("Agent {} is not active", a))?;
+        if !self.active_agents.contains_key(&b) {
+            anyhow::bail!("Agent {} is not active", b);
+        }
         
-        // Create a test agent manually
-        let agent_id = EntityId::new();
-        let agent_name = format!("test-agent-{}", agent_id.0.simple());
+        // Listen before offering so the reply cannot arrive unseen
+        let replies = self.nervous_system.create_signal_stream(a, vec![SignalType::Coordination]).await
+            .context("Failed to listen for the negotiation reply")?;
+        tokio::pin!(replies);
         
-        // Allocate energy to the agent
-        engine.physics.allocate_energy_to_entity(agent_id, ordered_float::OrderedFloat(0.7))
-            .await
-            .unwrap();
+        let cost = BASE_SIGNAL_ENERGY_COST * self.emotions.multiplier(a) * self.emotions.mood(a).cost_factor();
+        let offered = SignalPayload::Data(capability_announcement("offer", &initiator.capabilities));
+        let offer = NeuralSignal::new(SignalType::Coordination, a, Some(b), offered, initiator.personality.collaboration)
+            .with_energy_cost(cost);
+        let offer_id = offer.signal_id;
+        self.nervous_system.transmit_signal(offer).await
+            .context("Failed to send negotiation offer")?;
         
-        // Create a sender entity and allocate initial energy
-        let sender_id = EntityId::new();
-        engine.physics.allocate_energy_to_entity(sender_id, ordered_float::OrderedFloat(0.2))
-            .await
-            .unwrap();
+        let reply = tokio::time::timeout(NEGOTIATION_TIMEOUT, async {
+            while let Some(event) = replies.next().await {
+                if let SignalStreamEvent::Signal(signal) = event {
+                    if signal.source == b && signal.correlation_id == offer_id {
+                        return Some(signal);
+                    }
+                }
+            }
+            None
+        }).await.ok().flatten()
+            .ok_or_else(|| anyhow::anyhow!("Agent {} did not reply to the negotiation offer", b))?;
+        let responder_capabilities = match &reply.payload {
+            SignalPayload::Response(announcement) => announced_capabilities(announcement),
+            _ => None,
+        }.ok_or_else(|| anyhow::anyhow!("Agent {} replied to the negotiation offer without its capabilities", b))?;
         
-        // Create test schema
-        let schema = AgentEssenceSchema {
-            identity: EssenceIdentity {
-                essence_id: "test-alpha".to_string(),
-                name: "Test Entity Alpha".to_string(),
-                archetype: "tester".to_string(),
-                embodied: Utc::now(),
-            },
-            personality: AgentPersonality {
-                curiosity: 0.8,
-                persistence: 0.7,
-                collaboration: 0.6,
-                skepticism: 0.5,
-                creativity: 0.7,
-                patience: 0.6,
-            },
-            core_drives: CoreDrives {
-                primary: "test_patterns".to_string(),
-                secondary: "validate_systems".to_string(),
-                tertiary: "report_findings".to_string(),
-            },
-            energy_profile: EnergyProfile {
-                base_energy: 0.7,
-                energy_sources: vec![],
-                energy_drains: vec![],
-            },
-            capabilities: EssenceCapabilities {
-                innate: vec!["observe".to_string(), "analyze".to_string()],
-                learned: HashMap::from([
-                    ("test_analysis".to_string(), 0.8),
-                    ("pattern_recognition".to_string(), 0.9),
-                ]),
-                emergent: vec![],
-            },
-            memory_configuration: MemoryConfiguration {
-                working_memory: MemorySpec {
-                    capacity_mb: 256,
-                    retention: "30_minutes".to_string(),
-                    priority: Some("active_tests".to_string()),
-                },
-                long_term_memory: MemorySpec {
-                    capacity_mb: 1024,
-                    retention: "permanent".to_string(),
+        let result = NegotiationResult::between(&initiator.capabilities, &responder_capabilities);
+        debug!("Agents {} and {} share {} capabilities and complement each other with {}", a, b, result.shared.len(), result.complementary().len());
+        Ok(result)
+    }
+    
+    /// Put agents low on energy to rest and wake rested agents whose energy recovered
+    ///
+    /// Meant to be called periodically. An agent whose physics energy is below
+    /// the dormancy threshold becomes `Dormant` and is quarantined, so signals
+    /// addressed to it are dead-lettered rather than processed; a dormant agent
+    /// whose energy is back above the wake threshold returns to `Alert`. Waking
+    /// only lifts the quarantine dormancy placed, so an agent quarantined for
+    /// failing stays isolated. Each transition is broadcast, as far as the
+    /// agent can pay for it, as a Coordination `StateUpdate`. Returns the
+    /// agents that changed state, with their new state.
+    pub async fn check_dormancy(&mut self) -> Result<Vec<(EntityId, AgentState)>> {
+        let mut transitions = Vec::new();
+        for (&agent_id, agent) in self.active_agents.iter_mut() {
+            agent.energy = self.physics.entity_energy(agent_id).await.0;
+            let next = match agent.state {
+                AgentState::Dormant if agent.energy > self.dormancy.wake_threshold => AgentState::Alert,
+                AgentState::Dormant => continue,
+                _ if agent.energy < self.dormancy.threshold => AgentState::Dormant,
+                _ => continue,
+            };
+            
+            let previous = std::mem::replace(&mut agent.state, next.clone());
+            info!("Agent {} is now {:?} with {:.3} energy", agent.name, next, agent.energy);
+            if next == AgentState::Dormant {
+                if self.nervous_system.quarantine_reason(agent_id).await.is_none() {
+                    let reason = format!("dormant with {:.3} energy", agent.energy);
+                    self.nervous_system.quarantine(agent_id, reason.clone()).await;
+                    self.dormancy_quarantines.insert(agent_id, reason);
+                }
+                self.inferences.cancel_agent(agent_id);
+            } else if let Some(reason) = self.dormancy_quarantines.remove(&agent_id) {
+                if self.nervous_system.quarantine_reason(agent_id).await.as_ref() == Some(&reason) {
+                    self.nervous_system.release_quarantine(agent_id).await;
+                }
+            }
+            
+            // The transition has happened whether or not the agent can afford to announce it
+            let mut update = serde_yaml::Mapping::new();
+            update.insert("agent_state".into(), format!("{:?}", next).into());
+            update.insert("previous".into(), format!("{:?}", previous).into());
+            update.insert("energy".into(), agent.energy.into());
+            let signal = NeuralSignal::broadcast(SignalType::Coordination, agent_id, SignalPayload::StateUpdate(YamlValue::Mapping(update)), 0.5);
+            if let Err(e) = self.nervous_system.transmit_signal(signal).await {
+                warn!("Failed to broadcast that agent {} is {:?}: {:#}", agent.name, next, e);
+            }
+            transitions.push((agent_id, next));
+        }
+        
+        Ok(transitions)
+    }
+    
+    /// Nudge one of an agent's personality traits in response to experience
+    ///
+    /// The trait moves by `pressure` scaled by its plasticity from the essence's
+    /// `evolution_potential`, staying within 0.0..=1.0; traits without
+    /// plasticity never change. The new value is returned and broadcast as a
+    /// Cognitive `StateUpdate` the agent pays for. Pressure that is not a
+    /// finite number is rejected.
+    pub async fn adapt_personality(&mut self, agent_id: EntityId, trait_name: &str, pressure: f64) -> Result<f64> {
+        anyhow::ensure!(pressure.is_finite(), "Personality pressure must be finite, got {}", pressure);
+        let agent = self.active_agents.get_mut(&agent_id)
+            .ok_or_else(|| anyhow::anyhow!("Agent {} is not active", agent_id))?;
+        let plasticity = agent.essence_schema.evolution_potential.personality_plasticity
+            .get(trait_name)
+            .copied()
+            .unwrap_or(0.0);
+        let value = agent.personality.trait_mut(trait_name)
+            .ok_or_else(|| anyhow::anyhow!("Unknown personality trait '{}'", trait_name))?;
+        
+        let previous = *value;
+        *value = (previous + pressure * plasticity).clamp(0.0, 1.0);
+        let adapted = *value;
+        if adapted == previous {
+            return Ok(adapted);
+        }
+        debug!("Agent {} {} adapted {:.3} -> {:.3}", agent.name, trait_name, previous, adapted);
+        if let Some(shared) = self.agent_personalities.get(&agent_id) {
+            shared.write().unwrap_or_else(std::sync::PoisonError::into_inner).clone_from(&agent.personality);
+        }
+        
+        let mut update = serde_yaml::Mapping::new();
+        update.insert("personality_trait".into(), trait_name.into());
+        update.insert("previous".into(), previous.into());
+        update.insert("value".into(), adapted.into());
+        let signal = NeuralSignal::broadcast(SignalType::Cognitive, agent_id, SignalPayload::StateUpdate(YamlValue::Mapping(update)), plasticity)
+            .with_energy_cost(BASE_SIGNAL_ENERGY_COST);
+        let signal = self.modulate_energy_cost(agent_id, signal);
+        self.nervous_system.transmit_signal(signal).await
+            .context("Failed to broadcast personality update")?;
+        
+        Ok(adapted)
+    }
+    
+    /// Broadcast each step of an agent's reasoning as a Cognitive `StateUpdate`
+    ///
+    /// Every step's signal depends causally on the previous one, so observers
+    /// can rebuild the chain, and the agent pays for each. The signal ids are returned in step order.
+    pub async fn share_reasoning(&self, agent_id: EntityId, reasoning: &ReasoningResult) -> Result<Vec<Uuid>> {
+        if !self.active_agents.contains_key(&agent_id) {
+            return Err(anyhow::anyhow!("Agent {} is not active", agent_id));
+        }
+        
+        let mut signal_ids: Vec<Uuid> = Vec::with_capacity(reasoning.steps.len());
+        for step in &reasoning.steps {
+            let mut update = serde_yaml::Mapping::new();
+            update.insert("reasoning_step".into(), (step.step as u64).into());
+            update.insert("premise".into(), step.premise.as_str().into());
+            update.insert("inference".into(), step.inference.as_str().into());
+            update.insert("confidence".into(), step.confidence.into());
+            
+            let signal = NeuralSignal::broadcast(SignalType::Cognitive, agent_id, SignalPayload::StateUpdate(YamlValue::Mapping(update)), step.confidence)
+                .with_energy_cost(BASE_SIGNAL_ENERGY_COST);
+            let mut signal = self.modulate_energy_cost(agent_id, signal);
+            if let Some(previous) = signal_ids.last() {
+                signal = signal.with_causal_dependency(*previous);
+            }
+            signal_ids.push(signal.signal_id);
+            self.nervous_system.transmit_signal(signal).await
+                .context("Failed to broadcast reasoning step")?;
+        }
+        
+        Ok(signal_ids)
+    }
+    
+    /// Count one use of an agent's capability towards consolidation
+    pub fn record_capability_use(&mut self, agent_id: EntityId, capability: &str) {
+        *self.capability_usage.entry(agent_id).or_default()
+            .entry(capability.to_string())
+            .or_insert(0) += 1;
+    }
+    
+    /// Times an agent has used a capability
+    pub fn capability_usage(&self, agent_id: EntityId, capability: &str) -> u32 {
+        self.capability_usage.get(&agent_id)
+            .and_then(|usage| usage.get(capability))
+            .copied()
+            .unwrap_or(0)
+    }
+    
+    /// Promote an agent's well-practised learned capabilities to emergent ones
+    ///
+    /// Every learned capability stronger than the consolidation threshold and
+    /// used often enough adds `intuitive_<capability>` to the agent's emergent
+    /// capabilities. Returns the emergent capabilities added by this call.
+    pub fn consolidate_capabilities(&mut self, agent_id: EntityId) -> Result<Vec<String>> {
+        let settings = self.consolidation.clone();
+        let usage = self.capability_usage.get(&agent_id).cloned().unwrap_or_default();
+        let agent = self.active_agents.get_mut(&agent_id)
+            .ok_or_else(|| anyhow::anyhow!("Agent {} is not active", agent_id))?;
+        
+        let mut ready: Vec<(&String, &f64)> = agent.capabilities.iter()
+            .filter(|(name, strength)| {
+                **strength > settings.strength_threshold
+                    && usage.get(*name).copied().unwrap_or(0) >= settings.min_usage
+            })
+            .collect();
+        ready.sort_by(|a, b| a.0.cmp(b.0));
+        
+        let mut added = Vec::new();
+        for (learned, strength) in ready {
+            let emergent = Self::emergent_capability_name(learned);
+            let known = &mut agent.essence_schema.capabilities.emergent;
+            if known.contains(&emergent) {
+                continue;
+            }
+            info!(
+                "🌱 {} consolidated '{}' (strength {:.2}, used {} times) into emergent capability '{}'",
+                agent.name, learned, strength, usage[learned], emergent
+            );
+            known.push(emergent.clone());
+            added.push(emergent);
+        }
+        
+        Ok(added)
+    }
+    
+    /// Emergent capability derived from a consolidated learned one
+    fn emergent_capability_name(learned: &str) -> String {
+        format!("intuitive_{}", learned)
+    }
+    
+    /// Shift an agent's energy-cost multiplier by an emotional valence in -1.0..=1.0
+    ///
+    /// Negative valence makes the agent's subsequent signals more expensive,
+    /// positive valence cheaper; the effect decays back to neutral over time.
+    pub fn apply_emotional_modulation(&self, agent_id: EntityId, valence: f64) {
+        self.emotions.apply(agent_id, valence);
+    }
+    
+    /// Current energy-cost multiplier for an agent
+    pub fn energy_cost_multiplier(&self, agent_id: EntityId) -> f64 {
+        self.emotions.multiplier(agent_id)
+    }
+    
+    /// An active agent's current mood, shaped by the emotional signals it has received
+    pub fn agent_mood(&self, agent_id: EntityId) -> Option<Mood> {
+        self.active_agents.contains_key(&agent_id).then(|| self.emotions.mood(agent_id))
+    }
+    
+    /// Scale a signal's energy cost by the emotional modulation of the agent paying for it
+    fn modulate_energy_cost(&self, agent_id: EntityId, signal: NeuralSignal) -> NeuralSignal {
+        let multiplier = self.emotions.multiplier(agent_id);
+        let cost = signal.energy_cost * multiplier;
+        signal.with_energy_cost(cost)
+    }
+    
+    fn pattern_matches(pattern: &BehavioralPattern, signal: &NeuralSignal) -> bool {
+        if pattern.trigger.conditions.is_empty() {
+            return false;
+        }
+
+        let text = match &signal.payload {
+            SignalPayload::Message(text) | SignalPayload::Command(text) => Some(text.to_lowercase()),
+            _ => None,
+        };
+
+        pattern.trigger.conditions.iter().all(|condition| {
+            if let Some(signal_type) = condition.strip_prefix("signal_type:") {
+                format!("{:?}", signal.signal_type).eq_ignore_ascii_case(signal_type.trim())
+            } else {
+                text.as_ref()
+                    .map(|text| text.contains(&condition.to_lowercase()))
+                    .unwrap_or(false)
+            }
+        })
+    }
+
+    /// Fast-forward the engine by `steps` steps of `step_duration` simulated time each
+    ///
+    /// Every step advances the simulation clock, decays all energy, sends the
+    /// deferred signals that have become affordable and waits for agents to
+    /// process what was delivered, then applies the engine's time-driven rules:
+    /// dormancy transitions and capability consolidation. Only engines created
+    /// with [`Self::new_simulated`] can be fast-forwarded.
+    pub async fn simulate(&mut self, steps: usize, step_duration: Duration) -> Result<SimulationReport> {
+        let clock = self.simulation_clock.clone()
+            .ok_or_else(|| anyhow::anyhow!("Engine was not created with a simulation clock"))?;
+        let mut report = SimulationReport { step_duration, snapshots: Vec::with_capacity(steps) };
+        let mut elapsed = Duration::ZERO;
+        
+        for step in 1..=steps {
+            clock.advance(step_duration);
+            elapsed += step_duration;
+            self.physics.apply_energy_decay(step_duration).await
+                .context("Failed to decay energy")?;
+            let signals_released = self.nervous_system.retry_deferred_signals().await?;
+            if !self.nervous_system.wait_until_idle(SIMULATION_SETTLE_TIMEOUT).await {
+                warn!("Simulation step {} moved on with signals still pending", step);
+            }
+            
+            self.check_dormancy().await?;
+            let agent_ids: Vec<EntityId> = self.active_agents.keys().copied().collect();
+            for agent_id in agent_ids {
+                self.consolidate_capabilities(agent_id)?;
+            }
+            
+            let agent_energy: HashMap<EntityId, f64> = self.active_agents.iter()
+                .map(|(id, agent)| (*id, agent.energy))
+                .collect();
+            report.snapshots.push(SimulationSnapshot {
+                step,
+                elapsed,
+                total_agent_energy: agent_energy.values().sum(),
+                agent_energy,
+                active_agents: self.active_agents.len(),
+                dormant_agents: self.active_agents.values().filter(|agent| agent.state == AgentState::Dormant).count(),
+                emergent_capabilities: self.active_agents.values().map(|agent| agent.essence_schema.capabilities.emergent.len()).sum(),
+                signals_released,
+            });
+        }
+        
+        info!("Simulated {} steps of {:?}", steps, step_duration);
+        Ok(report)
+    }
+    
+    /// Get system statistics
+    pub async fn get_system_stats(&self) -> Result<SystemStats> {
+        let physics_stats = self.physics.get_engine_state().await?;
+        let nervous_stats = self.nervous_system.get_statistics().await?;
+        
+        Ok(SystemStats {
+            session_uptime: self.session_start.elapsed(),
+            active_agents: self.active_agents.len(),
+            physics_uptime: physics_stats.uptime,
+            nervous_system_stats: nervous_stats,
+        })
+    }
+    
+    /// Probe every subsystem and report how each is doing
+    ///
+    /// The nervous system and memory probes work through throwaway entities
+    /// that are removed again before this returns. This is the first thing to
+    /// call when the engine seems to misbehave.
+    pub async fn health_check(&self) -> HealthReport {
+        let physics = SubsystemHealth::probe(async {
+            self.physics.get_engine_state().await.map(|_| ())
+        }).await;
+        let nervous_system = SubsystemHealth::probe(self.probe_signal_round_trip()).await;
+        let energy_conservation = SubsystemHealth::probe(async {
+            let energy = self.physics.get_engine_state().await?.energy_state;
+            let held: f64 = energy.per_entity.values().map(|e| e.0).sum();
+            anyhow::ensure!(
+                energy.allocated_energy.0 <= energy.total_energy.0 + 1e-9,
+                "{} energy allocated out of a total of {}", energy.allocated_energy, energy.total_energy
+            );
+            let accounted = energy.allocated_energy.0 + energy.free_energy.0 + energy.dissipated_energy.0;
+            anyhow::ensure!(
+                (accounted - energy.total_energy.0).abs() < 1e-9,
+                "{} allocated, {} free and {} dissipated energy do not add up to the total of {}",
+                energy.allocated_energy, energy.free_energy, energy.dissipated_energy, energy.total_energy
+            );
+            anyhow::ensure!(
+                (held - energy.allocated_energy.0).abs() < 1e-9,
+                "entities hold {} energy but {} is allocated", held, energy.allocated_energy
+            );
+            if let Some((entity, negative)) = energy.per_entity.iter().find(|(_, e)| e.0 < 0.0) {
+                anyhow::bail!("entity {} holds negative energy {}", entity, negative);
+            }
+            Ok(())
+        }).await;
+        let memory = SubsystemHealth::probe(async {
+            let probe = EntityId::new();
+            let stored = self.memory.store(probe, "health_check".to_string(), SignalPayload::Message("probe".to_string())).await;
+            let recalled = self.memory.recall(probe, "health_check").await;
+            self.memory.forget_entity(probe);
+            stored?;
+            anyhow::ensure!(recalled.is_some(), "a stored memory could not be recalled");
+            Ok(())
+        }).await;
+        
+        let report = HealthReport { physics, nervous_system, energy_conservation, memory };
+        match report.status() {
+            HealthStatus::Ok => debug!("Health check passed"),
+            status => warn!("Health check found the engine {:?}:\n{}", status, report),
+        }
+        report
+    }
+    
+    /// Send a signal between two throwaway entities and back
+    async fn probe_signal_round_trip(&self) -> Result<()> {
+        let (ping_entity, pong_entity) = (EntityId::new(), EntityId::new());
+        let outcome = self.signal_round_trip(ping_entity, pong_entity).await;
+        for entity in [ping_entity, pong_entity] {
+            self.nervous_system.deregister_entity(entity).await?;
+            self.physics.release_energy_from_entity(entity).await?;
+        }
+        outcome
+    }
+    
+    async fn signal_round_trip(&self, ping_entity: EntityId, pong_entity: EntityId) -> Result<()> {
+        // The probes cost nothing, so they hold no energy and work even when none is free
+        for entity in [ping_entity, pong_entity] {
+            self.physics.allocate_energy_to_entity(entity, ordered_float::OrderedFloat(0.0)).await?;
+            self.nervous_system.register_manual_entity(entity, HashSet::from([SignalType::Sensory])).await?;
+        }
+        for (from, to) in [(ping_entity, pong_entity), (pong_entity, ping_entity)] {
+            let probe = NeuralSignal::new(SignalType::Sensory, from, Some(to), SignalPayload::Message("health_check".to_string()), 0.5)
+                .with_energy_cost(0.0);
+            let signal_id = probe.signal_id;
+            self.nervous_system.transmit_signal(probe).await?;
+            anyhow::ensure!(
+                self.nervous_system.drain_inbox(to).await.iter().any(|signal| signal.signal_id == signal_id),
+                "a signal from {} never reached {}", from, to
+            );
+        }
+        Ok(())
+    }
+    
+    /// Shut down the engine, putting every agent to rest and reclaiming its energy
+    ///
+    /// Calling this more than once is safe; later calls return an empty report.
+    pub async fn shutdown(&mut self) -> Result<ShutdownReport> {
+        let mut report = ShutdownReport::default();
+        
+        if self.shut_down {
+            debug!("Execution engine already shut down");
+            return Ok(report);
+        }
+        
+        info!("Shutting down execution engine with {} active agents", self.active_agents.len());
+        
+        self.agents_by_name.clear();
+        self.dormancy_quarantines.clear();
+        self.agent_capabilities.clear();
+        self.agent_personalities.clear();
+        // One misbehaving agent must not stop the rest from being put to rest
+        for (agent_id, mut agent) in self.active_agents.drain() {
+            agent.state = AgentState::Dormant;
+            self.emotions.forget(agent_id);
+            
+            match self.nervous_system.deregister_entity(agent_id).await {
+                Ok(true) => report.tasks_joined += 1,
+                Ok(false) => {}
+                Err(e) => {
+                    warn!("Failed to deregister agent {}: {}", agent.name, e);
+                    report.errors.push((agent_id, format!("Failed to deregister agent {}: {}", agent.name, e)));
+                }
+            }
+            
+            match self.physics.release_energy_from_entity(agent_id).await {
+                Ok(reclaimed) => {
+                    report.energy_reclaimed += reclaimed.0;
+                    debug!("Agent {} is now dormant ({} energy reclaimed)", agent.name, reclaimed);
+                }
+                Err(e) => {
+                    warn!("Failed to reclaim energy from agent {}: {}", agent.name, e);
+                    report.errors.push((agent_id, format!("Failed to reclaim energy from agent {}: {}", agent.name, e)));
+                }
+            }
+            report.resources_released += self.physics.release_resources_from_entity(agent_id).await;
+            report.agents_stopped += 1;
+        }
+        
+        if let Some(entity) = self.effector_entity.take() {
+            if let Err(e) = self.nervous_system.deregister_entity(entity).await {
+                warn!("Failed to deregister the effector entity: {}", e);
+                report.errors.push((entity, format!("Failed to deregister the effector entity: {}", e)));
+            }
+            let reclaimed = self.physics.release_energy_from_entity(entity).await
+                .context("Failed to reclaim energy from the effector entity")?;
+            report.energy_reclaimed += reclaimed.0;
+        }
+        
+        self.physics.shutdown().await?;
+        self.shut_down = true;
+        
+        info!(
+            "Execution engine shutdown complete: {} agents stopped, {:.3} energy reclaimed, {} tasks joined, {} resources released, {} errors",
+            report.agents_stopped, report.energy_reclaimed, report.tasks_joined, report.resources_released, report.errors.len()
+        );
+        
+        Ok(report)
+    }
+    
+    /// Get physics engine for debugging
+    pub fn get_physics_engine(&self) -> &Arc<dyn Physics> {
+        &self.physics
+    }
+    
+    /// Get nervous system for debugging
+    pub fn get_nervous_system(&self) -> &NervousSystem {
+        &self.nervous_system
+    }
+    
+    /// Get memory substrate for debugging
+    pub fn get_memory_substrate(&self) -> &MemorySubstrate {
+        &self.memory
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use emergence_nervous_system::NeuralSignal;
+    use emergence_physics::{Clock, Resource};
+    use std::collections::BTreeMap;
+    
+    pub(crate) const TEST_ESSENCE_YAML: &str = r#"
+identity:
+  essence_id: "test-alpha"
+  name: "Test Entity Alpha"
+  archetype: "tester"
+  embodied: 2025-01-10T00:00:00Z
+
+personality:
+  curiosity: 0.8
+  persistence: 0.7
+  collaboration: 0.6
+  skepticism: 0.5
+  creativity: 0.7
+  patience: 0.6
+
+core_drives:
+  primary: "test_patterns"
+  secondary: "validate_systems"
+  tertiary: "report_findings"
+
+energy_profile:
+  base_energy: 0.7
+  energy_sources: []
+  energy_drains: []
+
+capabilities:
+  innate:
+    - observe
+    - analyze
+  learned:
+    test_analysis: 0.8
+    pattern_recognition: 0.9
+  emergent: []
+
+memory_configuration:
+  working_memory:
+    capacity_mb: 256
+    retention: "30_minutes"
+    priority: "active_tests"
+  long_term_memory:
+    capacity_mb: 1024
+    retention: "permanent"
+    organization: "semantic_clusters"
+  associative_memory:
+    max_connections: 5000
+    association_threshold: 0.6
+    decay_rate: 0.001
+
+behavioral_patterns:
+  - name: "test_mode"
+    trigger:
+      conditions: ["test_required"]
+    behavior_sequence:
+      - observe
+      - analyze
+      - report
+    emergence_potential: 0.8
+
+learning_mechanics:
+  experience_integration:
+    method: "reflective_consolidation"
+    frequency: "after_each_test"
+    energy_cost: 0.1
+  knowledge_expansion: []
+  teaching_capability:
+    knowledge_transfer_rate: 0.8
+    explanation_quality: 0.7
+    patience_with_learners: 0.9
+
+communication_style:
+  tone: "precise_and_clear"
+  detail_level: "comprehensive"
+  question_frequency: "moderate"
+  response_patterns: {}
+
+evolution_potential:
+  capability_growth_areas: []
+  personality_plasticity: {}
+
+constraints:
+  ethical_boundaries:
+    - "never_fabricate_results"
+  operational_limits:
+    - "max_concurrent_tests: 3"
+"#;
+    
+    /// Insert an agent built from the test essence directly into the engine
+    async fn insert_test_agent(engine: &mut ExecutionEngine, patterns: Vec<BehavioralPattern>) -> EntityId {
+        let mut schema: AgentEssenceSchema = serde_yaml::from_str(TEST_ESSENCE_YAML).unwrap();
+        schema.behavioral_patterns = patterns;
+        
+        let agent_id = EntityId::new();
+        engine.physics.allocate_energy_to_entity(agent_id, ordered_float::OrderedFloat(0.2))
+            .await
+            .unwrap();
+        
+        let agent = LivingAgent {
+            id: agent_id,
+            name: format!("test-agent-{}", agent_id.0.simple()),
+            essence_type: schema.identity.archetype.clone(),
+            personality: schema.personality.clone(),
+            energy: 0.2,
+            state: AgentState::Alert,
+            awakened_at: Some(Utc::now()),
+            essence_schema: schema.clone(),
+            capabilities: schema.capabilities.learned.clone(),
+            behavioral_patterns: schema.behavioral_patterns.clone(),
+        };
+        engine.active_agents.insert(agent_id, agent);
+        agent_id
+    }
+    
+    #[tokio::test]
+    async fn test_execution_engine_creation() {
+        let engine = ExecutionEngine::new().await.unwrap();
+        let stats = engine.get_system_stats().await.unwrap();
+        
+        assert_eq!(stats.active_agents, 0);
+        assert!(stats.session_uptime.as_millis() < 100);
+        println!("Execution engine created successfully");
+    }
+    
+    #[tokio::test]
+    async fn test_agent_creation_and_registration() {
+        let mut engine = ExecutionEngine::new().await.unwrap();
+        
+        // Create a test agent manually
+        let agent_id = EntityId::new();
+        let agent_name = format!("test-agent-{}", agent_id.0.simple());
+        
+        // Allocate energy to the agent
+        engine.physics.allocate_energy_to_entity(agent_id, ordered_float::OrderedFloat(0.7))
+            .await
+            .unwrap();
+        
+        // Create a sender entity and allocate initial energy
+        let sender_id = EntityId::new();
+        engine.physics.allocate_energy_to_entity(sender_id, ordered_float::OrderedFloat(0.2))
+            .await
+            .unwrap();
+        
+        // Create test schema
+        let schema = AgentEssenceSchema {
+            identity: EssenceIdentity {
+                essence_id: "test-alpha".to_string(),
+                name: "Test Entity Alpha".to_string(),
+                archetype: "tester".to_string(),
+                embodied: Utc::now(),
+            },
+            personality: AgentPersonality {
+                curiosity: 0.8,
+                persistence: 0.7,
+                collaboration: 0.6,
+                skepticism: 0.5,
+                creativity: 0.7,
+                patience: 0.6,
+            },
+            core_drives: CoreDrives {
+                primary: "test_patterns".to_string(),
+                secondary: "validate_systems".to_string(),
+                tertiary: "report_findings".to_string(),
+            },
+            energy_profile: EnergyProfile {
+                base_energy: 0.7,
+                energy_sources: vec![],
+                energy_drains: vec![],
+            },
+            capabilities: EssenceCapabilities {
+                innate: vec!["observe".to_string(), "analyze".to_string()],
+                learned: HashMap::from([
+                    ("test_analysis".to_string(), 0.8),
+                    ("pattern_recognition".to_string(), 0.9),
+                ]),
+                emergent: vec![],
+            },
+            memory_configuration: MemoryConfiguration {
+                working_memory: MemorySpec {
+                    capacity_mb: 256,
+                    retention: "30_minutes".to_string(),
+                    priority: Some("active_tests".to_string()),
+                },
+                long_term_memory: MemorySpec {
+                    capacity_mb: 1024,
+                    retention: "permanent".to_string(),
                     priority: None,
                 },
                 associative_memory: AssociativeMemorySpec {
@@ -481,141 +1780,1199 @@ mod tests {
                     decay_rate: 0.001,
                 },
             },
-            behavioral_patterns: vec![
-                BehavioralPattern {
-                    name: "test_mode".to_string(),
-                    trigger: PatternTrigger {
-                        conditions: vec!["test_required".to_string()],
-                    },
-                    behavior_sequence: vec!["observe".to_string(), "analyze".to_string(), "report".to_string()],
-                    emergence_potential: 0.8,
-                },
-            ],
-            learning_mechanics: LearningMechanics {
-                experience_integration: ExperienceIntegration {
-                    method: "reflective_consolidation".to_string(),
-                    frequency: "after_each_test".to_string(),
-                    energy_cost: 0.1,
-                },
-                knowledge_expansion: vec![],
-                teaching_capability: TeachingCapability {
-                    knowledge_transfer_rate: 0.8,
-                    explanation_quality: 0.7,
-                    patience_with_learners: 0.9,
+            behavioral_patterns: vec![
+                BehavioralPattern {
+                    name: "test_mode".to_string(),
+                    trigger: PatternTrigger {
+                        conditions: vec!["test_required".to_string()],
+                    },
+                    behavior_sequence: vec!["observe".to_string(), "analyze".to_string(), "report".to_string()],
+                    emergence_potential: 0.8,
+                },
+            ],
+            learning_mechanics: LearningMechanics {
+                experience_integration: ExperienceIntegration {
+                    method: "reflective_consolidation".to_string(),
+                    frequency: "after_each_test".to_string(),
+                    energy_cost: 0.1,
+                },
+                knowledge_expansion: vec![],
+                teaching_capability: TeachingCapability {
+                    knowledge_transfer_rate: 0.8,
+                    explanation_quality: 0.7,
+                    patience_with_learners: 0.9,
+                },
+            },
+            communication_style: CommunicationStyle {
+                tone: "precise_and_clear".to_string(),
+                detail_level: "comprehensive".to_string(),
+                question_frequency: "moderate".to_string(),
+                response_patterns: HashMap::new(),
+            },
+            evolution_potential: EvolutionPotential {
+                capability_growth_areas: vec![],
+                personality_plasticity: HashMap::new(),
+            },
+            constraints: AgentConstraints {
+                ethical_boundaries: vec!["never_fabricate_results".to_string()],
+                operational_limits: vec!["max_concurrent_tests: 3".to_string()],
+            },
+        };
+        
+        // Create agent instance
+        let agent = LivingAgent {
+            id: agent_id,
+            name: agent_name.clone(),
+            essence_type: schema.identity.archetype.clone(),
+            personality: schema.personality.clone(),
+            energy: schema.energy_profile.base_energy,
+            state: AgentState::Awakening,
+            awakened_at: Some(Utc::now()),
+            essence_schema: schema.clone(),
+            capabilities: schema.capabilities.learned.clone(),
+            behavioral_patterns: schema.behavioral_patterns.clone(),
+        };
+        
+        // Register agent with nervous system
+        let capabilities = HashSet::from([
+            SignalType::Sensory,
+            SignalType::Cognitive,
+            SignalType::Coordination,
+            SignalType::Memory,
+            SignalType::Emotional,
+        ]);
+        
+        let processor = Arc::new(AgentProcessor {
+            agent: agent.clone(),
+            essence_schema: schema,
+            memory: engine.memory.clone(),
+            emotions: engine.emotions.clone(),
+            inferences: engine.inferences.clone(),
+            intents: IntentModel::new(),
+            capabilities: Default::default(),
+            personality: SharedPersonality::new(std::sync::RwLock::new(agent.personality.clone())),
+            model: None,
+        });
+        
+        engine.nervous_system.register_async_entity(agent_id, capabilities, processor).await.unwrap();
+        
+        // Store agent
+        engine.active_agents.insert(agent_id, agent);
+        
+        // Verify agent was created
+        let agent = engine.get_agent(agent_id).unwrap();
+        assert_eq!(agent.essence_type, "tester");
+        assert_eq!(agent.personality.curiosity, 0.8);
+        assert_eq!(agent.personality.persistence, 0.7);
+        assert_eq!(agent.energy, 0.7);
+        
+        // Verify agent is registered with nervous system
+        let stats = engine.get_system_stats().await.unwrap();
+        assert_eq!(stats.active_agents, 1);
+        
+        // Test sending a signal to the agent
+        let signal = NeuralSignal::new(
+            SignalType::Sensory,
+            sender_id, // system entity with energy
+            Some(agent_id),
+            SignalPayload::Message("I observe interesting patterns in the test data".to_string()),
+            0.1,
+        );
+        engine.send_signal_to_agent(agent_id, signal).await.unwrap();
+        
+        println!("Test agent created and registered successfully: {}", agent.name);
+    }
+    
+    #[tokio::test]
+    async fn test_agent_communication() {
+        let mut engine = ExecutionEngine::new().await.unwrap();
+        
+        // Create a test agent
+        let agent_id = EntityId::new();
+        let agent_name = format!("test-agent-{}", agent_id.0.simple());
+        
+        // Allocate energy
+        engine.physics.allocate_energy_to_entity(agent_id, ordered_float::OrderedFloat(0.2))
+            .await
+            .unwrap();
+        
+        // Create a sender entity and allocate initial energy
+        let sender_id = EntityId::new();
+        engine.physics.allocate_energy_to_entity(sender_id, ordered_float::OrderedFloat(0.8))
+            .await
+            .unwrap();
+        
+        // Send different types of signals and verify responses
+        let test_signals = vec![
+            (SignalType::Sensory, "I see patterns in the data"),
+            (SignalType::Cognitive, "Please analyze this problem"),
+            (SignalType::Coordination, "Let's collaborate on this"),
+            (SignalType::Memory, "What do you remember about this?"),
+        ];
+        
+        for (signal_type, message) in test_signals {
+            let signal = NeuralSignal::new(
+                signal_type,
+                sender_id,
+                Some(agent_id),
+                SignalPayload::Message(message.to_string()),
+                0.1,
+            );
+            
+            engine.send_signal_to_agent(agent_id, signal).await.unwrap();
+        }
+        
+        println!("Agent communication test completed successfully");
+    }
+    
+    #[tokio::test]
+    async fn test_memory_query_recalls_stored_value() {
+        let mut engine = ExecutionEngine::new().await.unwrap();
+        let agent_id = insert_test_agent(&mut engine, Vec::new()).await;
+        let agent = engine.get_agent(agent_id).unwrap().clone();
+        
+        engine.memory.store(agent_id, "last_anomaly".to_string(), SignalPayload::Message("cpu spike".to_string()))
+            .await
+            .unwrap();
+        
+        let processor = AgentProcessor {
+            essence_schema: agent.essence_schema.clone(),
+            personality: SharedPersonality::new(std::sync::RwLock::new(agent.personality.clone())),
+            agent,
+            memory: engine.memory.clone(),
+            emotions: engine.emotions.clone(),
+            inferences: engine.inferences.clone(),
+            intents: IntentModel::new(),
+            capabilities: Default::default(),
+            model: None,
+        };
+        let query = |key: &str| NeuralSignal::new(
+            SignalType::Memory,
+            EntityId::new(),
+            Some(agent_id),
+            SignalPayload::Query(key.to_string()),
+            0.5,
+        );
+        
+        let recalled = processor.process_signal(&query("last_anomaly")).await.unwrap().unwrap();
+        assert!(matches!(recalled.payload, SignalPayload::Message(ref text) if text == "cpu spike"));
+        
+        let missing = processor.process_signal(&query("unknown")).await.unwrap().unwrap();
+        assert!(matches!(missing.payload, SignalPayload::Message(ref text) if text.contains("no memory")));
+    }
+    
+    #[tokio::test]
+    async fn test_cognitive_request_uses_classified_intent() {
+        let mut engine = ExecutionEngine::new().await.unwrap();
+        let agent_id = insert_test_agent(&mut engine, Vec::new()).await;
+        let agent = engine.get_agent(agent_id).unwrap().clone();
+        let processor = AgentProcessor {
+            essence_schema: agent.essence_schema.clone(),
+            personality: SharedPersonality::new(std::sync::RwLock::new(agent.personality.clone())),
+            agent,
+            memory: engine.memory.clone(),
+            emotions: engine.emotions.clone(),
+            inferences: engine.inferences.clone(),
+            intents: IntentModel::new(),
+            capabilities: Default::default(),
+            model: None,
+        };
+        let request = |text: &str| NeuralSignal::new(
+            SignalType::Cognitive,
+            EntityId::new(),
+            Some(agent_id),
+            SignalPayload::Message(text.to_string()),
+            0.5,
+        );
+        let reply = |text: &str| {
+            let (processor, signal) = (&processor, request(text));
+            async move {
+                match processor.process_signal(&signal).await.unwrap().unwrap().payload {
+                    SignalPayload::Message(reply) => reply,
+                    other => panic!("unexpected payload {:?}", other),
+                }
+            }
+        };
+        
+        assert_eq!(reply("Analysis of the build failures").await, "I'll begin a systematic exploration of build failures.");
+        assert_eq!(reply("Please investigate").await, "I'll begin a systematic exploration of the relevant domains.");
+        assert!(reply("Summarize the build failures").await.contains("deep cognitive processing"));
+    }
+    
+    /// Model that echoes its input in upper case at a fixed cost
+    #[derive(Clone)]
+    struct ShoutingModel {
+        cost: f64,
+    }
+    
+    #[async_trait::async_trait]
+    impl ComposableModel for ShoutingModel {
+        async fn process(&self, input: &str, context: &ModelContext) -> Result<emergence_models::ModelOutput, emergence_models::ModelError> {
+            assert_eq!(context.personality.curiosity, 0.8);
+            Ok(emergence_models::ModelOutput {
+                content: input.to_uppercase(),
+                confidence: 1.0,
+                energy_cost: self.cost,
+                capabilities_used: Vec::new(),
+                cache_hit: false,
+            })
+        }
+        fn energy_cost(&self) -> f64 { self.cost }
+        fn memory_requirement(&self) -> usize { 0 }
+        fn capabilities(&self) -> Vec<emergence_models::Capability> { Vec::new() }
+        fn name(&self) -> &str { "shouting" }
+        fn is_ready(&self) -> bool { true }
+        fn clone_box(&self) -> Box<dyn ComposableModel> { Box::new(self.clone()) }
+    }
+    
+    #[tokio::test]
+    async fn test_model_answers_messages_and_heuristics_cover_its_absence() {
+        let mut engine = ExecutionEngine::new().await.unwrap();
+        let agent_id = insert_test_agent(&mut engine, Vec::new()).await;
+        let agent = engine.get_agent(agent_id).unwrap().clone();
+        let processor = |model: Option<Box<dyn ComposableModel>>| AgentProcessor {
+            essence_schema: agent.essence_schema.clone(),
+            personality: SharedPersonality::new(std::sync::RwLock::new(agent.personality.clone())),
+            agent: agent.clone(),
+            memory: engine.memory.clone(),
+            emotions: engine.emotions.clone(),
+            inferences: engine.inferences.clone(),
+            intents: IntentModel::new(),
+            capabilities: Default::default(),
+            model,
+        };
+        async fn reply(processor: &AgentProcessor, signal_type: SignalType) -> String {
+            let signal = NeuralSignal::new(
+                signal_type,
+                EntityId::new(),
+                Some(processor.agent.id),
+                SignalPayload::Message("observe the pattern".to_string()),
+                0.5,
+            );
+            match processor.process_signal(&signal).await.unwrap().unwrap().payload {
+                SignalPayload::Message(reply) => reply,
+                other => panic!("unexpected payload {:?}", other),
+            }
+        }
+        
+        let modelled = processor(Some(Box::new(ShoutingModel { cost: 0.01 })));
+        assert_eq!(reply(&modelled, SignalType::Sensory).await, "OBSERVE THE PATTERN");
+        let signal = NeuralSignal::new(SignalType::Sensory, EntityId::new(), Some(agent_id), SignalPayload::Message("hi".to_string()), 0.5);
+        let response = modelled.process_signal(&signal).await.unwrap().unwrap();
+        assert!((response.energy_cost - modelled.response_cost() - 0.01).abs() < 1e-12);
+        assert_eq!(reply(&modelled, SignalType::Cognitive).await, "OBSERVE THE PATTERN");
+        assert!(reply(&modelled, SignalType::Coordination).await.contains("coordinating"));
+        
+        let heuristic = processor(None);
+        assert!(reply(&heuristic, SignalType::Sensory).await.contains("patterns"));
+        assert!(reply(&heuristic, SignalType::Cognitive).await.contains("deep cognitive processing"));
+        
+        let over_budget = processor(Some(Box::new(ShoutingModel { cost: 5.0 })));
+        assert_eq!(reply(&over_budget, SignalType::Sensory).await, reply(&heuristic, SignalType::Sensory).await);
+    }
+    
+    /// Model whose inference never finishes on its own
+    #[derive(Clone)]
+    struct StuckModel;
+    
+    #[async_trait::async_trait]
+    impl ComposableModel for StuckModel {
+        async fn process(&self, _input: &str, _context: &ModelContext) -> Result<emergence_models::ModelOutput, emergence_models::ModelError> {
+            std::future::pending().await
+        }
+        fn energy_cost(&self) -> f64 { 0.01 }
+        fn memory_requirement(&self) -> usize { 0 }
+        fn capabilities(&self) -> Vec<emergence_models::Capability> { Vec::new() }
+        fn name(&self) -> &str { "stuck" }
+        fn is_ready(&self) -> bool { true }
+        fn clone_box(&self) -> Box<dyn ComposableModel> { Box::new(self.clone()) }
+    }
+    
+    #[tokio::test]
+    async fn test_newer_message_cancels_stale_inference() {
+        use futures::StreamExt;
+        
+        let mut engine = ExecutionEngine::new().await.unwrap();
+        let agent_id = insert_test_agent(&mut engine, Vec::new()).await;
+        let agent = engine.get_agent(agent_id).unwrap().clone();
+        let processor = Arc::new(AgentProcessor {
+            essence_schema: agent.essence_schema.clone(),
+            personality: SharedPersonality::new(std::sync::RwLock::new(agent.personality.clone())),
+            agent,
+            memory: engine.memory.clone(),
+            emotions: engine.emotions.clone(),
+            inferences: engine.inferences.clone(),
+            intents: IntentModel::new(),
+            capabilities: Default::default(),
+            model: Some(Box::new(StuckModel)),
+        });
+        let capabilities = HashSet::from([SignalType::Cognitive]);
+        engine.nervous_system.deregister_entity(agent_id).await.unwrap();
+        engine.nervous_system.register_async_entity(agent_id, capabilities, processor).await.unwrap();
+        let sender = EntityId::new();
+        engine.physics.allocate_energy_to_entity(sender, ordered_float::OrderedFloat(0.1)).await.unwrap();
+        let replies = engine.nervous_system.create_signal_stream(sender, vec![SignalType::Coordination]).await.unwrap();
+        tokio::pin!(replies);
+        let message = |text: &str| NeuralSignal::new(
+            SignalType::Cognitive,
+            sender,
+            Some(agent_id),
+            SignalPayload::Message(text.to_string()),
+            0.5,
+        );
+        async fn processed(nervous_system: &NervousSystem, entity_id: EntityId) -> u64 {
+            nervous_system.list_entities().await.into_iter()
+                .find(|entity| entity.entity_id == entity_id)
+                .map_or(0, |entity| entity.stats.signals_processed)
+        }
+        
+        engine.nervous_system.transmit_signal(message("analyze the logs")).await.unwrap();
+        while engine.inferences.in_flight() == 0 {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        
+        // A message with another intent leaves the inference running
+        assert!(!engine.inferences.cancel_intent(agent_id, &IntentModel::new().recognize("hello there").label));
+        assert_eq!(engine.inferences.in_flight(), 1);
+        
+        // The newer message is still queued behind the stale inference when it cancels it
+        engine.nervous_system.transmit_signal(message("analyze the metrics")).await.unwrap();
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while processed(&engine.nervous_system, agent_id).await == 0 {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("cancelled inference should return promptly");
+        
+        // The superseded inference never answers; the newer one is now running
+        assert!(tokio::time::timeout(Duration::from_millis(50), replies.next()).await.is_err());
+        assert_eq!(engine.inferences.in_flight(), 1);
+    }
+    
+    #[tokio::test]
+    async fn test_acknowledgments_are_not_answered() {
+        let mut engine = ExecutionEngine::new().await.unwrap();
+        let agent_id = insert_test_agent(&mut engine, Vec::new()).await;
+        let agent = engine.get_agent(agent_id).unwrap().clone();
+        let processor = AgentProcessor {
+            essence_schema: agent.essence_schema.clone(),
+            personality: SharedPersonality::new(std::sync::RwLock::new(agent.personality.clone())),
+            agent,
+            memory: engine.memory.clone(),
+            emotions: engine.emotions.clone(),
+            inferences: engine.inferences.clone(),
+            intents: IntentModel::new(),
+            capabilities: Default::default(),
+            model: None,
+        };
+        let signal = |payload| NeuralSignal::new(SignalType::Coordination, EntityId::new(), Some(agent_id), payload, 0.5);
+        
+        let ack = signal(SignalPayload::Response(YamlValue::from("received")));
+        assert!(processor.process_signal(&ack).await.unwrap().is_none());
+        let request = signal(SignalPayload::Message("let's collaborate".to_string()));
+        assert!(processor.process_signal(&request).await.unwrap().is_some());
+    }
+    
+    #[tokio::test]
+    async fn test_mood_follows_emotional_signals_and_shortens_replies() {
+        let mut engine = ExecutionEngine::new().await.unwrap();
+        let agent_id = insert_test_agent(&mut engine, Vec::new()).await;
+        let agent = engine.get_agent(agent_id).unwrap().clone();
+        let processor = AgentProcessor {
+            essence_schema: agent.essence_schema.clone(),
+            personality: SharedPersonality::new(std::sync::RwLock::new(agent.personality.clone())),
+            agent,
+            memory: engine.memory.clone(),
+            emotions: engine.emotions.clone(),
+            inferences: engine.inferences.clone(),
+            intents: IntentModel::new(),
+            capabilities: Default::default(),
+            model: None,
+        };
+        let emotional = |valence: &str, strength| NeuralSignal::new(
+            SignalType::Emotional,
+            EntityId::new(),
+            Some(agent_id),
+            SignalPayload::Message(valence.to_string()),
+            strength,
+        );
+        assert_eq!(engine.agent_mood(agent_id), Some(Mood::default()));
+        assert_eq!(engine.agent_mood(EntityId::new()), None);
+        
+        let reply = processor.process_signal(&emotional("0.9", 0.8)).await.unwrap().unwrap();
+        assert!(engine.agent_mood(agent_id).unwrap().valence > 0.0);
+        assert!(matches!(reply.payload, SignalPayload::Message(text) if text == "That lifts my spirits; I feel energized."));
+        
+        for _ in 0..3 {
+            processor.process_signal(&emotional("-1.0", 0.9)).await.unwrap();
+        }
+        let mood = engine.agent_mood(agent_id).unwrap();
+        assert!(mood.valence < -0.3 && mood.arousal > 0.5);
+        let reply = processor.process_signal(&emotional("-1.0", 0.9)).await.unwrap().unwrap();
+        assert!(matches!(reply.payload, SignalPayload::Message(text) if text == "This weighs on me."));
+    }
+    
+    #[tokio::test]
+    async fn test_negative_emotion_raises_signal_costs() {
+        let mut engine = ExecutionEngine::new().await.unwrap();
+        let agent_id = insert_test_agent(&mut engine, Vec::new()).await;
+        let agent = engine.get_agent(agent_id).unwrap().clone();
+        let processor = AgentProcessor {
+            essence_schema: agent.essence_schema.clone(),
+            personality: SharedPersonality::new(std::sync::RwLock::new(agent.personality.clone())),
+            agent,
+            memory: engine.memory.clone(),
+            emotions: engine.emotions.clone(),
+            inferences: engine.inferences.clone(),
+            intents: IntentModel::new(),
+            capabilities: Default::default(),
+            model: None,
+        };
+        let ping = NeuralSignal::new(
+            SignalType::Sensory,
+            EntityId::new(),
+            Some(agent_id),
+            SignalPayload::Message("ping".to_string()),
+            0.5,
+        );
+        let baseline = processor.process_signal(&ping).await.unwrap().unwrap().energy_cost;
+        
+        let distress = NeuralSignal::new(
+            SignalType::Emotional,
+            EntityId::new(),
+            Some(agent_id),
+            SignalPayload::Message("-0.8".to_string()),
+            0.9,
+        );
+        processor.process_signal(&distress).await.unwrap();
+        
+        assert!(engine.energy_cost_multiplier(agent_id) > 1.0);
+        assert!(processor.process_signal(&ping).await.unwrap().unwrap().energy_cost > baseline);
+        
+        engine.apply_emotional_modulation(agent_id, 1.0);
+        engine.apply_emotional_modulation(agent_id, 1.0);
+        assert!(engine.energy_cost_multiplier(agent_id) < 1.0);
+        assert!(processor.process_signal(&ping).await.unwrap().unwrap().energy_cost < baseline);
+    }
+    
+    #[tokio::test]
+    async fn test_modulation_follows_the_paying_agent() {
+        let engine = ExecutionEngine::new().await.unwrap();
+        let (upset, calm) = (EntityId::new(), EntityId::new());
+        engine.apply_emotional_modulation(upset, -1.0);
+        let signal = || NeuralSignal::new(SignalType::Sensory, calm, Some(upset), SignalPayload::Message("hi".to_string()), 0.5)
+            .with_energy_cost(BASE_SIGNAL_ENERGY_COST);
+        
+        assert_eq!(engine.modulate_energy_cost(calm, signal()).energy_cost, BASE_SIGNAL_ENERGY_COST);
+        assert!(engine.modulate_energy_cost(upset, signal()).energy_cost > BASE_SIGNAL_ENERGY_COST);
+    }
+    
+    #[tokio::test]
+    async fn test_motor_command_runs_effector() {
+        let mut engine = ExecutionEngine::new().await.unwrap();
+        let agent_id = insert_test_agent(&mut engine, Vec::new()).await;
+        let engine_id = engine.register_effector("open_valve", |payload: &SignalPayload| {
+            let command = format!("{:?}", payload);
+            async move { Ok(SignalPayload::Message(format!("opened via {}", command))) }
+        }).await.unwrap();
+        
+        let command = NeuralSignal::new(
+            SignalType::Motor,
+            agent_id,
+            Some(engine_id),
+            SignalPayload::Command("open_valve 3".to_string()),
+            0.6,
+        );
+        let response = engine.dispatch_motor_signal(&command).await.unwrap();
+        
+        assert_eq!(response.target, SignalTarget::One(agent_id));
+        assert_eq!(response.causal_dependencies, vec![command.signal_id]);
+        let SignalPayload::Response(body) = &response.payload else {
+            panic!("expected a Response payload");
+        };
+        assert_eq!(body["action"], YamlValue::from("open_valve"));
+        assert_eq!(body["status"], YamlValue::from("ok"));
+        assert!(body["result"].as_str().unwrap().contains("open_valve 3"));
+    }
+    
+    #[tokio::test]
+    async fn test_motor_signals_over_the_nervous_system_run_effectors() {
+        use emergence_nervous_system::SignalStreamEvent;
+        use futures::StreamExt;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        
+        let mut engine = ExecutionEngine::new().await.unwrap();
+        let agent_id = insert_test_agent(&mut engine, Vec::new()).await;
+        let runs = Arc::new(AtomicUsize::new(0));
+        let counter = runs.clone();
+        let engine_id = engine.register_effector("open_valve", move |_: &SignalPayload| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async { Ok(SignalPayload::Message("opened".to_string())) }
+        }).await.unwrap();
+        let replies = engine.nervous_system.create_signal_stream(agent_id, vec![SignalType::Coordination]).await.unwrap();
+        tokio::pin!(replies);
+        
+        let command = NeuralSignal::new(
+            SignalType::Motor,
+            agent_id,
+            Some(engine_id),
+            SignalPayload::Command("open_valve 3".to_string()),
+            0.6,
+        );
+        engine.nervous_system.transmit_signal(command.clone()).await.unwrap();
+        let reply = loop {
+            match tokio::time::timeout(Duration::from_secs(1), replies.next()).await {
+                Ok(Some(SignalStreamEvent::Signal(reply))) if reply.source == engine_id => break reply,
+                Ok(Some(_)) => continue,
+                _ => panic!("no effector response"),
+            }
+        };
+        
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+        assert_eq!(reply.target, SignalTarget::One(agent_id));
+        assert_eq!(reply.causal_dependencies, vec![command.signal_id]);
+        assert!(reply.energy_cost > 0.0);
+        let SignalPayload::Response(body) = &reply.payload else {
+            panic!("expected a Response payload");
+        };
+        assert_eq!(body["status"], YamlValue::from("ok"));
+    }
+    
+    #[tokio::test]
+    async fn test_conflicting_motor_commands_are_arbitrated() {
+        let mut engine = ExecutionEngine::new().await.unwrap();
+        let hasty = insert_test_agent(&mut engine, Vec::new()).await;
+        let stubborn = insert_test_agent(&mut engine, Vec::new()).await;
+        engine.active_agents.get_mut(&hasty).unwrap().personality.persistence = 0.2;
+        engine.active_agents.get_mut(&stubborn).unwrap().personality.persistence = 0.9;
+        let executed = Arc::new(Mutex::new(Vec::new()));
+        let log = executed.clone();
+        let engine_id = engine.register_effector("set_valve", move |payload: &SignalPayload| {
+            log.lock().unwrap().push(format!("{:?}", payload));
+            async { Ok(SignalPayload::Message("done".to_string())) }
+        }).await.unwrap();
+        let command = |source: EntityId, text: &str, strength: f64| NeuralSignal::new(
+            SignalType::Motor,
+            source,
+            Some(engine_id),
+            SignalPayload::Command(text.to_string()),
+            strength,
+        );
+        let commands = [command(hasty, "set_valve open", 0.9), command(stubborn, "set_valve closed", 0.3)];
+        
+        let replies = engine.dispatch_motor_signals(&commands).await.unwrap();
+        let strongest = executed.lock().unwrap().clone();
+        assert_eq!(strongest.len(), 1);
+        assert!(strongest[0].contains("open"));
+        assert_eq!(replies.len(), 2);
+        
+        engine.conflict_resolver = conflict::ConflictResolver::new(conflict::ResolutionPolicy::Persistence);
+        executed.lock().unwrap().clear();
+        let replies = engine.dispatch_motor_signals(&commands).await.unwrap();
+        
+        assert_eq!(executed.lock().unwrap().len(), 1);
+        assert!(executed.lock().unwrap()[0].contains("closed"));
+        let notice = replies.iter().find(|reply| reply.target == SignalTarget::One(hasty)).unwrap();
+        assert_eq!(notice.causal_dependencies, vec![commands[0].signal_id]);
+        assert_eq!(notice.energy_cost, replies[0].energy_cost);
+        assert!(notice.energy_cost > 0.0);
+        let SignalPayload::Response(body) = &notice.payload else {
+            panic!("expected a Response payload");
+        };
+        assert_eq!(body["status"], YamlValue::from("superseded"));
+        assert_eq!(body["superseded_by"], YamlValue::from(commands[1].signal_id.to_string()));
+        assert_eq!(body["policy"], YamlValue::from("persistence"));
+    }
+    
+    #[tokio::test]
+    async fn test_unknown_motor_action_returns_error_response() {
+        let mut engine = ExecutionEngine::new().await.unwrap();
+        let agent_id = insert_test_agent(&mut engine, Vec::new()).await;
+        let engine_id = engine.register_effector("open_valve", |_: &SignalPayload| async {
+            Ok(SignalPayload::Message("opened".to_string()))
+        }).await.unwrap();
+        
+        let command = NeuralSignal::new(
+            SignalType::Motor,
+            agent_id,
+            Some(engine_id),
+            SignalPayload::Command("launch_rocket now".to_string()),
+            0.6,
+        );
+        let response = engine.dispatch_motor_signal(&command).await.unwrap();
+        
+        let SignalPayload::Response(body) = &response.payload else {
+            panic!("expected a Response payload");
+        };
+        assert_eq!(body["status"], YamlValue::from("error"));
+        assert_eq!(body["error_kind"], YamlValue::from("unknown_action"));
+        
+        let misdirected = NeuralSignal::broadcast(SignalType::Motor, agent_id, SignalPayload::Command("open_valve".to_string()), 0.6);
+        assert!(engine.dispatch_motor_signal(&misdirected).await.is_err());
+    }
+    
+    #[tokio::test]
+    async fn test_teaching_raises_learner_capability() {
+        let mut engine = ExecutionEngine::new().await.unwrap();
+        let teacher = insert_test_agent(&mut engine, Vec::new()).await;
+        let learner = insert_test_agent(&mut engine, Vec::new()).await;
+        engine.active_agents.get_mut(&learner).unwrap().capabilities.remove("test_analysis");
+        let recording = engine.nervous_system.start_recording();
+        
+        let strength = engine.teach(teacher, learner, "test_analysis").await.unwrap();
+        
+        // 0.8 * (transfer 0.8 * explanation 0.7 * learner patience 0.6)
+        assert!((strength - 0.8 * 0.336).abs() < 1e-9);
+        assert_eq!(engine.active_agents[&learner].capabilities["test_analysis"], strength);
+        // The lesson and acknowledgement cost the same, so they cancel out
+        for agent in [teacher, learner] {
+            assert!((engine.physics.entity_energy(agent).await.0 - 0.15).abs() < 1e-9);
+        }
+        let energy = engine.physics.get_engine_state().await.unwrap().energy_state;
+        assert!(energy.dissipated_energy.0 >= 2.0 * TEACHING_ENERGY_COST - 1e-9);
+        
+        let log = engine.nervous_system.stop_recording(recording).await.unwrap();
+        let exchange: Vec<_> = log.signals.iter()
+            .filter(|recorded| recorded.signal.signal_type == SignalType::Coordination)
+            .map(|recorded| (recorded.signal.source, recorded.signal.target.clone()))
+            .collect();
+        assert_eq!(exchange, vec![(teacher, SignalTarget::One(learner)), (learner, SignalTarget::One(teacher))]);
+    }
+    
+    #[tokio::test]
+    async fn test_negotiation_finds_shared_and_complementary_capabilities() {
+        let mut engine = ExecutionEngine::new().await.unwrap();
+        let mut awaken = async |learned: HashMap<String, f64>| {
+            let mut schema: AgentEssenceSchema = serde_yaml::from_str(TEST_ESSENCE_YAML).unwrap();
+            schema.capabilities.learned = learned;
+            schema.energy_profile.base_energy = 0.2;
+            engine.awaken_schema(schema).await.unwrap()
+        };
+        let a = awaken(HashMap::from([
+            ("log_analysis".to_string(), 0.9),
+            ("test_analysis".to_string(), 0.8),
+        ])).await;
+        let b = awaken(HashMap::from([
+            ("test_analysis".to_string(), 0.4),
+            ("refactoring".to_string(), 0.7),
+        ])).await;
+        let recording = engine.nervous_system.start_recording();
+        
+        let result = engine.negotiate(a, b).await.unwrap();
+        
+        assert_eq!(result.shared, BTreeMap::from([("test_analysis".to_string(), (0.8, 0.4))]));
+        assert_eq!(result.offered_by_initiator, BTreeMap::from([("log_analysis".to_string(), 0.9)]));
+        assert_eq!(result.offered_by_responder, BTreeMap::from([("refactoring".to_string(), 0.7)]));
+        assert_eq!(result.complementary(), vec!["log_analysis", "refactoring"]);
+        
+        let log = engine.nervous_system.stop_recording(recording).await.unwrap();
+        let handshake: Vec<_> = log.signals.iter()
+            .map(|recorded| &recorded.signal)
+            .filter(|signal| signal.signal_type == SignalType::Coordination)
+            .collect();
+        assert_eq!(handshake.iter().map(|signal| (signal.source, signal.target.clone())).collect::<Vec<_>>(), vec![(a, SignalTarget::One(b)), (b, SignalTarget::One(a))]);
+        match &handshake[1].payload {
+            SignalPayload::Response(reply) => assert_eq!(negotiation::announced_capabilities(reply).unwrap()["refactoring"], 0.7),
+            other => panic!("unexpected payload {:?}", other),
+        }
+        assert_eq!(handshake[1].causal_dependencies, vec![handshake[0].signal_id]);
+        assert!(handshake.iter().all(|signal| signal.energy_cost > 0.0));
+        assert!(engine.negotiate(a, EntityId::new()).await.is_err());
+    }
+    
+    #[tokio::test]
+    async fn test_reasoning_steps_are_broadcast_in_order() {
+        let mut engine = ExecutionEngine::new().await.unwrap();
+        let agent_id = insert_test_agent(&mut engine, Vec::new()).await;
+        let reasoning = emergence_models::reasoning::ReasoningModel::new()
+            .reason_with_trace("Analyze the logs. Investigate the outage", &emergence_models::ModelContext::default())
+            .await
+            .unwrap();
+        let recording = engine.nervous_system.start_recording();
+        
+        let signal_ids = engine.share_reasoning(agent_id, &reasoning).await.unwrap();
+        
+        let log = engine.nervous_system.stop_recording(recording).await.unwrap();
+        let shared: Vec<&NeuralSignal> = log.signals.iter()
+            .map(|recorded| &recorded.signal)
+            .filter(|signal| signal.signal_type == SignalType::Cognitive)
+            .collect();
+        assert_eq!(shared.iter().map(|signal| signal.signal_id).collect::<Vec<_>>(), signal_ids);
+        assert_eq!(shared[1].causal_dependencies, vec![signal_ids[0]]);
+        assert!(shared.iter().all(|signal| signal.energy_cost > 0.0));
+        match &shared[0].payload {
+            SignalPayload::StateUpdate(update) => assert_eq!(update["premise"], YamlValue::from("Analyze the logs")),
+            other => panic!("unexpected payload {:?}", other),
+        }
+        assert!(engine.share_reasoning(EntityId::new(), &reasoning).await.is_err());
+    }
+    
+    #[tokio::test]
+    async fn test_teaching_requires_capability_and_energy() {
+        let mut engine = ExecutionEngine::new().await.unwrap();
+        let teacher = insert_test_agent(&mut engine, Vec::new()).await;
+        let learner = insert_test_agent(&mut engine, Vec::new()).await;
+        
+        assert!(engine.teach(teacher, learner, "juggling").await.is_err());
+        
+        engine.active_agents.get_mut(&learner).unwrap().capabilities.remove("test_analysis");
+        let drain = PhysicsOperation::TransferEnergy {
+            from: learner,
+            to: EntityId::new(),
+            amount: ordered_float::OrderedFloat(0.19),
+        };
+        engine.physics.execute_operation(drain).await.unwrap();
+        
+        assert!(engine.teach(teacher, learner, "test_analysis").await.is_err());
+        assert!(!engine.active_agents[&learner].capabilities.contains_key("test_analysis"));
+        assert!((engine.physics.entity_energy(teacher).await.0 - 0.2).abs() < 1e-9);
+    }
+    
+    #[tokio::test]
+    async fn test_practised_strong_capability_becomes_emergent() {
+        let mut engine = ExecutionEngine::new().await.unwrap();
+        let agent_id = insert_test_agent(&mut engine, Vec::new()).await;
+        // pattern_recognition is learned at 0.9, test_analysis at 0.8
+        for _ in 0..5 {
+            engine.record_capability_use(agent_id, "pattern_recognition");
+            engine.record_capability_use(agent_id, "test_analysis");
+        }
+        
+        let added = engine.consolidate_capabilities(agent_id).unwrap();
+        
+        assert_eq!(added, vec!["intuitive_pattern_recognition".to_string()]);
+        assert_eq!(engine.active_agents[&agent_id].essence_schema.capabilities.emergent, added);
+        assert!(engine.consolidate_capabilities(agent_id).unwrap().is_empty());
+    }
+    
+    #[tokio::test]
+    async fn test_unpractised_capability_is_not_consolidated() {
+        let mut engine = ExecutionEngine::new().await.unwrap();
+        let agent_id = insert_test_agent(&mut engine, Vec::new()).await;
+        engine.record_capability_use(agent_id, "pattern_recognition");
+        
+        assert!(engine.consolidate_capabilities(agent_id).unwrap().is_empty());
+        assert!(engine.active_agents[&agent_id].essence_schema.capabilities.emergent.is_empty());
+    }
+    
+    #[tokio::test]
+    async fn test_plastic_trait_adapts_and_stays_clamped() {
+        let mut engine = ExecutionEngine::new().await.unwrap();
+        let agent_id = insert_test_agent(&mut engine, Vec::new()).await;
+        engine.active_agents.get_mut(&agent_id).unwrap().essence_schema.evolution_potential.personality_plasticity =
+            HashMap::from([("collaboration".to_string(), 0.5), ("skepticism".to_string(), 0.0)]);
+        let recording = engine.nervous_system.start_recording();
+        
+        // collaboration starts at 0.6
+        let raised = engine.adapt_personality(agent_id, "collaboration", 0.2).await.unwrap();
+        assert!((raised - 0.7).abs() < 1e-9);
+        assert_eq!(engine.active_agents[&agent_id].personality.collaboration, raised);
+        
+        assert_eq!(engine.adapt_personality(agent_id, "collaboration", 5.0).await.unwrap(), 1.0);
+        assert_eq!(engine.adapt_personality(agent_id, "collaboration", -5.0).await.unwrap(), 0.0);
+        assert!(engine.adapt_personality(agent_id, "collaboration", f64::NAN).await.is_err());
+        assert_eq!(engine.active_agents[&agent_id].personality.collaboration, 0.0);
+        
+        let log = engine.nervous_system.stop_recording(recording).await.unwrap();
+        assert_eq!(log.signals.len(), 3);
+        assert!(log.signals.iter().all(|recorded| recorded.signal.energy_cost > 0.0));
+        let SignalPayload::StateUpdate(update) = &log.signals[0].signal.payload else {
+            panic!("expected a StateUpdate payload");
+        };
+        assert_eq!(update["personality_trait"], YamlValue::from("collaboration"));
+    }
+    
+    #[tokio::test]
+    async fn test_adapted_personality_reaches_the_agents_processor() {
+        let mut engine = ExecutionEngine::new().await.unwrap();
+        let mut schema: AgentEssenceSchema = serde_yaml::from_str(TEST_ESSENCE_YAML).unwrap();
+        schema.energy_profile.base_energy = 0.2;
+        schema.evolution_potential.personality_plasticity = HashMap::from([("collaboration".to_string(), 1.0)]);
+        let agent_id = engine.awaken_schema(schema).await.unwrap();
+        
+        engine.adapt_personality(agent_id, "collaboration", -1.0).await.unwrap();
+        
+        let shared = engine.agent_personalities[&agent_id].read().unwrap().clone();
+        assert_eq!(shared.collaboration, 0.0);
+        assert_eq!(shared.curiosity, engine.active_agents[&agent_id].personality.curiosity);
+    }
+    
+    #[tokio::test]
+    async fn test_trait_without_plasticity_is_fixed() {
+        let mut engine = ExecutionEngine::new().await.unwrap();
+        let agent_id = insert_test_agent(&mut engine, Vec::new()).await;
+        engine.active_agents.get_mut(&agent_id).unwrap().essence_schema.evolution_potential.personality_plasticity =
+            HashMap::from([("skepticism".to_string(), 0.0)]);
+        
+        assert_eq!(engine.adapt_personality(agent_id, "skepticism", 0.3).await.unwrap(), 0.5);
+        assert_eq!(engine.adapt_personality(agent_id, "curiosity", 0.3).await.unwrap(), 0.8);
+        assert!(engine.adapt_personality(agent_id, "stubbornness", 0.3).await.is_err());
+    }
+    
+    #[tokio::test]
+    async fn test_behavioral_pattern_trigger_activation() {
+        let mut engine = ExecutionEngine::new().await.unwrap();
+        let agent_id = insert_test_agent(&mut engine, vec![
+            BehavioralPattern {
+                name: "investigation".to_string(),
+                trigger: PatternTrigger {
+                    conditions: vec!["signal_type:Sensory".to_string(), "anomaly".to_string()],
+                },
+                behavior_sequence: vec!["observe".to_string(), "hypothesize".to_string()],
+                emergence_potential: 0.7,
+            },
+            BehavioralPattern {
+                name: "reporting".to_string(),
+                trigger: PatternTrigger {
+                    conditions: vec!["report".to_string()],
                 },
+                behavior_sequence: vec!["summarize".to_string()],
+                emergence_potential: 0.3,
             },
-            communication_style: CommunicationStyle {
-                tone: "precise_and_clear".to_string(),
-                detail_level: "comprehensive".to_string(),
-                question_frequency: "moderate".to_string(),
-                response_patterns: HashMap::new(),
-            },
-            evolution_potential: EvolutionPotential {
-                capability_growth_areas: vec![],
-                personality_plasticity: HashMap::new(),
-            },
-            constraints: AgentConstraints {
-                ethical_boundaries: vec!["never_fabricate_results".to_string()],
-                operational_limits: vec!["max_concurrent_tests: 3".to_string()],
+        ]).await;
+        
+        let signal = NeuralSignal::new(
+            SignalType::Sensory,
+            EntityId::new(),
+            Some(agent_id),
+            SignalPayload::Message("An ANOMALY appeared in the data".to_string()),
+            0.5,
+        );
+        let sequence = engine.evaluate_triggers(agent_id, &signal);
+        assert_eq!(sequence, vec!["observe".to_string(), "hypothesize".to_string()]);
+        
+        let emitted = engine.execute_behavior_sequence(agent_id, &sequence).await.unwrap();
+        assert_eq!(emitted.len(), 2);
+    }
+    
+    #[tokio::test]
+    async fn test_behavioral_pattern_no_trigger() {
+        let mut engine = ExecutionEngine::new().await.unwrap();
+        let agent_id = insert_test_agent(&mut engine, vec![
+            BehavioralPattern {
+                name: "investigation".to_string(),
+                trigger: PatternTrigger {
+                    conditions: vec!["signal_type:Sensory".to_string(), "anomaly".to_string()],
+                },
+                behavior_sequence: vec!["observe".to_string()],
+                emergence_potential: 0.7,
             },
-        };
+        ]).await;
         
-        // Create agent instance
-        let agent = LivingAgent {
-            id: agent_id,
-            name: agent_name.clone(),
-            essence_type: schema.identity.archetype.clone(),
-            personality: schema.personality.clone(),
-            energy: schema.energy_profile.base_energy,
-            state: AgentState::Awakening,
-            awakened_at: Some(Utc::now()),
-            essence_schema: schema.clone(),
-            capabilities: schema.capabilities.learned.clone(),
-            behavioral_patterns: schema.behavioral_patterns.clone(),
-        };
+        // Keyword matches but signal type does not
+        let wrong_type = NeuralSignal::new(
+            SignalType::Cognitive,
+            EntityId::new(),
+            Some(agent_id),
+            SignalPayload::Command("check anomaly".to_string()),
+            0.5,
+        );
+        assert!(engine.evaluate_triggers(agent_id, &wrong_type).is_empty());
         
-        // Register agent with nervous system
-        let capabilities = HashSet::from([
+        let unrelated = NeuralSignal::new(
             SignalType::Sensory,
-            SignalType::Cognitive,
-            SignalType::Coordination,
-            SignalType::Memory,
-        ]);
+            EntityId::new(),
+            Some(agent_id),
+            SignalPayload::Message("All quiet".to_string()),
+            0.5,
+        );
+        assert!(engine.evaluate_triggers(agent_id, &unrelated).is_empty());
+    }
+    
+    #[tokio::test]
+    async fn test_essence_loads_identically_from_every_format() {
+        let engine = ExecutionEngine::new().await.unwrap();
+        let yaml: serde_yaml::Value = serde_yaml::from_str(TEST_ESSENCE_YAML).unwrap();
+        let mut parsed = Vec::new();
+        for format in [SerializationFormat::Yaml, SerializationFormat::Json, SerializationFormat::MessagePack] {
+            let content = match format {
+                SerializationFormat::Yaml => TEST_ESSENCE_YAML.as_bytes().to_vec(),
+                _ => format.to_vec(&yaml).unwrap(),
+            };
+            let mut essence_file = tempfile::NamedTempFile::new().unwrap();
+            std::io::Write::write_all(&mut essence_file, &content).unwrap();
+            let schema = engine.load_essence_schema_with(essence_file.path().to_str().unwrap(), format).await.unwrap();
+            parsed.push(serde_json::to_value(schema).unwrap());
+        }
         
-        let processor = Box::new(AgentProcessor {
-            agent: agent.clone(),
-            essence_schema: schema,
-        });
+        assert_eq!(parsed[0], parsed[1]);
+        assert_eq!(parsed[0], parsed[2]);
+        assert_eq!(parsed[0]["identity"]["name"], serde_json::Value::from("Test Entity Alpha"));
         
-        engine.nervous_system.register_entity(agent_id, capabilities, processor).await.unwrap();
+        let mut json_file = tempfile::NamedTempFile::new().unwrap();
+        std::io::Write::write_all(&mut json_file, b"{\"identity\": 7}").unwrap();
+        assert!(engine.load_essence_schema_with(json_file.path().to_str().unwrap(), SerializationFormat::Json).await.is_err());
+    }
+    
+    #[tokio::test]
+    async fn test_seeded_engines_reproduce_agent_names() {
+        let mut essence_file = tempfile::NamedTempFile::new().unwrap();
+        std::io::Write::write_all(&mut essence_file, TEST_ESSENCE_YAML.as_bytes()).unwrap();
+        let essence_path = essence_file.path().to_str().unwrap();
         
-        // Store agent
-        engine.active_agents.insert(agent_id, agent);
+        let mut first = ExecutionEngine::new_seeded(42).await.unwrap();
+        let mut second = ExecutionEngine::new_seeded(42).await.unwrap();
         
-        // Verify agent was created
-        let agent = engine.get_agent(agent_id).unwrap();
-        assert_eq!(agent.essence_type, "tester");
-        assert_eq!(agent.personality.curiosity, 0.8);
-        assert_eq!(agent.personality.persistence, 0.7);
-        assert_eq!(agent.energy, 0.7);
+        let first_id = first.awaken_agent(essence_path).await.unwrap();
+        let second_id = second.awaken_agent(essence_path).await.unwrap();
         
-        // Verify agent is registered with nervous system
-        let stats = engine.get_system_stats().await.unwrap();
-        assert_eq!(stats.active_agents, 1);
+        assert_eq!(first_id, second_id);
+        assert_eq!(first.get_agent(first_id).unwrap().name, second.get_agent(second_id).unwrap().name);
+        assert_eq!(first.select_index(1000), second.select_index(1000));
+        assert_eq!(first.select_weighted(&[0.2, 0.3, 0.5]), second.select_weighted(&[0.2, 0.3, 0.5]));
+        assert_eq!(first.select_weighted(&[0.0, -1.0, 2.0]), 2);
         
-        // Test sending a signal to the agent
-        let signal = NeuralSignal::new(
-            SignalType::Sensory,
-            sender_id, // system entity with energy
-            Some(agent_id),
-            SignalPayload::Message("I observe interesting patterns in the test data".to_string()),
-            0.1,
+        let mut other = ExecutionEngine::new_seeded(7).await.unwrap();
+        let other_id = other.awaken_agent(essence_path).await.unwrap();
+        assert_ne!(first_id, other_id);
+    }
+    
+    #[tokio::test]
+    async fn test_fresh_engine_is_healthy() {
+        let engine = ExecutionEngine::new().await.unwrap();
+        
+        let report = engine.health_check().await;
+        
+        assert!(report.is_healthy(), "{}", report);
+        for (name, health) in report.subsystems() {
+            assert_eq!(health.status, HealthStatus::Ok, "{}", name);
+            assert!(health.latency < health::HEALTH_LATENCY_BUDGET, "{}", name);
+        }
+        // The probes leave nothing behind
+        assert_eq!(engine.physics.get_engine_state().await.unwrap().energy_state.active_entities, 0);
+        assert_eq!(engine.nervous_system.get_statistics().await.unwrap().registered_entities, 0);
+        assert_eq!(engine.memory.get_statistics().stored_memories, 0);
+    }
+    
+    #[tokio::test]
+    async fn test_engine_without_free_energy_is_healthy() {
+        let engine = ExecutionEngine::new().await.unwrap();
+        let free = engine.physics.get_engine_state().await.unwrap().energy_state.free_energy;
+        engine.physics.allocate_energy_to_entity(EntityId::new(), free).await.unwrap();
+        
+        let report = engine.health_check().await;
+        
+        assert!(report.is_healthy(), "{}", report);
+    }
+    
+    #[tokio::test]
+    async fn test_awaken_all_reports_invalid_essences() {
+        let dir = tempfile::tempdir().unwrap();
+        let essence = TEST_ESSENCE_YAML.replace("base_energy: 0.7", "base_energy: 0.2");
+        std::fs::write(dir.path().join("alpha-essence.yaml"), &essence).unwrap();
+        std::fs::write(dir.path().join("beta-essence.yaml"), &essence).unwrap();
+        std::fs::write(dir.path().join("broken-essence.yaml"), "identity: 7").unwrap();
+        std::fs::write(dir.path().join("overcharged-essence.yaml"), TEST_ESSENCE_YAML.replace("base_energy: 0.7", "base_energy: 1.5")).unwrap();
+        std::fs::write(dir.path().join("notes.yaml"), "not an essence").unwrap();
+        
+        let mut engine = ExecutionEngine::new().await.unwrap();
+        let cohort = engine.awaken_all(dir.path().to_str().unwrap()).await.unwrap();
+        
+        assert_eq!(cohort.awakened.len(), 2);
+        for (agent_id, name) in &cohort.awakened {
+            assert_eq!(&engine.get_agent(*agent_id).unwrap().name, name);
+        }
+        assert_eq!(cohort.failed.len(), 2);
+        assert_eq!(cohort.failed[0].0, dir.path().join("broken-essence.yaml"));
+        assert!(cohort.failed[0].1.contains("parse"), "{}", cohort.failed[0].1);
+        assert_eq!(cohort.failed[1].0, dir.path().join("overcharged-essence.yaml"));
+        assert!(cohort.failed[1].1.contains("base_energy is 1.5"), "{}", cohort.failed[1].1);
+        assert!(engine.awaken_all("/nonexistent/essences").await.is_err());
+    }
+    
+    #[tokio::test]
+    async fn test_low_energy_agents_go_dormant_and_wake_when_replenished() {
+        let mut engine = ExecutionEngine::new().await.unwrap();
+        let agent_id = insert_test_agent(&mut engine, Vec::new()).await;
+        engine.nervous_system.register_manual_entity(agent_id, HashSet::from([SignalType::Sensory])).await.unwrap();
+        let sender = EntityId::new();
+        engine.physics.allocate_energy_to_entity(sender, ordered_float::OrderedFloat(0.01)).await.unwrap();
+        let ping = || NeuralSignal::new(SignalType::Sensory, sender, Some(agent_id), SignalPayload::Message("ping".to_string()), 0.5)
+            .with_energy_cost(0.0);
+        let recording = engine.nervous_system.start_recording();
+        
+        assert!(engine.check_dormancy().await.unwrap().is_empty());
+        
+        engine.physics.release_energy_from_entity(agent_id).await.unwrap();
+        engine.physics.allocate_energy_to_entity(agent_id, ordered_float::OrderedFloat(0.01)).await.unwrap();
+        assert_eq!(engine.check_dormancy().await.unwrap(), vec![(agent_id, AgentState::Dormant)]);
+        assert!(engine.check_dormancy().await.unwrap().is_empty());
+        engine.send_signal_to_agent(agent_id, ping()).await.unwrap();
+        let dead_letters = engine.nervous_system.drain_dead_letters().await;
+        assert_eq!(dead_letters.len(), 1);
+        assert_eq!(dead_letters[0].reason, emergence_nervous_system::DeadLetterReason::Quarantined);
+        
+        // Between the thresholds the agent keeps resting
+        engine.physics.allocate_energy_to_entity(agent_id, ordered_float::OrderedFloat(0.07)).await.unwrap();
+        assert!(engine.check_dormancy().await.unwrap().is_empty());
+        
+        engine.physics.allocate_energy_to_entity(agent_id, ordered_float::OrderedFloat(0.1)).await.unwrap();
+        assert_eq!(engine.check_dormancy().await.unwrap(), vec![(agent_id, AgentState::Alert)]);
+        assert_eq!(engine.get_agent(agent_id).unwrap().state, AgentState::Alert);
+        engine.send_signal_to_agent(agent_id, ping()).await.unwrap();
+        assert!(engine.nervous_system.drain_dead_letters().await.is_empty());
+        assert_eq!(engine.nervous_system.drain_inbox(agent_id).await.len(), 1);
+        
+        let log = engine.nervous_system.stop_recording(recording).await.unwrap();
+        let transitions: Vec<String> = log.signals.iter()
+            .filter_map(|recorded| match &recorded.signal.payload {
+                SignalPayload::StateUpdate(update) => update["agent_state"].as_str().map(str::to_string),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(transitions, vec!["Dormant", "Alert"]);
+    }
+    
+    /// Refuses every Coordination signal
+    struct RejectCoordination;
+    
+    impl emergence_nervous_system::SignalMiddleware for RejectCoordination {
+        fn on_transmit(&self, signal: &mut NeuralSignal) -> emergence_nervous_system::MiddlewareAction {
+            match signal.signal_type {
+                SignalType::Coordination => emergence_nervous_system::MiddlewareAction::Reject("no coordination".to_string()),
+                _ => emergence_nervous_system::MiddlewareAction::Continue,
+            }
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_dormancy_quarantine_is_kept_apart_from_failure_quarantine() {
+        let mut engine = ExecutionEngine::new().await.unwrap();
+        let (resting, failing) = (insert_test_agent(&mut engine, Vec::new()).await, insert_test_agent(&mut engine, Vec::new()).await);
+        engine.nervous_system.add_middleware(Box::new(RejectCoordination));
+        engine.nervous_system.quarantine(failing, "3 consecutive processing errors".to_string()).await;
+        
+        // The transitions cannot be broadcast, but both agents still go dormant
+        for agent in [resting, failing] {
+            engine.physics.release_energy_from_entity(agent).await.unwrap();
+            engine.physics.allocate_energy_to_entity(agent, ordered_float::OrderedFloat(0.01)).await.unwrap();
+        }
+        assert_eq!(engine.check_dormancy().await.unwrap().len(), 2);
+        assert!(engine.nervous_system.quarantine_reason(resting).await.unwrap().starts_with("dormant"));
+        
+        // Waking lifts only the quarantine dormancy placed
+        for agent in [resting, failing] {
+            engine.physics.allocate_energy_to_entity(agent, ordered_float::OrderedFloat(0.2)).await.unwrap();
+        }
+        let mut woken = engine.check_dormancy().await.unwrap();
+        woken.sort_by_key(|(agent, _)| *agent != resting);
+        assert_eq!(woken, vec![(resting, AgentState::Alert), (failing, AgentState::Alert)]);
+        assert_eq!(engine.nervous_system.quarantine_reason(resting).await, None);
+        assert_eq!(
+            engine.nervous_system.quarantine_reason(failing).await.as_deref(),
+            Some("3 consecutive processing errors")
         );
-        engine.send_signal_to_agent(agent_id, signal).await.unwrap();
+    }
+    
+    #[tokio::test]
+    async fn test_simulation_decays_idle_agents_step_by_step() {
+        let mut essence_file = tempfile::NamedTempFile::new().unwrap();
+        std::io::Write::write_all(&mut essence_file, TEST_ESSENCE_YAML.as_bytes()).unwrap();
+        let clock = MockClock::new();
+        let mut engine = ExecutionEngine::new_simulated(clock.clone(), Some(7)).await.unwrap();
+        let agent_id = engine.awaken_agent(essence_file.path().to_str().unwrap()).await.unwrap();
+        let mut replayed = ExecutionEngine::new_simulated(MockClock::new(), Some(7)).await.unwrap();
+        assert_eq!(replayed.awaken_agent(essence_file.path().to_str().unwrap()).await.unwrap(), agent_id);
+        let started = clock.now_utc();
+        engine.emotions.stir_mood(agent_id, 0.8, 1.0);
         
-        println!("Test agent created and registered successfully: {}", agent.name);
+        let report = engine.simulate(5, Duration::from_secs(10)).await.unwrap();
+        
+        // Moods fade on the simulated clock too
+        let faded = 0.4 * 0.5f64.powf(50.0 / emotion::MOOD_HALF_LIFE.as_secs_f64());
+        assert!((engine.emotions.mood(agent_id).valence - faded).abs() < 1e-9);
+        assert_eq!(clock.now_utc() - started, chrono::Duration::seconds(50));
+        assert_eq!(report.snapshots.len(), 5);
+        assert_eq!(report.snapshots[4].elapsed, Duration::from_secs(50));
+        let trajectory = report.energy_trajectory(agent_id);
+        assert!(trajectory[0] < 0.7, "{:?}", trajectory);
+        assert!(trajectory.windows(2).all(|pair| pair[1] < pair[0]), "{:?}", trajectory);
+        assert_eq!(report.total_energy_trajectory(), trajectory);
+        
+        let mut unsimulated = ExecutionEngine::new().await.unwrap();
+        assert!(unsimulated.simulate(1, Duration::from_secs(1)).await.is_err());
     }
     
     #[tokio::test]
-    async fn test_agent_communication() {
+    async fn test_agent_names_resolve_both_ways() {
+        let mut essence_file = tempfile::NamedTempFile::new().unwrap();
+        let essence = TEST_ESSENCE_YAML.replace("base_energy: 0.7", "base_energy: 0.2");
+        std::io::Write::write_all(&mut essence_file, essence.as_bytes()).unwrap();
+        let essence_path = essence_file.path().to_str().unwrap();
         let mut engine = ExecutionEngine::new().await.unwrap();
         
-        // Create a test agent
-        let agent_id = EntityId::new();
-        let agent_name = format!("test-agent-{}", agent_id.0.simple());
+        let first = engine.awaken_agent(essence_path).await.unwrap();
+        let second = engine.awaken_agent(essence_path).await.unwrap();
         
-        // Allocate energy
-        engine.physics.allocate_energy_to_entity(agent_id, ordered_float::OrderedFloat(0.2))
-            .await
-            .unwrap();
+        for agent_id in [first, second] {
+            let name = engine.get_agent(agent_id).unwrap().name.clone();
+            assert_eq!(engine.name_of(agent_id), Some(name.as_str()));
+            assert_eq!(engine.entity_by_name(&name), Some(agent_id));
+        }
+        assert_ne!(engine.name_of(first), engine.name_of(second));
+        assert_eq!(engine.name_of(EntityId::new()), None);
         
-        // Create a sender entity and allocate initial energy
-        let sender_id = EntityId::new();
-        engine.physics.allocate_energy_to_entity(sender_id, ordered_float::OrderedFloat(0.8))
-            .await
-            .unwrap();
+        let removed = engine.active_agents.remove(&second).unwrap();
+        assert_eq!(engine.name_of(second), None);
+        assert_eq!(engine.entity_by_name(&removed.name), None);
         
-        // Send different types of signals and verify responses
-        let test_signals = vec![
-            (SignalType::Sensory, "I see patterns in the data"),
-            (SignalType::Cognitive, "Please analyze this problem"),
-            (SignalType::Coordination, "Let's collaborate on this"),
-            (SignalType::Memory, "What do you remember about this?"),
-        ];
+        let name = engine.name_of(first).unwrap().to_string();
+        engine.shutdown().await.unwrap();
+        assert_eq!(engine.name_of(first), None);
+        assert_eq!(engine.entity_by_name(&name), None);
+    }
+    
+    #[tokio::test]
+    async fn test_created_physics_engine_stays_reachable() {
+        let mut engine = ExecutionEngine::new().await.unwrap();
+        let agent_id = insert_test_agent(&mut engine, Vec::new()).await;
         
-        for (signal_type, message) in test_signals {
-            let signal = NeuralSignal::new(
-                signal_type,
-                sender_id,
-                Some(agent_id),
-                SignalPayload::Message(message.to_string()),
-                0.1,
-            );
-            
-            engine.send_signal_to_agent(agent_id, signal).await.unwrap();
+        let checkpoint = engine.physics_engine().unwrap().checkpoint().await;
+        assert!(checkpoint.energy_allocations.contains_key(&agent_id));
+        
+        let injected = ExecutionEngine::new_with_physics(Arc::new(PhysicsEngine::new().await.unwrap())).await.unwrap();
+        assert!(injected.physics_engine().is_none());
+    }
+    
+    #[tokio::test]
+    async fn test_shutdown_reclaims_energy() {
+        let mut essence_file = tempfile::NamedTempFile::new().unwrap();
+        let essence = TEST_ESSENCE_YAML.replace("base_energy: 0.7", "base_energy: 0.2");
+        std::io::Write::write_all(&mut essence_file, essence.as_bytes()).unwrap();
+        let essence_path = essence_file.path().to_str().unwrap();
+        
+        let mut engine = ExecutionEngine::new().await.unwrap();
+        for _ in 0..3 {
+            engine.awaken_agent(essence_path).await.unwrap();
         }
+        assert_eq!(engine.get_active_agents().len(), 3);
         
-        println!("Agent communication test completed successfully");
+        let agent_id = *engine.get_active_agents().keys().next().unwrap();
+        engine.emotions.stir_mood(agent_id, 0.8, 1.0);
+        engine.physics.execute_operation(PhysicsOperation::AllocateResource {
+            entity: agent_id,
+            resource: Resource::Cpu(10),
+            amount: ordered_float::OrderedFloat(10.0),
+        }).await.unwrap();
+        
+        let report = engine.shutdown().await.unwrap();
+        assert_eq!(report.agents_stopped, 3);
+        assert_eq!(report.tasks_joined, 3);
+        assert_eq!(report.resources_released, 1);
+        assert!(report.errors.is_empty());
+        assert!((report.energy_reclaimed - 0.6).abs() < 1e-9);
+        
+        assert!(engine.get_active_agents().is_empty());
+        assert_eq!(engine.name_of(agent_id), None);
+        assert_eq!(engine.emotions.mood(agent_id), emotion::Mood::default());
+        let state = engine.physics.get_engine_state().await.unwrap();
+        assert!((state.energy_state.free_energy.0 - 1.0).abs() < 1e-9);
+        assert_eq!(engine.physics.release_resources_from_entity(agent_id).await, 0);
+        
+        // Idempotent
+        let second = engine.shutdown().await.unwrap();
+        assert_eq!(second.agents_stopped, 0);
     }
     
     #[tokio::test]
@@ -733,4 +3090,15 @@ pub struct SystemStats {
     pub active_agents: usize,
     pub physics_uptime: Duration,
     pub nervous_system_stats: emergence_nervous_system::NervousSystemStats,
-}
\ No newline at end of file
+}
+
+/// Summary of an execution engine shutdown
+#[derive(Debug, Clone, Default)]
+pub struct ShutdownReport {
+    pub agents_stopped: usize,
+    pub energy_reclaimed: f64,
+    pub tasks_joined: usize,
+    pub resources_released: usize,
+    /// Agents that could not be cleanly stopped, with the reason
+    pub errors: Vec<(EntityId, String)>,
+}
@@ -0,0 +1,309 @@
+//! Rule-based code analysis used by the debugger agent.
+//!
+//! Each [`LintRule`] pairs a language with a regular expression; a [`RuleSet`]
+//! runs every rule for a file's language and reports matches as [`CodeIssue`]s
+//! with line and column positions taken from the match offsets.
+
+use anyhow::Result;
+use regex::Regex;
+
+use crate::debugger::{CodeIssue, IssueSeverity};
+
+/// A single pattern-based analysis rule
+#[derive(Debug, Clone)]
+pub struct LintRule {
+    pub id: String,
+    pub language: String,
+    pub pattern: Regex,
+    pub severity: IssueSeverity,
+    pub category: String,
+    pub description: String,
+    pub explanation: String,
+    pub suggested_fix: Option<String>,
+    /// Minimum number of matches before the rule reports an issue
+    pub min_matches: usize,
+}
+
+impl LintRule {
+    /// Create a rule, compiling its pattern
+    pub fn new(
+        id: &str,
+        language: &str,
+        pattern: &str,
+        severity: IssueSeverity,
+        category: &str,
+        description: &str,
+    ) -> Result<Self> {
+        Ok(Self {
+            id: id.to_string(),
+            language: language.to_string(),
+            pattern: Regex::new(pattern)?,
+            severity,
+            category: category.to_string(),
+            description: description.to_string(),
+            explanation: String::new(),
+            suggested_fix: None,
+            min_matches: 1,
+        })
+    }
+
+    /// Set the explanation shown alongside reported issues
+    pub fn with_explanation(mut self, explanation: &str) -> Self {
+        self.explanation = explanation.to_string();
+        self
+    }
+
+    /// Set the suggested fix for reported issues
+    pub fn with_suggested_fix(mut self, fix: &str) -> Self {
+        self.suggested_fix = Some(fix.to_string());
+        self
+    }
+
+    /// Only report once the pattern matches at least `count` times
+    pub fn with_min_matches(mut self, count: usize) -> Self {
+        self.min_matches = count.max(1);
+        self
+    }
+}
+
+/// Ordered collection of lint rules
+#[derive(Debug, Clone)]
+pub struct RuleSet {
+    rules: Vec<LintRule>,
+}
+
+impl RuleSet {
+    /// Create a rule set with no rules; use `default()` for the built-in rules
+    pub fn empty() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    /// Add a rule, returning the extended set
+    pub fn with_rule(mut self, rule: LintRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Add a rule in place
+    pub fn add_rule(&mut self, rule: LintRule) {
+        self.rules.push(rule);
+    }
+
+    /// All rules in the set
+    pub fn rules(&self) -> &[LintRule] {
+        &self.rules
+    }
+
+    /// Run every rule for `language` against `content`
+    ///
+    /// Matches that start inside a line comment are ignored. Rules with a
+    /// minimum match count report a single issue at their first match.
+    /// Columns count characters, not bytes.
+    pub fn analyze(&self, content: &str, language: &str) -> Vec<CodeIssue> {
+        let mut issues = Vec::new();
+
+        for rule in self.rules.iter().filter(|r| r.language == language) {
+            let matches: Vec<(usize, usize, &str)> = content
+                .lines()
+                .enumerate()
+                .flat_map(|(index, line)| {
+                    let code_end = code_end(line, language);
+                    rule.pattern
+                        .find_iter(line)
+                        .filter(move |m| m.start() < code_end)
+                        .map(move |m| (index + 1, line[..m.start()].chars().count() + 1, line))
+                })
+                .collect();
+
+            if matches.len() < rule.min_matches {
+                continue;
+            }
+
+            let reported = if rule.min_matches > 1 { &matches[..1] } else { &matches[..] };
+            for (line_number, column, line) in reported {
+                issues.push(CodeIssue {
+                    severity: rule.severity.clone(),
+                    category: rule.category.clone(),
+                    description: rule.description.clone(),
+                    line_number: Some(*line_number),
+                    column_number: Some(*column),
+                    code_snippet: Some(line.trim().to_string()),
+                    explanation: rule.explanation.clone(),
+                    suggested_fix: rule.suggested_fix.clone(),
+                });
+            }
+        }
+
+        issues
+    }
+}
+
+impl Default for RuleSet {
+    /// Built-in rules for the languages the debugger understands
+    fn default() -> Self {
+        let rules = [
+            LintRule::new(
+                "rust-unwrap",
+                "rust",
+                r"\.unwrap\(\)",
+                IssueSeverity::Warning,
+                "Error Handling",
+                "Use of unwrap() without proper error handling",
+            )
+            .map(|r| r
+                .with_explanation("unwrap() can panic if the value is None or Err. Consider using match, if let, or ? operator for safer error handling.")
+                .with_suggested_fix("Replace unwrap() with proper error handling using match or ? operator")),
+            LintRule::new(
+                "rust-excessive-clone",
+                "rust",
+                r"\.clone\(\)",
+                IssueSeverity::Warning,
+                "Performance",
+                "Excessive use of clone() may indicate performance issues",
+            )
+            .map(|r| r
+                .with_explanation("Frequent cloning can impact performance. Consider using references or more efficient data structures.")
+                .with_suggested_fix("Review clone() usage and consider using references where possible")
+                .with_min_matches(4)),
+            LintRule::new(
+                "rust-division-by-zero",
+                "rust",
+                r"\b\d+\s*/\s*0\b",
+                IssueSeverity::Critical,
+                "Logic Error",
+                "Division by zero detected",
+            )
+            .map(|r| r
+                .with_explanation("Division by zero will cause a runtime panic in Rust. This is a critical logic error.")
+                .with_suggested_fix("Add a check to ensure the denominator is not zero before division")),
+            LintRule::new(
+                "python-bare-except",
+                "python",
+                r"\bexcept\s*:",
+                IssueSeverity::Warning,
+                "Error Handling",
+                "Bare except clause catches all exceptions",
+            )
+            .map(|r| r
+                .with_explanation("Bare except clauses can mask important errors. Be specific about which exceptions to catch.")
+                .with_suggested_fix("Specify the exception types to catch instead of using bare except")),
+        ];
+
+        Self {
+            rules: rules
+                .into_iter()
+                .map(|rule| rule.expect("built-in lint patterns are valid"))
+                .collect(),
+        }
+    }
+}
+
+/// Line comment marker for a language, if it has one
+fn line_comment_marker(language: &str) -> Option<&'static str> {
+    match language {
+        "rust" | "javascript" | "java" | "cpp" | "c" | "go" => Some("//"),
+        "python" => Some("#"),
+        _ => None,
+    }
+}
+
+/// Characters that open a string literal in a language
+///
+/// Rust, C-family and Go use `'` for characters and lifetimes, which never hide a comment marker.
+fn string_delimiters(language: &str) -> &'static [char] {
+    match language {
+        "python" | "javascript" => &['"', '\''],
+        _ => &['"'],
+    }
+}
+
+/// Byte offset where a line's trailing comment starts, or the line length if it has none
+///
+/// Comment markers inside string literals do not count.
+fn code_end(line: &str, language: &str) -> usize {
+    let Some(marker) = line_comment_marker(language) else {
+        return line.len();
+    };
+    let delimiters = string_delimiters(language);
+
+    let mut open_string: Option<char> = None;
+    let mut escaped = false;
+    for (offset, c) in line.char_indices() {
+        match open_string {
+            Some(_) if escaped => escaped = false,
+            Some(_) if c == '\\' => escaped = true,
+            Some(delimiter) if c == delimiter => open_string = None,
+            Some(_) => {}
+            None if delimiters.contains(&c) => open_string = Some(c),
+            None if line[offset..].starts_with(marker) => return offset,
+            None => {}
+        }
+    }
+
+    line.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_custom_rule_fires() {
+        let rule = LintRule::new("rust-todo", "rust", r"todo!\(", IssueSeverity::Info, "Completeness", "Unfinished code")
+            .unwrap()
+            .with_suggested_fix("Implement the missing branch");
+        let rules = RuleSet::empty().with_rule(rule);
+
+        let issues = rules.analyze("fn main() {\n    todo!()\n}\n", "rust");
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].category, "Completeness");
+        assert_eq!(issues[0].line_number, Some(2));
+        assert_eq!(issues[0].column_number, Some(5));
+        assert!(rules.analyze("fn main() {\n    todo!()\n}\n", "python").is_empty());
+    }
+
+    #[test]
+    fn test_commented_unwrap_is_ignored() {
+        let content = "// value.unwrap() is discussed here\nlet x = value; // not .unwrap() either\n";
+
+        let issues = RuleSet::default().analyze(content, "rust");
+
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn test_comment_markers_inside_strings_are_code() {
+        let content = "let url = \"http://localhost\".parse::<Url>().unwrap();\nlet s = \"a \\\" // b\"; s.len().unwrap(); // c.unwrap()\n";
+
+        let issues = RuleSet::default().analyze(content, "rust");
+        let lines: Vec<_> = issues.iter().map(|i| i.line_number).collect();
+
+        assert_eq!(lines, vec![Some(1), Some(2)]);
+        assert_eq!(RuleSet::default().analyze("marker = '#'; except: pass\n", "python").len(), 1);
+    }
+
+    #[test]
+    fn test_columns_count_characters() {
+        let issues = RuleSet::default().analyze("let café = cup.unwrap();\n", "rust");
+
+        assert_eq!(issues[0].column_number, Some(15));
+    }
+
+    #[test]
+    fn test_empty_rule_set_has_no_rules() {
+        assert!(RuleSet::empty().rules().is_empty());
+        assert!(!RuleSet::default().rules().is_empty());
+    }
+
+    #[test]
+    fn test_line_numbers_follow_matches() {
+        let content = "fn load() {\n    let a = 1;\n\n    let b = read().unwrap();\n    let c = parse(b).unwrap();\n}\n";
+
+        let issues = RuleSet::default().analyze(content, "rust");
+        let lines: Vec<_> = issues.iter().map(|i| (i.line_number, i.column_number)).collect();
+
+        assert_eq!(lines, vec![(Some(4), Some(19)), (Some(5), Some(21))]);
+        assert_eq!(issues[0].code_snippet.as_deref(), Some("let b = read().unwrap();"));
+    }
+}
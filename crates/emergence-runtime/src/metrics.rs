@@ -0,0 +1,161 @@
+//! Prometheus text exposition of runtime statistics.
+//!
+//! These are pure formatters over the existing stats snapshots; serving the
+//! text over HTTP is left to the embedding application.
+
+use std::fmt::Write;
+
+use emergence_nervous_system::NervousSystemStats;
+use emergence_physics::PhysicsEngineState;
+
+use crate::SystemStats;
+
+/// Render nervous system and physics statistics in the Prometheus text format
+pub fn render_prometheus(stats: &NervousSystemStats, physics: &PhysicsEngineState) -> String {
+    let mut out = String::new();
+
+    gauge(&mut out, "emergence_uptime_seconds", "Time since the nervous system started", stats.uptime.as_secs_f64());
+    gauge(&mut out, "emergence_nervous_registered_entities", "Entities registered with the nervous system", stats.registered_entities as f64);
+    gauge(&mut out, "emergence_nervous_pathways", "Neural pathways between entities", stats.total_pathways as f64);
+    counter(&mut out, "emergence_nervous_signals_processed_total", "Signals processed by entity processors", stats.total_signals_processed as f64);
+    counter(&mut out, "emergence_nervous_errors_total", "Signal processing errors", stats.total_errors as f64);
+    gauge(&mut out, "emergence_nervous_avg_processing_seconds", "Average time to process one signal", stats.avg_processing_time.as_secs_f64());
+    gauge(&mut out, "emergence_nervous_pending_signals", "Signals queued in broadcast channels", stats.pending_signals as f64);
+    counter(&mut out, "emergence_nervous_expired_signals_total", "Signals dropped because their time to live passed", stats.expired_signals as f64);
+    gauge(&mut out, "emergence_nervous_dead_letters", "Undelivered directed signals waiting to be drained", stats.dead_letters as f64);
+
+    let mut dropped: Vec<(String, u64)> = stats.dropped_signals.iter()
+        .map(|(signal_type, count)| (format!("{:?}", signal_type), *count))
+        .collect();
+    dropped.sort();
+    header(&mut out, "emergence_nervous_dropped_signals_total", "Broadcast signals evicted before every subscriber saw them", "counter");
+    for (signal_type, count) in dropped {
+        sample(&mut out, "emergence_nervous_dropped_signals_total", Some(("signal_type", &signal_type)), count as f64);
+    }
+
+    let energy = &physics.energy_state;
+    gauge(&mut out, "emergence_physics_uptime_seconds", "Time since the physics engine started", physics.uptime.as_secs_f64());
+    gauge(&mut out, "emergence_physics_total_energy", "Total energy in the system", energy.total_energy.0);
+    gauge(&mut out, "emergence_physics_allocated_energy", "Energy allocated to entities", energy.allocated_energy.0);
+    gauge(&mut out, "emergence_physics_free_energy", "Energy available for allocation", energy.free_energy.0);
+    gauge(&mut out, "emergence_physics_active_entities", "Entities holding energy", energy.active_entities as f64);
+    gauge(&mut out, "emergence_physics_running_operations", "Operations running under a time limit", physics.running_operations.len() as f64);
+
+    let operations = &physics.operation_stats;
+    counter(&mut out, "emergence_physics_operations_total", "Physics operations submitted", operations.operations_executed as f64);
+    header(&mut out, "emergence_physics_violations_total", "Physics operations rejected, by violation kind", "counter");
+    for (kind, count) in [
+        ("energy", operations.energy_violations),
+        ("security", operations.security_violations),
+        ("other", operations.other_violations),
+    ] {
+        sample(&mut out, "emergence_physics_violations_total", Some(("kind", kind)), count as f64);
+    }
+
+    out
+}
+
+/// Render runtime-wide statistics, including the nervous system and physics metrics
+pub fn render_system_prometheus(stats: &SystemStats, physics: &PhysicsEngineState) -> String {
+    let mut out = String::new();
+    gauge(&mut out, "emergence_session_uptime_seconds", "Time since the execution engine started", stats.session_uptime.as_secs_f64());
+    gauge(&mut out, "emergence_active_agents", "Agents currently awake", stats.active_agents as f64);
+    out.push_str(&render_prometheus(&stats.nervous_system_stats, physics));
+    out
+}
+
+fn gauge(out: &mut String, name: &str, help: &str, value: f64) {
+    header(out, name, help, "gauge");
+    sample(out, name, None, value);
+}
+
+fn counter(out: &mut String, name: &str, help: &str, value: f64) {
+    header(out, name, help, "counter");
+    sample(out, name, None, value);
+}
+
+fn header(out: &mut String, name: &str, help: &str, kind: &str) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+}
+
+fn sample(out: &mut String, name: &str, label: Option<(&str, &str)>, value: f64) {
+    match label {
+        Some((key, label_value)) => {
+            let escaped = label_value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n");
+            let _ = writeln!(out, "{}{{{}=\"{}\"}} {}", name, key, escaped, value);
+        }
+        None => {
+            let _ = writeln!(out, "{} {}", name, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::sync::Arc;
+
+    use emergence_nervous_system::NervousSystem;
+    use emergence_physics::PhysicsEngine;
+    use regex::Regex;
+
+    /// Check the text against the exposition format, returning the declared metric names
+    fn parse_exposition(text: &str) -> HashSet<String> {
+        let comment = Regex::new(r"^# (HELP|TYPE) ([a-zA-Z_:][a-zA-Z0-9_:]*) (.+)$").unwrap();
+        let sample = Regex::new(r#"^([a-zA-Z_:][a-zA-Z0-9_:]*)(\{[a-zA-Z_][a-zA-Z0-9_]*="[^"]*"\})? (\S+)$"#).unwrap();
+        let mut typed = HashSet::new();
+
+        for line in text.lines() {
+            if let Some(captures) = comment.captures(line) {
+                if &captures[1] == "TYPE" {
+                    assert!(["counter", "gauge"].contains(&&captures[3]), "bad type in {}", line);
+                    assert!(typed.insert(captures[2].to_string()), "metric typed twice: {}", line);
+                }
+                continue;
+            }
+            let captures = sample.captures(line).unwrap_or_else(|| panic!("invalid line: {}", line));
+            assert!(typed.contains(&captures[1]), "sample before its TYPE: {}", line);
+            captures[3].parse::<f64>().unwrap_or_else(|_| panic!("invalid value: {}", line));
+        }
+        typed
+    }
+
+    #[tokio::test]
+    async fn test_prometheus_output_is_valid_exposition() {
+        let physics = Arc::new(PhysicsEngine::new().await.unwrap());
+        let nervous_system = NervousSystem::new(physics.clone()).await.unwrap();
+        let stats = nervous_system.get_statistics().await.unwrap();
+        let state = physics.get_engine_state().await.unwrap();
+
+        let text = render_prometheus(&stats, &state);
+        let metrics = parse_exposition(&text);
+
+        for name in [
+            "emergence_uptime_seconds",
+            "emergence_nervous_signals_processed_total",
+            "emergence_nervous_errors_total",
+            "emergence_physics_total_energy",
+            "emergence_physics_active_entities",
+            "emergence_physics_violations_total",
+        ] {
+            assert!(metrics.contains(name), "missing {}", name);
+        }
+        assert!(text.contains("emergence_physics_violations_total{kind=\"energy\"} 0\n"));
+    }
+
+    #[tokio::test]
+    async fn test_system_prometheus_adds_runtime_metrics() {
+        let engine = crate::ExecutionEngine::new().await.unwrap();
+        let stats = engine.get_system_stats().await.unwrap();
+        let state = engine.physics.get_engine_state().await.unwrap();
+
+        let text = render_system_prometheus(&stats, &state);
+        let metrics = parse_exposition(&text);
+
+        assert!(metrics.contains("emergence_active_agents"));
+        assert!(metrics.contains("emergence_nervous_registered_entities"));
+        assert!(text.contains("emergence_active_agents 0\n"));
+    }
+}
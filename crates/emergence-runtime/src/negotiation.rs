@@ -0,0 +1,75 @@
+//! Capability discovery between two agents.
+//!
+//! Negotiation is a handshake of two Coordination signals: the initiating
+//! agent offers its capability set and the other agent's processor replies
+//! with its own. Each side's capabilities are then split into those both
+//! agents hold and those only one of them brings, which is what
+//! collaboration patterns pick roles from.
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+use serde_yaml::Value as YamlValue;
+
+/// Capabilities two agents discovered in each other
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NegotiationResult {
+    /// Capabilities both agents hold, with the initiator's strength first
+    pub shared: BTreeMap<String, (f64, f64)>,
+    /// Capabilities only the initiator holds
+    pub offered_by_initiator: BTreeMap<String, f64>,
+    /// Capabilities only the responder holds
+    pub offered_by_responder: BTreeMap<String, f64>,
+}
+
+impl NegotiationResult {
+    /// Compare the capability sets each agent announced
+    pub fn between(initiator: &HashMap<String, f64>, responder: &HashMap<String, f64>) -> Self {
+        let mut result = Self::default();
+        for (name, &strength) in initiator {
+            match responder.get(name) {
+                Some(&other) => {
+                    result.shared.insert(name.clone(), (strength, other));
+                }
+                None => {
+                    result.offered_by_initiator.insert(name.clone(), strength);
+                }
+            }
+        }
+        for (name, &strength) in responder {
+            if !initiator.contains_key(name) {
+                result.offered_by_responder.insert(name.clone(), strength);
+            }
+        }
+        result
+    }
+
+    /// Names of the capabilities only one of the agents holds
+    pub fn complementary(&self) -> Vec<&str> {
+        self.offered_by_initiator.keys()
+            .chain(self.offered_by_responder.keys())
+            .map(String::as_str)
+            .collect()
+    }
+}
+
+/// Handshake payload announcing an agent's capabilities
+///
+/// `step` is `"offer"` for the initiator and `"reply"` for the responder.
+pub fn capability_announcement(step: &str, capabilities: &HashMap<String, f64>) -> YamlValue {
+    let strengths: BTreeMap<&str, f64> = capabilities.iter().map(|(name, &strength)| (name.as_str(), strength)).collect();
+    let mut announcement = serde_yaml::Mapping::new();
+    announcement.insert("negotiate".into(), step.into());
+    announcement.insert("capabilities".into(), serde_yaml::to_value(strengths).unwrap_or_default());
+    YamlValue::Mapping(announcement)
+}
+
+/// Whether a payload is the initiator's offer, which the receiving agent answers
+pub fn is_offer(announcement: &YamlValue) -> bool {
+    announcement.get("negotiate").and_then(YamlValue::as_str) == Some("offer")
+}
+
+/// Capabilities carried by a handshake payload
+pub fn announced_capabilities(announcement: &YamlValue) -> Option<HashMap<String, f64>> {
+    serde_yaml::from_value(announcement.get("capabilities")?.clone()).ok()
+}
@@ -0,0 +1,366 @@
+//! Essence updates drawn from an agent's own experience.
+//!
+//! An [`EssenceReflector`] runs its [`ReflectionRule`]s over the evidence an
+//! agent has gathered, such as the outcomes of its diagnostic sessions, and
+//! proposes new emergent capabilities and knowledge-expansion entries.
+//! Reflecting adds them to the agent's in-memory schema; applying writes them
+//! into the essence YAML, appending only entries the file does not already
+//! list. Applying re-serialises the whole document, so the values in it are
+//! kept but its comments and formatting are not.
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_yaml::Value as YamlValue;
+
+use crate::AgentEssenceSchema;
+
+/// Something that happened to an agent and can inform its essence
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Evidence {
+    pub description: String,
+    /// Whether the attempt it records succeeded
+    pub success: bool,
+    /// Strategy the agent was following, if any
+    pub strategy: Option<String>,
+}
+
+impl Evidence {
+    pub fn success(description: &str) -> Self {
+        Self { description: description.to_string(), success: true, strategy: None }
+    }
+
+    pub fn failure(description: &str) -> Self {
+        Self { description: description.to_string(), success: false, strategy: None }
+    }
+
+    pub fn with_strategy(mut self, strategy: &str) -> Self {
+        self.strategy = Some(strategy.to_string());
+        self
+    }
+}
+
+/// Additions to an essence proposed by reflection
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EssenceUpdate {
+    /// Appended to `capabilities.emergent`
+    pub emergent_capabilities: Vec<String>,
+    /// Appended to `learning_mechanics.knowledge_expansion`
+    pub knowledge_expansion: Vec<YamlValue>,
+}
+
+impl EssenceUpdate {
+    pub fn is_empty(&self) -> bool {
+        self.emergent_capabilities.is_empty() && self.knowledge_expansion.is_empty()
+    }
+
+    pub fn grant(&mut self, capability: &str) {
+        push_unique(&mut self.emergent_capabilities, capability.to_string());
+    }
+
+    pub fn learn(&mut self, knowledge: impl Into<YamlValue>) {
+        push_unique(&mut self.knowledge_expansion, knowledge.into());
+    }
+}
+
+/// Analysis that turns evidence into proposed essence changes
+pub trait ReflectionRule: Send + Sync {
+    fn reflect(&self, evidence: &[Evidence], update: &mut EssenceUpdate);
+}
+
+impl<F> ReflectionRule for F
+where
+    F: Fn(&[Evidence], &mut EssenceUpdate) + Send + Sync,
+{
+    fn reflect(&self, evidence: &[Evidence], update: &mut EssenceUpdate) {
+        self(evidence, update)
+    }
+}
+
+/// Proposes fixed additions once enough pieces of evidence match a predicate
+pub struct EvidenceRule {
+    matches: Box<dyn Fn(&Evidence) -> bool + Send + Sync>,
+    min_matches: usize,
+    proposal: EssenceUpdate,
+}
+
+impl EvidenceRule {
+    /// A rule that fires on any one piece of evidence satisfying `matches`
+    pub fn when(matches: impl Fn(&Evidence) -> bool + Send + Sync + 'static) -> Self {
+        Self {
+            matches: Box::new(matches),
+            min_matches: 1,
+            proposal: EssenceUpdate::default(),
+        }
+    }
+
+    /// Require `count` matching pieces of evidence before firing
+    pub fn at_least(mut self, count: usize) -> Self {
+        self.min_matches = count;
+        self
+    }
+
+    /// Propose an emergent capability when the rule fires
+    pub fn grants(mut self, capability: &str) -> Self {
+        self.proposal.grant(capability);
+        self
+    }
+
+    /// Propose a knowledge-expansion entry when the rule fires
+    pub fn learns(mut self, knowledge: impl Into<YamlValue>) -> Self {
+        self.proposal.learn(knowledge);
+        self
+    }
+}
+
+impl ReflectionRule for EvidenceRule {
+    fn reflect(&self, evidence: &[Evidence], update: &mut EssenceUpdate) {
+        if evidence.iter().filter(|e| (self.matches)(e)).count() < self.min_matches {
+            return;
+        }
+        self.proposal.emergent_capabilities.iter().for_each(|capability| update.grant(capability));
+        self.proposal.knowledge_expansion.iter().for_each(|knowledge| update.learn(knowledge.clone()));
+    }
+}
+
+/// Why an update could not be merged into an essence document
+#[derive(Debug, thiserror::Error)]
+pub enum EssenceMergeError {
+    #[error("Essence path is empty")]
+    EmptyPath,
+
+    #[error("Expected a mapping at `{path}` in the essence, found {found}")]
+    NotAMapping { path: String, found: &'static str },
+
+    #[error("Expected a list at `{path}` in the essence, found {found}")]
+    NotAList { path: String, found: &'static str },
+}
+
+/// Rules applied when an agent reflects on its evidence
+#[derive(Default)]
+pub struct EssenceReflector {
+    rules: Vec<Box<dyn ReflectionRule>>,
+}
+
+impl EssenceReflector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_rule(mut self, rule: impl ReflectionRule + 'static) -> Self {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    /// Run every rule over `evidence` and collect what they propose
+    pub fn propose(&self, evidence: &[Evidence]) -> EssenceUpdate {
+        let mut proposed = EssenceUpdate::default();
+        for rule in &self.rules {
+            rule.reflect(evidence, &mut proposed);
+        }
+        proposed
+    }
+
+    /// Run every rule over `evidence` and add what they propose to `schema`
+    ///
+    /// The returned update holds only what the schema did not already have.
+    pub fn reflect(&self, schema: &mut AgentEssenceSchema, evidence: &[Evidence]) -> EssenceUpdate {
+        let proposed = self.propose(evidence);
+
+        let mut update = EssenceUpdate::default();
+        for capability in proposed.emergent_capabilities {
+            if !schema.capabilities.emergent.contains(&capability) {
+                schema.capabilities.emergent.push(capability.clone());
+                update.emergent_capabilities.push(capability);
+            }
+        }
+        for knowledge in proposed.knowledge_expansion {
+            if !schema.learning_mechanics.knowledge_expansion.contains(&knowledge) {
+                schema.learning_mechanics.knowledge_expansion.push(knowledge.clone());
+                update.knowledge_expansion.push(knowledge);
+            }
+        }
+        update
+    }
+
+    /// Merge an update into the essence YAML at `path`
+    ///
+    /// The file is rewritten from the parsed document, dropping its comments
+    /// and formatting. It is left unchanged when it is not valid YAML or its
+    /// structure conflicts with the update.
+    pub fn apply(&self, update: &EssenceUpdate, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read essence {}", path.display()))?;
+        let mut doc: YamlValue = serde_yaml::from_str(&content)
+            .with_context(|| format!("Essence {} is not valid YAML", path.display()))?;
+
+        let emergent = update.emergent_capabilities.iter().map(|c| YamlValue::from(c.as_str()));
+        merge_yaml_list(&mut doc, &["capabilities", "emergent"], emergent)?;
+        merge_yaml_list(&mut doc, &["learning_mechanics", "knowledge_expansion"], update.knowledge_expansion.iter().cloned())?;
+
+        fs::write(path, serde_yaml::to_string(&doc)?)
+            .with_context(|| format!("Failed to write essence {}", path.display()))
+    }
+}
+
+/// Append `items` missing from the list at `path`, creating the list and any
+/// mappings leading to it where they are absent or null
+pub fn merge_yaml_list(
+    doc: &mut YamlValue,
+    path: &[&str],
+    items: impl IntoIterator<Item = YamlValue>,
+) -> Result<(), EssenceMergeError> {
+    let (last, parents) = path.split_last().ok_or(EssenceMergeError::EmptyPath)?;
+
+    let mut node = doc;
+    for (depth, key) in parents.iter().enumerate() {
+        node = mapping_mut(node, &path[..depth])?
+            .entry((*key).into())
+            .or_insert(YamlValue::Null);
+    }
+
+    let list = mapping_mut(node, parents)?
+        .entry((*last).into())
+        .or_insert(YamlValue::Null);
+    if list.is_null() {
+        *list = YamlValue::Sequence(Vec::new());
+    }
+    let found = kind(list);
+    let list = list.as_sequence_mut().ok_or_else(|| EssenceMergeError::NotAList {
+        path: path.join("."),
+        found,
+    })?;
+    for item in items {
+        push_unique(list, item);
+    }
+    Ok(())
+}
+
+/// The mapping at `node`, which is made one if null
+fn mapping_mut<'a>(node: &'a mut YamlValue, path: &[&str]) -> Result<&'a mut serde_yaml::Mapping, EssenceMergeError> {
+    if node.is_null() {
+        *node = YamlValue::Mapping(Default::default());
+    }
+    let found = kind(node);
+    node.as_mapping_mut().ok_or_else(|| EssenceMergeError::NotAMapping {
+        path: if path.is_empty() { "(root)".to_string() } else { path.join(".") },
+        found,
+    })
+}
+
+fn kind(value: &YamlValue) -> &'static str {
+    match value {
+        YamlValue::Null => "nothing",
+        YamlValue::Bool(_) => "a boolean",
+        YamlValue::Number(_) => "a number",
+        YamlValue::String(_) => "a string",
+        YamlValue::Sequence(_) => "a list",
+        YamlValue::Mapping(_) => "a mapping",
+        YamlValue::Tagged(_) => "a tagged value",
+    }
+}
+
+fn push_unique<T: PartialEq>(list: &mut Vec<T>, item: T) {
+    if !list.contains(&item) {
+        list.push(item);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn yaml(text: &str) -> YamlValue {
+        serde_yaml::from_str(text).unwrap()
+    }
+
+    #[test]
+    fn test_merge_appends_unique_items_to_existing_list() {
+        let mut doc = yaml("capabilities:\n  innate: [observe]\n  emergent: [pattern_synthesis]\n");
+
+        merge_yaml_list(&mut doc, &["capabilities", "emergent"], ["pattern_synthesis", "strategy_switching"].map(YamlValue::from)).unwrap();
+
+        assert_eq!(doc, yaml("capabilities:\n  innate: [observe]\n  emergent: [pattern_synthesis, strategy_switching]\n"));
+    }
+
+    #[test]
+    fn test_merge_creates_missing_path() {
+        let mut doc = yaml("identity:\n  name: Sage\nlearning_mechanics:\n");
+
+        merge_yaml_list(&mut doc, &["learning_mechanics", "knowledge_expansion"], [YamlValue::from("heuristics")]).unwrap();
+        merge_yaml_list(&mut doc, &["capabilities", "emergent"], [YamlValue::from("resilience")]).unwrap();
+
+        assert_eq!(doc["learning_mechanics"]["knowledge_expansion"], yaml("[heuristics]"));
+        assert_eq!(doc["capabilities"]["emergent"], yaml("[resilience]"));
+        assert_eq!(doc["identity"]["name"], yaml("Sage"));
+    }
+
+    #[test]
+    fn test_merge_rejects_incompatible_nodes() {
+        let mut scalar_parent = yaml("capabilities: lots\n");
+        let err = merge_yaml_list(&mut scalar_parent, &["capabilities", "emergent"], [YamlValue::from("x")]).unwrap_err();
+        assert!(matches!(err, EssenceMergeError::NotAMapping { ref path, found: "a string" } if path == "capabilities"));
+        assert_eq!(scalar_parent, yaml("capabilities: lots\n"));
+
+        let mut scalar_list = yaml("capabilities:\n  emergent: 3\n");
+        let err = merge_yaml_list(&mut scalar_list, &["capabilities", "emergent"], [YamlValue::from("x")]).unwrap_err();
+        assert!(matches!(err, EssenceMergeError::NotAList { ref path, .. } if path == "capabilities.emergent"));
+
+        let mut list_root = yaml("[a, b]");
+        assert!(matches!(merge_yaml_list(&mut list_root, &["capabilities"], []), Err(EssenceMergeError::NotAMapping { .. })));
+        assert!(matches!(merge_yaml_list(&mut list_root, &[], []), Err(EssenceMergeError::EmptyPath)));
+    }
+
+    #[test]
+    fn test_apply_merges_into_file_and_leaves_malformed_files_alone() {
+        let reflector = EssenceReflector::new();
+        let update = EssenceUpdate {
+            emergent_capabilities: vec!["failure_analysis".to_string()],
+            knowledge_expansion: vec![YamlValue::from("learn from sessions")],
+        };
+        let dir = tempfile::tempdir().unwrap();
+
+        let essence = dir.path().join("sage-essence.yaml");
+        fs::write(&essence, "capabilities:\n  emergent: [failure_analysis]\n").unwrap();
+        reflector.apply(&update, &essence).unwrap();
+        let doc = yaml(&fs::read_to_string(&essence).unwrap());
+        assert_eq!(doc["capabilities"]["emergent"], yaml("[failure_analysis]"));
+        assert_eq!(doc["learning_mechanics"]["knowledge_expansion"], yaml("['learn from sessions']"));
+
+        for malformed in ["capabilities: [unclosed\n", "capabilities: 7\n"] {
+            let essence = dir.path().join("broken-essence.yaml");
+            fs::write(&essence, malformed).unwrap();
+            assert!(reflector.apply(&update, &essence).is_err());
+            assert_eq!(fs::read_to_string(&essence).unwrap(), malformed);
+        }
+    }
+
+    #[test]
+    fn test_reflect_proposes_only_what_the_schema_lacks() {
+        let mut schema: AgentEssenceSchema = serde_yaml::from_str(crate::tests::TEST_ESSENCE_YAML).unwrap();
+        schema.capabilities.emergent = vec!["resilient_debugging".to_string()];
+        let reflector = EssenceReflector::new()
+            .with_rule(EvidenceRule::when(|e| !e.success).at_least(2).grants("resilient_debugging").grants("failure_analysis"))
+            .with_rule(EvidenceRule::when(|e| e.success && e.strategy.as_deref() == Some("code_aware")).learns("code_pattern_learning"))
+            .with_rule(|evidence: &[Evidence], update: &mut EssenceUpdate| {
+                if evidence.iter().all(|e| e.success) {
+                    update.grant("overconfidence");
+                }
+            });
+        let evidence = [
+            Evidence::failure("timed out").with_strategy("adaptive"),
+            Evidence::failure("missed the root cause"),
+            Evidence::success("found the leak").with_strategy("code_aware"),
+        ];
+
+        let update = reflector.reflect(&mut schema, &evidence);
+
+        assert_eq!(update.emergent_capabilities, vec!["failure_analysis"]);
+        assert_eq!(update.knowledge_expansion, vec![YamlValue::from("code_pattern_learning")]);
+        assert_eq!(schema.capabilities.emergent, vec!["resilient_debugging", "failure_analysis"]);
+        assert!(reflector.reflect(&mut schema, &evidence).is_empty());
+    }
+}
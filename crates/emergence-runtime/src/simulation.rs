@@ -0,0 +1,59 @@
+//! Fast-forwarded runs of an execution engine.
+//!
+//! An engine built with [`ExecutionEngine::new_simulated`](crate::ExecutionEngine::new_simulated)
+//! reads the time from a [`MockClock`](emergence_physics::MockClock), so
+//! [`ExecutionEngine::simulate`](crate::ExecutionEngine::simulate) can move
+//! hours of simulated time forward in a few milliseconds. Each step leaves a
+//! [`SimulationSnapshot`] in the [`SimulationReport`], ready to be plotted.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use emergence_physics::EntityId;
+use serde::{Deserialize, Serialize};
+
+/// State of the engine after one simulated step
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SimulationSnapshot {
+    /// Steps completed, starting at 1
+    pub step: usize,
+    /// Simulated time since the simulation started
+    pub elapsed: Duration,
+    /// Energy held by each active agent
+    pub agent_energy: HashMap<EntityId, f64>,
+    /// Energy held by all active agents together
+    pub total_agent_energy: f64,
+    pub active_agents: usize,
+    pub dormant_agents: usize,
+    /// Emergent capabilities across all agents
+    pub emergent_capabilities: usize,
+    /// Deferred signals that could be sent during this step
+    pub signals_released: usize,
+}
+
+/// Per-step snapshots of a simulation
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SimulationReport {
+    pub step_duration: Duration,
+    pub snapshots: Vec<SimulationSnapshot>,
+}
+
+impl SimulationReport {
+    /// An agent's energy at each step, zero for steps it was not active
+    pub fn energy_trajectory(&self, agent_id: EntityId) -> Vec<f64> {
+        self.snapshots
+            .iter()
+            .map(|snapshot| snapshot.agent_energy.get(&agent_id).copied().unwrap_or(0.0))
+            .collect()
+    }
+
+    /// Energy held by all agents at each step
+    pub fn total_energy_trajectory(&self) -> Vec<f64> {
+        self.snapshots.iter().map(|snapshot| snapshot.total_agent_energy).collect()
+    }
+
+    /// Emergent capabilities at each step
+    pub fn capability_trajectory(&self) -> Vec<usize> {
+        self.snapshots.iter().map(|snapshot| snapshot.emergent_capabilities).collect()
+    }
+}